@@ -0,0 +1,81 @@
+//! Clone cost of a [`TxGoat`] with its decoded action inline, as it is stored, against the same
+//! transaction with the action shared behind an [`Arc`].
+
+#![allow(missing_docs)]
+
+use alloy_consensus::{
+    transaction::goat::{DepositTx, DistributeRewardTx, NewBtcBlockTx, TxGoat, TxGoatInner},
+    SignableTransaction, Signed,
+};
+use alloy_primitives::{Address, Bytes, ChainId, Signature, B256, U256};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::sync::Arc;
+
+/// A [`TxGoat`] with the decoded action behind an [`Arc`].
+#[derive(Clone)]
+#[allow(dead_code)]
+struct ArcTxGoat {
+    chain_id: ChainId,
+    nonce: u64,
+    input: Bytes,
+    inner: Arc<TxGoatInner>,
+}
+
+impl From<&TxGoat> for ArcTxGoat {
+    fn from(tx: &TxGoat) -> Self {
+        Self {
+            chain_id: tx.chain_id,
+            nonce: tx.nonce,
+            input: tx.input.clone(),
+            inner: Arc::new(tx.inner),
+        }
+    }
+}
+
+const fn actions() -> [(&'static str, TxGoatInner); 3] {
+    [
+        ("new_btc_block", TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::repeat_byte(1) })),
+        (
+            "deposit",
+            TxGoatInner::Deposit(DepositTx {
+                tx_id: B256::repeat_byte(2),
+                tx_out: 1,
+                target: Address::repeat_byte(3),
+                amount: U256::MAX,
+                tax: U256::MAX,
+            }),
+        ),
+        (
+            "distribute_reward",
+            TxGoatInner::DistributeReward(DistributeRewardTx {
+                id: U256::MAX,
+                recipient: Address::repeat_byte(4),
+                goat: U256::MAX,
+                gas_reward: U256::MAX,
+            }),
+        ),
+    ]
+}
+
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("goat_clone");
+    for (name, inner) in actions() {
+        let tx = TxGoat::new(48816, 7, inner);
+        let arc = ArcTxGoat::from(&tx);
+        let signed: Signed<TxGoat> = tx.clone().into_signed(Signature::test_signature());
+
+        group.bench_with_input(BenchmarkId::new("inline", name), &tx, |b, tx| {
+            b.iter(|| black_box(tx).clone())
+        });
+        group.bench_with_input(BenchmarkId::new("arc", name), &arc, |b, tx| {
+            b.iter(|| black_box(tx).clone())
+        });
+        group.bench_with_input(BenchmarkId::new("signed", name), &signed, |b, tx| {
+            b.iter(|| black_box(tx).clone())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, clone);
+criterion_main!(benches);
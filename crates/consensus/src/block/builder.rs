@@ -0,0 +1,350 @@
+//! Assembly of GOAT block bodies.
+
+use crate::{
+    transaction::goat::{
+        Action, Module, TxGoat, TxGoatInner, BRIDGE_MODULE, GOAT_TX_TYPE_ID, LOCKING_MODULE,
+        RELAYER_MODULE,
+    },
+    Signed, TxEnvelope,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use alloy_eips::Typed2718;
+use alloy_primitives::{Address, B256};
+
+/// Errors returned by the [`BlockBuilder`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BlockBuilderError {
+    /// A goat system transaction was pushed after a user transaction.
+    #[error("goat system transactions must precede user transactions")]
+    GoatAfterUserTx,
+    /// A goat system transaction was pushed as a user transaction.
+    #[error("goat system transactions must be pushed with `push_goat_txs`")]
+    UnexpectedGoatTx,
+    /// A goat system transaction is not at its canonical position.
+    #[error(
+        "goat action {}/{} can not follow action {}/{}",
+        current.0,
+        current.0.action_name(current.1).unwrap_or("UNKNOWN"),
+        previous.0,
+        previous.0.action_name(previous.1).unwrap_or("UNKNOWN")
+    )]
+    OutOfOrder {
+        /// The module and action of the previous system transaction.
+        previous: (Module, Action),
+        /// The module and action of the offending system transaction.
+        current: (Module, Action),
+    },
+    /// A bitcoin block hash is submitted more than once in the block, see
+    /// [`BlockBuilder::with_reject_duplicate_btc_blocks`].
+    #[error("bitcoin block {0} is already submitted in the block")]
+    DuplicateBtcBlock(B256),
+    /// The nonce of a goat system transaction does not follow the previous nonce of its sender.
+    #[error("nonce gap for executor {sender}: expected {expected}, got {got}")]
+    NonceGap {
+        /// The system executor sending the transaction.
+        sender: Address,
+        /// The expected nonce.
+        expected: u64,
+        /// The nonce of the transaction.
+        got: u64,
+    },
+}
+
+/// Assembles the transactions of a block, placing the goat system transactions at their canonical
+/// positions.
+///
+/// System transactions come first, ordered by module (bridge, relayer, then locking) and by action
+/// within a module, and are followed by the user transactions. This is a reusable primitive for
+/// sequencer implementations:
+///
+/// ```
+/// # use alloy_consensus::{BlockBuilder, TxEnvelope};
+/// # fn build(
+/// #     system_txs: Vec<alloy_consensus::Signed<alloy_consensus::transaction::goat::TxGoat>>,
+/// #     user_txs: Vec<TxEnvelope>,
+/// # ) -> Result<(), alloy_consensus::BlockBuilderError> {
+/// let mut builder = BlockBuilder::<TxEnvelope>::new();
+/// builder.push_goat_txs(system_txs)?;
+/// builder.push_txs(user_txs)?;
+/// let transactions = builder.into_transactions();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BlockBuilder<T = TxEnvelope> {
+    /// The transactions of the block.
+    transactions: Vec<T>,
+    /// The number of leading system transactions.
+    goat_txs: usize,
+    /// The module and action of the last system transaction.
+    last_action: Option<(Module, Action)>,
+    /// The bitcoin block hashes submitted in the block.
+    btc_hashes: BTreeSet<B256>,
+    /// The next expected nonce of each system executor.
+    nonces: BTreeMap<Address, u64>,
+    /// Whether a repeated bitcoin block submission is an error instead of being dropped.
+    reject_duplicate_btc_blocks: bool,
+}
+
+impl<T> Default for BlockBuilder<T> {
+    fn default() -> Self {
+        Self {
+            transactions: Vec::new(),
+            goat_txs: 0,
+            last_action: None,
+            btc_hashes: BTreeSet::new(),
+            nonces: BTreeMap::new(),
+            reject_duplicate_btc_blocks: false,
+        }
+    }
+}
+
+impl<T> BlockBuilder<T> {
+    /// Creates an empty block builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the next expected nonce of a system executor, e.g. from the parent state.
+    ///
+    /// Without it, the nonce of the first system transaction of the executor is accepted as is.
+    pub fn with_executor_nonce(mut self, executor: Address, nonce: u64) -> Self {
+        self.nonces.insert(executor, nonce);
+        self
+    }
+
+    /// Sets whether a bitcoin block hash submitted more than once is rejected with
+    /// [`BlockBuilderError::DuplicateBtcBlock`].
+    ///
+    /// By default, the repeated submissions are dropped without consuming their nonce.
+    pub const fn with_reject_duplicate_btc_blocks(mut self, reject: bool) -> Self {
+        self.reject_duplicate_btc_blocks = reject;
+        self
+    }
+
+    /// Returns the transactions of the block.
+    pub fn transactions(&self) -> &[T] {
+        &self.transactions
+    }
+
+    /// Returns the number of goat system transactions in the block.
+    pub const fn goat_tx_count(&self) -> usize {
+        self.goat_txs
+    }
+
+    /// Returns the next expected nonce of a system executor, if known.
+    pub fn next_nonce(&self, executor: &Address) -> Option<u64> {
+        self.nonces.get(executor).copied()
+    }
+
+    /// Consumes the builder and returns the transactions of the block.
+    pub fn into_transactions(self) -> Vec<T> {
+        self.transactions
+    }
+
+    /// Appends goat system transactions to the block.
+    ///
+    /// The transactions must follow the canonical order and the nonces of each executor must be
+    /// consecutive. A bitcoin block hash is only submitted once per block, repeated submissions
+    /// are dropped unless
+    /// [`with_reject_duplicate_btc_blocks`](Self::with_reject_duplicate_btc_blocks) is set, and
+    /// the nonce of a dropped transaction is not consumed.
+    ///
+    /// Returns the number of appended transactions. On error, the block is left unchanged.
+    pub fn push_goat_txs(
+        &mut self,
+        txs: impl IntoIterator<Item = Signed<TxGoat>>,
+    ) -> Result<usize, BlockBuilderError>
+    where
+        T: From<Signed<TxGoat>>,
+    {
+        if self.transactions.len() > self.goat_txs {
+            return Err(BlockBuilderError::GoatAfterUserTx);
+        }
+
+        let mut last_action = self.last_action;
+        let mut btc_hashes = self.btc_hashes.clone();
+        let mut nonces = self.nonces.clone();
+        let mut accepted = Vec::new();
+
+        for tx in txs {
+            let goat = tx.tx();
+            if let TxGoatInner::NewBtcBlock(block) = &goat.inner {
+                if !btc_hashes.insert(block.hash) {
+                    if self.reject_duplicate_btc_blocks {
+                        return Err(BlockBuilderError::DuplicateBtcBlock(block.hash));
+                    }
+                    continue;
+                }
+            }
+
+            let current = (goat.module, goat.action);
+            if let Some(previous) = last_action {
+                if canonical_rank(current) < canonical_rank(previous) {
+                    return Err(BlockBuilderError::OutOfOrder { previous, current });
+                }
+            }
+            last_action = Some(current);
+
+            let sender = goat.sender();
+            let expected = *nonces.entry(sender).or_insert(goat.nonce);
+            if goat.nonce != expected {
+                return Err(BlockBuilderError::NonceGap { sender, expected, got: goat.nonce });
+            }
+            nonces.insert(sender, expected + 1);
+
+            accepted.push(tx);
+        }
+
+        let count = accepted.len();
+        self.transactions.extend(accepted.into_iter().map(T::from));
+        self.goat_txs += count;
+        self.last_action = last_action;
+        self.btc_hashes = btc_hashes;
+        self.nonces = nonces;
+        Ok(count)
+    }
+
+    /// Appends a user transaction to the block.
+    pub fn push_tx(&mut self, tx: T) -> Result<(), BlockBuilderError>
+    where
+        T: Typed2718,
+    {
+        if tx.ty() == GOAT_TX_TYPE_ID {
+            return Err(BlockBuilderError::UnexpectedGoatTx);
+        }
+        self.transactions.push(tx);
+        Ok(())
+    }
+
+    /// Appends user transactions to the block.
+    ///
+    /// On error, the transactions preceding the offending one have been appended.
+    pub fn push_txs(&mut self, txs: impl IntoIterator<Item = T>) -> Result<(), BlockBuilderError>
+    where
+        T: Typed2718,
+    {
+        txs.into_iter().try_for_each(|tx| self.push_tx(tx))
+    }
+}
+
+/// Returns the rank of a system action in the canonical block order.
+///
+/// Modules are ordered by identifier, like the actions of [`GOAT_ACTIONS`]: bridge, relayer, then
+/// locking.
+///
+/// [`GOAT_ACTIONS`]: crate::transaction::goat::GOAT_ACTIONS
+pub(super) const fn canonical_rank((module, action): (Module, Action)) -> (u8, Action) {
+    let module = match module {
+        BRIDGE_MODULE | RELAYER_MODULE | LOCKING_MODULE => module.get(),
+        _ => u8::MAX,
+    };
+    (module, action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::{
+            test_utils::{new_btc_block_tx, signed_goat_tx},
+            DepositTx, DistributeRewardTx, BRIDGE_DEPOSIT_ACTION, RELAYER_EXECUTOR,
+            RELAYER_NEW_BTC_BLOCK_ACTION,
+        },
+        SignableTransaction, TxEip1559,
+    };
+    use alloy_primitives::{Signature, U256};
+
+    fn deposit(nonce: u64) -> Signed<TxGoat> {
+        signed_goat_tx(
+            nonce,
+            TxGoatInner::Deposit(DepositTx { amount: U256::from(1), ..Default::default() }),
+        )
+    }
+
+    fn reward(nonce: u64) -> Signed<TxGoat> {
+        signed_goat_tx(nonce, TxGoatInner::DistributeReward(DistributeRewardTx::default()))
+    }
+
+    fn user_tx() -> TxEnvelope {
+        TxEip1559::default().into_signed(Signature::test_signature()).into()
+    }
+
+    #[test]
+    fn canonical_block() {
+        let mut builder =
+            BlockBuilder::<TxEnvelope>::new().with_executor_nonce(RELAYER_EXECUTOR, 10);
+        let count = builder
+            .push_goat_txs([
+                deposit(10),
+                new_btc_block_tx(11, B256::with_last_byte(1)),
+                new_btc_block_tx(12, B256::with_last_byte(2)),
+                reward(0),
+            ])
+            .unwrap();
+        assert_eq!(count, 4);
+        builder.push_txs([user_tx(), user_tx()]).unwrap();
+
+        assert_eq!(builder.goat_tx_count(), 4);
+        assert_eq!(builder.next_nonce(&RELAYER_EXECUTOR), Some(13));
+        let txs = builder.into_transactions();
+        assert_eq!(txs.len(), 6);
+        assert!(txs[..4].iter().all(|tx| tx.is_goat()));
+        assert!(txs[4..].iter().all(|tx| tx.is_eip1559()));
+    }
+
+    #[test]
+    fn rejects_invalid_goat_txs() {
+        let mut builder = BlockBuilder::<TxEnvelope>::new();
+        assert_eq!(
+            builder.push_goat_txs([new_btc_block_tx(0, B256::ZERO), deposit(1)]),
+            Err(BlockBuilderError::OutOfOrder {
+                previous: (RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION),
+                current: (BRIDGE_MODULE, BRIDGE_DEPOSIT_ACTION),
+            })
+        );
+        assert_eq!(
+            builder.push_goat_txs([deposit(0), deposit(2)]),
+            Err(BlockBuilderError::NonceGap { sender: RELAYER_EXECUTOR, expected: 1, got: 2 })
+        );
+        let mut builder = builder.with_reject_duplicate_btc_blocks(true);
+        assert_eq!(
+            builder
+                .push_goat_txs([new_btc_block_tx(0, B256::ZERO), new_btc_block_tx(1, B256::ZERO)]),
+            Err(BlockBuilderError::DuplicateBtcBlock(B256::ZERO))
+        );
+        // failed pushes leave the block unchanged
+        assert!(builder.transactions().is_empty());
+
+        builder.push_goat_txs([deposit(0)]).unwrap();
+        assert_eq!(builder.push_tx(deposit(1).into()), Err(BlockBuilderError::UnexpectedGoatTx));
+        builder.push_tx(user_tx()).unwrap();
+        assert_eq!(builder.push_goat_txs([deposit(1)]), Err(BlockBuilderError::GoatAfterUserTx));
+    }
+
+    #[test]
+    fn drops_duplicate_btc_blocks() {
+        let mut builder = BlockBuilder::<TxEnvelope>::new();
+        let count = builder
+            .push_goat_txs([
+                new_btc_block_tx(0, B256::ZERO),
+                new_btc_block_tx(1, B256::ZERO),
+                new_btc_block_tx(1, B256::with_last_byte(1)),
+            ])
+            .unwrap();
+        assert_eq!(count, 2);
+        // the dropped submission does not consume its nonce, nor a later batch
+        assert_eq!(builder.push_goat_txs([new_btc_block_tx(2, B256::ZERO)]), Ok(0));
+        assert_eq!(builder.next_nonce(&RELAYER_EXECUTOR), Some(2));
+
+        let txs = builder.into_transactions();
+        let hashes: Vec<_> = txs
+            .iter()
+            .map(|tx| tx.as_goat().unwrap().tx().inner.as_new_btc_block().unwrap().hash)
+            .collect();
+        assert_eq!(hashes, [B256::ZERO, B256::with_last_byte(1)]);
+    }
+}
@@ -0,0 +1,162 @@
+//! GOAT header extension and block types.
+
+use crate::{transaction::Recovered, Block, Header, TxEnvelope};
+use alloy_primitives::{Bytes, Sealed, B256};
+use alloy_rlp::{Decodable, RlpDecodable, RlpEncodable};
+
+/// The GOAT specific fields of a block header, carried in its `extra_data`.
+///
+/// The fields are RLP encoded as a list `[btc_block_hash, btc_height]`, which is longer than the
+/// [`MAXIMUM_EXTRA_DATA_SIZE`](crate::constants::MAXIMUM_EXTRA_DATA_SIZE) of ethereum, see
+/// [`GOAT_MAXIMUM_EXTRA_DATA_SIZE`](crate::constants::GOAT_MAXIMUM_EXTRA_DATA_SIZE).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatHeaderExtra {
+    /// The hash of the bitcoin block the block is anchored to.
+    pub btc_block_hash: B256,
+    /// The height of the bitcoin block the block is anchored to.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub btc_height: u64,
+}
+
+impl GoatHeaderExtra {
+    /// Creates a new header extension anchored to the given bitcoin block.
+    pub const fn new(btc_block_hash: B256, btc_height: u64) -> Self {
+        Self { btc_block_hash, btc_height }
+    }
+
+    /// Decodes the header extension from the `extra_data` of a header.
+    ///
+    /// Returns an error if the data is not exactly one encoded extension.
+    pub fn decode_extra_data(mut extra_data: &[u8]) -> alloy_rlp::Result<Self> {
+        let extra = Self::decode(&mut extra_data)?;
+        if !extra_data.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        Ok(extra)
+    }
+
+    /// Encodes the header extension as the `extra_data` of a header.
+    pub fn encode_extra_data(&self) -> Bytes {
+        alloy_rlp::encode(self).into()
+    }
+}
+
+impl Header {
+    /// Decodes the GOAT header extension from the `extra_data` of the header.
+    pub fn goat_extra(&self) -> alloy_rlp::Result<GoatHeaderExtra> {
+        GoatHeaderExtra::decode_extra_data(&self.extra_data)
+    }
+
+    /// Sets the `extra_data` of the header to the encoded GOAT header extension.
+    pub fn set_goat_extra(&mut self, extra: GoatHeaderExtra) {
+        self.extra_data = extra.encode_extra_data();
+    }
+
+    /// Returns the header with the `extra_data` set to the encoded GOAT header extension.
+    pub fn with_goat_extra(mut self, extra: GoatHeaderExtra) -> Self {
+        self.set_goat_extra(extra);
+        self
+    }
+}
+
+/// A GOAT block.
+pub type GoatBlock<H = Header> = Block<TxEnvelope, H>;
+
+/// A sealed GOAT block.
+pub type SealedGoatBlock<H = Header> = Sealed<GoatBlock<H>>;
+
+/// A GOAT block with the senders of its transactions.
+pub type RecoveredGoatBlock<H = Header> = Block<Recovered<TxEnvelope>, H>;
+
+impl<Eip4844, H> Block<crate::EthereumTxEnvelope<Eip4844>, H>
+where
+    crate::EthereumTxEnvelope<Eip4844>: crate::transaction::SignerRecoverable,
+{
+    /// Recovers the senders of all transactions of the block.
+    ///
+    /// The sender of a goat system transaction is its system executor, which is derived from its
+    /// module instead of being recovered from its signature, see
+    /// [`Signed::into_recovered_goat`](crate::Signed::into_recovered_goat).
+    pub fn try_into_recovered_goat(
+        self,
+    ) -> Result<Block<Recovered<crate::EthereumTxEnvelope<Eip4844>>, H>, crate::crypto::RecoveryError>
+    {
+        self.try_map_transactions(crate::transaction::SignerRecoverable::try_into_recovered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, hex};
+
+    #[test]
+    fn goat_header_extra_roundtrip() {
+        let extra = GoatHeaderExtra::new(
+            b256!("0x000000000000000000016a1b6e7e8e84a9e0b95c8a3d3e8f3a0b7e1c2d4f6a8b"),
+            850_000,
+        );
+        let header = Header::default().with_goat_extra(extra);
+        assert_eq!(
+            header.extra_data[..],
+            hex!("0xe5a0000000000000000000016a1b6e7e8e84a9e0b95c8a3d3e8f3a0b7e1c2d4f6a8b830cf850")
+        );
+        assert_eq!(header.goat_extra(), Ok(extra));
+
+        let max = GoatHeaderExtra::new(B256::repeat_byte(0xff), u64::MAX).encode_extra_data();
+        assert_eq!(max.len(), crate::constants::GOAT_MAXIMUM_EXTRA_DATA_SIZE);
+
+        let decoded = Header::decode(&mut alloy_rlp::encode(&header).as_slice()).unwrap();
+        assert_eq!(decoded.goat_extra(), Ok(extra));
+    }
+
+    #[test]
+    fn goat_header_extra_invalid() {
+        assert!(Header::default().goat_extra().is_err());
+
+        let mut extra_data = GoatHeaderExtra::default().encode_extra_data().to_vec();
+        extra_data.push(0);
+        let header = Header { extra_data: extra_data.into(), ..Default::default() };
+        assert_eq!(header.goat_extra(), Err(alloy_rlp::Error::UnexpectedLength));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_goat_header_extra() {
+        let extra = GoatHeaderExtra::new(B256::with_last_byte(1), 16);
+        let json = serde_json::to_string(&extra).unwrap();
+        assert_eq!(
+            json,
+            r#"{"btcBlockHash":"0x0000000000000000000000000000000000000000000000000000000000000001","btcHeight":"0x10"}"#
+        );
+        assert_eq!(serde_json::from_str::<GoatHeaderExtra>(&json).unwrap(), extra);
+    }
+
+    #[cfg(any(feature = "secp256k1", feature = "k256"))]
+    #[test]
+    fn recover_goat_block() {
+        use crate::{
+            transaction::{
+                goat::{test_utils::new_btc_block_tx, RELAYER_EXECUTOR},
+                SignerRecoverable,
+            },
+            BlockBody, SignableTransaction, TxEip1559,
+        };
+        use alloc::{vec, vec::Vec};
+        use alloy_primitives::Signature;
+
+        let goat: TxEnvelope = new_btc_block_tx(0, B256::ZERO).into();
+        let user: TxEnvelope = TxEip1559::default().into_signed(Signature::test_signature()).into();
+        let user_sender = user.recover_signer().unwrap();
+
+        let block: GoatBlock = Block::new(
+            Header::default(),
+            BlockBody { transactions: vec![goat, user], ..Default::default() },
+        );
+        let recovered: RecoveredGoatBlock = block.try_into_recovered_goat().unwrap();
+        let senders: Vec<_> = recovered.body.transactions.iter().map(|tx| tx.signer()).collect();
+        assert_eq!(senders, [RELAYER_EXECUTOR, user_sender]);
+    }
+}
@@ -1,11 +1,23 @@
 //! Block-related consensus types.
 
+mod builder;
+pub use builder::{BlockBuilder, BlockBuilderError};
+
+mod goat;
+pub use goat::{GoatBlock, GoatHeaderExtra, RecoveredGoatBlock, SealedGoatBlock};
+
 mod header;
 pub use header::{BlockHeader, Header};
 
 mod traits;
 pub use traits::EthBlock;
 
+mod validation;
+pub use validation::{
+    validate_goat_block, validate_goat_tx_limits, GoatBlockConfig, GoatBlockError,
+    GoatTxLimitError, GoatTxLimits,
+};
+
 mod meta;
 pub use meta::HeaderInfo;
 
@@ -0,0 +1,416 @@
+//! Validation of GOAT block bodies.
+
+use super::builder::canonical_rank;
+use crate::{
+    proofs::calculate_transaction_root,
+    transaction::goat::{
+        Action, AsTxGoat, DepositKey, DepositSet, GoatTxIterExt, GoatVerifyError, Module,
+        TxGoatInner,
+    },
+    BlockBody, BlockHeader, EthereumTxEnvelope,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloy_eips::{goat::GoatParams, Encodable2718};
+use alloy_primitives::{Address, ChainId, B256};
+
+/// Configuration of [`validate_goat_block`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GoatBlockConfig {
+    /// The chain id of the system transactions, if it should be checked.
+    pub chain_id: Option<ChainId>,
+    /// The next expected nonce of the system executors, e.g. from the parent state.
+    ///
+    /// The nonce of the first system transaction of an executor missing from the map is accepted
+    /// as is.
+    pub executor_nonces: BTreeMap<Address, u64>,
+    /// The per-block limits of the system transactions, if they should be checked.
+    pub limits: Option<GoatTxLimits>,
+}
+
+impl GoatBlockConfig {
+    /// Sets the chain id of the system transactions.
+    pub const fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the next expected nonce of a system executor.
+    pub fn with_executor_nonce(mut self, executor: Address, nonce: u64) -> Self {
+        self.executor_nonces.insert(executor, nonce);
+        self
+    }
+
+    /// Sets the per-block limits of the system transactions.
+    pub const fn with_limits(mut self, limits: GoatTxLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+}
+
+/// Per-block limits of the goat system transactions, see [`validate_goat_tx_limits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GoatTxLimits {
+    /// The maximum number of submitted bitcoin blocks.
+    pub max_new_btc_blocks: usize,
+    /// The maximum number of deposits, if capped.
+    pub max_deposits: Option<usize>,
+}
+
+impl Default for GoatTxLimits {
+    /// At most one bitcoin block, and no cap on the deposits.
+    fn default() -> Self {
+        Self { max_new_btc_blocks: 1, max_deposits: None }
+    }
+}
+
+impl GoatTxLimits {
+    /// Returns the default limits, with the deposits capped by the bridge parameters.
+    pub fn from_params(params: &GoatParams) -> Self {
+        Self {
+            max_deposits: Some(params.max_deposits_per_block.try_into().unwrap_or(usize::MAX)),
+            ..Default::default()
+        }
+    }
+}
+
+/// A per-block limit exceeded by the goat system transactions, returned by
+/// [`validate_goat_tx_limits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GoatTxLimitError {
+    /// Too many bitcoin blocks are submitted.
+    #[error("{count} bitcoin blocks are submitted, at most {max} are allowed")]
+    TooManyNewBtcBlocks {
+        /// The number of submitted bitcoin blocks.
+        count: usize,
+        /// The maximum number of submitted bitcoin blocks.
+        max: usize,
+    },
+    /// Too many deposits are processed.
+    #[error("{count} deposits are processed, at most {max} are allowed")]
+    TooManyDeposits {
+        /// The number of processed deposits.
+        count: usize,
+        /// The maximum number of processed deposits.
+        max: usize,
+    },
+}
+
+/// Errors returned by [`validate_goat_block`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GoatBlockError {
+    /// The transactions root of the header does not match the body.
+    #[error("transactions root mismatch: header {header}, body {body}")]
+    TransactionsRootMismatch {
+        /// The transactions root of the header.
+        header: B256,
+        /// The transactions root of the body.
+        body: B256,
+    },
+    /// A goat system transaction follows a user transaction.
+    #[error("goat system transaction {index} follows a user transaction")]
+    GoatAfterUserTx {
+        /// The index of the system transaction in the block.
+        index: usize,
+    },
+    /// A goat system transaction is not consistent with its input.
+    #[error("invalid goat system transaction {index}: {error}")]
+    InvalidGoatTx {
+        /// The index of the system transaction in the block.
+        index: usize,
+        /// The verification error.
+        error: GoatVerifyError,
+    },
+    /// A goat system transaction is for another chain.
+    #[error("goat system transaction {index} has chain id {got}, expected {expected}")]
+    ChainIdMismatch {
+        /// The index of the system transaction in the block.
+        index: usize,
+        /// The expected chain id.
+        expected: ChainId,
+        /// The chain id of the transaction.
+        got: ChainId,
+    },
+    /// A goat system transaction is not at its canonical position.
+    #[error(
+        "goat action {}/{} can not follow action {}/{}",
+        current.0,
+        current.0.action_name(current.1).unwrap_or("UNKNOWN"),
+        previous.0,
+        previous.0.action_name(previous.1).unwrap_or("UNKNOWN")
+    )]
+    OutOfOrder {
+        /// The module and action of the previous system transaction.
+        previous: (Module, Action),
+        /// The module and action of the offending system transaction.
+        current: (Module, Action),
+    },
+    /// The nonce of a goat system transaction does not follow the previous nonce of its sender.
+    #[error("nonce gap for executor {sender}: expected {expected}, got {got}")]
+    NonceGap {
+        /// The system executor sending the transaction.
+        sender: Address,
+        /// The expected nonce.
+        expected: u64,
+        /// The nonce of the transaction.
+        got: u64,
+    },
+    /// A bitcoin block hash is submitted twice in the block.
+    #[error("bitcoin block {0} is submitted twice")]
+    DuplicateBtcBlock(B256),
+    /// A bitcoin deposit is processed twice in the block.
+    #[error("deposit {}:{} is processed twice", .0.tx_id(), .0.tx_out())]
+    DuplicateDeposit(DepositKey),
+    /// The system transactions exceed a per-block limit.
+    #[error(transparent)]
+    Limit(#[from] GoatTxLimitError),
+}
+
+/// Validates the per-block limits of the goat system transactions among the given transactions.
+///
+/// Both the sequencer building a block and the verifiers must enforce these limits.
+pub fn validate_goat_tx_limits<T: AsTxGoat>(
+    txs: &[T],
+    limits: &GoatTxLimits,
+) -> Result<(), GoatTxLimitError> {
+    let (mut new_btc_blocks, mut deposits) = (0, 0);
+    for (_, tx) in txs.iter().goat_txs() {
+        match tx.inner {
+            TxGoatInner::NewBtcBlock(_) => new_btc_blocks += 1,
+            TxGoatInner::Deposit(_) => deposits += 1,
+            _ => {}
+        }
+    }
+
+    if new_btc_blocks > limits.max_new_btc_blocks {
+        return Err(GoatTxLimitError::TooManyNewBtcBlocks {
+            count: new_btc_blocks,
+            max: limits.max_new_btc_blocks,
+        });
+    }
+    if let Some(max) = limits.max_deposits.filter(|max| deposits > *max) {
+        return Err(GoatTxLimitError::TooManyDeposits { count: deposits, max });
+    }
+    Ok(())
+}
+
+/// Validates the GOAT rules of a block.
+///
+/// This checks that:
+/// - the transactions root of the header matches the body,
+/// - the system transactions precede the user transactions, in canonical order,
+/// - the system transactions are consistent with their input and chain id,
+/// - the nonces of each system executor are consecutive,
+/// - no bitcoin block hash is submitted and no deposit is processed twice,
+/// - the system transactions are within the configured limits, see [`validate_goat_tx_limits`].
+pub fn validate_goat_block<H, Eip4844>(
+    header: &H,
+    body: &BlockBody<EthereumTxEnvelope<Eip4844>, H>,
+    config: &GoatBlockConfig,
+) -> Result<(), GoatBlockError>
+where
+    H: BlockHeader,
+    EthereumTxEnvelope<Eip4844>: Encodable2718,
+{
+    let root = calculate_transaction_root(&body.transactions);
+    if root != header.transactions_root() {
+        return Err(GoatBlockError::TransactionsRootMismatch {
+            header: header.transactions_root(),
+            body: root,
+        });
+    }
+
+    let mut user_txs = false;
+    let mut last_action = None;
+    let mut nonces = config.executor_nonces.clone();
+    let mut btc_hashes = BTreeSet::new();
+    let mut deposits = DepositSet::new();
+
+    for (index, tx) in body.transactions.iter().enumerate() {
+        let EthereumTxEnvelope::Goat(tx) = tx else {
+            user_txs = true;
+            continue;
+        };
+        if user_txs {
+            return Err(GoatBlockError::GoatAfterUserTx { index });
+        }
+
+        let goat = tx.tx();
+        goat.verify_against_input()
+            .map_err(|error| GoatBlockError::InvalidGoatTx { index, error })?;
+        if let Some(expected) = config.chain_id {
+            if goat.chain_id != expected {
+                return Err(GoatBlockError::ChainIdMismatch {
+                    index,
+                    expected,
+                    got: goat.chain_id,
+                });
+            }
+        }
+
+        let current = (goat.module, goat.action);
+        if let Some(previous) = last_action {
+            if canonical_rank(current) < canonical_rank(previous) {
+                return Err(GoatBlockError::OutOfOrder { previous, current });
+            }
+        }
+        last_action = Some(current);
+
+        let sender = goat.sender();
+        let expected = *nonces.entry(sender).or_insert(goat.nonce);
+        if goat.nonce != expected {
+            return Err(GoatBlockError::NonceGap { sender, expected, got: goat.nonce });
+        }
+        nonces.insert(sender, expected + 1);
+
+        match &goat.inner {
+            TxGoatInner::NewBtcBlock(block) if !btc_hashes.insert(block.hash) => {
+                return Err(GoatBlockError::DuplicateBtcBlock(block.hash));
+            }
+            TxGoatInner::Deposit(deposit) if !deposits.insert(deposit.deposit_key()) => {
+                return Err(GoatBlockError::DuplicateDeposit(deposit.deposit_key()));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(limits) = &config.limits {
+        validate_goat_tx_limits(&body.transactions, limits)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::{
+            test_utils::{goat_tx, new_btc_block_tx, signed_goat_tx, CHAIN_ID},
+            DepositTx, NewBtcBlockTx, BRIDGE_DEPOSIT_ACTION, BRIDGE_MODULE, RELAYER_EXECUTOR,
+            RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION,
+        },
+        Header, SignableTransaction, TxEip1559, TxEnvelope,
+    };
+    use alloc::{vec, vec::Vec};
+    use alloy_primitives::{Bytes, Signature, U256};
+
+    fn new_btc_block(nonce: u64, hash: B256) -> TxEnvelope {
+        new_btc_block_tx(nonce, hash).into()
+    }
+
+    fn deposit(nonce: u64, tx_out: u32) -> TxEnvelope {
+        signed_goat_tx(
+            nonce,
+            TxGoatInner::Deposit(DepositTx { tx_out, amount: U256::from(1), ..Default::default() }),
+        )
+        .into()
+    }
+
+    fn user_tx() -> TxEnvelope {
+        TxEip1559::default().into_signed(Signature::test_signature()).into()
+    }
+
+    fn validate(transactions: Vec<TxEnvelope>) -> Result<(), GoatBlockError> {
+        let header = Header {
+            transactions_root: calculate_transaction_root(&transactions),
+            ..Default::default()
+        };
+        let body = BlockBody { transactions, ommers: vec![], withdrawals: None };
+        validate_goat_block(&header, &body, &GoatBlockConfig::default().with_chain_id(CHAIN_ID))
+    }
+
+    #[test]
+    fn valid_goat_block() {
+        assert_eq!(
+            validate(vec![
+                deposit(0, 0),
+                deposit(1, 1),
+                new_btc_block(2, B256::with_last_byte(1)),
+                user_tx(),
+            ]),
+            Ok(())
+        );
+        assert_eq!(validate(vec![user_tx()]), Ok(()));
+    }
+
+    #[test]
+    fn invalid_goat_blocks() {
+        assert_eq!(
+            validate(vec![user_tx(), deposit(0, 0)]),
+            Err(GoatBlockError::GoatAfterUserTx { index: 1 })
+        );
+        assert_eq!(
+            validate(vec![new_btc_block(0, B256::ZERO), deposit(1, 0)]),
+            Err(GoatBlockError::OutOfOrder {
+                previous: (RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION),
+                current: (BRIDGE_MODULE, BRIDGE_DEPOSIT_ACTION),
+            })
+        );
+        assert_eq!(
+            validate(vec![deposit(0, 0), deposit(2, 1)]),
+            Err(GoatBlockError::NonceGap { sender: RELAYER_EXECUTOR, expected: 1, got: 2 })
+        );
+        assert_eq!(
+            validate(vec![new_btc_block(0, B256::ZERO), new_btc_block(1, B256::ZERO)]),
+            Err(GoatBlockError::DuplicateBtcBlock(B256::ZERO))
+        );
+        assert_eq!(
+            validate(vec![deposit(0, 3), deposit(1, 3)]),
+            Err(GoatBlockError::DuplicateDeposit(DepositKey(B256::ZERO, 3)))
+        );
+
+        let mut other_chain = goat_tx(0, TxGoatInner::NewBtcBlock(NewBtcBlockTx::default()));
+        other_chain.chain_id += 1;
+        assert_eq!(
+            validate(vec![other_chain.clone().into_signed(Signature::test_signature()).into()]),
+            Err(GoatBlockError::ChainIdMismatch {
+                index: 0,
+                expected: CHAIN_ID,
+                got: CHAIN_ID + 1
+            })
+        );
+        other_chain.chain_id = CHAIN_ID;
+        other_chain.input = Bytes::new();
+        assert!(matches!(
+            validate(vec![other_chain.into_signed(Signature::test_signature()).into()]),
+            Err(GoatBlockError::InvalidGoatTx { index: 0, .. })
+        ));
+
+        let body = BlockBody::<TxEnvelope> { transactions: vec![user_tx()], ..Default::default() };
+        assert!(matches!(
+            validate_goat_block(&Header::default(), &body, &GoatBlockConfig::default()),
+            Err(GoatBlockError::TransactionsRootMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn goat_tx_limits() {
+        let limits = GoatTxLimits { max_deposits: Some(2), ..Default::default() };
+        let txs = [new_btc_block(0, B256::ZERO), deposit(1, 0), deposit(2, 1)];
+        assert_eq!(validate_goat_tx_limits(&txs, &limits), Ok(()));
+        assert_eq!(validate_goat_tx_limits(&txs, &GoatTxLimits::default()), Ok(()));
+
+        let txs = [new_btc_block(0, B256::ZERO), new_btc_block(1, B256::with_last_byte(1))];
+        assert_eq!(
+            validate_goat_tx_limits(&txs, &limits),
+            Err(GoatTxLimitError::TooManyNewBtcBlocks { count: 2, max: 1 })
+        );
+
+        let txs = vec![deposit(0, 0), deposit(1, 1), deposit(2, 2), user_tx()];
+        let goat_txs = txs.iter().filter_map(|tx| tx.as_goat()).map(|tx| tx.tx().clone());
+        assert_eq!(
+            validate_goat_tx_limits(&goat_txs.collect::<Vec<_>>(), &limits),
+            Err(GoatTxLimitError::TooManyDeposits { count: 3, max: 2 })
+        );
+
+        let header =
+            Header { transactions_root: calculate_transaction_root(&txs), ..Default::default() };
+        let body = BlockBody { transactions: txs, ommers: vec![], withdrawals: None };
+        assert_eq!(validate_goat_block(&header, &body, &GoatBlockConfig::default()), Ok(()));
+        assert_eq!(
+            validate_goat_block(&header, &body, &GoatBlockConfig::default().with_limits(limits)),
+            Err(GoatBlockError::Limit(GoatTxLimitError::TooManyDeposits { count: 3, max: 2 }))
+        );
+        let params = GoatParams { btc_confirmations: 6, max_deposits_per_block: 2, max_tax_bps: 0 };
+        assert_eq!(GoatTxLimits::from_params(&params), limits);
+    }
+}
@@ -13,6 +13,12 @@ pub const SELECTOR_LEN: usize = 4;
 /// Maximum extra data size in a block after genesis
 pub const MAXIMUM_EXTRA_DATA_SIZE: usize = 32;
 
+/// Maximum extra data size in a block of a GOAT chain.
+///
+/// GOAT headers carry an encoded [`GoatHeaderExtra`](crate::GoatHeaderExtra) instead of vanity
+/// data, which takes up to 43 bytes and therefore does not fit into [`MAXIMUM_EXTRA_DATA_SIZE`].
+pub const GOAT_MAXIMUM_EXTRA_DATA_SIZE: usize = 43;
+
 /// Multiplier for converting gwei to wei.
 pub const GWEI_TO_WEI: u64 = 1_000_000_000;
 
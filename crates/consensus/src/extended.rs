@@ -7,7 +7,7 @@ use alloy_eips::{
     eip7702::SignedAuthorization,
     Decodable2718, Encodable2718, Typed2718,
 };
-use alloy_primitives::{Bytes, ChainId, TxKind, B256, U256};
+use alloy_primitives::{Address, Bytes, ChainId, TxKind, B256, U256};
 use alloy_rlp::{BufMut, Decodable, Encodable, Result as RlpResult};
 
 macro_rules! delegate {
@@ -191,6 +191,10 @@ where
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         delegate!(self => tx.authorization_list())
     }
+
+    fn system_sender(&self) -> Option<Address> {
+        delegate!(self => tx.system_sender())
+    }
 }
 
 impl<B, T> IsTyped2718 for Extended<B, T>
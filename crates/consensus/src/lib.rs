@@ -20,15 +20,21 @@ pub use alloy_trie::TrieAccount;
 pub use alloy_trie::TrieAccount as Account;
 
 mod block;
-pub use block::{Block, BlockBody, BlockHeader, EthBlock, Header, HeaderInfo};
+pub use block::{
+    validate_goat_block, validate_goat_tx_limits, Block, BlockBody, BlockBuilder,
+    BlockBuilderError, BlockHeader, EthBlock, GoatBlock, GoatBlockConfig, GoatBlockError,
+    GoatHeaderExtra, GoatTxLimitError, GoatTxLimits, Header, HeaderInfo, RecoveredGoatBlock,
+    SealedGoatBlock,
+};
 
 pub mod constants;
 pub use constants::{EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH};
 
 mod receipt;
 pub use receipt::{
-    Eip2718DecodableReceipt, Eip2718EncodableReceipt, Eip658Value, Receipt, ReceiptEnvelope,
-    ReceiptWithBloom, Receipts, RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt,
+    Eip2718DecodableReceipt, Eip2718EncodableReceipt, Eip658Value, GoatReceipt, Receipt,
+    ReceiptEnvelope, ReceiptWithBloom, Receipts, RlpDecodableReceipt, RlpEncodableReceipt,
+    TxReceipt,
 };
 
 pub mod conditional;
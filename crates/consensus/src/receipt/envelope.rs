@@ -1,4 +1,6 @@
-use crate::{Eip658Value, Receipt, ReceiptWithBloom, TxReceipt, TxType};
+use crate::{
+    transaction::goat::GOAT_TX_TYPE_ID, Eip658Value, Receipt, ReceiptWithBloom, TxReceipt, TxType,
+};
 use alloc::vec::Vec;
 use alloy_eips::{
     eip2718::{
@@ -50,6 +52,13 @@ pub enum ReceiptEnvelope<T = Log> {
     /// [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
     #[cfg_attr(feature = "serde", serde(rename = "0x4", alias = "0x04"))]
     Eip7702(ReceiptWithBloom<Receipt<T>>),
+    /// Receipt envelope with type flag 0x60, containing a goat system transaction receipt.
+    ///
+    /// The goat receipt has the same payload as the other typed receipts. The goat fields reported
+    /// over RPC, such as the minted tokens, are not part of its encoding and thus not committed to
+    /// by the receipts root.
+    #[cfg_attr(feature = "serde", serde(rename = "0x60"))]
+    Goat(ReceiptWithBloom<Receipt<T>>),
 }
 
 impl<T> ReceiptEnvelope<T> {
@@ -64,6 +73,7 @@ impl<T> ReceiptEnvelope<T> {
             TxType::Eip1559 => Self::Eip1559(receipt.into()),
             TxType::Eip4844 => Self::Eip4844(receipt.into()),
             TxType::Eip7702 => Self::Eip7702(receipt.into()),
+            TxType::Goat => Self::Goat(receipt.into()),
         }
     }
 
@@ -77,6 +87,7 @@ impl<T> ReceiptEnvelope<T> {
             Self::Eip1559(r) => ReceiptEnvelope::Eip1559(r.map_logs(f)),
             Self::Eip4844(r) => ReceiptEnvelope::Eip4844(r.map_logs(f)),
             Self::Eip7702(r) => ReceiptEnvelope::Eip7702(r.map_logs(f)),
+            Self::Goat(r) => ReceiptEnvelope::Goat(r.map_logs(f)),
         }
     }
 
@@ -101,6 +112,7 @@ impl<T> ReceiptEnvelope<T> {
             Self::Eip1559(_) => TxType::Eip1559,
             Self::Eip4844(_) => TxType::Eip4844,
             Self::Eip7702(_) => TxType::Eip7702,
+            Self::Goat(_) => TxType::Goat,
         }
     }
 
@@ -142,7 +154,8 @@ impl<T> ReceiptEnvelope<T> {
             | Self::Eip2930(t)
             | Self::Eip1559(t)
             | Self::Eip4844(t)
-            | Self::Eip7702(t) => Some(t),
+            | Self::Eip7702(t)
+            | Self::Goat(t) => Some(t),
         }
     }
 
@@ -154,7 +167,8 @@ impl<T> ReceiptEnvelope<T> {
             | Self::Eip2930(t)
             | Self::Eip1559(t)
             | Self::Eip4844(t)
-            | Self::Eip7702(t) => Some(t),
+            | Self::Eip7702(t)
+            | Self::Goat(t) => Some(t),
         }
     }
 
@@ -165,7 +179,8 @@ impl<T> ReceiptEnvelope<T> {
             | Self::Eip2930(t)
             | Self::Eip1559(t)
             | Self::Eip4844(t)
-            | Self::Eip7702(t) => t.receipt,
+            | Self::Eip7702(t)
+            | Self::Goat(t) => t.receipt,
         }
     }
 
@@ -177,7 +192,8 @@ impl<T> ReceiptEnvelope<T> {
             | Self::Eip2930(t)
             | Self::Eip1559(t)
             | Self::Eip4844(t)
-            | Self::Eip7702(t) => Some(&t.receipt),
+            | Self::Eip7702(t)
+            | Self::Goat(t) => Some(&t.receipt),
         }
     }
 }
@@ -264,6 +280,7 @@ impl Typed2718 for ReceiptEnvelope {
             Self::Eip1559(_) => EIP1559_TX_TYPE_ID,
             Self::Eip4844(_) => EIP4844_TX_TYPE_ID,
             Self::Eip7702(_) => EIP7702_TX_TYPE_ID,
+            Self::Goat(_) => GOAT_TX_TYPE_ID,
         }
     }
 }
@@ -296,6 +313,7 @@ impl Decodable2718 for ReceiptEnvelope {
             TxType::Eip1559 => Ok(Self::Eip1559(receipt)),
             TxType::Eip4844 => Ok(Self::Eip4844(receipt)),
             TxType::Eip7702 => Ok(Self::Eip7702(receipt)),
+            TxType::Goat => Ok(Self::Goat(receipt)),
             TxType::Legacy => Err(Eip2718Error::UnexpectedType(0)),
         }
     }
@@ -313,12 +331,13 @@ where
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let receipt = ReceiptWithBloom::<Receipt<T>>::arbitrary(u)?;
 
-        match u.int_in_range(0..=4)? {
+        match u.int_in_range(0..=5)? {
             0 => Ok(Self::Legacy(receipt)),
             1 => Ok(Self::Eip2930(receipt)),
             2 => Ok(Self::Eip1559(receipt)),
             3 => Ok(Self::Eip4844(receipt)),
             4 => Ok(Self::Eip7702(receipt)),
+            5 => Ok(Self::Goat(receipt)),
             _ => unreachable!(),
         }
     }
@@ -396,6 +415,7 @@ pub(crate) mod serde_bincode_compat {
                 TxType::Eip1559 => Self::Eip1559(receipt),
                 TxType::Eip4844 => Self::Eip4844(receipt),
                 TxType::Eip7702 => Self::Eip7702(receipt),
+                TxType::Goat => Self::Goat(receipt),
             }
         }
     }
@@ -496,6 +516,40 @@ mod test {
         );
     }
 
+    #[test]
+    fn goat_receipt_2718() {
+        use crate::proofs::calculate_receipt_root;
+        use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+        use alloy_primitives::{Address, Bytes, LogData};
+
+        let receipt = Receipt {
+            status: true.into(),
+            cumulative_gas_used: 0,
+            logs: vec![Log {
+                address: Address::with_last_byte(3),
+                data: LogData::new_unchecked(vec![], Bytes::from_static(&[1, 2, 3])),
+            }],
+        };
+        let goat = ReceiptEnvelope::from_typed(TxType::Goat, receipt.clone());
+        let encoded = goat.encoded_2718();
+        assert_eq!(encoded[0], 0x60);
+        assert_eq!(encoded.len(), goat.encode_2718_len());
+        assert_eq!(ReceiptEnvelope::decode_2718_exact(&encoded).unwrap(), goat);
+
+        // the network encoding wraps the same bytes
+        let mut network = Vec::new();
+        goat.network_encode(&mut network);
+        assert_eq!(ReceiptEnvelope::network_decode(&mut network.as_slice()).unwrap(), goat);
+
+        // the type flag is committed to by the receipts root
+        let legacy = ReceiptEnvelope::from_typed(TxType::Legacy, Receipt::<Log>::default());
+        let eip1559 = ReceiptEnvelope::from_typed(TxType::Eip1559, receipt);
+        let root = calculate_receipt_root(&[legacy.clone(), goat]);
+        assert_ne!(root, calculate_receipt_root(&[legacy.clone(), eip1559]));
+        let decoded = ReceiptEnvelope::decode_2718_exact(&encoded).unwrap();
+        assert_eq!(calculate_receipt_root(&[legacy, decoded]), root);
+    }
+
     #[test]
     fn convert_envelope() {
         let receipt = Receipt::<Log>::default();
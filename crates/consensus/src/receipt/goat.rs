@@ -0,0 +1,133 @@
+use crate::{
+    Eip658Value, Receipt, ReceiptWithBloom, RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt,
+};
+use alloc::vec::Vec;
+use alloy_primitives::{Bloom, Log, U256};
+use alloy_rlp::{BufMut, Decodable, Encodable};
+use core::fmt;
+
+/// Receipt of a goat node, tracking the native tokens minted in the block.
+///
+/// The cumulative mint is reported by goat-geth next to the regular receipt fields, but it is not
+/// part of the consensus encoding, so the receipt is encoded as the inner [`Receipt`] and decodes
+/// without it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatReceipt<T = Log> {
+    /// The regular receipt fields.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub inner: Receipt<T>,
+    /// The native tokens minted in the block up to and including the transaction, in wei.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub cumulative_mint: Option<U256>,
+}
+
+impl<T> GoatReceipt<T> {
+    /// Converts the receipt's log type by applying a function to each log.
+    pub fn map_logs<U>(self, f: impl FnMut(T) -> U) -> GoatReceipt<U> {
+        GoatReceipt { inner: self.inner.map_logs(f), cumulative_mint: self.cumulative_mint }
+    }
+}
+
+impl<T> From<Receipt<T>> for GoatReceipt<T> {
+    fn from(inner: Receipt<T>) -> Self {
+        Self { inner, cumulative_mint: None }
+    }
+}
+
+impl<T> TxReceipt for GoatReceipt<T>
+where
+    T: AsRef<Log> + Clone + fmt::Debug + PartialEq + Eq + Send + Sync,
+{
+    type Log = T;
+
+    fn status_or_post_state(&self) -> Eip658Value {
+        self.inner.status_or_post_state()
+    }
+
+    fn status(&self) -> bool {
+        self.inner.status()
+    }
+
+    fn bloom(&self) -> Bloom {
+        self.inner.bloom()
+    }
+
+    fn cumulative_gas_used(&self) -> u64 {
+        self.inner.cumulative_gas_used()
+    }
+
+    fn logs(&self) -> &[Self::Log] {
+        self.inner.logs()
+    }
+
+    fn cumulative_mint(&self) -> Option<U256> {
+        self.cumulative_mint
+    }
+
+    fn into_logs(self) -> Vec<Self::Log>
+    where
+        Self::Log: Clone,
+    {
+        self.inner.logs
+    }
+}
+
+impl<T: Encodable> RlpEncodableReceipt for GoatReceipt<T> {
+    fn rlp_encoded_length_with_bloom(&self, bloom: &Bloom) -> usize {
+        self.inner.rlp_encoded_length_with_bloom(bloom)
+    }
+
+    fn rlp_encode_with_bloom(&self, bloom: &Bloom, out: &mut dyn BufMut) {
+        self.inner.rlp_encode_with_bloom(bloom, out);
+    }
+}
+
+impl<T: Decodable> RlpDecodableReceipt for GoatReceipt<T> {
+    fn rlp_decode_with_bloom(buf: &mut &[u8]) -> alloy_rlp::Result<ReceiptWithBloom<Self>> {
+        Ok(Receipt::rlp_decode_with_bloom(buf)?.map_receipt(Into::into))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes, LogData};
+
+    #[test]
+    fn goat_receipt_cumulative_mint() {
+        let json = r#"{"status":"0x1","cumulativeGasUsed":"0x5208","logs":[{"address":"0xbc10000000000000000000000000000000000003","topics":["0xb28ad1ef4e1e8e06e8e8d5a5e7b0cbb1cfbc8e4a4bb3e2ab1ee26d7b3e6b2a9f"],"data":"0x01"}],"cumulativeMint":"0xe043da617250000"}"#;
+        let receipt: GoatReceipt = serde_json::from_str(json).unwrap();
+        let log = Log {
+            address: address!("0xbc10000000000000000000000000000000000003"),
+            data: LogData::new_unchecked(
+                vec![b256!("0xb28ad1ef4e1e8e06e8e8d5a5e7b0cbb1cfbc8e4a4bb3e2ab1ee26d7b3e6b2a9f")],
+                bytes!("01"),
+            ),
+        };
+        assert_eq!(
+            receipt.inner,
+            Receipt { status: true.into(), cumulative_gas_used: 21000, logs: vec![log] }
+        );
+        let cumulative_mint = U256::from(1_010_000_000_000_000_000u64);
+        assert_eq!(receipt.cumulative_mint(), Some(cumulative_mint));
+        assert_eq!(serde_json::to_string(&receipt).unwrap(), json);
+
+        // the cumulative mint is forwarded, but not part of the consensus encoding
+        let with_bloom = ReceiptWithBloom::from(receipt.clone());
+        assert_eq!(with_bloom.cumulative_mint(), Some(cumulative_mint));
+        let encoded = alloy_rlp::encode(&with_bloom);
+        assert_eq!(encoded, alloy_rlp::encode(receipt.inner.clone().with_bloom()));
+        let decoded = ReceiptWithBloom::<GoatReceipt>::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.receipt, GoatReceipt::from(receipt.inner));
+        assert_eq!(decoded.cumulative_mint(), None);
+
+        // receipts of nodes not reporting the mint
+        let receipt: GoatReceipt =
+            serde_json::from_str(r#"{"status":"0x1","cumulativeGasUsed":"0x0","logs":[]}"#)
+                .unwrap();
+        assert_eq!(receipt.cumulative_mint(), None);
+    }
+}
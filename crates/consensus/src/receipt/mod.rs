@@ -1,11 +1,14 @@
 use alloc::vec::Vec;
-use alloy_primitives::Bloom;
+use alloy_primitives::{Bloom, U256};
 use alloy_rlp::BufMut;
 use core::fmt;
 
 mod envelope;
 pub use envelope::ReceiptEnvelope;
 
+mod goat;
+pub use goat::GoatReceipt;
+
 mod receipts;
 pub use receipts::{Receipt, ReceiptWithBloom, Receipts};
 
@@ -89,6 +92,15 @@ pub trait TxReceipt: Clone + fmt::Debug + PartialEq + Eq + Send + Sync {
     /// Returns the logs emitted by this transaction.
     fn logs(&self) -> &[Self::Log];
 
+    /// Returns the native tokens minted outside of the EVM in the block after this transaction
+    /// was executed, in wei, e.g. by goat deposits.
+    ///
+    /// The supply delta of a block can be read off its last receipt. Returns `None` if the receipt
+    /// does not track mints.
+    fn cumulative_mint(&self) -> Option<U256> {
+        None
+    }
+
     /// Consumes the type and returns the logs emitted by this transaction as a vector.
     #[auto_impl(keep_default_for(&, Arc))]
     fn into_logs(self) -> Vec<Self::Log>
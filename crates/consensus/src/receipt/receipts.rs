@@ -7,7 +7,7 @@ use alloy_eips::{
     eip2718::{Eip2718Result, Encodable2718},
     Decodable2718, Typed2718,
 };
-use alloy_primitives::{Bloom, Log};
+use alloy_primitives::{Bloom, Log, U256};
 use alloy_rlp::{BufMut, Decodable, Encodable, Header};
 use core::fmt;
 
@@ -298,6 +298,10 @@ where
     fn logs(&self) -> &[Self::Log] {
         self.receipt.logs()
     }
+
+    fn cumulative_mint(&self) -> Option<U256> {
+        self.receipt.cumulative_mint()
+    }
 }
 
 impl<R> From<R> for ReceiptWithBloom<R>
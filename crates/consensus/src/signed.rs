@@ -10,7 +10,7 @@ use alloy_eips::{
     eip7702::SignedAuthorization,
     Decodable2718, Encodable2718, Typed2718,
 };
-use alloy_primitives::{Bytes, Sealed, Signature, TxKind, B256, U256};
+use alloy_primitives::{Address, Bytes, Sealed, Signature, TxKind, B256, U256};
 use alloy_rlp::BufMut;
 use core::{
     fmt::Debug,
@@ -241,9 +241,14 @@ impl<T: TxHashable<Sig> + PartialEq, Sig: PartialEq> Eq for Signed<T, Sig> {}
 #[cfg(feature = "k256")]
 impl<T: SignableTransaction<Signature>> Signed<T, Signature> {
     /// Recover the signer of the transaction
+    ///
+    /// The sender of a [system transaction](Transaction::system_sender) is returned as is.
     pub fn recover_signer(
         &self,
     ) -> Result<alloy_primitives::Address, alloy_primitives::SignatureError> {
+        if let Some(sender) = self.tx.system_sender() {
+            return Ok(sender);
+        }
         let sighash = self.tx.signature_hash();
         self.signature.recover_address_from_prehash(&sighash)
     }
@@ -384,6 +389,11 @@ impl<T: Transaction, Sig: Debug + Send + Sync + 'static> Transaction for Signed<
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         self.tx.authorization_list()
     }
+
+    #[inline]
+    fn system_sender(&self) -> Option<Address> {
+        self.tx.system_sender()
+    }
 }
 
 impl<T: Transaction> Transaction for Sealed<T> {
@@ -470,6 +480,11 @@ impl<T: Transaction> Transaction for Sealed<T> {
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         self.inner().authorization_list()
     }
+
+    #[inline]
+    fn system_sender(&self) -> Option<Address> {
+        self.inner().system_sender()
+    }
 }
 
 #[cfg(any(feature = "secp256k1", feature = "k256"))]
@@ -477,7 +492,12 @@ impl<T> crate::transaction::SignerRecoverable for Signed<T>
 where
     T: SignableTransaction<Signature>,
 {
+    /// The sender of a [system transaction](Transaction::system_sender) is returned as is, without
+    /// recovering it from the signature.
     fn recover_signer(&self) -> Result<alloy_primitives::Address, crate::crypto::RecoveryError> {
+        if let Some(sender) = self.tx.system_sender() {
+            return Ok(sender);
+        }
         let signature_hash = self.signature_hash();
         crate::crypto::secp256k1::recover_signer(self.signature(), signature_hash)
     }
@@ -485,6 +505,9 @@ where
     fn recover_signer_unchecked(
         &self,
     ) -> Result<alloy_primitives::Address, crate::crypto::RecoveryError> {
+        if let Some(sender) = self.tx.system_sender() {
+            return Ok(sender);
+        }
         let signature_hash = self.signature_hash();
         crate::crypto::secp256k1::recover_signer_unchecked(self.signature(), signature_hash)
     }
@@ -493,6 +516,9 @@ where
         &self,
         buf: &mut alloc::vec::Vec<u8>,
     ) -> Result<alloy_primitives::Address, crate::crypto::RecoveryError> {
+        if let Some(sender) = self.tx.system_sender() {
+            return Ok(sender);
+        }
         buf.clear();
         self.tx.encode_for_signing(buf);
         let signature_hash = alloy_primitives::keccak256(buf);
@@ -3,6 +3,7 @@ use crate::{
     error::ValueError,
     transaction::{
         eip4844::{TxEip4844, TxEip4844Variant},
+        goat::TxGoat,
         RlpEcdsaEncodableTx, TxHashRef,
     },
     Signed, TransactionEnvelope, TxEip1559, TxEip2930, TxEip4844WithSidecar, TxEip7702, TxLegacy,
@@ -28,7 +29,8 @@ impl<T: Encodable7594> EthereumTxEnvelope<TxEip4844Variant<T>> {
     /// Attempts to convert the envelope into the pooled variant.
     ///
     /// Returns an error if the envelope's variant is incompatible with the pooled format:
-    /// [`crate::TxEip4844`] without the sidecar.
+    /// [`crate::TxEip4844`] without the sidecar, or a [`TxGoat`] system transaction, which is never
+    /// propagated over p2p.
     pub fn try_into_pooled(
         self,
     ) -> Result<EthereumTxEnvelope<TxEip4844WithSidecar<T>>, ValueError<Self>> {
@@ -38,6 +40,9 @@ impl<T: Encodable7594> EthereumTxEnvelope<TxEip4844Variant<T>> {
             Self::Eip1559(tx) => Ok(tx.into()),
             Self::Eip4844(tx) => EthereumTxEnvelope::try_from(tx).map_err(ValueError::convert),
             Self::Eip7702(tx) => Ok(tx.into()),
+            Self::Goat(tx) => {
+                Err(ValueError::new_static(tx.into(), "goat system transactions can not be pooled"))
+            }
         }
     }
 }
@@ -46,7 +51,8 @@ impl EthereumTxEnvelope<TxEip4844> {
     /// Attempts to convert the envelope into the pooled variant.
     ///
     /// Returns an error if the envelope's variant is incompatible with the pooled format:
-    /// [`crate::TxEip4844`] without the sidecar.
+    /// [`crate::TxEip4844`] without the sidecar, or a [`TxGoat`] system transaction, which is never
+    /// propagated over p2p.
     pub fn try_into_pooled<T>(
         self,
     ) -> Result<EthereumTxEnvelope<TxEip4844WithSidecar<T>>, ValueError<Self>> {
@@ -58,6 +64,9 @@ impl EthereumTxEnvelope<TxEip4844> {
                 Err(ValueError::new(tx.into(), "pooled transaction requires 4844 sidecar"))
             }
             Self::Eip7702(tx) => Ok(tx.into()),
+            Self::Goat(tx) => {
+                Err(ValueError::new_static(tx.into(), "goat system transactions can not be pooled"))
+            }
         }
     }
 
@@ -128,6 +137,7 @@ impl<T> EthereumTxEnvelope<T> {
             Self::Eip1559(tx) => EthereumTypedTransaction::Eip1559(tx.into_parts().0),
             Self::Eip4844(tx) => EthereumTypedTransaction::Eip4844(tx.into_parts().0),
             Self::Eip7702(tx) => EthereumTypedTransaction::Eip7702(tx.into_parts().0),
+            Self::Goat(tx) => EthereumTypedTransaction::Goat(tx.into_parts().0),
         }
     }
 
@@ -143,6 +153,7 @@ impl<T> EthereumTxEnvelope<T> {
             Self::Legacy(tx) => &mut tx.tx_mut().input,
             Self::Eip7702(tx) => &mut tx.tx_mut().input,
             Self::Eip4844(tx) => &mut tx.tx_mut().as_mut().input,
+            Self::Goat(tx) => &mut tx.tx_mut().input,
         }
     }
 }
@@ -229,6 +240,7 @@ impl<T> EthereumTypedTransaction<TxEip4844Variant<T>> {
                 (EthereumTypedTransaction::Eip4844(tx_variant), sidecar)
             }
             Self::Eip7702(tx) => (EthereumTypedTransaction::Eip7702(tx), None),
+            Self::Goat(tx) => (EthereumTypedTransaction::Goat(tx), None),
         }
     }
 
@@ -448,6 +460,56 @@ impl EthereumTxEnvelope<TxEip4844Variant<alloy_eips::eip4844::BlobTransactionSid
     }
 }
 
+#[cfg(feature = "kzg")]
+impl<T: super::TxEip4844Sidecar> EthereumTxEnvelope<TxEip4844Variant<T>> {
+    /// Verifies that the blob data, commitments, and proofs of an EIP-4844 transaction are all
+    /// valid.
+    ///
+    /// Goat system transactions are rejected with
+    /// [`GoatTxCannotHaveBlobs`](super::BlobTransactionValidationError::GoatTxCannotHaveBlobs),
+    /// all other non-EIP-4844 transactions with
+    /// [`NotBlobTransaction`](super::BlobTransactionValidationError::NotBlobTransaction).
+    ///
+    /// See also [`TxEip4844Variant::validate`].
+    pub fn validate_blob(
+        &self,
+        proof_settings: &c_kzg::KzgSettings,
+    ) -> Result<(), super::BlobTransactionValidationError> {
+        match self {
+            Self::Eip4844(tx) => tx.tx().validate(proof_settings),
+            Self::Goat(_) => Err(super::BlobTransactionValidationError::GoatTxCannotHaveBlobs),
+            _ => {
+                Err(super::BlobTransactionValidationError::NotBlobTransaction(self.tx_type() as u8))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "kzg")]
+impl<T: super::TxEip4844Sidecar> EthereumTxEnvelope<TxEip4844WithSidecar<T>> {
+    /// Verifies that the blob data, commitments, and proofs of an EIP-4844 transaction are all
+    /// valid.
+    ///
+    /// Goat system transactions are rejected with
+    /// [`GoatTxCannotHaveBlobs`](super::BlobTransactionValidationError::GoatTxCannotHaveBlobs),
+    /// all other non-EIP-4844 transactions with
+    /// [`NotBlobTransaction`](super::BlobTransactionValidationError::NotBlobTransaction).
+    ///
+    /// See also [`TxEip4844WithSidecar::validate_blob`].
+    pub fn validate_blob(
+        &self,
+        proof_settings: &c_kzg::KzgSettings,
+    ) -> Result<(), super::BlobTransactionValidationError> {
+        match self {
+            Self::Eip4844(tx) => tx.tx().validate_blob(proof_settings),
+            Self::Goat(_) => Err(super::BlobTransactionValidationError::GoatTxCannotHaveBlobs),
+            _ => {
+                Err(super::BlobTransactionValidationError::NotBlobTransaction(self.tx_type() as u8))
+            }
+        }
+    }
+}
+
 #[cfg(feature = "kzg")]
 impl TryFrom<EthereumTxEnvelope<TxEip4844WithSidecar<alloy_eips::eip4844::BlobTransactionSidecar>>>
     for EthereumTxEnvelope<TxEip4844WithSidecar<alloy_eips::eip7594::BlobTransactionSidecarEip7594>>
@@ -492,7 +554,11 @@ impl TryFrom<EthereumTxEnvelope<TxEip4844Variant<alloy_eips::eip4844::BlobTransa
     alloy_consensus = crate,
     tx_type_name = TxType,
     typed = EthereumTypedTransaction,
-    arbitrary_cfg(feature = "arbitrary")
+    arbitrary_cfg(feature = "arbitrary"),
+    typed_decode_check(
+        path = Self::check_typed_decode,
+        bound = "Eip4844: super::pooled::Eip4844Representation"
+    )
 )]
 #[doc(alias = "TransactionEnvelope")]
 pub enum EthereumTxEnvelope<Eip4844> {
@@ -517,6 +583,9 @@ pub enum EthereumTxEnvelope<Eip4844> {
     /// A [`TxEip7702`] tagged with type 4.
     #[envelope(ty = 4)]
     Eip7702(Signed<TxEip7702>),
+    /// A [`TxGoat`] tagged with type 0x60.
+    #[envelope(ty = 96)]
+    Goat(Signed<TxGoat>),
 }
 
 impl<T, Eip4844> From<Signed<T>> for EthereumTxEnvelope<Eip4844>
@@ -548,6 +617,10 @@ where
                 let tx = Signed::new_unchecked(tx_eip7702, sig, hash);
                 Self::Eip7702(tx)
             }
+            EthereumTypedTransaction::Goat(tx_goat) => {
+                let tx = Signed::new_unchecked(tx_goat, sig, hash);
+                Self::Goat(tx)
+            }
         }
     }
 }
@@ -562,6 +635,36 @@ where
     }
 }
 
+impl<Eip4844> TryFrom<EthereumTxEnvelope<Eip4844>> for Signed<TxGoat> {
+    type Error = ValueError<EthereumTxEnvelope<Eip4844>>;
+
+    fn try_from(value: EthereumTxEnvelope<Eip4844>) -> Result<Self, Self::Error> {
+        match value {
+            EthereumTxEnvelope::Goat(tx) => Ok(tx),
+            _ => Err(ValueError::new_static(value, "Expected goat transaction")),
+        }
+    }
+}
+
+impl<'a, Eip4844> TryFrom<&'a EthereumTxEnvelope<Eip4844>> for &'a Signed<TxGoat> {
+    type Error = ValueError<&'a EthereumTxEnvelope<Eip4844>>;
+
+    fn try_from(value: &'a EthereumTxEnvelope<Eip4844>) -> Result<Self, Self::Error> {
+        match value {
+            EthereumTxEnvelope::Goat(tx) => Ok(tx),
+            _ => Err(ValueError::new_static(value, "Expected goat transaction")),
+        }
+    }
+}
+
+impl<'a, Eip4844> TryFrom<&'a EthereumTxEnvelope<Eip4844>> for &'a TxGoat {
+    type Error = ValueError<&'a EthereumTxEnvelope<Eip4844>>;
+
+    fn try_from(value: &'a EthereumTxEnvelope<Eip4844>) -> Result<Self, Self::Error> {
+        <&Signed<TxGoat>>::try_from(value).map(Signed::tx)
+    }
+}
+
 impl<Eip4844> From<(EthereumTypedTransaction<Eip4844>, Signature)> for EthereumTxEnvelope<Eip4844>
 where
     Eip4844: RlpEcdsaEncodableTx + SignableTransaction<Signature>,
@@ -601,6 +704,7 @@ impl<Eip4844> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => EthereumTxEnvelope::Eip1559(tx),
             Self::Eip4844(tx) => EthereumTxEnvelope::Eip4844(tx.map(f)),
             Self::Eip7702(tx) => EthereumTxEnvelope::Eip7702(tx),
+            Self::Goat(tx) => EthereumTxEnvelope::Goat(tx),
         }
     }
 
@@ -616,6 +720,7 @@ impl<Eip4844> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => Ok(EthereumTxEnvelope::Eip1559(tx)),
             Self::Eip4844(tx) => tx.try_map(f).map(EthereumTxEnvelope::Eip4844),
             Self::Eip7702(tx) => Ok(EthereumTxEnvelope::Eip7702(tx)),
+            Self::Goat(tx) => Ok(EthereumTxEnvelope::Goat(tx)),
         }
     }
 
@@ -628,6 +733,7 @@ impl<Eip4844> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(_) => TxType::Eip1559,
             Self::Eip4844(_) => TxType::Eip4844,
             Self::Eip7702(_) => TxType::Eip7702,
+            Self::Goat(_) => TxType::Goat,
         }
     }
 
@@ -642,6 +748,7 @@ impl<Eip4844> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => tx.convert(),
             Self::Eip4844(tx) => tx.convert(),
             Self::Eip7702(tx) => tx.convert(),
+            Self::Goat(tx) => tx.convert(),
         }
     }
 }
@@ -677,6 +784,12 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
         matches!(self, Self::Eip7702(_))
     }
 
+    /// Returns true if the transaction is a goat system transaction.
+    #[inline]
+    pub const fn is_goat(&self) -> bool {
+        matches!(self, Self::Goat(_))
+    }
+
     /// Returns true if the transaction is replay protected.
     ///
     /// All non-legacy transactions are replay protected, as the chain id is
@@ -733,6 +846,14 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
         }
     }
 
+    /// Returns the [`TxGoat`] variant if the transaction is a goat system transaction.
+    pub const fn as_goat(&self) -> Option<&Signed<TxGoat>> {
+        match self {
+            Self::Goat(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
     /// Consumes the type and returns the [`TxLegacy`] variant if the transaction is a legacy
     /// transaction. Returns an error otherwise.
     pub fn try_into_legacy(self) -> Result<Signed<TxLegacy>, ValueError<Self>> {
@@ -778,6 +899,15 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
         }
     }
 
+    /// Consumes the type and returns the [`TxGoat`] variant if the transaction is a goat system
+    /// transaction. Returns an error otherwise.
+    pub fn try_into_goat(self) -> Result<Signed<TxGoat>, ValueError<Self>> {
+        match self {
+            Self::Goat(tx) => Ok(tx),
+            _ => Err(ValueError::new_static(self, "Expected goat transaction")),
+        }
+    }
+
     /// Calculate the signing hash for the transaction.
     pub fn signature_hash(&self) -> B256
     where
@@ -789,6 +919,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => tx.signature_hash(),
             Self::Eip4844(tx) => tx.signature_hash(),
             Self::Eip7702(tx) => tx.signature_hash(),
+            Self::Goat(tx) => tx.signature_hash(),
         }
     }
 
@@ -800,6 +931,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => tx.signature(),
             Self::Eip4844(tx) => tx.signature(),
             Self::Eip7702(tx) => tx.signature(),
+            Self::Goat(tx) => tx.signature(),
         }
     }
 
@@ -812,6 +944,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => tx.hash(),
             Self::Eip4844(tx) => tx.hash(),
             Self::Eip7702(tx) => tx.hash(),
+            Self::Goat(tx) => tx.hash(),
         }
     }
 
@@ -823,6 +956,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(tx) => tx.hash(),
             Self::Eip7702(tx) => tx.hash(),
             Self::Eip4844(tx) => tx.hash(),
+            Self::Goat(tx) => tx.hash(),
         }
     }
 
@@ -834,6 +968,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTxEnvelope<Eip4844> {
             Self::Eip1559(t) => t.eip2718_encoded_length(),
             Self::Eip4844(t) => t.eip2718_encoded_length(),
             Self::Eip7702(t) => t.eip2718_encoded_length(),
+            Self::Goat(t) => t.eip2718_encoded_length(),
         }
     }
 }
@@ -856,6 +991,7 @@ where
             Self::Eip1559(tx) => crate::transaction::SignerRecoverable::recover_signer(tx),
             Self::Eip4844(tx) => crate::transaction::SignerRecoverable::recover_signer(tx),
             Self::Eip7702(tx) => crate::transaction::SignerRecoverable::recover_signer(tx),
+            Self::Goat(tx) => crate::transaction::SignerRecoverable::recover_signer(tx),
         }
     }
 
@@ -876,6 +1012,7 @@ where
             Self::Eip7702(tx) => {
                 crate::transaction::SignerRecoverable::recover_signer_unchecked(tx)
             }
+            Self::Goat(tx) => crate::transaction::SignerRecoverable::recover_signer_unchecked(tx),
         }
     }
 
@@ -899,6 +1036,9 @@ where
             Self::Eip7702(tx) => {
                 crate::transaction::SignerRecoverable::recover_unchecked_with_buf(tx, buf)
             }
+            Self::Goat(tx) => {
+                crate::transaction::SignerRecoverable::recover_unchecked_with_buf(tx, buf)
+            }
         }
     }
 }
@@ -974,6 +1114,13 @@ pub mod serde_bincode_compat {
                             tx.tx().into(),
                         ),
                 },
+                super::EthereumTxEnvelope::Goat(tx) => Self {
+                    signature: *tx.signature(),
+                    transaction:
+                        crate::serde_bincode_compat::transaction::EthereumTypedTransaction::Goat(
+                            tx.tx().into(),
+                        ),
+                },
             }
         }
     }
@@ -991,6 +1138,7 @@ pub mod serde_bincode_compat {
                     Self::Eip4844(Signed::new_unhashed(tx, signature))
                 }
                 EthereumTypedTransaction::Eip7702(tx) => Signed::new_unhashed(tx, signature).into(),
+                EthereumTypedTransaction::Goat(tx) => Signed::new_unhashed(tx, signature).into(),
             }
         }
     }
@@ -1950,6 +2098,28 @@ mod tests {
         assert!(recovered_envelope.is_eip4844());
     }
 
+    #[test]
+    fn test_try_from_goat() {
+        use crate::transaction::goat::test_utils::new_btc_block_tx;
+
+        let goat = new_btc_block_tx(0, B256::ZERO);
+        let envelope = TxEnvelope::from(goat.clone());
+        assert_eq!(<&TxGoat>::try_from(&envelope).unwrap(), goat.tx());
+        assert_eq!(<&Signed<TxGoat>>::try_from(&envelope).unwrap(), &goat);
+        assert_eq!(Signed::<TxGoat>::try_from(envelope).unwrap(), goat);
+
+        let legacy = TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy::default(),
+            Signature::test_signature(),
+            Default::default(),
+        ));
+        let error = <&TxGoat>::try_from(&legacy).unwrap_err();
+        assert!(error.to_string().contains("Expected goat transaction"));
+        assert!(error.value().is_legacy());
+        let error = Signed::<TxGoat>::try_from(legacy).unwrap_err();
+        assert!(error.into_value().is_legacy());
+    }
+
     // <https://sepolia.etherscan.io/getRawTx?tx=0xe5b458ba9de30b47cb7c0ea836bec7b072053123a7416c5082c97f959a4eebd6>
     #[test]
     fn decode_raw_legacy() {
@@ -2051,4 +2221,47 @@ mod tests {
 
         let _ = serde_json::from_str::<TxEnvelope>(rpc_tx).unwrap();
     }
+
+    #[test]
+    #[cfg(feature = "kzg")]
+    fn validate_blob_rejects_goat_tx() {
+        use crate::transaction::goat::test_utils::new_btc_block_tx;
+        use alloy_eips::eip4844::{env_settings::EnvKzgSettings, BlobTransactionValidationError};
+
+        let settings = EnvKzgSettings::Default.get();
+        let goat = new_btc_block_tx(0, B256::ZERO);
+
+        let tx = TxEnvelope::from(goat.clone());
+        assert!(matches!(
+            tx.validate_blob(settings),
+            Err(BlobTransactionValidationError::GoatTxCannotHaveBlobs)
+        ));
+        assert_eq!(tx.blob_versioned_hashes(), None);
+
+        let pooled = crate::transaction::PooledTransaction::from(goat);
+        assert!(matches!(
+            pooled.validate_blob(settings),
+            Err(BlobTransactionValidationError::GoatTxCannotHaveBlobs)
+        ));
+
+        let tx = TxEnvelope::Eip1559(Signed::new_unchecked(
+            TxEip1559::default(),
+            Signature::test_signature(),
+            Default::default(),
+        ));
+        assert!(matches!(
+            tx.validate_blob(settings),
+            Err(BlobTransactionValidationError::NotBlobTransaction(2))
+        ));
+
+        let tx = TxEnvelope::Eip4844(Signed::new_unchecked(
+            TxEip4844Variant::TxEip4844(TxEip4844::default()),
+            Signature::test_signature(),
+            Default::default(),
+        ));
+        assert!(matches!(
+            tx.validate_blob(settings),
+            Err(BlobTransactionValidationError::MissingSidecar)
+        ));
+    }
 }
@@ -0,0 +1,589 @@
+//! Minimal ABI calldata helpers for the fixed-size goat actions.
+//!
+//! All goat action calldata is a 4-byte method id followed by static 32-byte words, so there is no
+//! need for a full ABI decoder here.
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_rlp::BufMut;
+
+/// Size of an ABI word.
+pub(super) const WORD: usize = 32;
+
+/// Size of a method id.
+pub(super) const SELECTOR: usize = 4;
+
+/// A value encoded as a single static ABI word.
+pub(super) trait AbiWord: Sized {
+    /// Decodes the value from the word at index `I` of the calldata, rejecting dirty padding.
+    fn decode_at<const I: usize, const N: usize>(
+        calldata: &FixedCalldata<'_, N>,
+    ) -> alloy_rlp::Result<Self>;
+
+    /// Encodes the value as a word.
+    fn encode_word(&self, out: &mut dyn BufMut);
+}
+
+impl AbiWord for B256 {
+    fn decode_at<const I: usize, const N: usize>(
+        calldata: &FixedCalldata<'_, N>,
+    ) -> alloy_rlp::Result<Self> {
+        Ok(calldata.b256_at::<I>())
+    }
+
+    fn encode_word(&self, out: &mut dyn BufMut) {
+        out.put_slice(self.as_slice());
+    }
+}
+
+impl AbiWord for U256 {
+    fn decode_at<const I: usize, const N: usize>(
+        calldata: &FixedCalldata<'_, N>,
+    ) -> alloy_rlp::Result<Self> {
+        Ok(calldata.u256_at::<I>())
+    }
+
+    fn encode_word(&self, out: &mut dyn BufMut) {
+        out.put_slice(&self.to_be_bytes::<WORD>());
+    }
+}
+
+impl AbiWord for u32 {
+    fn decode_at<const I: usize, const N: usize>(
+        calldata: &FixedCalldata<'_, N>,
+    ) -> alloy_rlp::Result<Self> {
+        calldata.u32_at::<I>()
+    }
+
+    fn encode_word(&self, out: &mut dyn BufMut) {
+        out.put_bytes(0, WORD - 4);
+        out.put_u32(*self);
+    }
+}
+
+impl AbiWord for Address {
+    fn decode_at<const I: usize, const N: usize>(
+        calldata: &FixedCalldata<'_, N>,
+    ) -> alloy_rlp::Result<Self> {
+        calldata.address_at::<I>()
+    }
+
+    fn encode_word(&self, out: &mut dyn BufMut) {
+        out.put_bytes(0, WORD - 20);
+        out.put_slice(self.as_slice());
+    }
+}
+
+/// The calldata of an action with `N` static words.
+///
+/// The words are addressed by their index, which is checked against `N` at compile time.
+#[derive(Debug)]
+pub(super) struct FixedCalldata<'a, const N: usize> {
+    words: &'a [[u8; WORD]; N],
+}
+
+impl<'a, const N: usize> FixedCalldata<'a, N> {
+    /// The size of the calldata, including the method id.
+    pub(super) const SIZE: usize = SELECTOR + N * WORD;
+
+    /// Checks the method id and length of the calldata, and advances the buffer past it.
+    pub(super) fn new(buf: &mut &'a [u8], method_id: [u8; 4]) -> alloy_rlp::Result<Self> {
+        if buf.len() < Self::SIZE {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        if buf[..SELECTOR] != method_id {
+            return Err(alloy_rlp::Error::Custom("unexpected goat method id"));
+        }
+        let (words, _) = buf[SELECTOR..Self::SIZE].as_chunks::<WORD>();
+        *buf = &buf[Self::SIZE..];
+        Ok(Self { words: words.try_into().unwrap() })
+    }
+
+    /// Returns the word at index `I`.
+    pub(super) const fn word<const I: usize>(&self) -> &'a [u8; WORD] {
+        const { assert!(I < N, "word index out of bounds") };
+        &self.words[I]
+    }
+
+    /// Returns the `bytes32` at index `I`.
+    pub(super) const fn b256_at<const I: usize>(&self) -> B256 {
+        B256::new(*self.word::<I>())
+    }
+
+    /// Returns the `uint256` at index `I`.
+    pub(super) const fn u256_at<const I: usize>(&self) -> U256 {
+        U256::from_be_bytes(*self.word::<I>())
+    }
+
+    /// Returns the `uint32` at index `I`, rejecting dirty padding.
+    pub(super) fn u32_at<const I: usize>(&self) -> alloy_rlp::Result<u32> {
+        let word = self.word::<I>();
+        if word[..WORD - 4].iter().any(|b| *b != 0) {
+            return Err(alloy_rlp::Error::Overflow);
+        }
+        Ok(u32::from_be_bytes(word[WORD - 4..].try_into().unwrap()))
+    }
+
+    /// Returns the `address` at index `I`, rejecting dirty padding.
+    pub(super) fn address_at<const I: usize>(&self) -> alloy_rlp::Result<Address> {
+        let word = self.word::<I>();
+        if word[..WORD - 20].iter().any(|b| *b != 0) {
+            return Err(alloy_rlp::Error::Custom("dirty address padding"));
+        }
+        Ok(Address::from_slice(&word[WORD - 20..]))
+    }
+
+    /// Decodes the word at index `I` with the accessor of its type.
+    pub(super) fn get<const I: usize, T: AbiWord>(&self) -> alloy_rlp::Result<T> {
+        T::decode_at::<I, N>(self)
+    }
+}
+
+/// Declares a goat action whose calldata is a method id followed by one static word per field.
+///
+/// Generates the struct with the derives shared by all actions, its `METHOD_ID`, `SIZE` and
+/// [`GoatActionMeta`](super::GoatActionMeta) registry entry `META`, and its [`GoatTx`],
+/// [`Encodable`](alloy_rlp::Encodable) and [`Decodable`](alloy_rlp::Decodable) impls. The fields
+/// are encoded in declaration order, and must implement [`AbiWord`]. Overrides of the provided
+/// [`GoatTx`] methods can be given in a trailing `impl GoatTx { .. }` block.
+///
+/// The declared actions are registered with a single `goat_action! { @registry .. }` invocation,
+/// listing the [`TxGoatInner`] variant, type and [`GoatActionKind`] label of every action. It
+/// generates [`TxGoatInner`], [`GoatActionKind`] and [`GOAT_ACTIONS`], and the dispatch of
+/// [`decode_tx`] and [`decode_call`], so a new action only has to be declared and listed.
+///
+/// [`GoatTx`]: super::GoatTx
+/// [`TxGoatInner`]: super::TxGoatInner
+/// [`GoatActionKind`]: super::GoatActionKind
+/// [`GOAT_ACTIONS`]: super::GOAT_ACTIONS
+/// [`decode_tx`]: super::decode_tx
+/// [`decode_call`]: super::decode_call
+macro_rules! goat_action {
+    (@registry $($variant:ident($ty:ident, $label:literal)),+ $(,)?) => {
+        /// The decoded action of a [`TxGoat`].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+        #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+        pub enum TxGoatInner {
+            $(
+                #[doc = concat!("A [`", stringify!($ty), "`].")]
+                $variant($ty),
+            )+
+        }
+
+        /// The kind of a [`TxGoatInner`], without its fields.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum GoatActionKind {
+            $(
+                #[doc = concat!("A [`", stringify!($ty), "`].")]
+                $variant,
+            )+
+        }
+
+        /// The metadata of all supported goat actions, ordered by module and action.
+        pub const GOAT_ACTIONS: &[GoatActionMeta] = &[$($ty::META),+];
+
+        impl GoatActionKind {
+            /// All the kinds, ordered by module and action.
+            pub const ALL: [Self; GOAT_ACTIONS.len()] = [$(Self::$variant),+];
+
+            /// Returns the module of the action.
+            pub const fn module(self) -> Module {
+                match self {
+                    $(Self::$variant => $ty::META.module,)+
+                }
+            }
+
+            /// Returns the action within the module.
+            pub const fn action(self) -> Action {
+                match self {
+                    $(Self::$variant => $ty::META.action,)+
+                }
+            }
+
+            /// Returns the snake case label of the kind, e.g. `new_btc_block`.
+            ///
+            /// Labels are stable across releases, so they can be used as metric labels. They are
+            /// parsed back with [`FromStr`](core::str::FromStr).
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $label,)+
+                }
+            }
+        }
+
+        impl TxGoatInner {
+            /// Returns the kind of the action.
+            pub const fn kind(&self) -> GoatActionKind {
+                match self {
+                    $(Self::$variant(_) => GoatActionKind::$variant,)+
+                }
+            }
+
+            /// Returns the module of the action.
+            pub fn module(&self) -> Module {
+                match self {
+                    $(Self::$variant(tx) => tx.module(),)+
+                }
+            }
+
+            /// Returns the action within the module.
+            pub fn action(&self) -> Action {
+                match self {
+                    $(Self::$variant(tx) => tx.action(),)+
+                }
+            }
+
+            /// Returns the system executor sending the action.
+            pub fn sender(&self) -> Address {
+                match self {
+                    $(Self::$variant(tx) => tx.sender(),)+
+                }
+            }
+
+            /// Returns the predeploy called by the action.
+            pub fn contract(&self) -> Address {
+                match self {
+                    $(Self::$variant(tx) => tx.contract(),)+
+                }
+            }
+
+            /// Returns the predeploy called by the action, as a [`GoatContract`].
+            pub const fn goat_contract(&self) -> GoatContract {
+                match self {
+                    $(Self::$variant(_) => $ty::CONTRACT,)+
+                }
+            }
+
+            /// Returns the native tokens minted by the action, if any.
+            pub fn deposit(&self) -> Option<Mint> {
+                match self {
+                    $(Self::$variant(tx) => tx.deposit(),)+
+                }
+            }
+
+            /// Returns the action as a [`GoatTx`] trait object.
+            pub fn as_goat_tx(&self) -> &dyn GoatTx {
+                match self {
+                    $(Self::$variant(tx) => tx,)+
+                }
+            }
+
+            /// Returns the length of the calldata of the action.
+            pub fn input_length(&self) -> usize {
+                match self {
+                    $(Self::$variant(tx) => tx.length(),)+
+                }
+            }
+
+            /// Encodes the action as calldata.
+            pub fn encode_input(&self, out: &mut dyn BufMut) {
+                match self {
+                    $(Self::$variant(tx) => tx.encode(out),)+
+                }
+            }
+        }
+
+        /// Decodes the calldata of a goat action.
+        ///
+        /// Returns an error if the module and action are unknown, or if the input is not exactly
+        /// the calldata of the action.
+        pub fn decode_tx(
+            module: Module,
+            action: Action,
+            input: &[u8],
+        ) -> alloy_rlp::Result<TxGoatInner> {
+            $(
+                if (module, action) == ($ty::META.module, $ty::META.action) {
+                    return decode_exact(input).map(TxGoatInner::$variant);
+                }
+            )+
+            Err(alloy_rlp::Error::Custom("unknown goat module or action"))
+        }
+
+        /// Decodes the calldata of a call to a goat predeploy, e.g. a frame of a call trace.
+        ///
+        /// The module and action are inferred from the called predeploy and the method id of the
+        /// calldata. Returns `None` if the call is not a goat action, and an error if it is but the
+        /// calldata is malformed.
+        pub fn decode_call(to: Address, input: &[u8]) -> Option<alloy_rlp::Result<TxGoatInner>> {
+            let contract = GoatContract::from_address(&to)?;
+            let method_id: [u8; 4] = input.get(..4)?.try_into().ok()?;
+            $(
+                if (contract, method_id) == ($ty::CONTRACT, $ty::METHOD_ID) {
+                    return Some(decode_exact(input).map(TxGoatInner::$variant));
+                }
+            )+
+            None
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                pub $field:ident: $ty:ty,
+            )+
+        }
+
+        signature = $signature:literal,
+        method_id = $method_id:expr,
+        module = $module:expr,
+        action = $action:expr,
+        sender = $sender:expr,
+        contract = $contract:expr,
+        $(impl GoatTx { $($goat_tx:tt)* })?
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+        #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+        pub struct $name {
+            $(
+                $(#[$field_attr])*
+                pub $field: $ty,
+            )+
+        }
+
+        impl $name {
+            #[doc = concat!("The method id of `", $signature, "`.")]
+            pub const METHOD_ID: [u8; 4] = $method_id;
+
+            /// The size of the calldata.
+            pub const SIZE: usize =
+                $crate::transaction::goat::abi::FixedCalldata::<{ $name::FIELDS.len() }>::SIZE;
+
+            /// The predeploy called by the action.
+            pub const CONTRACT: $crate::transaction::goat::GoatContract = $contract;
+
+            #[doc = concat!("The metadata of `", $signature, "`.")]
+            pub const META: $crate::transaction::goat::GoatActionMeta =
+                $crate::transaction::goat::GoatActionMeta {
+                    module: $module,
+                    action: $action,
+                    selector: alloy_primitives::Selector::new(Self::METHOD_ID),
+                    signature: $signature,
+                    fixed_size: Some(Self::SIZE),
+                };
+
+            /// The names of the calldata words, in order.
+            pub(super) const FIELDS: &'static [&'static str] = &[$(stringify!($field)),+];
+        }
+
+        impl $crate::transaction::goat::GoatTx for $name {
+            fn module(&self) -> $crate::transaction::goat::Module {
+                $module
+            }
+
+            fn action(&self) -> $crate::transaction::goat::Action {
+                $action
+            }
+
+            fn sender(&self) -> alloy_primitives::Address {
+                $sender
+            }
+
+            fn contract(&self) -> alloy_primitives::Address {
+                *Self::CONTRACT.address()
+            }
+
+            $($($goat_tx)*)?
+        }
+
+        impl alloy_rlp::Encodable for $name {
+            fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+                out.put_slice(&Self::METHOD_ID);
+                $($crate::transaction::goat::abi::AbiWord::encode_word(&self.$field, out);)+
+            }
+
+            fn length(&self) -> usize {
+                Self::SIZE
+            }
+        }
+
+        impl alloy_rlp::Decodable for $name {
+            fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+                #[allow(non_camel_case_types)]
+                enum Index {
+                    $($field),+
+                }
+
+                let calldata = $crate::transaction::goat::abi::FixedCalldata::<
+                    { $name::FIELDS.len() },
+                >::new(buf, Self::METHOD_ID)?;
+                Ok(Self { $($field: calldata.get::<{ Index::$field as usize }, $ty>()?),+ })
+            }
+        }
+    };
+}
+
+pub(super) use goat_action;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            decode_tx, Cancel2Tx, CompleteUnlockTx, DepositTx, DistributeRewardTx, GoatActionMeta,
+            NewBtcBlockTx, PaidTx, TxGoatInner, GOAT_ACTIONS,
+        },
+        FixedCalldata,
+    };
+    use alloc::vec::Vec;
+    use alloy_primitives::{Address, B256, U256};
+    use alloy_sol_types::{sol, SolCall};
+    use arbitrary::Unstructured;
+    use rand::Rng;
+
+    sol! {
+        function deposit(bytes32 txId, uint32 txOut, address target, uint256 amount, uint256 tax);
+        function cancel2(uint256 id);
+        function paid(uint256 id, bytes32 txId, uint32 txOut, uint256 amount);
+        function newBlockHash(bytes32 hash);
+        function completeUnlock(uint256 id, address recipient, address token, uint256 amount);
+        function distributeReward(uint256 id, address recipient, uint256 goat, uint256 gasReward);
+    }
+
+    /// Checks that the `sol!` generated decoder accepts the calldata and encodes it back to the
+    /// same bytes, and returns the calldata of the `sol!` generated encoder.
+    fn sol_calldata<C: SolCall>(call: C, meta: &GoatActionMeta, calldata: &[u8]) -> Vec<u8> {
+        assert_eq!(C::SIGNATURE, meta.signature);
+        assert_eq!(C::SELECTOR, meta.selector);
+        assert_eq!(C::abi_decode_validate(calldata).unwrap().abi_encode(), calldata);
+        call.abi_encode()
+    }
+
+    /// Returns the calldata of the action encoded by the `sol!` generated encoder.
+    fn sol_encode(inner: &TxGoatInner) -> Vec<u8> {
+        let meta = GoatActionMeta::find(inner.module(), inner.action()).unwrap();
+        let calldata = &inner.input()[..];
+        match *inner {
+            TxGoatInner::Deposit(tx) => sol_calldata(
+                depositCall {
+                    txId: tx.tx_id,
+                    txOut: tx.tx_out,
+                    target: tx.target,
+                    amount: tx.amount,
+                    tax: tx.tax,
+                },
+                meta,
+                calldata,
+            ),
+            TxGoatInner::Cancel2(tx) => sol_calldata(cancel2Call { id: tx.id }, meta, calldata),
+            TxGoatInner::Paid(tx) => sol_calldata(
+                paidCall { id: tx.id, txId: tx.tx_id, txOut: tx.tx_out, amount: tx.amount },
+                meta,
+                calldata,
+            ),
+            TxGoatInner::NewBtcBlock(tx) => {
+                sol_calldata(newBlockHashCall { hash: tx.hash }, meta, calldata)
+            }
+            TxGoatInner::CompleteUnlock(tx) => sol_calldata(
+                completeUnlockCall {
+                    id: tx.id,
+                    recipient: tx.recipient,
+                    token: tx.token,
+                    amount: tx.amount,
+                },
+                meta,
+                calldata,
+            ),
+            TxGoatInner::DistributeReward(tx) => sol_calldata(
+                distributeRewardCall {
+                    id: tx.id,
+                    recipient: tx.recipient,
+                    goat: tx.goat,
+                    gasReward: tx.gas_reward,
+                },
+                meta,
+                calldata,
+            ),
+        }
+    }
+
+    /// Returns one action of every kind, with arbitrary fields.
+    fn arbitrary_actions(u: &mut Unstructured<'_>) -> [TxGoatInner; 6] {
+        [
+            TxGoatInner::Deposit(u.arbitrary::<DepositTx>().unwrap()),
+            TxGoatInner::Cancel2(u.arbitrary::<Cancel2Tx>().unwrap()),
+            TxGoatInner::Paid(u.arbitrary::<PaidTx>().unwrap()),
+            TxGoatInner::NewBtcBlock(u.arbitrary::<NewBtcBlockTx>().unwrap()),
+            TxGoatInner::CompleteUnlock(u.arbitrary::<CompleteUnlockTx>().unwrap()),
+            TxGoatInner::DistributeReward(u.arbitrary::<DistributeRewardTx>().unwrap()),
+        ]
+    }
+
+    #[test]
+    fn calldata_matches_solidity_abi() {
+        let mut bytes = [0u8; 1024];
+        for _ in 0..256 {
+            rand::thread_rng().fill(bytes.as_mut_slice());
+            let actions = arbitrary_actions(&mut Unstructured::new(&bytes));
+
+            // every supported action is covered
+            let mut kinds = actions.iter().map(TxGoatInner::kind).collect::<Vec<_>>();
+            kinds.dedup();
+            assert_eq!(kinds.len(), GOAT_ACTIONS.len());
+
+            for inner in actions {
+                let calldata = sol_encode(&inner);
+                assert_eq!(inner.input(), calldata);
+
+                // encode(decode(x)) == x
+                let decoded = decode_tx(inner.module(), inner.action(), &calldata).unwrap();
+                assert_eq!(decoded, inner);
+                assert_eq!(decoded.input(), calldata);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_calldata() {
+        let mut calldata = alloc::vec![0u8; super::SELECTOR + 2 * super::WORD + 1];
+        calldata[..super::SELECTOR].copy_from_slice(&[1, 2, 3, 4]);
+        calldata[super::SELECTOR + super::WORD - 1] = 7;
+        calldata[super::SELECTOR + super::WORD] = 1;
+
+        let mut buf = &calldata[..];
+        let words = FixedCalldata::<2>::new(&mut buf, [1, 2, 3, 4]).unwrap();
+        assert_eq!(buf, [0]);
+        assert_eq!(words.get::<0, u32>(), Ok(7));
+        assert_eq!(words.get::<0, U256>(), Ok(U256::from(7)));
+        assert_eq!(words.get::<1, u32>(), Err(alloy_rlp::Error::Overflow));
+        assert!(words.get::<1, Address>().is_err());
+        assert_eq!(words.get::<1, B256>().unwrap()[0], 1);
+
+        assert_eq!(
+            FixedCalldata::<3>::new(&mut &calldata[..], [1, 2, 3, 4]).unwrap_err(),
+            alloy_rlp::Error::InputTooShort
+        );
+        assert!(FixedCalldata::<2>::new(&mut &calldata[..], [4, 3, 2, 1]).is_err());
+    }
+
+    #[test]
+    fn arbitrary_calldata_roundtrip() {
+        let mut rng = rand::thread_rng();
+        for meta in GOAT_ACTIONS {
+            let size = meta.fixed_size.unwrap();
+            for _ in 0..256 {
+                let mut calldata = alloc::vec![0u8; size];
+                rng.fill(&mut calldata[super::SELECTOR..]);
+                calldata[..super::SELECTOR].copy_from_slice(meta.selector.as_slice());
+                // clear the padding of some words, so that narrow words decode as well
+                for word in calldata[super::SELECTOR..].chunks_mut(super::WORD) {
+                    if rng.gen_bool(0.5) {
+                        word[..super::WORD - 4].fill(0);
+                    }
+                }
+
+                // calldata that decodes encodes to the same bytes
+                if let Ok(inner) = decode_tx(meta.module, meta.action, &calldata) {
+                    assert_eq!(inner.input(), calldata);
+                    assert_eq!(sol_encode(&inner), calldata);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Conversions between bitcoin amounts and the native token amounts of goat.
+
+use super::DepositTx;
+use alloy_primitives::U256;
+use core::fmt;
+
+/// The number of wei per satoshi.
+///
+/// Bitcoin amounts have 8 decimals, while the native token of goat has 18 decimals.
+pub const WEI_PER_SAT: U256 = U256::from_limbs([10_000_000_000, 0, 0, 0]);
+
+/// Converts an amount of satoshis to wei.
+///
+/// This cannot overflow, as any `u64` amount of satoshis fits in a [`U256`] amount of wei.
+pub fn sats_to_wei(sats: u64) -> U256 {
+    U256::from(sats) * WEI_PER_SAT
+}
+
+/// Converts an amount of wei to satoshis.
+///
+/// Returns an error if the amount is not a whole number of satoshis, instead of rounding, or if it
+/// does not fit in a `u64` amount of satoshis.
+pub fn wei_to_sats(wei: U256) -> Result<u64, SatConversionError> {
+    let (sats, rem) = wei.div_rem(WEI_PER_SAT);
+    if !rem.is_zero() {
+        return Err(SatConversionError::Unaligned(wei));
+    }
+    u64::try_from(sats).map_err(|_| SatConversionError::Overflow(wei))
+}
+
+/// Error returned when converting an amount of wei to satoshis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SatConversionError {
+    /// The amount is not a whole number of satoshis.
+    #[error("{0} wei is not a whole number of satoshis")]
+    Unaligned(U256),
+    /// The amount exceeds the range of satoshi amounts.
+    #[error("{0} wei exceeds the range of satoshi amounts")]
+    Overflow(U256),
+}
+
+/// An amount of satoshis.
+///
+/// Serialized as a plain number, like bitcoin amounts in the APIs of bitcoin nodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SatAmount(pub u64);
+
+impl SatAmount {
+    /// The zero amount.
+    pub const ZERO: Self = Self(0);
+
+    /// Creates a new amount of satoshis.
+    pub const fn new(sats: u64) -> Self {
+        Self(sats)
+    }
+
+    /// Returns the amount of satoshis.
+    pub const fn sats(self) -> u64 {
+        self.0
+    }
+
+    /// Converts the amount to wei.
+    pub fn to_wei(self) -> U256 {
+        sats_to_wei(self.0)
+    }
+
+    /// Converts an amount of wei to satoshis, see [`wei_to_sats`].
+    pub fn from_wei(wei: U256) -> Result<Self, SatConversionError> {
+        wei_to_sats(wei).map(Self)
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(sats) => Some(Self(sats)),
+            None => None,
+        }
+    }
+
+    /// Subtracts two amounts, returning `None` on underflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(sats) => Some(Self(sats)),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for SatAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sat", self.0)
+    }
+}
+
+impl From<u64> for SatAmount {
+    fn from(sats: u64) -> Self {
+        Self(sats)
+    }
+}
+
+impl From<SatAmount> for u64 {
+    fn from(amount: SatAmount) -> Self {
+        amount.0
+    }
+}
+
+impl From<SatAmount> for U256 {
+    fn from(amount: SatAmount) -> Self {
+        amount.to_wei()
+    }
+}
+
+impl TryFrom<U256> for SatAmount {
+    type Error = SatConversionError;
+
+    fn try_from(wei: U256) -> Result<Self, Self::Error> {
+        Self::from_wei(wei)
+    }
+}
+
+impl DepositTx {
+    /// Returns the deposited bitcoin amount, i.e. the minted amount plus the bridge tax, in
+    /// satoshis.
+    ///
+    /// Both amounts originate from a bitcoin output, so their sum must be a whole number of
+    /// satoshis. Otherwise the deposit was converted with a rounding error, which is returned as
+    /// [`SatConversionError::Unaligned`].
+    pub fn deposited_sats(&self) -> Result<SatAmount, SatConversionError> {
+        let total =
+            self.amount.checked_add(self.tax).ok_or(SatConversionError::Overflow(U256::MAX))?;
+        SatAmount::from_wei(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sat_conversions() {
+        assert_eq!(sats_to_wei(1), U256::from(10u64.pow(10)));
+        assert_eq!(sats_to_wei(u64::MAX), U256::from(u64::MAX) * WEI_PER_SAT);
+        assert_eq!(wei_to_sats(sats_to_wei(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(wei_to_sats(U256::ZERO), Ok(0));
+
+        let unaligned = sats_to_wei(5) + U256::from(1);
+        assert_eq!(wei_to_sats(unaligned), Err(SatConversionError::Unaligned(unaligned)));
+        let overflow = sats_to_wei(u64::MAX) + WEI_PER_SAT;
+        assert_eq!(wei_to_sats(overflow), Err(SatConversionError::Overflow(overflow)));
+
+        let amount = SatAmount::new(150_000);
+        assert_eq!(SatAmount::try_from(amount.to_wei()), Ok(amount));
+        assert_eq!(amount.checked_add(SatAmount::new(u64::MAX)), None);
+        assert_eq!(SatAmount::ZERO.checked_sub(amount), None);
+        assert_eq!(amount.to_string(), "150000 sat");
+    }
+
+    #[test]
+    fn deposited_sats() {
+        let mut deposit = DepositTx {
+            amount: sats_to_wei(99_000),
+            tax: sats_to_wei(1_000),
+            ..Default::default()
+        };
+        assert_eq!(deposit.deposited_sats(), Ok(SatAmount::new(100_000)));
+
+        // a tax rounded in wei instead of satoshis
+        deposit.tax -= U256::from(1);
+        assert!(matches!(deposit.deposited_sats(), Err(SatConversionError::Unaligned(_))));
+
+        deposit.amount = U256::MAX;
+        assert!(matches!(deposit.deposited_sats(), Err(SatConversionError::Overflow(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn sat_amount_serde() {
+        let amount = SatAmount::new(21);
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "21");
+        assert_eq!(serde_json::from_str::<SatAmount>("21").unwrap(), amount);
+    }
+}
@@ -0,0 +1,187 @@
+//! Tracking of the bitcoin blocks anchored by the relayer.
+
+use super::{AsTxGoat, GoatTxIterExt, NewBtcBlockTx};
+use crate::{Block, BlockHeader};
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    vec::Vec,
+};
+use alloy_primitives::{BlockNumber, B256};
+
+/// Errors returned by [`BtcAnchorTracker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BtcAnchorError {
+    /// The bitcoin block was already submitted within the window.
+    #[error("bitcoin block {hash} was already submitted in block {first}")]
+    Duplicate {
+        /// The hash of the bitcoin block.
+        hash: B256,
+        /// The block in which the bitcoin block was first submitted.
+        first: BlockNumber,
+    },
+    /// The submission is from an older block than the last ingested one.
+    #[error("block {got} is older than the last ingested block {last}")]
+    NonMonotonic {
+        /// The last ingested block.
+        last: BlockNumber,
+        /// The block of the submission.
+        got: BlockNumber,
+    },
+}
+
+/// Tracks the bitcoin blocks submitted by [`NewBtcBlockTx`]s over a sliding window of blocks,
+/// rejecting duplicate submissions.
+///
+/// Blocks must be ingested in ascending order. Submissions older than `window` blocks behind the
+/// last ingested block are forgotten.
+#[derive(Clone, Debug)]
+pub struct BtcAnchorTracker {
+    window: u64,
+    last: Option<BlockNumber>,
+    anchors: VecDeque<(BlockNumber, B256)>,
+    submitted: BTreeMap<B256, BlockNumber>,
+}
+
+impl BtcAnchorTracker {
+    /// Creates a tracker remembering the submissions of the last `window` blocks.
+    pub const fn new(window: u64) -> Self {
+        Self { window, last: None, anchors: VecDeque::new(), submitted: BTreeMap::new() }
+    }
+
+    /// Ingests the submissions of a block.
+    ///
+    /// On error, the tracker is left unchanged.
+    pub fn push_block<T: AsTxGoat, H: BlockHeader>(
+        &mut self,
+        block: &Block<T, H>,
+    ) -> Result<(), BtcAnchorError> {
+        let txs = block.body.transactions.iter().goat_txs();
+        let txs = txs.filter_map(|(_, tx)| tx.inner.as_new_btc_block()).collect::<Vec<_>>();
+        self.push_txs(block.header.number(), txs)
+    }
+
+    /// Ingests the submissions of the block with the given number.
+    ///
+    /// On error, the tracker is left unchanged.
+    pub fn push_txs<'a>(
+        &mut self,
+        number: BlockNumber,
+        txs: impl IntoIterator<Item = &'a NewBtcBlockTx>,
+    ) -> Result<(), BtcAnchorError> {
+        if let Some(last) = self.last.filter(|last| number < *last) {
+            return Err(BtcAnchorError::NonMonotonic { last, got: number });
+        }
+
+        let hashes = txs.into_iter().map(|tx| tx.hash).collect::<Vec<_>>();
+        for (i, hash) in hashes.iter().enumerate() {
+            if let Some(&first) = self.submitted.get(hash) {
+                return Err(BtcAnchorError::Duplicate { hash: *hash, first });
+            }
+            if hashes[..i].contains(hash) {
+                return Err(BtcAnchorError::Duplicate { hash: *hash, first: number });
+            }
+        }
+
+        self.last = Some(number);
+        for hash in hashes {
+            self.anchors.push_back((number, hash));
+            self.submitted.insert(hash, number);
+        }
+        while let Some(&(oldest, hash)) = self.anchors.front() {
+            if oldest.saturating_add(self.window) > number {
+                break;
+            }
+            self.anchors.pop_front();
+            self.submitted.remove(&hash);
+        }
+        Ok(())
+    }
+
+    /// Returns the last submitted bitcoin block within the window, with the block submitting it.
+    pub fn tip(&self) -> Option<(BlockNumber, B256)> {
+        self.anchors.back().copied()
+    }
+
+    /// Returns the block in which the bitcoin block was submitted, if it is within the window.
+    pub fn submitted_in(&self, hash: &B256) -> Option<BlockNumber> {
+        self.submitted.get(hash).copied()
+    }
+
+    /// Returns the last ingested block.
+    pub const fn last_block(&self) -> Option<BlockNumber> {
+        self.last
+    }
+
+    /// Returns the number of submissions within the window.
+    pub fn len(&self) -> usize {
+        self.anchors.len()
+    }
+
+    /// Returns true if there is no submission within the window.
+    pub fn is_empty(&self) -> bool {
+        self.anchors.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchor(n: u8) -> NewBtcBlockTx {
+        NewBtcBlockTx { hash: B256::with_last_byte(n) }
+    }
+
+    #[test]
+    fn btc_anchor_tracker() {
+        let mut tracker = BtcAnchorTracker::new(3);
+        tracker.push_txs(10, &[anchor(1)]).unwrap();
+        tracker.push_txs(11, &[]).unwrap();
+        tracker.push_txs(12, &[anchor(2)]).unwrap();
+        assert_eq!(tracker.tip(), Some((12, B256::with_last_byte(2))));
+
+        let duplicate = Err(BtcAnchorError::Duplicate { hash: B256::with_last_byte(1), first: 10 });
+        assert_eq!(tracker.push_txs(12, &[anchor(3), anchor(1)]), duplicate);
+        assert_eq!(
+            tracker.push_txs(9, &[anchor(4)]),
+            Err(BtcAnchorError::NonMonotonic { last: 12, got: 9 })
+        );
+        assert_eq!(
+            tracker.push_txs(13, &[anchor(4), anchor(4)]),
+            Err(BtcAnchorError::Duplicate { hash: B256::with_last_byte(4), first: 13 })
+        );
+        // failed submissions leave the tracker unchanged
+        assert_eq!((tracker.len(), tracker.last_block()), (2, Some(12)));
+        assert_eq!(tracker.submitted_in(&B256::with_last_byte(3)), None);
+
+        // the submission of block 10 leaves the window
+        tracker.push_txs(13, &[anchor(3)]).unwrap();
+        assert_eq!(tracker.submitted_in(&B256::with_last_byte(1)), None);
+        assert_eq!(tracker.submitted_in(&B256::with_last_byte(2)), Some(12));
+        tracker.push_txs(14, &[anchor(1)]).unwrap();
+        assert_eq!(tracker.len(), 3);
+    }
+
+    #[test]
+    fn btc_anchor_tracker_blocks() {
+        use crate::{
+            transaction::goat::test_utils::new_btc_block_tx, BlockBody, Header, TxEnvelope,
+        };
+
+        let block = |number, hashes: &[u8]| Block::<TxEnvelope> {
+            header: Header { number, ..Default::default() },
+            body: BlockBody {
+                transactions: hashes
+                    .iter()
+                    .map(|n| new_btc_block_tx(0, anchor(*n).hash).into())
+                    .collect(),
+                ommers: Vec::new(),
+                withdrawals: None,
+            },
+        };
+        let mut tracker = BtcAnchorTracker::new(64);
+        tracker.push_block(&block(1, &[1])).unwrap();
+        tracker.push_block(&block(2, &[2])).unwrap();
+        assert!(tracker.push_block(&block(3, &[1])).is_err());
+        assert_eq!(tracker.tip(), Some((2, B256::with_last_byte(2))));
+    }
+}
@@ -0,0 +1,264 @@
+//! Bridge module actions.
+
+use super::{
+    abi::goat_action, GoatContract, Mint, BRIDGE_CANCEL2_ACTION, BRIDGE_DEPOSIT_ACTION,
+    BRIDGE_MODULE, BRIDGE_PAID_ACTION, RELAYER_EXECUTOR,
+};
+use alloc::collections::BTreeSet;
+use alloy_primitives::{Address, TxHash, B256, U256};
+
+goat_action! {
+    /// A bitcoin deposit, minting the deposited amount to the target.
+    ///
+    /// Calldata of `deposit(bytes32,uint32,address,uint256,uint256)`.
+    ///
+    /// The `txid` and `txout` spellings of older goat-geth versions are accepted when
+    /// deserializing.
+    pub struct DepositTx {
+        /// The bitcoin transaction id of the deposit.
+        #[cfg_attr(feature = "serde", serde(alias = "txid"))]
+        pub tx_id: B256,
+        /// The output index of the deposit in the bitcoin transaction.
+        #[cfg_attr(feature = "serde", serde(alias = "txout"))]
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub tx_out: u32,
+        /// The recipient of the deposit.
+        pub target: Address,
+        /// The deposited amount, in wei.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub amount: U256,
+        /// The bridge tax taken from the deposit, in wei.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub tax: U256,
+    }
+
+    signature = "deposit(bytes32,uint32,address,uint256,uint256)",
+    method_id = [0x90, 0x41, 0x83, 0xcb],
+    module = BRIDGE_MODULE,
+    action = BRIDGE_DEPOSIT_ACTION,
+    sender = RELAYER_EXECUTOR,
+    contract = GoatContract::Bridge,
+    impl GoatTx {
+        fn deposit(&self) -> Option<Mint> {
+            Some(Mint { target: self.target, amount: self.amount, tax: self.tax })
+        }
+    }
+}
+
+impl DepositTx {
+    /// Returns the bitcoin outpoint of the deposit, which must only be processed once.
+    pub const fn dedup_key(&self) -> (TxHash, u32) {
+        (self.tx_id, self.tx_out)
+    }
+
+    /// Returns the [`DepositKey`] of the deposit.
+    pub const fn deposit_key(&self) -> DepositKey {
+        DepositKey(self.tx_id, self.tx_out)
+    }
+}
+
+/// The bitcoin outpoint of a deposit, i.e. its bitcoin transaction id and output index.
+///
+/// A deposit must only be processed once, see [`DepositSet`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositKey(pub TxHash, pub u32);
+
+impl DepositKey {
+    /// Returns the bitcoin transaction id of the deposit.
+    pub const fn tx_id(&self) -> TxHash {
+        self.0
+    }
+
+    /// Returns the output index of the deposit in the bitcoin transaction.
+    pub const fn tx_out(&self) -> u32 {
+        self.1
+    }
+}
+
+impl From<(TxHash, u32)> for DepositKey {
+    fn from((tx_id, tx_out): (TxHash, u32)) -> Self {
+        Self(tx_id, tx_out)
+    }
+}
+
+impl From<&DepositTx> for DepositKey {
+    fn from(deposit: &DepositTx) -> Self {
+        deposit.deposit_key()
+    }
+}
+
+/// A set of processed deposits, keyed by their [`DepositKey`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DepositSet {
+    keys: BTreeSet<DepositKey>,
+}
+
+impl DepositSet {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self { keys: BTreeSet::new() }
+    }
+
+    /// Inserts a deposit if it is absent.
+    ///
+    /// Returns false if the deposit has already been inserted, in which case it must not be
+    /// processed again.
+    pub fn insert(&mut self, deposit: impl Into<DepositKey>) -> bool {
+        self.keys.insert(deposit.into())
+    }
+
+    /// Returns true if the deposit has been inserted.
+    pub fn contains(&self, deposit: impl Into<DepositKey>) -> bool {
+        self.keys.contains(&deposit.into())
+    }
+
+    /// Returns the number of deposits in the set.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns an iterator over the deposits in the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &DepositKey> {
+        self.keys.iter()
+    }
+}
+
+impl<K: Into<DepositKey>> FromIterator<K> for DepositSet {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        Self { keys: iter.into_iter().map(Into::into).collect() }
+    }
+}
+
+impl<K: Into<DepositKey>> Extend<K> for DepositSet {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        self.keys.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+goat_action! {
+    /// The cancellation of a pending withdrawal.
+    ///
+    /// Calldata of `cancel2(uint256)`.
+    pub struct Cancel2Tx {
+        /// The withdrawal id.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub id: U256,
+    }
+
+    signature = "cancel2(uint256)",
+    method_id = [0xc1, 0x9d, 0xd3, 0x20],
+    module = BRIDGE_MODULE,
+    action = BRIDGE_CANCEL2_ACTION,
+    sender = RELAYER_EXECUTOR,
+    contract = GoatContract::Bridge,
+}
+
+goat_action! {
+    /// The payment of a withdrawal on bitcoin.
+    ///
+    /// Calldata of `paid(uint256,bytes32,uint32,uint256)`.
+    ///
+    /// The `txid` and `txout` spellings of older goat-geth versions are accepted when
+    /// deserializing.
+    pub struct PaidTx {
+        /// The withdrawal id.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub id: U256,
+        /// The bitcoin transaction id of the payment.
+        #[cfg_attr(feature = "serde", serde(alias = "txid"))]
+        pub tx_id: B256,
+        /// The output index of the payment in the bitcoin transaction.
+        #[cfg_attr(feature = "serde", serde(alias = "txout"))]
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub tx_out: u32,
+        /// The paid amount, in wei.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub amount: U256,
+    }
+
+    signature = "paid(uint256,bytes32,uint32,uint256)",
+    method_id = [0xb6, 0x70, 0xab, 0x5e],
+    module = BRIDGE_MODULE,
+    action = BRIDGE_PAID_ACTION,
+    sender = RELAYER_EXECUTOR,
+    contract = GoatContract::Bridge,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deposit_set() {
+        let deposit = DepositTx { tx_id: B256::with_last_byte(1), tx_out: 2, ..Default::default() };
+        assert_eq!(deposit.dedup_key(), (B256::with_last_byte(1), 2));
+
+        let mut set = DepositSet::new();
+        assert!(set.insert(&deposit));
+        // the same outpoint with a different amount is still a duplicate
+        assert!(!set.insert(&DepositTx { amount: U256::from(1), ..deposit }));
+        assert!(set.insert(DepositKey(deposit.tx_id, 3)));
+        assert!(set.contains(deposit.dedup_key()));
+        assert!(!set.contains((B256::ZERO, 2)));
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [deposit.deposit_key(), DepositKey(deposit.tx_id, 3)]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_legacy_field_names() {
+        let deposit = DepositTx {
+            tx_id: B256::with_last_byte(1),
+            tx_out: 2,
+            target: Address::with_last_byte(3),
+            amount: U256::from(4),
+            tax: U256::from(5),
+        };
+        let json = serde_json::to_value(deposit).unwrap();
+        assert_eq!(json["txId"], serde_json::json!(B256::with_last_byte(1)));
+        assert_eq!(json["txOut"], "0x2");
+
+        let legacy = r#"{"txid":"0x0000000000000000000000000000000000000000000000000000000000000001","txout":2,"target":"0x0000000000000000000000000000000000000003","amount":"0x4","tax":"0x5"}"#;
+        assert_eq!(serde_json::from_str::<DepositTx>(legacy).unwrap(), deposit);
+        assert_eq!(serde_json::from_value::<DepositTx>(json).unwrap(), deposit);
+
+        // numeric fields as decimal strings
+        let decimal = r#"{"txId":"0x0000000000000000000000000000000000000000000000000000000000000001","txOut":"2","target":"0x0000000000000000000000000000000000000003","amount":"4","tax":"5"}"#;
+        assert_eq!(serde_json::from_str::<DepositTx>(decimal).unwrap(), deposit);
+
+        let paid = PaidTx {
+            id: U256::from(1),
+            tx_id: B256::with_last_byte(2),
+            tx_out: 3,
+            amount: U256::from(4),
+        };
+        let json = serde_json::to_value(paid).unwrap();
+        assert_eq!(
+            json.as_object().unwrap().keys().collect::<Vec<_>>(),
+            ["amount", "id", "txId", "txOut"]
+        );
+
+        let legacy = r#"{"id":"0x1","txid":"0x0000000000000000000000000000000000000000000000000000000000000002","txout":3,"amount":"0x4"}"#;
+        assert_eq!(serde_json::from_str::<PaidTx>(legacy).unwrap(), paid);
+        assert_eq!(serde_json::from_value::<PaidTx>(json).unwrap(), paid);
+
+        let decimal = r#"{"id":"1","txId":"0x0000000000000000000000000000000000000000000000000000000000000002","txOut":"3","amount":"4"}"#;
+        assert_eq!(serde_json::from_str::<PaidTx>(decimal).unwrap(), paid);
+    }
+}
@@ -0,0 +1,97 @@
+//! Canonical JSON encoding of goat payloads, see [`to_canonical_json`].
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Encodes the value as canonical JSON, so that independent signers hash identical bytes.
+///
+/// The canonical encoding has no whitespace, sorts the keys of all objects by their bytes, and
+/// lowercases all `0x`-prefixed hex strings, including keys, e.g. checksummed addresses. The
+/// order of the keys of [`serde_json::Map`] depends on the `preserve_order` feature of
+/// `serde_json`, which may be enabled by any crate of the dependency graph, so the plain serde
+/// output is not deterministic.
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    let value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_value(&value, &mut out);
+    Ok(out)
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries =
+                map.iter().map(|(key, value)| (lower_hex(key), value)).collect::<Vec<_>>();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_str(&key, out);
+                out.push(':');
+                write_value(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(value, out);
+            }
+            out.push(']');
+        }
+        Value::String(s) => write_str(&lower_hex(s), out),
+        Value::Null | Value::Bool(_) | Value::Number(_) => out.push_str(&value.to_string()),
+    }
+}
+
+fn write_str(s: &str, out: &mut String) {
+    out.push_str(&Value::from(s).to_string());
+}
+
+/// Lowercases the string if it is `0x`-prefixed hex.
+fn lower_hex(s: &str) -> String {
+    if s.strip_prefix("0x").is_some_and(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit())) {
+        s.to_ascii_lowercase()
+    } else {
+        s.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[derive(Serialize)]
+    struct Payload {
+        zeta: u64,
+        alpha: &'static str,
+        nested: Value,
+    }
+
+    #[test]
+    fn canonical_json() {
+        let payload = Payload {
+            zeta: 1,
+            alpha: "0xABcd",
+            nested: serde_json::json!({
+                "b": [true, null, "Quote \" 0xNotHex"],
+                "0xAB": address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").to_checksum(None),
+                "a": -1.5,
+            }),
+        };
+        assert_eq!(
+            to_canonical_json(&payload).unwrap(),
+            r#"{"alpha":"0xabcd","nested":{"0xab":"0xd8da6bf26964af9d7eed9e03e53415d37aa96045","a":-1.5,"b":[true,null,"Quote \" 0xNotHex"]},"zeta":1}"#
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Columnar export of bitcoin deposits.
+
+use super::{AsTxGoat, DepositTx, GoatTxIterExt};
+use crate::Block;
+use alloc::vec::Vec;
+use alloy_primitives::{Address, TxHash, U256};
+
+/// Bitcoin deposits stored column by column, e.g. for analytics pipelines ingesting the bridge
+/// history.
+///
+/// The `i`-th deposit is made of the `i`-th element of every column, all columns have the same
+/// length. With the `arrow` feature, the columns can be converted into an Arrow record batch, see
+/// [`DepositColumns::to_record_batch`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DepositColumns {
+    /// The bitcoin transaction ids of the deposits.
+    pub tx_ids: Vec<TxHash>,
+    /// The output indexes of the deposits in their bitcoin transaction.
+    pub tx_outs: Vec<u32>,
+    /// The recipients of the deposits.
+    pub targets: Vec<Address>,
+    /// The deposited amounts, in wei.
+    pub amounts: Vec<U256>,
+    /// The bridge taxes taken from the deposits, in wei.
+    pub taxes: Vec<U256>,
+}
+
+impl DepositColumns {
+    /// Creates empty columns with room for the given number of deposits.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            tx_ids: Vec::with_capacity(capacity),
+            tx_outs: Vec::with_capacity(capacity),
+            targets: Vec::with_capacity(capacity),
+            amounts: Vec::with_capacity(capacity),
+            taxes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Collects the deposits of a block, in transaction order.
+    pub fn from_block<T: AsTxGoat, H>(block: &Block<T, H>) -> Self {
+        let mut columns = Self::default();
+        columns.push_block(block);
+        columns
+    }
+
+    /// Collects the deposits of the blocks, in block and transaction order.
+    pub fn from_blocks<'a, T, H>(blocks: impl IntoIterator<Item = &'a Block<T, H>>) -> Self
+    where
+        T: AsTxGoat + 'a,
+        H: 'a,
+    {
+        let mut columns = Self::default();
+        for block in blocks {
+            columns.push_block(block);
+        }
+        columns
+    }
+
+    /// Appends the deposits of a block, in transaction order.
+    pub fn push_block<T: AsTxGoat, H>(&mut self, block: &Block<T, H>) {
+        self.extend(block.body.transactions.iter().deposits().map(|(_, deposit)| deposit));
+    }
+
+    /// Appends a deposit.
+    pub fn push(&mut self, deposit: &DepositTx) {
+        self.tx_ids.push(deposit.tx_id);
+        self.tx_outs.push(deposit.tx_out);
+        self.targets.push(deposit.target);
+        self.amounts.push(deposit.amount);
+        self.taxes.push(deposit.tax);
+    }
+
+    /// Returns the deposit at the given index.
+    pub fn get(&self, index: usize) -> Option<DepositTx> {
+        Some(DepositTx {
+            tx_id: *self.tx_ids.get(index)?,
+            tx_out: *self.tx_outs.get(index)?,
+            target: *self.targets.get(index)?,
+            amount: *self.amounts.get(index)?,
+            tax: *self.taxes.get(index)?,
+        })
+    }
+
+    /// Returns the number of deposits.
+    pub const fn len(&self) -> usize {
+        self.tx_ids.len()
+    }
+
+    /// Returns true if there are no deposits.
+    pub const fn is_empty(&self) -> bool {
+        self.tx_ids.is_empty()
+    }
+
+    /// Returns an iterator over the deposits, in order.
+    pub fn iter(&self) -> impl Iterator<Item = DepositTx> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+}
+
+impl<'a> Extend<&'a DepositTx> for DepositColumns {
+    fn extend<I: IntoIterator<Item = &'a DepositTx>>(&mut self, iter: I) {
+        for deposit in iter {
+            self.push(deposit);
+        }
+    }
+}
+
+impl Extend<DepositTx> for DepositColumns {
+    fn extend<I: IntoIterator<Item = DepositTx>>(&mut self, iter: I) {
+        for deposit in iter {
+            self.push(&deposit);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a DepositTx> for DepositColumns {
+    fn from_iter<I: IntoIterator<Item = &'a DepositTx>>(iter: I) -> Self {
+        let mut columns = Self::default();
+        columns.extend(iter);
+        columns
+    }
+}
+
+impl FromIterator<DepositTx> for DepositColumns {
+    fn from_iter<I: IntoIterator<Item = DepositTx>>(iter: I) -> Self {
+        let mut columns = Self::default();
+        columns.extend(iter);
+        columns
+    }
+}
+
+#[cfg(feature = "arrow")]
+mod arrow {
+    use super::DepositColumns;
+    use alloc::{sync::Arc, vec, vec::Vec};
+    use arrow_array::{ArrayRef, FixedSizeBinaryArray, RecordBatch, UInt32Array};
+    use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+    impl DepositColumns {
+        /// Returns the Arrow schema of [`DepositColumns::to_record_batch`].
+        ///
+        /// Hashes and addresses are fixed size binaries. Amounts are 32-byte big-endian fixed size
+        /// binaries, since a `U256` does not fit the 76 digits of an Arrow `Decimal256`.
+        pub fn arrow_schema() -> SchemaRef {
+            Arc::new(Schema::new(vec![
+                Field::new("tx_id", DataType::FixedSizeBinary(32), false),
+                Field::new("tx_out", DataType::UInt32, false),
+                Field::new("target", DataType::FixedSizeBinary(20), false),
+                Field::new("amount", DataType::FixedSizeBinary(32), false),
+                Field::new("tax", DataType::FixedSizeBinary(32), false),
+            ]))
+        }
+
+        /// Converts the columns into an Arrow record batch, e.g. to write them to Parquet.
+        pub fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+            let binary = |size: i32, values: Vec<&[u8]>| -> Result<ArrayRef, ArrowError> {
+                Ok(Arc::new(FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                    values.into_iter().map(Some),
+                    size,
+                )?))
+            };
+            let amounts = self.amounts.iter().map(|amount| amount.to_be_bytes::<32>());
+            let amounts = amounts.collect::<Vec<_>>();
+            let taxes = self.taxes.iter().map(|tax| tax.to_be_bytes::<32>()).collect::<Vec<_>>();
+
+            RecordBatch::try_new(
+                Self::arrow_schema(),
+                vec![
+                    binary(32, self.tx_ids.iter().map(|tx_id| tx_id.as_slice()).collect())?,
+                    Arc::new(UInt32Array::from(self.tx_outs.clone())),
+                    binary(20, self.targets.iter().map(|target| target.as_slice()).collect())?,
+                    binary(32, amounts.iter().map(|amount| amount.as_slice()).collect())?,
+                    binary(32, taxes.iter().map(|tax| tax.as_slice()).collect())?,
+                ],
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::{
+            test_utils::{new_btc_block_tx, signed_goat_tx},
+            TxGoatInner,
+        },
+        BlockBody, TxEnvelope,
+    };
+    use alloy_primitives::B256;
+
+    fn block(deposits: &[DepositTx]) -> Block<TxEnvelope> {
+        let mut transactions = vec![new_btc_block_tx(0, B256::ZERO).into()];
+        transactions.extend(
+            deposits.iter().zip(1..).map(|(deposit, nonce)| {
+                signed_goat_tx(nonce, TxGoatInner::Deposit(*deposit)).into()
+            }),
+        );
+        Block {
+            header: Default::default(),
+            body: BlockBody { transactions, ommers: Vec::new(), withdrawals: None },
+        }
+    }
+
+    fn deposit(n: u8) -> DepositTx {
+        DepositTx {
+            tx_id: B256::with_last_byte(n),
+            tx_out: n.into(),
+            target: Address::with_last_byte(n),
+            amount: U256::from(n) * U256::from(10),
+            tax: U256::from(n),
+        }
+    }
+
+    #[test]
+    fn deposit_columns() {
+        let blocks = [block(&[deposit(1), deposit(2)]), block(&[]), block(&[deposit(3)])];
+        let columns = DepositColumns::from_blocks(&blocks);
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns.tx_outs, [1, 2, 3]);
+        assert_eq!(columns.get(2), Some(deposit(3)));
+        assert_eq!(columns.get(3), None);
+        assert_eq!(columns.iter().collect::<Vec<_>>(), [deposit(1), deposit(2), deposit(3)]);
+        assert_eq!(columns.iter().collect::<DepositColumns>(), columns);
+        assert!(DepositColumns::from_block(&blocks[1]).is_empty());
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn deposit_columns_record_batch() {
+        use arrow_array::{Array, FixedSizeBinaryArray};
+
+        let columns = [deposit(1), deposit(2)].iter().collect::<DepositColumns>();
+        let batch = columns.to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema(), DepositColumns::arrow_schema());
+
+        let amounts =
+            batch.column(3).as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap().clone();
+        assert_eq!(U256::from_be_slice(amounts.value(1)), U256::from(20));
+        assert_eq!(amounts.null_count(), 0);
+        assert_eq!(DepositColumns::default().to_record_batch().unwrap().num_rows(), 0);
+    }
+}
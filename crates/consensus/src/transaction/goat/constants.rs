@@ -0,0 +1,391 @@
+//! GOAT protocol constants.
+//!
+//! The names mirror the identifiers used by goat-geth. The misspelled names under which earlier
+//! releases of the goat patch exported the bridge constants are kept as deprecated aliases, since
+//! downstream matches still refer to them.
+
+use alloy_primitives::{address, Address};
+use alloy_rlp::{BufMut, Decodable, Encodable};
+use core::fmt;
+
+/// Identifier for GOAT system transactions.
+pub const GOAT_TX_TYPE_ID: u8 = 0x60;
+
+/// Identifier of a GOAT system module.
+///
+/// Only the known modules can be represented, use [`Module::try_from`] to convert a raw
+/// identifier. It is encoded as a single byte.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Module(u8);
+
+/// Identifier of an action within a GOAT system [`Module`].
+///
+/// Only the identifiers of known actions can be represented, use [`Action::try_from`] to convert
+/// a raw identifier. It is encoded as a single byte.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Action(u8);
+
+/// The bridge module, handling bitcoin deposits and withdrawals.
+pub const BRIDGE_MODULE: Module = Module(1);
+
+/// Misspelled alias of [`BRIDGE_MODULE`], the name earlier releases of the goat patch exported.
+#[deprecated(note = "use `BRIDGE_MODULE` instead")]
+pub const BIRDGE_MODULE: Module = BRIDGE_MODULE;
+
+/// The relayer module, handling bitcoin block submissions.
+pub const RELAYER_MODULE: Module = Module(2);
+
+/// The locking module, handling validator unlocks and rewards.
+pub const LOCKING_MODULE: Module = Module(3);
+
+/// Deposit action of the [`BRIDGE_MODULE`].
+pub const BRIDGE_DEPOSIT_ACTION: Action = Action(1);
+
+/// Misspelled alias of [`BRIDGE_DEPOSIT_ACTION`], the name earlier releases of the goat patch
+/// exported.
+#[deprecated(note = "use `BRIDGE_DEPOSIT_ACTION` instead")]
+pub const BRIDGE_DEPOIT_ACTION: Action = BRIDGE_DEPOSIT_ACTION;
+
+/// Withdrawal cancellation action of the [`BRIDGE_MODULE`].
+pub const BRIDGE_CANCEL2_ACTION: Action = Action(2);
+
+/// Withdrawal payment action of the [`BRIDGE_MODULE`].
+pub const BRIDGE_PAID_ACTION: Action = Action(3);
+
+/// New bitcoin block action of the [`RELAYER_MODULE`].
+pub const RELAYER_NEW_BTC_BLOCK_ACTION: Action = Action(1);
+
+/// Unlock completion action of the [`LOCKING_MODULE`].
+pub const LOCKING_COMPLETE_UNLOCK_ACTION: Action = Action(1);
+
+/// Reward distribution action of the [`LOCKING_MODULE`].
+pub const LOCKING_DISTRIBUTE_REWARD_ACTION: Action = Action(2);
+
+/// Error returned when converting an unknown identifier into a [`Module`] or an [`Action`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum UnknownGoatId {
+    /// The identifier is not a known module.
+    #[error("unknown goat module {0}")]
+    Module(u8),
+    /// The identifier is not a known action.
+    #[error("unknown goat action {0}")]
+    Action(u8),
+}
+
+impl Module {
+    /// Returns the raw identifier of the module.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the symbolic name of the module.
+    pub const fn name(self) -> &'static str {
+        match self {
+            BRIDGE_MODULE => "BRIDGE",
+            RELAYER_MODULE => "RELAYER",
+            LOCKING_MODULE => "LOCKING",
+            _ => "UNKNOWN",
+        }
+    }
+
+    /// Returns the system executor sending the actions of the module.
+    pub const fn executor(self) -> Address {
+        match self {
+            LOCKING_MODULE => LOCKING_EXECUTOR,
+            _ => RELAYER_EXECUTOR,
+        }
+    }
+
+    /// Returns the module with the given symbolic name, see [`Module::name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        [BRIDGE_MODULE, RELAYER_MODULE, LOCKING_MODULE]
+            .into_iter()
+            .find(|module| module.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the action of the module with the given symbolic name, see
+    /// [`Module::action_name`].
+    pub fn action_from_name(self, name: &str) -> Option<Action> {
+        (1..=3).map(Action).find(|action| {
+            self.action_name(*action).is_some_and(|action| action.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Returns the symbolic name of an action of the module, if the module has this action.
+    pub const fn action_name(self, action: Action) -> Option<&'static str> {
+        Some(match (self, action) {
+            (BRIDGE_MODULE, BRIDGE_DEPOSIT_ACTION) => "DEPOSIT",
+            (BRIDGE_MODULE, BRIDGE_CANCEL2_ACTION) => "CANCEL2",
+            (BRIDGE_MODULE, BRIDGE_PAID_ACTION) => "PAID",
+            (RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION) => "NEW_BTC_BLOCK",
+            (LOCKING_MODULE, LOCKING_COMPLETE_UNLOCK_ACTION) => "COMPLETE_UNLOCK",
+            (LOCKING_MODULE, LOCKING_DISTRIBUTE_REWARD_ACTION) => "DISTRIBUTE_REWARD",
+            _ => return None,
+        })
+    }
+}
+
+impl Action {
+    /// Returns the raw identifier of the action.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the symbolic names of the action in the modules that define it.
+    ///
+    /// Action identifiers are only unique within a module, use [`Module::action_name`] for the
+    /// name of the action of a given module.
+    pub const fn names(self) -> &'static [&'static str] {
+        match self.0 {
+            1 => &["DEPOSIT", "NEW_BTC_BLOCK", "COMPLETE_UNLOCK"],
+            2 => &["CANCEL2", "DISTRIBUTE_REWARD"],
+            3 => &["PAID"],
+            _ => &[],
+        }
+    }
+}
+
+impl TryFrom<u8> for Module {
+    type Error = UnknownGoatId;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        match id {
+            1..=3 => Ok(Self(id)),
+            _ => Err(UnknownGoatId::Module(id)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Action {
+    type Error = UnknownGoatId;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        match id {
+            1..=3 => Ok(Self(id)),
+            _ => Err(UnknownGoatId::Action(id)),
+        }
+    }
+}
+
+impl From<Module> for u8 {
+    fn from(module: Module) -> Self {
+        module.0
+    }
+}
+
+impl From<Action> for u8 {
+    fn from(action: Action) -> Self {
+        action.0
+    }
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Debug for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Formats the names of the action in every module that defines it, separated by `|`, e.g.
+/// `CANCEL2|DISTRIBUTE_REWARD`.
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.names().iter().enumerate() {
+            if i > 0 {
+                f.write_str("|")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_goat_id {
+    ($ty:ident, $err:literal) => {
+        impl Encodable for $ty {
+            fn encode(&self, out: &mut dyn BufMut) {
+                self.0.encode(out)
+            }
+
+            fn length(&self) -> usize {
+                self.0.length()
+            }
+        }
+
+        impl Decodable for $ty {
+            fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+                Self::try_from(u8::decode(buf)?).map_err(|_| alloy_rlp::Error::Custom($err))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                alloy_serde::quantity::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let id: u8 = alloy_serde::quantity::deserialize(deserializer)?;
+                Self::try_from(id).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+impl_goat_id!(Module, "unknown goat module");
+impl_goat_id!(Action, "unknown goat action");
+
+/// The GOAT token predeploy.
+pub const GOAT_TOKEN_CONTRACT: Address = address!("0xbc10000000000000000000000000000000000001");
+
+/// The bridge predeploy.
+pub const BRIDGE_CONTRACT: Address = address!("0xbc10000000000000000000000000000000000003");
+
+/// The locking predeploy.
+pub const LOCKING_CONTRACT: Address = address!("0xbc10000000000000000000000000000000000004");
+
+/// The bitcoin block predeploy.
+pub const BITCOIN_CONTRACT: Address = address!("0xbc10000000000000000000000000000000000005");
+
+/// A GOAT predeploy.
+///
+/// The predeploys share all but the last byte of their address, so resolving an address with
+/// [`GoatContract::from_address`] is a single prefix comparison rather than one comparison per
+/// predeploy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GoatContract {
+    /// The [`GOAT_TOKEN_CONTRACT`].
+    GoatToken,
+    /// The [`BRIDGE_CONTRACT`].
+    Bridge,
+    /// The [`LOCKING_CONTRACT`].
+    Locking,
+    /// The [`BITCOIN_CONTRACT`].
+    Bitcoin,
+}
+
+impl GoatContract {
+    /// All predeploys, ordered by address.
+    pub const ALL: [Self; 4] = [Self::GoatToken, Self::Bridge, Self::Locking, Self::Bitcoin];
+
+    /// Returns the address of the predeploy.
+    ///
+    /// The reference is static, so it can be used as a map key without copying the address.
+    pub const fn address(self) -> &'static Address {
+        match self {
+            Self::GoatToken => &GOAT_TOKEN_CONTRACT,
+            Self::Bridge => &BRIDGE_CONTRACT,
+            Self::Locking => &LOCKING_CONTRACT,
+            Self::Bitcoin => &BITCOIN_CONTRACT,
+        }
+    }
+
+    /// Returns the predeploy at the given address, if any.
+    pub fn from_address(address: &Address) -> Option<Self> {
+        let (prefix, last) = address.split_at(Address::len_bytes() - 1);
+        if prefix != &GOAT_TOKEN_CONTRACT[..Address::len_bytes() - 1] {
+            return None;
+        }
+        Some(match last[0] {
+            0x01 => Self::GoatToken,
+            0x03 => Self::Bridge,
+            0x04 => Self::Locking,
+            0x05 => Self::Bitcoin,
+            _ => return None,
+        })
+    }
+}
+
+impl From<GoatContract> for Address {
+    fn from(contract: GoatContract) -> Self {
+        *contract.address()
+    }
+}
+
+/// The executor sending bridge and relayer system transactions.
+pub const RELAYER_EXECUTOR: Address = address!("0xbc10000000000000000000000000000000001000");
+
+/// The executor sending locking system transactions.
+pub const LOCKING_EXECUTOR: Address = address!("0xbc10000000000000000000000000000000001001");
+
+/// Returns `true` if the address is one of the GOAT system executors.
+#[inline]
+pub fn is_executor(address: &Address) -> bool {
+    *address == RELAYER_EXECUTOR || *address == LOCKING_EXECUTOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn goat_ids() {
+        assert_eq!(Module::try_from(1), Ok(BRIDGE_MODULE));
+        assert_eq!(Module::try_from(4), Err(UnknownGoatId::Module(4)));
+        assert_eq!(Action::try_from(0), Err(UnknownGoatId::Action(0)));
+        assert_eq!(u8::from(LOCKING_DISTRIBUTE_REWARD_ACTION), 2);
+        assert_eq!(BRIDGE_MODULE.executor(), RELAYER_EXECUTOR);
+        assert_eq!(RELAYER_MODULE.executor(), RELAYER_EXECUTOR);
+        assert_eq!(LOCKING_MODULE.executor(), LOCKING_EXECUTOR);
+
+        assert_eq!(format!("{RELAYER_MODULE:?}"), "RELAYER");
+        assert_eq!(
+            format!("{:?}", (LOCKING_MODULE, LOCKING_DISTRIBUTE_REWARD_ACTION)),
+            "(LOCKING, CANCEL2|DISTRIBUTE_REWARD)"
+        );
+        assert_eq!(BRIDGE_PAID_ACTION.to_string(), "PAID");
+        assert_eq!(
+            LOCKING_COMPLETE_UNLOCK_ACTION.names(),
+            ["DEPOSIT", "NEW_BTC_BLOCK", "COMPLETE_UNLOCK"]
+        );
+        assert_eq!(BRIDGE_MODULE.action_name(BRIDGE_PAID_ACTION), Some("PAID"));
+        assert_eq!(RELAYER_MODULE.action_name(BRIDGE_PAID_ACTION), None);
+
+        for contract in GoatContract::ALL {
+            assert_eq!(GoatContract::from_address(contract.address()), Some(contract));
+        }
+        assert!(GoatContract::ALL.windows(2).all(|pair| pair[0].address() < pair[1].address()));
+        assert_eq!(GoatContract::from_address(&RELAYER_EXECUTOR), None);
+        assert_eq!(GoatContract::from_address(&Address::ZERO), None);
+        assert_eq!(
+            GoatContract::from_address(&address!("0xbc10000000000000000000000000000000000002")),
+            None
+        );
+
+        assert_eq!(Module::from_name("bridge"), Some(BRIDGE_MODULE));
+        assert_eq!(Module::from_name("BIRDGE"), None);
+        assert_eq!(BRIDGE_MODULE.action_from_name("DEPOSIT"), Some(BRIDGE_DEPOSIT_ACTION));
+        assert_eq!(
+            LOCKING_MODULE.action_from_name("distribute_reward"),
+            Some(LOCKING_DISTRIBUTE_REWARD_ACTION)
+        );
+        assert_eq!(RELAYER_MODULE.action_from_name("DEPOSIT"), None);
+        #[allow(deprecated)]
+        {
+            assert_eq!(BIRDGE_MODULE, BRIDGE_MODULE);
+            assert_eq!(BRIDGE_DEPOIT_ACTION, BRIDGE_DEPOSIT_ACTION);
+        }
+
+        let mut buf = Vec::new();
+        RELAYER_MODULE.encode(&mut buf);
+        assert_eq!(buf, [2]);
+        assert_eq!(Module::decode(&mut &buf[..]), Ok(RELAYER_MODULE));
+        assert!(Action::decode(&mut &[0x80][..]).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! Field-wise comparison of goat calldata, see [`TxGoat::verify_input_matches_inner`].
+//!
+//! [`TxGoat::verify_input_matches_inner`]: super::TxGoat::verify_input_matches_inner
+
+use super::{
+    abi, Cancel2Tx, CompleteUnlockTx, DepositTx, DistributeRewardTx, NewBtcBlockTx, PaidTx,
+    TxGoatInner,
+};
+use alloc::vec::Vec;
+use alloy_primitives::Bytes;
+use core::fmt;
+
+/// A field of the input that differs from the encoding of the decoded action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalldataFieldDiff {
+    /// The name of the field, `selector` for the method id, or `trailing` for bytes past the
+    /// calldata of the action.
+    pub field: &'static str,
+    /// The bytes of the field in the encoding of the decoded action.
+    pub expected: Bytes,
+    /// The bytes of the field in the input, truncated if the input is too short.
+    pub got: Bytes,
+}
+
+impl fmt::Display for CalldataFieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (expected {}, got {})", self.field, self.expected, self.got)
+    }
+}
+
+/// The fields of the input that differ from the encoding of the decoded action, returned by
+/// [`TxGoat::verify_input_matches_inner`](super::TxGoat::verify_input_matches_inner).
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub struct CalldataDiff(pub Vec<CalldataFieldDiff>);
+
+impl fmt::Display for CalldataDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("goat input does not match the decoded action: ")?;
+        for (i, diff) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            diff.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the names of the calldata words of the action, in order.
+const fn field_names(inner: &TxGoatInner) -> &'static [&'static str] {
+    match inner {
+        TxGoatInner::Deposit(_) => DepositTx::FIELDS,
+        TxGoatInner::Cancel2(_) => Cancel2Tx::FIELDS,
+        TxGoatInner::Paid(_) => PaidTx::FIELDS,
+        TxGoatInner::NewBtcBlock(_) => NewBtcBlockTx::FIELDS,
+        TxGoatInner::CompleteUnlock(_) => CompleteUnlockTx::FIELDS,
+        TxGoatInner::DistributeReward(_) => DistributeRewardTx::FIELDS,
+    }
+}
+
+/// Compares the input against the encoding of the decoded action, field by field.
+pub(super) fn diff_calldata(inner: &TxGoatInner, input: &[u8]) -> Vec<CalldataFieldDiff> {
+    let expected = inner.input();
+    let segment = |data: &[u8], start: usize, end: usize| {
+        Bytes::copy_from_slice(&data[start.min(data.len())..end.min(data.len())])
+    };
+
+    let fields = field_names(inner).iter().enumerate().map(|(i, field)| {
+        let start = abi::SELECTOR + i * abi::WORD;
+        (*field, start, start + abi::WORD)
+    });
+    core::iter::once(("selector", 0, abi::SELECTOR))
+        .chain(fields)
+        .chain(core::iter::once(("trailing", expected.len(), input.len().max(expected.len()))))
+        .filter_map(|(field, start, end)| {
+            let expected = segment(&expected, start, end);
+            let got = segment(input, start, end);
+            (expected != got).then_some(CalldataFieldDiff { field, expected, got })
+        })
+        .collect()
+}
@@ -0,0 +1,220 @@
+//! Bulk encoding of goat system transactions.
+
+use super::{DepositTx, TxGoat, TxGoatInner, GOAT_ACTIONS};
+use crate::Signed;
+use alloc::vec::Vec;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Bytes, ChainId};
+use alloy_rlp::{bytes::BytesMut, Header};
+
+/// A reusable buffer for encoding goat system transactions.
+///
+/// The buffer keeps its allocation between transactions, so encoding many transactions one after
+/// another only allocates when a transaction is larger than all previous ones.
+#[derive(Clone, Debug, Default)]
+pub struct EncoderBuf {
+    buf: Vec<u8>,
+}
+
+impl EncoderBuf {
+    /// Creates a new, empty buffer.
+    pub const fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+
+    /// Creates a new, empty buffer with at least the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { buf: Vec::with_capacity(capacity) }
+    }
+
+    /// Encodes the transaction in its EIP-2718 form, replacing the previous contents of the
+    /// buffer, and returns the encoding.
+    pub fn encode(&mut self, tx: &Signed<TxGoat>) -> &[u8] {
+        tx.rlp_encode_into(&mut self.buf);
+        &self.buf
+    }
+
+    /// Encodes the transactions with [`encode_goat_txs`], replacing the previous contents of the
+    /// buffer, and returns the encoding.
+    pub fn encode_all(&mut self, txs: &[Signed<TxGoat>]) -> &[u8] {
+        encode_goat_txs(txs, &mut self.buf);
+        &self.buf
+    }
+
+    /// Returns the contents of the buffer.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Consumes the buffer and returns its contents.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+impl Signed<TxGoat> {
+    /// Encodes the transaction in its EIP-2718 form into `out`, replacing its previous contents.
+    ///
+    /// `out` is grown at most once, and not at all if it is already large enough.
+    pub fn rlp_encode_into(&self, out: &mut Vec<u8>) {
+        out.clear();
+        out.reserve(self.encode_2718_len());
+        self.encode_2718(out);
+    }
+}
+
+/// The size of the largest goat calldata.
+const MAX_INPUT_SIZE: usize = DepositTx::SIZE;
+
+const _: () = {
+    let mut i = 0;
+    while i < GOAT_ACTIONS.len() {
+        assert!(matches!(GOAT_ACTIONS[i].fixed_size, Some(size) if size <= MAX_INPUT_SIZE));
+        i += 1;
+    }
+};
+
+/// A buffer for building the inputs of goat system transactions with few allocations.
+///
+/// [`TxGoat::new`] allocates the input of each transaction separately. The inputs built by the
+/// arena are instead views into a shared chunk, so building the goat transactions of a block
+/// allocates once per chunk rather than once per transaction. A chunk is freed once all the
+/// inputs taken from it are dropped.
+#[derive(Debug, Default)]
+pub struct InputArena {
+    buf: BytesMut,
+    chunk_size: usize,
+}
+
+impl InputArena {
+    /// The number of inputs a chunk of [`InputArena::new`] fits.
+    pub const DEFAULT_CHUNK_INPUTS: usize = 16;
+
+    /// Creates an arena with chunks of [`InputArena::DEFAULT_CHUNK_INPUTS`] inputs.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CHUNK_INPUTS)
+    }
+
+    /// Creates an arena with chunks fitting at least the given number of inputs, e.g. the expected
+    /// number of goat transactions of a block.
+    pub fn with_capacity(inputs: usize) -> Self {
+        let chunk_size = inputs.max(1) * MAX_INPUT_SIZE;
+        Self { buf: BytesMut::with_capacity(chunk_size), chunk_size }
+    }
+
+    /// Returns the calldata of the action, see [`TxGoatInner::input`].
+    pub fn input(&mut self, inner: &TxGoatInner) -> Bytes {
+        let len = inner.input_length();
+        if self.buf.capacity() < len {
+            self.buf = BytesMut::with_capacity(self.chunk_size.max(len));
+        }
+        inner.encode_input(&mut self.buf);
+        self.buf.split_to(len).freeze().into()
+    }
+
+    /// Creates a new system transaction from its decoded action, like [`TxGoat::new`].
+    pub fn tx(&mut self, chain_id: ChainId, nonce: u64, inner: TxGoatInner) -> TxGoat {
+        TxGoat {
+            chain_id,
+            module: inner.module(),
+            action: inner.action(),
+            nonce,
+            input: self.input(&inner),
+            inner,
+        }
+    }
+}
+
+/// Encodes the transactions as the RLP list of a block body into `out`, replacing its previous
+/// contents.
+///
+/// The output is the same as the RLP encoding of the transactions as
+/// [`TxEnvelope`](crate::TxEnvelope)s, but `out` is grown at most once.
+pub fn encode_goat_txs(txs: &[Signed<TxGoat>], out: &mut Vec<u8>) {
+    let header = Header { list: true, payload_length: txs.iter().map(|tx| tx.network_len()).sum() };
+    out.clear();
+    out.reserve(header.length_with_payload());
+    header.encode(out);
+    for tx in txs {
+        tx.network_encode(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::{
+            test_utils::signed_goat_tx, Cancel2Tx, DepositTx, NewBtcBlockTx, TxGoatInner,
+        },
+        TxEnvelope,
+    };
+    use alloy_primitives::{B256, U256};
+
+    fn txs() -> Vec<Signed<TxGoat>> {
+        [
+            TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) }),
+            TxGoatInner::Deposit(DepositTx { amount: U256::from(100), ..Default::default() }),
+            TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(2) }),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(nonce, inner)| signed_goat_tx(nonce as u64, inner))
+        .collect()
+    }
+
+    #[test]
+    fn input_arena() {
+        let mut arena = InputArena::with_capacity(2);
+        let txs = txs();
+        let built = txs
+            .iter()
+            .map(|tx| arena.tx(tx.tx().chain_id, tx.tx().nonce, tx.tx().inner))
+            .collect::<Vec<_>>();
+        for (built, tx) in built.iter().zip(&txs) {
+            assert_eq!(built, tx.tx());
+            assert_eq!(built.input, tx.tx().inner.input());
+        }
+
+        // the inputs share a chunk until it is full
+        for pair in built.windows(2) {
+            let (previous, next) = (&pair[0].input, &pair[1].input);
+            assert_eq!(previous.as_ptr().wrapping_add(previous.len()), next.as_ptr());
+        }
+        let last = &built[2].input;
+        let next = arena.input(&txs[1].tx().inner);
+        assert_ne!(last.as_ptr().wrapping_add(last.len()), next.as_ptr());
+        assert_eq!(next, txs[1].tx().input);
+    }
+
+    #[test]
+    fn encode_goat_txs_matches_envelopes() {
+        let txs = txs();
+        let envelopes = txs.iter().cloned().map(TxEnvelope::from).collect::<Vec<_>>();
+
+        let mut out = vec![0xff; 3];
+        encode_goat_txs(&txs, &mut out);
+        assert_eq!(out, alloy_rlp::encode(&envelopes));
+
+        encode_goat_txs(&[], &mut out);
+        assert_eq!(out, [alloy_rlp::EMPTY_LIST_CODE]);
+    }
+
+    #[test]
+    fn encoder_buf_reuse() {
+        let txs = txs();
+        let mut buf = EncoderBuf::with_capacity(1024);
+        let ptr = buf.as_slice().as_ptr();
+        for tx in &txs {
+            assert_eq!(buf.encode(tx), tx.encoded_2718());
+            assert_eq!(buf.as_slice().as_ptr(), ptr);
+        }
+
+        let all = buf.encode_all(&txs).to_vec();
+        assert_eq!(buf.as_slice().as_ptr(), ptr);
+        let mut out = Vec::new();
+        encode_goat_txs(&txs, &mut out);
+        assert_eq!(buf.into_inner(), out);
+        assert_eq!(all, out);
+    }
+}
@@ -0,0 +1,123 @@
+//! Iterator adapters over goat system transactions.
+
+use super::{Cancel2Tx, DepositTx, PaidTx, TxGoat, TxGoatInner};
+use crate::{EthereumTxEnvelope, Signed};
+use alloy_primitives::U256;
+
+/// A transaction that may be a goat system transaction.
+pub trait AsTxGoat {
+    /// Returns the goat system transaction, if this is one.
+    fn as_tx_goat(&self) -> Option<&TxGoat>;
+}
+
+impl AsTxGoat for TxGoat {
+    fn as_tx_goat(&self) -> Option<&TxGoat> {
+        Some(self)
+    }
+}
+
+impl AsTxGoat for Signed<TxGoat> {
+    fn as_tx_goat(&self) -> Option<&TxGoat> {
+        Some(self.tx())
+    }
+}
+
+impl<Eip4844> AsTxGoat for EthereumTxEnvelope<Eip4844> {
+    fn as_tx_goat(&self) -> Option<&TxGoat> {
+        match self {
+            Self::Goat(tx) => Some(tx.tx()),
+            _ => None,
+        }
+    }
+}
+
+/// A bridge withdrawal action, as yielded by [`GoatTxIterExt::withdrawals`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GoatWithdrawal<'a> {
+    /// The withdrawal was paid on bitcoin.
+    Paid(&'a PaidTx),
+    /// The withdrawal was cancelled.
+    Cancel2(&'a Cancel2Tx),
+}
+
+impl GoatWithdrawal<'_> {
+    /// Returns the withdrawal id.
+    pub const fn id(&self) -> U256 {
+        match self {
+            Self::Paid(tx) => tx.id,
+            Self::Cancel2(tx) => tx.id,
+        }
+    }
+}
+
+/// Extension trait for iterators over transactions, yielding the goat system transactions among
+/// them.
+///
+/// Every adapter yields the items together with their index in the original iterator, e.g. the
+/// transaction index within a block.
+pub trait GoatTxIterExt<'a, T>: Iterator<Item = &'a T> + Sized
+where
+    T: AsTxGoat + 'a,
+{
+    /// Yields the goat system transactions.
+    fn goat_txs(self) -> impl Iterator<Item = (usize, &'a TxGoat)> {
+        self.enumerate().filter_map(|(index, tx)| Some((index, tx.as_tx_goat()?)))
+    }
+
+    /// Yields the bitcoin deposits.
+    fn deposits(self) -> impl Iterator<Item = (usize, &'a DepositTx)> {
+        self.goat_txs().filter_map(|(index, tx)| Some((index, tx.inner.as_deposit()?)))
+    }
+
+    /// Yields the paid and cancelled bridge withdrawals.
+    fn withdrawals(self) -> impl Iterator<Item = (usize, GoatWithdrawal<'a>)> {
+        self.goat_txs().filter_map(|(index, tx)| match &tx.inner {
+            TxGoatInner::Paid(paid) => Some((index, GoatWithdrawal::Paid(paid))),
+            TxGoatInner::Cancel2(cancel) => Some((index, GoatWithdrawal::Cancel2(cancel))),
+            _ => None,
+        })
+    }
+}
+
+impl<'a, T, I> GoatTxIterExt<'a, T> for I
+where
+    T: AsTxGoat + 'a,
+    I: Iterator<Item = &'a T>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::test_utils::{new_btc_block_tx, signed_goat_tx},
+        SignableTransaction, TxEnvelope, TxLegacy,
+    };
+    use alloy_primitives::{Signature, B256};
+
+    #[test]
+    fn goat_iter_adapters() {
+        let goat = |nonce, inner| TxEnvelope::from(signed_goat_tx(nonce, inner));
+        let txs = [
+            new_btc_block_tx(0, B256::with_last_byte(1)).into(),
+            TxEnvelope::from(TxLegacy::default().into_signed(Signature::test_signature())),
+            goat(1, TxGoatInner::Deposit(DepositTx { tx_out: 1, ..Default::default() })),
+            goat(2, TxGoatInner::Paid(PaidTx { id: U256::from(5), ..Default::default() })),
+            goat(3, TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(6) })),
+        ];
+
+        let indices = txs.iter().goat_txs().map(|(index, _)| index).collect::<Vec<_>>();
+        assert_eq!(indices, [0, 2, 3, 4]);
+
+        let deposits = txs.iter().deposits().collect::<Vec<_>>();
+        assert_eq!(deposits, [(2, &DepositTx { tx_out: 1, ..Default::default() })]);
+
+        let withdrawals =
+            txs.iter().withdrawals().map(|(index, w)| (index, w.id())).collect::<Vec<_>>();
+        assert_eq!(withdrawals, [(3, U256::from(5)), (4, U256::from(6))]);
+
+        // plain goat transactions
+        let goat_txs = txs.iter().filter_map(|tx| tx.as_goat()).collect::<Vec<_>>();
+        assert_eq!(goat_txs.into_iter().deposits().count(), 1);
+    }
+}
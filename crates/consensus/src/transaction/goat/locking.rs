@@ -0,0 +1,134 @@
+//! Locking module actions.
+
+use super::{
+    abi::goat_action, GoatContract, GOAT_TOKEN_CONTRACT, LOCKING_COMPLETE_UNLOCK_ACTION,
+    LOCKING_DISTRIBUTE_REWARD_ACTION, LOCKING_EXECUTOR, LOCKING_MODULE,
+};
+use alloy_primitives::{Address, U256};
+
+goat_action! {
+    /// The completion of a validator unlock, releasing the locked tokens to the recipient.
+    ///
+    /// Calldata of `completeUnlock(uint256,address,address,uint256)`.
+    pub struct CompleteUnlockTx {
+        /// The unlock id.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub id: U256,
+        /// The recipient of the unlocked tokens.
+        pub recipient: Address,
+        /// The unlocked token, or the zero address for the native token.
+        pub token: Address,
+        /// The unlocked amount.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub amount: U256,
+    }
+
+    signature = "completeUnlock(uint256,address,address,uint256)",
+    method_id = [0x93, 0x9f, 0x0a, 0xc4],
+    module = LOCKING_MODULE,
+    action = LOCKING_COMPLETE_UNLOCK_ACTION,
+    sender = LOCKING_EXECUTOR,
+    contract = GoatContract::Locking,
+}
+
+impl CompleteUnlockTx {
+    /// Returns the kind of the unlocked token.
+    pub fn token_kind(&self) -> TokenKind {
+        TokenKind::from_address(self.token)
+    }
+}
+
+/// The kind of a token locked by a validator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// The native token, i.e. bridged BTC.
+    Native,
+    /// The GOAT token predeploy.
+    Goat(Address),
+    /// Any other ERC-20 token.
+    Erc20(Address),
+}
+
+impl TokenKind {
+    /// Resolves the kind of a token from its address, the zero address being the native token.
+    pub fn from_address(token: Address) -> Self {
+        if token.is_zero() {
+            Self::Native
+        } else if token == GOAT_TOKEN_CONTRACT {
+            Self::Goat(token)
+        } else {
+            Self::Erc20(token)
+        }
+    }
+
+    /// Returns the address of the token, or the zero address for the native token.
+    pub const fn address(&self) -> Address {
+        match self {
+            Self::Native => Address::ZERO,
+            Self::Goat(token) | Self::Erc20(token) => *token,
+        }
+    }
+
+    /// Returns true if this is the native token.
+    pub const fn is_native(&self) -> bool {
+        matches!(self, Self::Native)
+    }
+}
+
+impl From<Address> for TokenKind {
+    fn from(token: Address) -> Self {
+        Self::from_address(token)
+    }
+}
+
+goat_action! {
+    /// The distribution of a validator reward.
+    ///
+    /// Calldata of `distributeReward(uint256,address,uint256,uint256)`.
+    pub struct DistributeRewardTx {
+        /// The reward id.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub id: U256,
+        /// The recipient of the reward.
+        pub recipient: Address,
+        /// The GOAT token reward.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub goat: U256,
+        /// The gas fee reward, in wei.
+        #[cfg_attr(feature = "serde", serde(with = "super::quantity"))]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+        pub gas_reward: U256,
+    }
+
+    signature = "distributeReward(uint256,address,uint256,uint256)",
+    method_id = [0x90, 0x52, 0x90, 0xa1],
+    module = LOCKING_MODULE,
+    action = LOCKING_DISTRIBUTE_REWARD_ACTION,
+    sender = LOCKING_EXECUTOR,
+    contract = GoatContract::Locking,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn unlock_token_kind() {
+        let unlock = CompleteUnlockTx::default();
+        assert_eq!(unlock.token_kind(), TokenKind::Native);
+        assert_eq!(
+            CompleteUnlockTx { token: GOAT_TOKEN_CONTRACT, ..unlock }.token_kind(),
+            TokenKind::Goat(GOAT_TOKEN_CONTRACT)
+        );
+        let erc20 = address!("0xdac17f958d2ee523a2206206994597c13d831ec7");
+        let kind = CompleteUnlockTx { token: erc20, ..unlock }.token_kind();
+        assert_eq!(kind, TokenKind::Erc20(erc20));
+        assert_eq!(kind.address(), erc20);
+        assert_eq!(TokenKind::Native.address(), Address::ZERO);
+    }
+}
@@ -0,0 +1,98 @@
+//! Machine-readable metadata of the goat actions.
+
+use super::{Action, Module, GOAT_ACTIONS};
+use alloy_primitives::Selector;
+
+/// Metadata of a supported goat action.
+///
+/// See [`GOAT_ACTIONS`] for the metadata of all supported actions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatActionMeta {
+    /// The module of the action.
+    pub module: Module,
+    /// The action within the module.
+    pub action: Action,
+    /// The method id of the predeploy call.
+    pub selector: Selector,
+    /// The solidity signature of the predeploy call.
+    pub signature: &'static str,
+    /// The length of the calldata, if it does not depend on the action.
+    pub fixed_size: Option<usize>,
+}
+
+impl GoatActionMeta {
+    /// Returns the metadata of the given action, if it is supported.
+    pub fn find(module: Module, action: Action) -> Option<&'static Self> {
+        GOAT_ACTIONS.iter().find(|meta| meta.module == module && meta.action == action)
+    }
+
+    /// Returns the symbolic name of the action, see [`Module::action_name`].
+    pub const fn name(&self) -> &'static str {
+        match self.module.action_name(self.action) {
+            Some(name) => name,
+            None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::goat::{decode_tx, TxGoatInner};
+    use alloy_primitives::keccak256;
+
+    #[test]
+    fn goat_actions() {
+        let mut bytes = [0u8; 4096];
+        rand::Rng::fill(&mut rand::thread_rng(), bytes.as_mut_slice());
+        let mut u = arbitrary::Unstructured::new(&bytes);
+        for _ in 0..32 {
+            let inner: TxGoatInner = u.arbitrary().unwrap();
+            let meta = GoatActionMeta::find(inner.module(), inner.action()).unwrap();
+            let input = inner.input();
+            assert_eq!(input[..4], meta.selector[..]);
+            assert_eq!(meta.fixed_size, Some(input.len()));
+        }
+
+        for (i, meta) in GOAT_ACTIONS.iter().enumerate() {
+            assert_eq!(meta.selector[..], keccak256(meta.signature)[..4], "{}", meta.name());
+            assert_eq!(meta.module.action_from_name(meta.name()), Some(meta.action));
+            if let Some(next) = GOAT_ACTIONS.get(i + 1) {
+                assert!((meta.module, meta.action) < (next.module, next.action));
+            }
+        }
+
+        // every action accepted by `decode_tx` is listed
+        for module in 1..=3 {
+            for action in 1..=3 {
+                let module = Module::try_from(module).unwrap();
+                let action = Action::try_from(action).unwrap();
+                let known = !matches!(
+                    decode_tx(module, action, &[]),
+                    Err(alloy_rlp::Error::Custom("unknown goat module or action"))
+                );
+                assert_eq!(GoatActionMeta::find(module, action).is_some(), known);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_goat_action_meta() {
+        use crate::transaction::goat::Cancel2Tx;
+
+        let meta = Cancel2Tx::META;
+        assert_eq!(
+            serde_json::to_value(meta).unwrap(),
+            serde_json::json!({
+                "module": "0x1",
+                "action": "0x2",
+                "selector": "0xc19dd320",
+                "signature": "cancel2(uint256)",
+                "fixedSize": 36
+            })
+        );
+    }
+}
@@ -0,0 +1,598 @@
+//! GOAT system transactions are typed with [`GOAT_TX_TYPE_ID`] and are sent by the system
+//! executors to the protocol predeploys.
+//!
+//! The input of a [`TxGoat`] is the calldata of the predeploy call, and is decoded into a
+//! [`TxGoatInner`] according to its module and action.
+
+use crate::{transaction::RlpEcdsaDecodableTx, Signed};
+use alloc::{format, string::String, vec::Vec};
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_rlp::{BufMut, Decodable, Encodable};
+
+mod abi;
+
+mod anchor;
+pub use anchor::{BtcAnchorError, BtcAnchorTracker};
+
+mod amount;
+pub use amount::{sats_to_wei, wei_to_sats, SatAmount, SatConversionError, WEI_PER_SAT};
+
+mod bridge;
+pub use bridge::{Cancel2Tx, DepositKey, DepositSet, DepositTx, PaidTx};
+
+#[cfg(feature = "serde")]
+mod canonical;
+#[cfg(feature = "serde")]
+pub use canonical::to_canonical_json;
+
+mod columns;
+pub use columns::DepositColumns;
+
+mod constants;
+pub use constants::*;
+
+mod diff;
+pub use diff::{CalldataDiff, CalldataFieldDiff};
+
+mod encoder;
+pub use encoder::{encode_goat_txs, EncoderBuf, InputArena};
+
+mod iter;
+pub use iter::{AsTxGoat, GoatTxIterExt, GoatWithdrawal};
+
+mod locking;
+pub use locking::{CompleteUnlockTx, DistributeRewardTx, TokenKind};
+
+mod meta;
+pub use meta::GoatActionMeta;
+
+#[cfg(feature = "goat-p2p-messages")]
+mod p2p;
+#[cfg(feature = "goat-p2p-messages")]
+pub use p2p::{
+    DepositProposal, GoatMessage, GoatMessageType, SignedGoatMessage, Vote, WithdrawalProposal,
+};
+
+mod psgt;
+pub use psgt::{BtcReference, GoatTxMetadata, PartiallySignedGoatTx, PsgtError};
+
+#[cfg(feature = "proto")]
+pub mod proto;
+
+#[cfg(feature = "serde")]
+mod quantity;
+
+mod registry;
+pub use registry::{decode_goat_tx, GoatAction, GoatActionDecoder, GoatModuleRegistry};
+
+mod relayer;
+pub use relayer::NewBtcBlockTx;
+
+mod relayer_set;
+pub use relayer_set::{RelayerSet, RelayerSetError, RelayerSetUpdate};
+
+mod reward;
+pub use reward::{RewardEpoch, RewardMismatch, RewardSchedule};
+
+#[cfg(feature = "jsonschema")]
+mod schema;
+
+mod settlement;
+pub use settlement::{WithdrawalRequest, WithdrawalSettlement, WithdrawalSettlementError};
+
+mod summary;
+pub use summary::{summarize_block, GoatBlockSummary};
+
+#[cfg(test)]
+pub(crate) mod test_utils;
+
+mod tx;
+pub use tx::TxGoat;
+
+/// Bincode-compatible serde implementations for goat transaction types.
+#[cfg(all(feature = "serde", feature = "serde-bincode-compat"))]
+pub(super) mod serde_bincode_compat {
+    pub use super::tx::serde_bincode_compat::*;
+}
+
+/// A GOAT system action, encoded as the calldata of the predeploy call.
+///
+/// This trait is object safe, decoding is provided by [`DecodableGoatTx`].
+pub trait GoatTx: Encodable {
+    /// Returns the module of the action.
+    fn module(&self) -> Module;
+
+    /// Returns the action within the module.
+    fn action(&self) -> Action;
+
+    /// Returns the system executor sending the action.
+    fn sender(&self) -> Address;
+
+    /// Returns the predeploy called by the action.
+    fn contract(&self) -> Address;
+
+    /// Returns the native tokens minted by the action, if any.
+    fn deposit(&self) -> Option<Mint> {
+        None
+    }
+}
+
+/// A [`GoatTx`] that can be decoded from its calldata.
+pub trait DecodableGoatTx: GoatTx + Decodable {}
+
+impl<T: GoatTx + Decodable> DecodableGoatTx for T {}
+
+/// Native tokens minted by a bitcoin deposit.
+///
+/// The amounts are serialized as hex quantities, and deserialized from hex or decimal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Mint {
+    /// The recipient of the deposit.
+    pub target: Address,
+    /// The minted amount, in wei.
+    #[cfg_attr(feature = "serde", serde(with = "quantity"))]
+    #[cfg_attr(feature = "jsonschema", schemars(schema_with = "schema::quantity"))]
+    pub amount: U256,
+    /// The bridge tax, in wei.
+    #[cfg_attr(feature = "serde", serde(with = "quantity"))]
+    #[cfg_attr(feature = "jsonschema", schemars(schema_with = "schema::quantity"))]
+    pub tax: U256,
+}
+
+abi::goat_action! {
+    @registry
+    Deposit(DepositTx, "deposit"),
+    Cancel2(Cancel2Tx, "cancel2"),
+    Paid(PaidTx, "paid"),
+    NewBtcBlock(NewBtcBlockTx, "new_btc_block"),
+    CompleteUnlock(CompleteUnlockTx, "complete_unlock"),
+    DistributeReward(DistributeRewardTx, "distribute_reward"),
+}
+
+impl GoatActionKind {
+    /// Returns the kind of the given module and action, if it is supported.
+    pub fn from_ids(module: Module, action: Action) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.module() == module && kind.action() == action)
+    }
+}
+
+impl core::fmt::Display for GoatActionKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for GoatActionKind {
+    type Err = ParseGoatActionKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| ParseGoatActionKindError { input: s.into() })
+    }
+}
+
+/// Error returned when parsing an unknown [`GoatActionKind`] label.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("unknown goat action kind: '{input}'")]
+pub struct ParseGoatActionKindError {
+    /// The unknown label.
+    input: String,
+}
+
+impl TxGoatInner {
+    /// Returns the [`DepositTx`] if the action is a deposit.
+    pub const fn as_deposit(&self) -> Option<&DepositTx> {
+        match self {
+            Self::Deposit(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Cancel2Tx`] if the action is a withdrawal cancellation.
+    pub const fn as_cancel2(&self) -> Option<&Cancel2Tx> {
+        match self {
+            Self::Cancel2(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`PaidTx`] if the action is a withdrawal payment.
+    pub const fn as_paid(&self) -> Option<&PaidTx> {
+        match self {
+            Self::Paid(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`NewBtcBlockTx`] if the action is a new bitcoin block.
+    pub const fn as_new_btc_block(&self) -> Option<&NewBtcBlockTx> {
+        match self {
+            Self::NewBtcBlock(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`CompleteUnlockTx`] if the action is a unlock completion.
+    pub const fn as_complete_unlock(&self) -> Option<&CompleteUnlockTx> {
+        match self {
+            Self::CompleteUnlock(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`DistributeRewardTx`] if the action is a reward distribution.
+    pub const fn as_distribute_reward(&self) -> Option<&DistributeRewardTx> {
+        match self {
+            Self::DistributeReward(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns the calldata of the action.
+    pub fn input(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.input_length());
+        self.encode_input(&mut out);
+        out.into()
+    }
+
+    /// Returns the solidity call of the action, e.g. `cancel2(7)`, for logs and explorers.
+    ///
+    /// The transferred amounts and the rewards are formatted in hex, the other integers, i.e. ids,
+    /// output indices and the bridge tax, in decimal. Hashes and addresses are formatted in
+    /// lowercase hex.
+    pub fn to_sol_call_string(&self) -> String {
+        match self {
+            Self::Deposit(tx) => format!(
+                "deposit({}, {}, {:#x}, {:#x}, {})",
+                tx.tx_id, tx.tx_out, tx.target, tx.amount, tx.tax
+            ),
+            Self::Cancel2(tx) => format!("cancel2({})", tx.id),
+            Self::Paid(tx) => {
+                format!("paid({}, {}, {}, {:#x})", tx.id, tx.tx_id, tx.tx_out, tx.amount)
+            }
+            Self::NewBtcBlock(tx) => format!("newBlockHash({})", tx.hash),
+            Self::CompleteUnlock(tx) => format!(
+                "completeUnlock({}, {:#x}, {:#x}, {:#x})",
+                tx.id, tx.recipient, tx.token, tx.amount
+            ),
+            Self::DistributeReward(tx) => format!(
+                "distributeReward({}, {:#x}, {:#x}, {:#x})",
+                tx.id, tx.recipient, tx.goat, tx.gas_reward
+            ),
+        }
+    }
+}
+
+/// Decodes the calldata of an action, rejecting trailing bytes.
+fn decode_exact<T: DecodableGoatTx>(mut input: &[u8]) -> alloy_rlp::Result<T> {
+    let tx = T::decode(&mut input)?;
+    if !input.is_empty() {
+        return Err(alloy_rlp::Error::UnexpectedLength);
+    }
+    Ok(tx)
+}
+
+/// Error returned by [`decode_raw_tx`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GoatDecodeError {
+    /// The transaction is not typed with [`GOAT_TX_TYPE_ID`].
+    #[error("not a goat transaction")]
+    NotGoat,
+    /// The transaction is typed with [`GOAT_TX_TYPE_ID`], but is not a valid goat transaction.
+    #[error("malformed goat transaction: {0}")]
+    Malformed(alloy_rlp::Error),
+}
+
+impl GoatDecodeError {
+    /// Returns true if the transaction is not a goat transaction.
+    pub const fn is_not_goat(&self) -> bool {
+        matches!(self, Self::NotGoat)
+    }
+
+    /// Returns true if the transaction is a corrupt goat transaction.
+    pub const fn is_malformed(&self) -> bool {
+        matches!(self, Self::Malformed(_))
+    }
+}
+
+/// Error returned by [`TxGoat::verify_against_input`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GoatVerifyError {
+    /// The input is not the calldata of the module and action of the transaction.
+    #[error("malformed goat input: {0}")]
+    Malformed(alloy_rlp::Error),
+    /// The decoded action does not match the input.
+    #[error("goat inner does not match the input")]
+    InnerMismatch,
+}
+
+/// Decodes an EIP-2718 encoded goat system transaction.
+///
+/// Transactions of any other type are rejected with [`GoatDecodeError::NotGoat`] by only looking
+/// at their first byte, so this can be used to cheaply classify incoming raw transactions.
+pub fn decode_raw_tx(buf: &[u8]) -> Result<Signed<TxGoat>, GoatDecodeError> {
+    let Some((&GOAT_TX_TYPE_ID, mut buf)) = buf.split_first() else {
+        return Err(GoatDecodeError::NotGoat);
+    };
+    let tx = TxGoat::rlp_decode_signed(&mut buf).map_err(GoatDecodeError::Malformed)?;
+    if !buf.is_empty() {
+        return Err(GoatDecodeError::Malformed(alloy_rlp::Error::UnexpectedLength));
+    }
+    Ok(tx)
+}
+
+/// Decodes the goat system transactions of a block body from raw EIP-2718 transactions, in
+/// parallel, returning them with their index in the block.
+///
+/// Transactions of other types are skipped with [`decode_raw_tx`], without decoding them. Returns
+/// an error if a goat transaction is malformed. Meant for backfills processing many blocks, where
+/// decoding dominates.
+#[cfg(feature = "rayon")]
+pub fn extract_goat_txs_par<T: AsRef<[u8]> + Sync>(
+    raw_txs: &[T],
+) -> Result<Vec<(usize, Signed<TxGoat>)>, GoatDecodeError> {
+    use rayon::prelude::*;
+
+    raw_txs
+        .par_iter()
+        .enumerate()
+        .filter_map(|(index, raw)| match decode_raw_tx(raw.as_ref()) {
+            Ok(tx) => Some(Ok((index, tx))),
+            Err(GoatDecodeError::NotGoat) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+}
+
+// The goat types are shared across tasks by watchers and builders, so they must stay thread safe.
+const _: () = {
+    const fn _assert<T: Send + Sync>() {}
+    _assert::<TxGoat>();
+    _assert::<TxGoatInner>();
+    _assert::<GoatActionKind>();
+    _assert::<GoatActionMeta>();
+    _assert::<GoatDecodeError>();
+    _assert::<GoatVerifyError>();
+    _assert::<UnknownGoatId>();
+    _assert::<CalldataDiff>();
+    _assert::<DepositSet>();
+    _assert::<EncoderBuf>();
+    _assert::<GoatWithdrawal<'static>>();
+    _assert::<PartiallySignedGoatTx>();
+    _assert::<PsgtError>();
+    _assert::<GoatModuleRegistry<TxGoatInner>>();
+    _assert::<GoatAction<TxGoatInner>>();
+    _assert::<RelayerSet>();
+    _assert::<RelayerSetError>();
+    _assert::<RewardSchedule>();
+    _assert::<RewardMismatch>();
+    _assert::<SatConversionError>();
+    _assert::<WithdrawalSettlementError>();
+    #[cfg(feature = "goat-p2p-messages")]
+    _assert::<SignedGoatMessage>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SignableTransaction, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{address, b256, hex, keccak256, Signature, B256};
+
+    #[test]
+    fn method_ids() {
+        let selector = |sig: &str| <[u8; 4]>::try_from(&keccak256(sig)[..4]).unwrap();
+        assert_eq!(
+            DepositTx::METHOD_ID,
+            selector("deposit(bytes32,uint32,address,uint256,uint256)")
+        );
+        assert_eq!(Cancel2Tx::METHOD_ID, selector("cancel2(uint256)"));
+        assert_eq!(PaidTx::METHOD_ID, selector("paid(uint256,bytes32,uint32,uint256)"));
+        assert_eq!(NewBtcBlockTx::METHOD_ID, selector("newBlockHash(bytes32)"));
+        assert_eq!(
+            CompleteUnlockTx::METHOD_ID,
+            selector("completeUnlock(uint256,address,address,uint256)")
+        );
+        assert_eq!(
+            DistributeRewardTx::METHOD_ID,
+            selector("distributeReward(uint256,address,uint256,uint256)")
+        );
+    }
+
+    #[test]
+    fn decode_deposit() {
+        let input = hex!(
+            "904183cb"
+            "b0b8d5a7b8a1e4a4a8bdd0cbf6fc46f6d1d31ae4b8f3f7b0d0a6b2a4f0a4b1c2"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+            "0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+            "00000000000000000000000000000000000000000000000000038d7ea4c68000"
+        );
+        let inner = decode_tx(BRIDGE_MODULE, BRIDGE_DEPOSIT_ACTION, &input).unwrap();
+        let TxGoatInner::Deposit(deposit) = inner else { panic!("expected deposit") };
+        assert_eq!(
+            deposit.tx_id,
+            b256!("0xb0b8d5a7b8a1e4a4a8bdd0cbf6fc46f6d1d31ae4b8f3f7b0d0a6b2a4f0a4b1c2")
+        );
+        assert_eq!(deposit.tx_out, 2);
+        assert_eq!(deposit.target, address!("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"));
+        assert_eq!(deposit.amount, U256::from(10u128.pow(18)));
+        assert_eq!(deposit.tax, U256::from(10u128.pow(15)));
+        assert_eq!(inner.sender(), RELAYER_EXECUTOR);
+        assert_eq!(inner.contract(), BRIDGE_CONTRACT);
+        assert_eq!(
+            inner.deposit(),
+            Some(Mint { target: deposit.target, amount: deposit.amount, tax: deposit.tax })
+        );
+        assert_eq!(inner.input()[..], input[..]);
+
+        assert_eq!(decode_call(BRIDGE_CONTRACT, &input), Some(Ok(inner)));
+        assert_eq!(decode_call(LOCKING_CONTRACT, &input), None);
+        assert_eq!(decode_call(BRIDGE_CONTRACT, &input[..3]), None);
+        assert!(decode_call(BRIDGE_CONTRACT, &input[..36]).unwrap().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_mint() {
+        let mint = Mint {
+            target: address!("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"),
+            amount: U256::from(10u128.pow(18)),
+            tax: U256::ZERO,
+        };
+        let json = serde_json::json!({
+            "target": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "amount": "0xde0b6b3a7640000",
+            "tax": "0x0"
+        });
+        assert_eq!(serde_json::to_value(mint).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Mint>(json).unwrap(), mint);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let input = NewBtcBlockTx::default();
+        let mut buf = Vec::new();
+        input.encode(&mut buf);
+
+        assert!(decode_tx(RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION, &buf).is_ok());
+        // wrong action
+        assert!(decode_tx(BRIDGE_MODULE, BRIDGE_CANCEL2_ACTION, &buf).is_err());
+        // unknown action
+        assert!(decode_tx(RELAYER_MODULE, BRIDGE_CANCEL2_ACTION, &buf).is_err());
+        // truncated
+        assert!(decode_tx(RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION, &buf[..35]).is_err());
+        // trailing bytes
+        buf.push(0);
+        assert!(decode_tx(RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION, &buf).is_err());
+    }
+
+    #[test]
+    fn dirty_padding() {
+        let mut buf = Vec::new();
+        PaidTx { tx_out: 7, ..Default::default() }.encode(&mut buf);
+        assert!(decode_tx(BRIDGE_MODULE, BRIDGE_PAID_ACTION, &buf).is_ok());
+        // the padding of the `tx_out` word
+        buf[4 + 2 * 32] = 1;
+        assert_eq!(
+            decode_tx(BRIDGE_MODULE, BRIDGE_PAID_ACTION, &buf),
+            Err(alloy_rlp::Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn action_kind_labels() {
+        for kind in GoatActionKind::ALL {
+            assert_eq!(kind.as_str().parse(), Ok(kind));
+            assert_eq!(GoatActionKind::from_ids(kind.module(), kind.action()), Some(kind));
+            let name = kind.module().action_name(kind.action()).unwrap();
+            assert_eq!(kind.to_string(), name.to_ascii_lowercase());
+        }
+        assert!("NEW_BTC_BLOCK".parse::<GoatActionKind>().is_err());
+        assert_eq!(GoatActionKind::from_ids(RELAYER_MODULE, BRIDGE_PAID_ACTION), None);
+        let kinds = GOAT_ACTIONS
+            .iter()
+            .map(|meta| GoatActionKind::from_ids(meta.module, meta.action))
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, GoatActionKind::ALL.map(Some));
+    }
+
+    #[test]
+    fn inner_accessors() {
+        let inner = TxGoatInner::Paid(PaidTx { tx_out: 3, ..Default::default() });
+        assert_eq!(inner.kind(), GoatActionKind::Paid);
+        assert_eq!(inner.as_paid().map(|paid| paid.tx_out), Some(3));
+        assert_eq!(inner.as_deposit(), None);
+        assert_eq!(inner.as_cancel2(), None);
+
+        let mut bytes = [0u8; 1024];
+        rand::Rng::fill(&mut rand::thread_rng(), bytes.as_mut_slice());
+        let mut u = arbitrary::Unstructured::new(&bytes);
+        for _ in 0..8 {
+            let inner: TxGoatInner = u.arbitrary().unwrap();
+            let kind = inner.kind();
+            assert_eq!((kind.module(), kind.action()), (inner.module(), inner.action()));
+            assert_eq!(*inner.goat_contract().address(), inner.contract());
+        }
+    }
+
+    #[test]
+    fn goat_tx_trait_objects() {
+        let actions: Vec<Box<dyn GoatTx>> = vec![
+            Box::new(NewBtcBlockTx::default()),
+            Box::new(Cancel2Tx { id: U256::from(1) }),
+            Box::new(DistributeRewardTx::default()),
+        ];
+        let modules = actions.iter().map(|tx| tx.module()).collect::<Vec<_>>();
+        assert_eq!(modules, [RELAYER_MODULE, BRIDGE_MODULE, LOCKING_MODULE]);
+        assert_eq!(actions[2].sender(), LOCKING_EXECUTOR);
+
+        let inner = TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(1) });
+        let tx = inner.as_goat_tx();
+        assert_eq!((tx.module(), tx.action()), (inner.module(), inner.action()));
+        let mut input = Vec::new();
+        tx.encode(&mut input);
+        assert_eq!(input, inner.input());
+    }
+
+    #[test]
+    fn inner_roundtrip() {
+        let mut bytes = [0u8; 1024];
+        rand::Rng::fill(&mut rand::thread_rng(), bytes.as_mut_slice());
+        let mut u = arbitrary::Unstructured::new(&bytes);
+        for _ in 0..8 {
+            let inner: TxGoatInner = u.arbitrary().unwrap();
+            let decoded = decode_tx(inner.module(), inner.action(), &inner.input()).unwrap();
+            assert_eq!(decoded, inner);
+        }
+    }
+
+    #[test]
+    fn classify_raw_tx() {
+        let tx = test_utils::new_btc_block_tx(0, B256::ZERO);
+        let mut encoded = tx.encoded_2718();
+        assert_eq!(decode_raw_tx(&encoded), Ok(tx));
+
+        let legacy = TxLegacy::default().into_signed(Signature::test_signature()).encoded_2718();
+        assert_eq!(decode_raw_tx(&legacy), Err(GoatDecodeError::NotGoat));
+        assert_eq!(decode_raw_tx(&[0x02]), Err(GoatDecodeError::NotGoat));
+        assert_eq!(decode_raw_tx(&[]), Err(GoatDecodeError::NotGoat));
+
+        assert!(decode_raw_tx(&encoded[..encoded.len() - 1]).unwrap_err().is_malformed());
+        encoded.push(0);
+        assert_eq!(
+            decode_raw_tx(&encoded),
+            Err(GoatDecodeError::Malformed(alloy_rlp::Error::UnexpectedLength))
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn extract_goat_txs() {
+        let goat = |nonce| {
+            test_utils::signed_goat_tx(
+                nonce,
+                TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(nonce) }),
+            )
+        };
+        let legacy = TxLegacy::default().into_signed(Signature::test_signature()).encoded_2718();
+        let mut raw = (0..64).map(|nonce| goat(nonce).encoded_2718()).collect::<Vec<_>>();
+        raw.insert(3, legacy.clone());
+        raw.push(legacy);
+
+        let txs = extract_goat_txs_par(&raw).unwrap();
+        assert_eq!(txs.len(), 64);
+        assert_eq!(txs[2], (2, goat(2)));
+        assert_eq!(txs[3], (4, goat(3)));
+        assert!(extract_goat_txs_par::<Vec<u8>>(&[]).unwrap().is_empty());
+
+        raw[10].pop();
+        assert!(extract_goat_txs_par(&raw).unwrap_err().is_malformed());
+    }
+}
@@ -0,0 +1,347 @@
+//! Messages exchanged by relayer nodes to agree on goat system transactions before sending them.
+//!
+//! A message is encoded as its [`GoatMessageType`] byte followed by the RLP list of its fields,
+//! and is signed by its sender over the keccak256 hash of this encoding, see
+//! [`GoatMessage::signature_hash`].
+
+use super::{DepositTx, PaidTx, TxGoatInner};
+use alloc::vec::Vec;
+use alloy_primitives::{keccak256, Address, Signature, B256};
+use alloy_rlp::{BufMut, Decodable, Encodable, Header};
+
+/// The type of a [`GoatMessage`], prefixed to its encoding.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GoatMessageType {
+    /// A [`DepositProposal`].
+    DepositProposal = 1,
+    /// A [`WithdrawalProposal`].
+    WithdrawalProposal = 2,
+    /// A [`Vote`].
+    Vote = 3,
+}
+
+impl TryFrom<u8> for GoatMessageType {
+    type Error = alloy_rlp::Error;
+
+    fn try_from(ty: u8) -> Result<Self, Self::Error> {
+        Ok(match ty {
+            1 => Self::DepositProposal,
+            2 => Self::WithdrawalProposal,
+            3 => Self::Vote,
+            _ => return Err(alloy_rlp::Error::Custom("unknown goat message type")),
+        })
+    }
+}
+
+/// A proposal to send a bitcoin deposit.
+///
+/// Encoded as the RLP list `[round, proposer, calldata]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DepositProposal {
+    /// The coordination round of the proposal.
+    pub round: u64,
+    /// The relayer proposing the deposit.
+    pub proposer: Address,
+    /// The proposed deposit.
+    pub deposit: DepositTx,
+}
+
+/// A proposal to send the payment of a bridge withdrawal.
+///
+/// Encoded as the RLP list `[round, proposer, calldata]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WithdrawalProposal {
+    /// The coordination round of the proposal.
+    pub round: u64,
+    /// The relayer proposing the payment.
+    pub proposer: Address,
+    /// The proposed payment.
+    pub payment: PaidTx,
+}
+
+/// A vote on a proposal, identified by its [hash](GoatMessage::signature_hash).
+///
+/// Encoded as the RLP list `[round, proposal, approve]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vote {
+    /// The coordination round of the proposal.
+    pub round: u64,
+    /// The hash of the proposal.
+    pub proposal: B256,
+    /// Whether the proposal is approved.
+    pub approve: bool,
+}
+
+/// A message exchanged by relayer nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GoatMessage {
+    /// A [`DepositProposal`].
+    DepositProposal(DepositProposal),
+    /// A [`WithdrawalProposal`].
+    WithdrawalProposal(WithdrawalProposal),
+    /// A [`Vote`].
+    Vote(Vote),
+}
+
+impl From<DepositProposal> for GoatMessage {
+    fn from(proposal: DepositProposal) -> Self {
+        Self::DepositProposal(proposal)
+    }
+}
+
+impl From<WithdrawalProposal> for GoatMessage {
+    fn from(proposal: WithdrawalProposal) -> Self {
+        Self::WithdrawalProposal(proposal)
+    }
+}
+
+impl From<Vote> for GoatMessage {
+    fn from(vote: Vote) -> Self {
+        Self::Vote(vote)
+    }
+}
+
+impl GoatMessage {
+    /// Returns the type of the message.
+    pub const fn ty(&self) -> GoatMessageType {
+        match self {
+            Self::DepositProposal(_) => GoatMessageType::DepositProposal,
+            Self::WithdrawalProposal(_) => GoatMessageType::WithdrawalProposal,
+            Self::Vote(_) => GoatMessageType::Vote,
+        }
+    }
+
+    /// Returns the coordination round of the message.
+    pub const fn round(&self) -> u64 {
+        match self {
+            Self::DepositProposal(proposal) => proposal.round,
+            Self::WithdrawalProposal(proposal) => proposal.round,
+            Self::Vote(vote) => vote.round,
+        }
+    }
+
+    /// Returns the goat action proposed by the message, if it is a proposal.
+    pub const fn proposed_action(&self) -> Option<TxGoatInner> {
+        match self {
+            Self::DepositProposal(proposal) => Some(TxGoatInner::Deposit(proposal.deposit)),
+            Self::WithdrawalProposal(proposal) => Some(TxGoatInner::Paid(proposal.payment)),
+            Self::Vote(_) => None,
+        }
+    }
+
+    /// Encodes the message, prefixed with its type.
+    pub fn encode(&self, out: &mut dyn BufMut) {
+        out.put_u8(self.ty() as u8);
+        match self {
+            Self::DepositProposal(proposal) => {
+                let fields = [&proposal.round as &dyn Encodable, &proposal.proposer];
+                encode_list(&fields, Some(&proposal.deposit), out)
+            }
+            Self::WithdrawalProposal(proposal) => {
+                let fields = [&proposal.round as &dyn Encodable, &proposal.proposer];
+                encode_list(&fields, Some(&proposal.payment), out)
+            }
+            Self::Vote(vote) => {
+                let fields = [&vote.round as &dyn Encodable, &vote.proposal, &vote.approve];
+                encode_list(&fields, None, out)
+            }
+        }
+    }
+
+    /// Returns the encoding of the message.
+    pub fn encoded(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+
+    /// Decodes a message from its encoding.
+    ///
+    /// Returns an error if the data is not exactly one encoded message.
+    pub fn decode_exact(data: &[u8]) -> alloy_rlp::Result<Self> {
+        let (&ty, mut buf) = data.split_first().ok_or(alloy_rlp::Error::InputTooShort)?;
+        let ty = GoatMessageType::try_from(ty)?;
+        let header = Header::decode(&mut buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        if buf.len() != header.payload_length {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        let message = match ty {
+            GoatMessageType::DepositProposal => Self::DepositProposal(DepositProposal {
+                round: Decodable::decode(&mut buf)?,
+                proposer: Decodable::decode(&mut buf)?,
+                deposit: decode_call(&mut buf)?,
+            }),
+            GoatMessageType::WithdrawalProposal => Self::WithdrawalProposal(WithdrawalProposal {
+                round: Decodable::decode(&mut buf)?,
+                proposer: Decodable::decode(&mut buf)?,
+                payment: decode_call(&mut buf)?,
+            }),
+            GoatMessageType::Vote => Self::Vote(Vote {
+                round: Decodable::decode(&mut buf)?,
+                proposal: Decodable::decode(&mut buf)?,
+                approve: Decodable::decode(&mut buf)?,
+            }),
+        };
+        if !buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        Ok(message)
+    }
+
+    /// Returns the keccak256 hash of the encoding, which is signed by the sender and identifies
+    /// proposals in [`Vote`]s.
+    pub fn signature_hash(&self) -> B256 {
+        keccak256(self.encoded())
+    }
+
+    /// Attaches the signature of the sender to the message.
+    pub const fn into_signed(self, signature: Signature) -> SignedGoatMessage {
+        SignedGoatMessage { message: self, signature }
+    }
+}
+
+/// A [`GoatMessage`] signed by its sender.
+///
+/// Encoded as the RLP list `[message, y_parity, r, s]`, where `message` is the encoding of the
+/// message as a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SignedGoatMessage {
+    /// The message.
+    pub message: GoatMessage,
+    /// The signature of the sender over the [hash](GoatMessage::signature_hash) of the message.
+    pub signature: Signature,
+}
+
+impl SignedGoatMessage {
+    /// Encodes the signed message.
+    pub fn encode_signed(&self, out: &mut dyn BufMut) {
+        let message = self.message.encoded();
+        let signature = &self.signature;
+        let payload_length =
+            message.as_slice().length() + signature.rlp_rs_len() + signature.v().length();
+        Header { list: true, payload_length }.encode(out);
+        message.as_slice().encode(out);
+        signature.write_rlp_vrs(out, signature.v());
+    }
+
+    /// Decodes a signed message.
+    ///
+    /// Returns an error if the data is not exactly one encoded signed message.
+    pub fn decode_signed(mut data: &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(&mut data)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        if data.len() != header.payload_length {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        let message = GoatMessage::decode_exact(Header::decode_bytes(&mut data, false)?)?;
+        let signature = Signature::decode_rlp_vrs(&mut data, bool::decode)?;
+        if !data.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        Ok(message.into_signed(signature))
+    }
+
+    /// Recovers the sender of the message from its signature.
+    #[cfg(feature = "k256")]
+    pub fn recover_sender(&self) -> Result<Address, alloy_primitives::SignatureError> {
+        self.signature.recover_address_from_prehash(&self.message.signature_hash())
+    }
+}
+
+/// Encodes an RLP list of the fields, followed by the calldata of the action as a string.
+fn encode_list(fields: &[&dyn Encodable], call: Option<&dyn Encodable>, out: &mut dyn BufMut) {
+    let call_header = |call: &dyn Encodable| Header { list: false, payload_length: call.length() };
+    let payload_length = fields.iter().map(|field| field.length()).sum::<usize>()
+        + call.map_or(0, |call| call_header(call).length_with_payload());
+    Header { list: true, payload_length }.encode(out);
+    fields.iter().for_each(|field| field.encode(out));
+    if let Some(call) = call {
+        call_header(call).encode(out);
+        call.encode(out);
+    }
+}
+
+/// Decodes the calldata of an action, encoded as a string.
+fn decode_call<T: Decodable>(buf: &mut &[u8]) -> alloy_rlp::Result<T> {
+    let mut call = Header::decode_bytes(buf, false)?;
+    let action = T::decode(&mut call)?;
+    if !call.is_empty() {
+        return Err(alloy_rlp::Error::UnexpectedLength);
+    }
+    Ok(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    fn messages() -> [GoatMessage; 3] {
+        let deposit = DepositTx {
+            tx_id: B256::with_last_byte(1),
+            tx_out: 2,
+            target: Address::with_last_byte(3),
+            amount: U256::from(4),
+            tax: U256::from(5),
+        };
+        let payment = PaidTx {
+            id: U256::from(6),
+            tx_id: B256::with_last_byte(7),
+            tx_out: 8,
+            amount: U256::from(9),
+        };
+        let proposer = Address::with_last_byte(10);
+        let proposal = GoatMessage::from(DepositProposal { round: 11, proposer, deposit });
+        [
+            proposal,
+            WithdrawalProposal { round: 12, proposer, payment }.into(),
+            Vote { round: 11, proposal: proposal.signature_hash(), approve: true }.into(),
+        ]
+    }
+
+    #[test]
+    fn goat_message_roundtrip() {
+        for message in messages() {
+            let encoded = message.encoded();
+            assert_eq!(encoded[0], message.ty() as u8);
+            assert_eq!(GoatMessage::decode_exact(&encoded).unwrap(), message);
+
+            let mut trailing = encoded.clone();
+            trailing.push(0);
+            assert!(GoatMessage::decode_exact(&trailing).is_err());
+            let mut retyped = encoded;
+            retyped[0] = 4;
+            assert!(GoatMessage::decode_exact(&retyped).is_err());
+
+            let signed = message.into_signed(Signature::test_signature());
+            let mut out = Vec::new();
+            signed.encode_signed(&mut out);
+            assert_eq!(SignedGoatMessage::decode_signed(&out).unwrap(), signed);
+        }
+
+        let [deposit, payment, vote] = messages();
+        assert!(matches!(deposit.proposed_action(), Some(TxGoatInner::Deposit(_))));
+        assert!(matches!(payment.proposed_action(), Some(TxGoatInner::Paid(_))));
+        assert_eq!(vote.proposed_action(), None);
+        assert_eq!(vote.round(), 11);
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn goat_message_sender() {
+        let secret = B256::with_last_byte(42);
+        let sender =
+            Address::from_private_key(&k256::ecdsa::SigningKey::from_slice(&secret[..]).unwrap());
+        for message in messages() {
+            let signature =
+                crate::crypto::secp256k1::sign_message(secret, message.signature_hash()).unwrap();
+            assert_eq!(message.into_signed(signature).recover_sender().unwrap(), sender);
+        }
+    }
+}
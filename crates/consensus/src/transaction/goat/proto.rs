@@ -0,0 +1,566 @@
+//! Protobuf messages of the goat system transactions, for the relayers exchanging them over gRPC.
+//!
+//! The messages are derived with [`prost`] by hand, so that building the crate does not require
+//! `protoc`, and are tested against `proto/goat.proto`, which is the schema to generate the code of
+//! other languages from. Hashes and addresses are encoded as fixed size bytes, and `uint256` values
+//! as big-endian bytes without leading zeros, matching `big.Int.Bytes` in Go.
+//!
+//! The conversions from the goat types are lossless, and the conversions back validate the sizes
+//! of the bytes fields, see [`GoatProtoError`].
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+
+/// An unsigned goat system transaction, see [`super::TxGoat`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct TxGoat {
+    /// The chain id of the transaction.
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    /// The nonce of the transaction.
+    #[prost(uint64, tag = "2")]
+    pub nonce: u64,
+    /// The action of the transaction.
+    #[prost(oneof = "tx_goat::Inner", tags = "3, 4, 5, 6, 7, 8")]
+    pub inner: Option<tx_goat::Inner>,
+}
+
+/// Nested types of [`TxGoat`].
+pub mod tx_goat {
+    /// The action of a [`TxGoat`](super::TxGoat), see [`TxGoatInner`](super::super::TxGoatInner).
+    #[derive(Clone, PartialEq, Eq, prost::Oneof)]
+    pub enum Inner {
+        /// A bitcoin deposit.
+        #[prost(message, tag = "3")]
+        Deposit(super::DepositTx),
+        /// The cancellation of a pending withdrawal.
+        #[prost(message, tag = "4")]
+        Cancel2(super::Cancel2Tx),
+        /// The payment of a withdrawal on bitcoin.
+        #[prost(message, tag = "5")]
+        Paid(super::PaidTx),
+        /// The submission of a new bitcoin block hash.
+        #[prost(message, tag = "6")]
+        NewBtcBlock(super::NewBtcBlockTx),
+        /// The completion of a validator unlock.
+        #[prost(message, tag = "7")]
+        CompleteUnlock(super::CompleteUnlockTx),
+        /// The distribution of a validator reward.
+        #[prost(message, tag = "8")]
+        DistributeReward(super::DistributeRewardTx),
+    }
+}
+
+/// A bitcoin deposit, see [`super::DepositTx`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct DepositTx {
+    /// The bitcoin transaction id of the deposit.
+    #[prost(bytes = "vec", tag = "1")]
+    pub tx_id: Vec<u8>,
+    /// The output index of the deposit in the bitcoin transaction.
+    #[prost(uint32, tag = "2")]
+    pub tx_out: u32,
+    /// The recipient of the deposit.
+    #[prost(bytes = "vec", tag = "3")]
+    pub target: Vec<u8>,
+    /// The deposited amount, in wei.
+    #[prost(bytes = "vec", tag = "4")]
+    pub amount: Vec<u8>,
+    /// The bridge tax taken from the deposit, in wei.
+    #[prost(bytes = "vec", tag = "5")]
+    pub tax: Vec<u8>,
+}
+
+/// The cancellation of a pending withdrawal, see [`super::Cancel2Tx`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct Cancel2Tx {
+    /// The withdrawal id.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+}
+
+/// The payment of a withdrawal on bitcoin, see [`super::PaidTx`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct PaidTx {
+    /// The withdrawal id.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    /// The bitcoin transaction id of the payment.
+    #[prost(bytes = "vec", tag = "2")]
+    pub tx_id: Vec<u8>,
+    /// The output index of the payment in the bitcoin transaction.
+    #[prost(uint32, tag = "3")]
+    pub tx_out: u32,
+    /// The paid amount, in wei.
+    #[prost(bytes = "vec", tag = "4")]
+    pub amount: Vec<u8>,
+}
+
+/// The submission of a new bitcoin block hash, see [`super::NewBtcBlockTx`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct NewBtcBlockTx {
+    /// The bitcoin block hash.
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: Vec<u8>,
+}
+
+/// The completion of a validator unlock, see [`super::CompleteUnlockTx`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CompleteUnlockTx {
+    /// The unlock id.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    /// The recipient of the unlocked tokens.
+    #[prost(bytes = "vec", tag = "2")]
+    pub recipient: Vec<u8>,
+    /// The unlocked token, or the zero address for the native token.
+    #[prost(bytes = "vec", tag = "3")]
+    pub token: Vec<u8>,
+    /// The unlocked amount.
+    #[prost(bytes = "vec", tag = "4")]
+    pub amount: Vec<u8>,
+}
+
+/// The distribution of a validator reward, see [`super::DistributeRewardTx`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct DistributeRewardTx {
+    /// The reward id.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    /// The recipient of the reward.
+    #[prost(bytes = "vec", tag = "2")]
+    pub recipient: Vec<u8>,
+    /// The GOAT token reward.
+    #[prost(bytes = "vec", tag = "3")]
+    pub goat: Vec<u8>,
+    /// The gas fee reward, in wei.
+    #[prost(bytes = "vec", tag = "4")]
+    pub gas_reward: Vec<u8>,
+}
+
+/// Errors returned when converting the protobuf messages to the goat types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GoatProtoError {
+    /// The transaction has no action.
+    #[error("missing goat action")]
+    MissingAction,
+    /// A hash or address field has the wrong size.
+    #[error("invalid {field}: expected {expected} bytes, got {got}")]
+    InvalidLength {
+        /// The name of the field.
+        field: &'static str,
+        /// The expected size.
+        expected: usize,
+        /// The actual size.
+        got: usize,
+    },
+    /// A `uint256` field is longer than 32 bytes.
+    #[error("invalid {field}: {got} bytes overflow uint256")]
+    Overflow {
+        /// The name of the field.
+        field: &'static str,
+        /// The actual size.
+        got: usize,
+    },
+}
+
+fn uint(value: U256) -> Vec<u8> {
+    value.to_be_bytes_trimmed_vec()
+}
+
+fn parse_uint(field: &'static str, bytes: &[u8]) -> Result<U256, GoatProtoError> {
+    U256::try_from_be_slice(bytes).ok_or(GoatProtoError::Overflow { field, got: bytes.len() })
+}
+
+fn parse_fixed<const N: usize>(
+    field: &'static str,
+    bytes: &[u8],
+) -> Result<FixedBytes<N>, GoatProtoError> {
+    FixedBytes::try_from(bytes).map_err(|_| GoatProtoError::InvalidLength {
+        field,
+        expected: N,
+        got: bytes.len(),
+    })
+}
+
+fn parse_address(field: &'static str, bytes: &[u8]) -> Result<Address, GoatProtoError> {
+    parse_fixed(field, bytes).map(Address::from)
+}
+
+fn parse_hash(field: &'static str, bytes: &[u8]) -> Result<B256, GoatProtoError> {
+    parse_fixed(field, bytes)
+}
+
+impl From<super::TxGoat> for TxGoat {
+    fn from(tx: super::TxGoat) -> Self {
+        Self::from(&tx)
+    }
+}
+
+impl From<&super::TxGoat> for TxGoat {
+    fn from(tx: &super::TxGoat) -> Self {
+        Self { chain_id: tx.chain_id, nonce: tx.nonce, inner: Some(tx.inner.into()) }
+    }
+}
+
+impl TryFrom<TxGoat> for super::TxGoat {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: TxGoat) -> Result<Self, Self::Error> {
+        let inner = tx.inner.ok_or(GoatProtoError::MissingAction)?;
+        Ok(Self::new(tx.chain_id, tx.nonce, inner.try_into()?))
+    }
+}
+
+impl From<super::TxGoatInner> for tx_goat::Inner {
+    fn from(inner: super::TxGoatInner) -> Self {
+        match inner {
+            super::TxGoatInner::Deposit(tx) => Self::Deposit(tx.into()),
+            super::TxGoatInner::Cancel2(tx) => Self::Cancel2(tx.into()),
+            super::TxGoatInner::Paid(tx) => Self::Paid(tx.into()),
+            super::TxGoatInner::NewBtcBlock(tx) => Self::NewBtcBlock(tx.into()),
+            super::TxGoatInner::CompleteUnlock(tx) => Self::CompleteUnlock(tx.into()),
+            super::TxGoatInner::DistributeReward(tx) => Self::DistributeReward(tx.into()),
+        }
+    }
+}
+
+impl TryFrom<tx_goat::Inner> for super::TxGoatInner {
+    type Error = GoatProtoError;
+
+    fn try_from(inner: tx_goat::Inner) -> Result<Self, Self::Error> {
+        Ok(match inner {
+            tx_goat::Inner::Deposit(tx) => Self::Deposit(tx.try_into()?),
+            tx_goat::Inner::Cancel2(tx) => Self::Cancel2(tx.try_into()?),
+            tx_goat::Inner::Paid(tx) => Self::Paid(tx.try_into()?),
+            tx_goat::Inner::NewBtcBlock(tx) => Self::NewBtcBlock(tx.try_into()?),
+            tx_goat::Inner::CompleteUnlock(tx) => Self::CompleteUnlock(tx.try_into()?),
+            tx_goat::Inner::DistributeReward(tx) => Self::DistributeReward(tx.try_into()?),
+        })
+    }
+}
+
+impl From<super::DepositTx> for DepositTx {
+    fn from(tx: super::DepositTx) -> Self {
+        Self {
+            tx_id: tx.tx_id.to_vec(),
+            tx_out: tx.tx_out,
+            target: tx.target.to_vec(),
+            amount: uint(tx.amount),
+            tax: uint(tx.tax),
+        }
+    }
+}
+
+impl TryFrom<DepositTx> for super::DepositTx {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: DepositTx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tx_id: parse_hash("deposit.tx_id", &tx.tx_id)?,
+            tx_out: tx.tx_out,
+            target: parse_address("deposit.target", &tx.target)?,
+            amount: parse_uint("deposit.amount", &tx.amount)?,
+            tax: parse_uint("deposit.tax", &tx.tax)?,
+        })
+    }
+}
+
+impl From<super::Cancel2Tx> for Cancel2Tx {
+    fn from(tx: super::Cancel2Tx) -> Self {
+        Self { id: uint(tx.id) }
+    }
+}
+
+impl TryFrom<Cancel2Tx> for super::Cancel2Tx {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: Cancel2Tx) -> Result<Self, Self::Error> {
+        Ok(Self { id: parse_uint("cancel2.id", &tx.id)? })
+    }
+}
+
+impl From<super::PaidTx> for PaidTx {
+    fn from(tx: super::PaidTx) -> Self {
+        Self {
+            id: uint(tx.id),
+            tx_id: tx.tx_id.to_vec(),
+            tx_out: tx.tx_out,
+            amount: uint(tx.amount),
+        }
+    }
+}
+
+impl TryFrom<PaidTx> for super::PaidTx {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: PaidTx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uint("paid.id", &tx.id)?,
+            tx_id: parse_hash("paid.tx_id", &tx.tx_id)?,
+            tx_out: tx.tx_out,
+            amount: parse_uint("paid.amount", &tx.amount)?,
+        })
+    }
+}
+
+impl From<super::NewBtcBlockTx> for NewBtcBlockTx {
+    fn from(tx: super::NewBtcBlockTx) -> Self {
+        Self { hash: tx.hash.to_vec() }
+    }
+}
+
+impl TryFrom<NewBtcBlockTx> for super::NewBtcBlockTx {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: NewBtcBlockTx) -> Result<Self, Self::Error> {
+        Ok(Self { hash: parse_hash("new_btc_block.hash", &tx.hash)? })
+    }
+}
+
+impl From<super::CompleteUnlockTx> for CompleteUnlockTx {
+    fn from(tx: super::CompleteUnlockTx) -> Self {
+        Self {
+            id: uint(tx.id),
+            recipient: tx.recipient.to_vec(),
+            token: tx.token.to_vec(),
+            amount: uint(tx.amount),
+        }
+    }
+}
+
+impl TryFrom<CompleteUnlockTx> for super::CompleteUnlockTx {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: CompleteUnlockTx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uint("complete_unlock.id", &tx.id)?,
+            recipient: parse_address("complete_unlock.recipient", &tx.recipient)?,
+            token: parse_address("complete_unlock.token", &tx.token)?,
+            amount: parse_uint("complete_unlock.amount", &tx.amount)?,
+        })
+    }
+}
+
+impl From<super::DistributeRewardTx> for DistributeRewardTx {
+    fn from(tx: super::DistributeRewardTx) -> Self {
+        Self {
+            id: uint(tx.id),
+            recipient: tx.recipient.to_vec(),
+            goat: uint(tx.goat),
+            gas_reward: uint(tx.gas_reward),
+        }
+    }
+}
+
+impl TryFrom<DistributeRewardTx> for super::DistributeRewardTx {
+    type Error = GoatProtoError;
+
+    fn try_from(tx: DistributeRewardTx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uint("distribute_reward.id", &tx.id)?,
+            recipient: parse_address("distribute_reward.recipient", &tx.recipient)?,
+            goat: parse_uint("distribute_reward.goat", &tx.goat)?,
+            gas_reward: parse_uint("distribute_reward.gas_reward", &tx.gas_reward)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::goat::{self as goat, test_utils::goat_tx, TxGoatInner};
+    use alloc::{collections::BTreeMap, string::ToString, vec};
+    use alloy_primitives::hex;
+    use prost::{
+        encoding::{decode_key, decode_varint, encode_key, encode_varint, WireType},
+        Message,
+    };
+
+    /// A field of the schema: its type, name and tag.
+    type Field = (&'static str, &'static str, u32);
+
+    /// Parses the fields of the messages of `proto/goat.proto`, including their oneof members.
+    fn schema() -> BTreeMap<&'static str, Vec<Field>> {
+        let mut schema = BTreeMap::new();
+        let (mut message, mut oneof) = (None, false);
+        for line in include_str!("../../../proto/goat.proto").lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("message ") {
+                message = Some(name.trim_end_matches(" {"));
+                schema.insert(message.unwrap(), Vec::new());
+            } else if line.starts_with("oneof ") {
+                oneof = true;
+            } else if line == "}" {
+                if !core::mem::take(&mut oneof) {
+                    message = None;
+                }
+            } else if let (Some(message), Some(field)) = (message, line.strip_suffix(';')) {
+                let [ty, name, "=", tag] = field.split_whitespace().collect::<Vec<_>>()[..] else {
+                    panic!("unexpected field {line:?}");
+                };
+                schema.get_mut(message).unwrap().push((ty, name, tag.parse().unwrap()));
+            }
+        }
+        schema
+    }
+
+    fn wire_type(schema: &BTreeMap<&str, Vec<Field>>, ty: &str) -> WireType {
+        match ty {
+            "uint32" | "uint64" => WireType::Varint,
+            "bytes" => WireType::LengthDelimited,
+            message if schema.contains_key(message) => WireType::LengthDelimited,
+            ty => panic!("unsupported type {ty}"),
+        }
+    }
+
+    fn reencode(message: &str, bytes: &[u8]) -> Vec<u8> {
+        fn reencode<M: Message + Default>(bytes: &[u8]) -> Vec<u8> {
+            M::decode(bytes).unwrap().encode_to_vec()
+        }
+        match message {
+            "TxGoat" => reencode::<TxGoat>(bytes),
+            "DepositTx" => reencode::<DepositTx>(bytes),
+            "Cancel2Tx" => reencode::<Cancel2Tx>(bytes),
+            "PaidTx" => reencode::<PaidTx>(bytes),
+            "NewBtcBlockTx" => reencode::<NewBtcBlockTx>(bytes),
+            "CompleteUnlockTx" => reencode::<CompleteUnlockTx>(bytes),
+            "DistributeRewardTx" => reencode::<DistributeRewardTx>(bytes),
+            message => panic!("missing message {message}"),
+        }
+    }
+
+    /// Checks that every field of `bytes` is declared with the same tag and type in the schema.
+    fn check_fields(schema: &BTreeMap<&str, Vec<Field>>, message: &str, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let (tag, wire) = decode_key(&mut bytes).unwrap();
+            let Some(&(ty, name, _)) = schema[message].iter().find(|field| field.2 == tag) else {
+                panic!("{message} has no field {tag}");
+            };
+            assert_eq!(wire, wire_type(schema, ty), "{message}.{name}");
+            // the value of a varint, or the length of a length-delimited field
+            let value = decode_varint(&mut bytes).unwrap() as usize;
+            if wire == WireType::LengthDelimited {
+                if schema.contains_key(ty) {
+                    check_fields(schema, ty, &bytes[..value]);
+                }
+                bytes = &bytes[value..];
+            }
+        }
+    }
+
+    #[test]
+    fn proto_schema() {
+        let schema = schema();
+        assert_eq!(schema.len(), 7);
+        assert_eq!(schema["TxGoat"].len(), 8);
+
+        // every field of the schema is decoded, and encoded back unchanged
+        for (message, fields) in &schema {
+            for &(ty, name, tag) in fields {
+                let mut bytes = Vec::new();
+                let wire = wire_type(&schema, ty);
+                encode_key(tag, wire, &mut bytes);
+                match ty {
+                    "uint32" | "uint64" => encode_varint(u64::from(tag), &mut bytes),
+                    "bytes" => bytes.extend([1, tag as u8]),
+                    _ => bytes.push(0),
+                }
+                assert_eq!(reencode(message, &bytes), bytes, "{message}.{name}");
+            }
+        }
+
+        // every field of the messages is declared in the schema
+        for inner in inners() {
+            let tx = goat_tx(7, inner);
+            check_fields(&schema, "TxGoat", &TxGoat::from(&tx).encode_to_vec());
+        }
+    }
+
+    fn inners() -> Vec<TxGoatInner> {
+        vec![
+            TxGoatInner::Deposit(goat::DepositTx {
+                tx_id: B256::with_last_byte(1),
+                tx_out: 2,
+                target: Address::with_last_byte(3),
+                amount: U256::from(10u64).pow(U256::from(18)),
+                tax: U256::ZERO,
+            }),
+            TxGoatInner::Cancel2(goat::Cancel2Tx { id: U256::MAX }),
+            TxGoatInner::Paid(goat::PaidTx {
+                id: U256::from(4),
+                tx_id: B256::with_last_byte(5),
+                tx_out: u32::MAX,
+                amount: U256::from(6),
+            }),
+            TxGoatInner::NewBtcBlock(goat::NewBtcBlockTx { hash: B256::repeat_byte(7) }),
+            TxGoatInner::CompleteUnlock(goat::CompleteUnlockTx {
+                id: U256::from(8),
+                recipient: Address::with_last_byte(9),
+                token: Address::ZERO,
+                amount: U256::from(10),
+            }),
+            TxGoatInner::DistributeReward(goat::DistributeRewardTx {
+                id: U256::from(11),
+                recipient: Address::with_last_byte(12),
+                goat: U256::from(13),
+                gas_reward: U256::from(14),
+            }),
+        ]
+    }
+
+    #[test]
+    fn proto_roundtrip() {
+        for (nonce, inner) in inners().into_iter().enumerate() {
+            let tx = goat_tx(nonce as u64, inner);
+            let encoded = TxGoat::from(&tx).encode_to_vec();
+            let decoded = TxGoat::decode(encoded.as_slice()).unwrap();
+            assert_eq!(goat::TxGoat::try_from(decoded).unwrap(), tx);
+        }
+    }
+
+    #[test]
+    fn proto_encoding() {
+        let tx = goat_tx(7, inners().remove(0));
+        let proto = TxGoat::from(&tx);
+        let Some(tx_goat::Inner::Deposit(deposit)) = &proto.inner else { unreachable!() };
+        // uint256 values without leading zeros, and zero as empty bytes
+        assert_eq!(deposit.amount, hex!("0de0b6b3a7640000"));
+        assert!(deposit.tax.is_empty());
+        assert_eq!(deposit.target.len(), 20);
+        assert_eq!(deposit.tx_id.len(), 32);
+
+        // leading zeros are accepted
+        let mut padded = deposit.clone();
+        padded.amount = U256::from(10u64).pow(U256::from(18)).to_be_bytes_vec();
+        let padded = goat::DepositTx::try_from(padded).unwrap();
+        assert_eq!(TxGoatInner::Deposit(padded), tx.inner);
+    }
+
+    #[test]
+    fn proto_errors() {
+        assert_eq!(goat::TxGoat::try_from(TxGoat::default()), Err(GoatProtoError::MissingAction));
+
+        let TxGoatInner::Deposit(deposit) = inners()[0] else { unreachable!() };
+        let deposit = DepositTx::from(deposit);
+
+        let mut invalid = deposit.clone();
+        invalid.target.pop();
+        assert_eq!(
+            goat::DepositTx::try_from(invalid),
+            Err(GoatProtoError::InvalidLength { field: "deposit.target", expected: 20, got: 19 })
+        );
+
+        let mut invalid = deposit.clone();
+        invalid.tx_id.clear();
+        assert_eq!(
+            goat::DepositTx::try_from(invalid),
+            Err(GoatProtoError::InvalidLength { field: "deposit.tx_id", expected: 32, got: 0 })
+        );
+
+        let mut invalid = deposit;
+        invalid.amount = vec![1; 33];
+        let err = goat::DepositTx::try_from(invalid).unwrap_err();
+        assert_eq!(err, GoatProtoError::Overflow { field: "deposit.amount", got: 33 });
+        assert_eq!(err.to_string(), "invalid deposit.amount: 33 bytes overflow uint256");
+    }
+}
@@ -0,0 +1,347 @@
+//! Partially signed goat transactions, for offline signing ceremonies.
+
+use super::{GoatActionMeta, TxGoat, TxGoatInner};
+use crate::{SignableTransaction, Signed};
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use alloy_primitives::{Address, Signature, B256};
+
+/// A bitcoin object a goat system transaction refers to, for the review of the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")
+)]
+pub enum BtcReference {
+    /// A bitcoin transaction output, e.g. of a deposit or a withdrawal payment.
+    Output {
+        /// The bitcoin transaction id.
+        tx_id: B256,
+        /// The output index in the bitcoin transaction.
+        tx_out: u32,
+    },
+    /// A bitcoin block.
+    Block {
+        /// The hash of the block.
+        hash: B256,
+    },
+}
+
+impl BtcReference {
+    /// Returns the bitcoin object the action refers to, if any.
+    pub const fn from_inner(inner: &TxGoatInner) -> Option<Self> {
+        match inner {
+            TxGoatInner::Deposit(tx) => Some(Self::Output { tx_id: tx.tx_id, tx_out: tx.tx_out }),
+            TxGoatInner::Paid(tx) => Some(Self::Output { tx_id: tx.tx_id, tx_out: tx.tx_out }),
+            TxGoatInner::NewBtcBlock(tx) => Some(Self::Block { hash: tx.hash }),
+            _ => None,
+        }
+    }
+}
+
+/// The description of a goat system transaction presented to its signers.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatTxMetadata {
+    /// A human readable summary of the transaction.
+    pub summary: String,
+    /// Why the transaction is sent.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub rationale: Option<String>,
+    /// The bitcoin objects the transaction refers to.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub btc_refs: Vec<BtcReference>,
+}
+
+impl GoatTxMetadata {
+    /// Returns the default metadata of the transaction, summarizing its action and referring to
+    /// the bitcoin object of the action.
+    pub fn for_tx(tx: &TxGoat) -> Self {
+        let action =
+            GoatActionMeta::find(tx.module, tx.action).map_or("UNKNOWN", |meta| meta.name());
+        Self {
+            summary: format!("{} {} #{}", tx.module, action, tx.nonce),
+            rationale: None,
+            btc_refs: BtcReference::from_inner(&tx.inner).into_iter().collect(),
+        }
+    }
+
+    /// Sets the rationale of the transaction.
+    pub fn with_rationale(mut self, rationale: impl Into<String>) -> Self {
+        self.rationale = Some(rationale.into());
+        self
+    }
+}
+
+/// Errors returned when collecting the signatures of a [`PartiallySignedGoatTx`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PsgtError {
+    /// The signer is not a member of the committee.
+    #[error("{0} is not a member of the signing committee")]
+    NotInCommittee(Address),
+    /// The signer already provided another signature.
+    #[error("conflicting signatures of {0}")]
+    ConflictingSignature(Address),
+    /// The merged containers are for different transactions or committees.
+    #[error("cannot merge partially signed goat transactions of different ceremonies")]
+    Mismatch,
+    /// Not enough committee members signed the transaction.
+    #[error("{signed} of the {threshold} required signatures were collected")]
+    Incomplete {
+        /// The number of collected signatures.
+        signed: usize,
+        /// The number of required signatures.
+        threshold: usize,
+    },
+    /// A signature was not made by its claimed signer.
+    #[error("invalid signature of {0}")]
+    InvalidSignature(Address),
+}
+
+/// A partially signed goat transaction ("PSGT").
+///
+/// Bundles an unsigned [`TxGoat`] with its [`GoatTxMetadata`] and the signatures collected from
+/// a signing committee, so that the transaction can be passed between air-gapped signers. Each
+/// signer reviews the metadata, signs the [`signature_hash`](Self::signature_hash) and adds its
+/// signature; the containers returned by the signers are then [merged](Self::merge) and
+/// [finalized](Self::finalize) once the threshold is reached.
+///
+/// The committee is ordered by priority: the finalized transaction carries the signature of the
+/// first committee member that signed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PartiallySignedGoatTx {
+    /// The unsigned transaction.
+    pub tx: TxGoat,
+    /// The description of the transaction.
+    pub metadata: GoatTxMetadata,
+    /// The members of the signing committee, by priority.
+    pub committee: Vec<Address>,
+    /// The number of signatures required to finalize the transaction.
+    pub threshold: usize,
+    /// The collected signatures, by signer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub signatures: BTreeMap<Address, Signature>,
+}
+
+impl PartiallySignedGoatTx {
+    /// Creates a new container without signatures, with the [default metadata] of the
+    /// transaction.
+    ///
+    /// [default metadata]: GoatTxMetadata::for_tx
+    pub fn new(tx: TxGoat, committee: Vec<Address>, threshold: usize) -> Self {
+        let metadata = GoatTxMetadata::for_tx(&tx);
+        Self { tx, metadata, committee, threshold, signatures: BTreeMap::new() }
+    }
+
+    /// Sets the metadata of the transaction.
+    pub fn with_metadata(mut self, metadata: GoatTxMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Returns the hash signed by the committee members.
+    pub fn signature_hash(&self) -> B256 {
+        self.tx.signature_hash()
+    }
+
+    /// Returns the canonical JSON of the container without its signatures, see
+    /// [`to_canonical_json`](super::to_canonical_json).
+    ///
+    /// The signers of a ceremony can compare the [`payload_hash`](Self::payload_hash) of their
+    /// copies to check that they review the same transaction, metadata and committee.
+    #[cfg(feature = "serde")]
+    pub fn canonical_payload(&self) -> String {
+        let mut value = serde_json::to_value(self).expect("failed to serialize goat payload");
+        if let Some(object) = value.as_object_mut() {
+            object.remove("signatures");
+        }
+        super::to_canonical_json(&value).expect("failed to serialize goat payload")
+    }
+
+    /// Returns the keccak256 hash of the [`canonical_payload`](Self::canonical_payload).
+    #[cfg(feature = "serde")]
+    pub fn payload_hash(&self) -> B256 {
+        alloy_primitives::keccak256(self.canonical_payload())
+    }
+
+    /// Adds the signature of a committee member.
+    ///
+    /// The signature is not verified, see [`verify_signatures`](Self::verify_signatures). Adding
+    /// the same signature twice is a no-op.
+    pub fn add_signature(
+        &mut self,
+        signer: Address,
+        signature: Signature,
+    ) -> Result<(), PsgtError> {
+        if !self.committee.contains(&signer) {
+            return Err(PsgtError::NotInCommittee(signer));
+        }
+        match self.signatures.get(&signer) {
+            Some(existing) if *existing != signature => {
+                Err(PsgtError::ConflictingSignature(signer))
+            }
+            _ => {
+                self.signatures.insert(signer, signature);
+                Ok(())
+            }
+        }
+    }
+
+    /// Merges the signatures of another container of the same ceremony.
+    ///
+    /// The metadata of `self` is kept. On error, the signatures merged before the failing one are
+    /// kept.
+    pub fn merge(&mut self, other: Self) -> Result<(), PsgtError> {
+        if self.tx != other.tx
+            || self.committee != other.committee
+            || self.threshold != other.threshold
+        {
+            return Err(PsgtError::Mismatch);
+        }
+        other.signatures.into_iter().try_for_each(|(signer, sig)| self.add_signature(signer, sig))
+    }
+
+    /// Returns true if the threshold of signatures is reached.
+    pub fn is_complete(&self) -> bool {
+        self.signatures.len() >= self.threshold
+    }
+
+    /// Verifies that every collected signature was made by its claimed signer.
+    #[cfg(feature = "k256")]
+    pub fn verify_signatures(&self) -> Result<(), PsgtError> {
+        let hash = self.signature_hash();
+        self.signatures.iter().try_for_each(|(signer, sig)| {
+            match sig.recover_address_from_prehash(&hash) {
+                Ok(recovered) if recovered == *signer => Ok(()),
+                _ => Err(PsgtError::InvalidSignature(*signer)),
+            }
+        })
+    }
+
+    /// Returns the signed transaction once the threshold of signatures is reached, signed with
+    /// the signature of the first committee member that signed.
+    pub fn finalize(self) -> Result<Signed<TxGoat>, PsgtError> {
+        let signature = self.committee.iter().find_map(|signer| self.signatures.get(signer));
+        match signature {
+            Some(signature) if self.is_complete() => Ok(self.tx.into_signed(*signature)),
+            _ => Err(PsgtError::Incomplete {
+                signed: self.signatures.len(),
+                threshold: self.threshold,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::goat::{
+        test_utils::{goat_tx, new_btc_block_tx},
+        DepositTx,
+    };
+
+    fn deposit() -> TxGoat {
+        let deposit = DepositTx { tx_id: B256::with_last_byte(1), tx_out: 2, ..Default::default() };
+        goat_tx(5, TxGoatInner::Deposit(deposit))
+    }
+
+    #[test]
+    fn psgt_metadata() {
+        let metadata = GoatTxMetadata::for_tx(&deposit());
+        assert_eq!(metadata.summary, "BRIDGE DEPOSIT #5");
+        assert_eq!(
+            metadata.btc_refs,
+            [BtcReference::Output { tx_id: B256::with_last_byte(1), tx_out: 2 }]
+        );
+
+        let tx = new_btc_block_tx(0, B256::with_last_byte(3)).strip_signature();
+        assert_eq!(
+            GoatTxMetadata::for_tx(&tx).btc_refs,
+            [BtcReference::Block { hash: B256::with_last_byte(3) }]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn psgt_ceremony() {
+        use alloy_primitives::keccak256;
+        use k256::ecdsa::SigningKey;
+
+        let keys = (1u8..=3)
+            .map(|i| SigningKey::from_slice(keccak256([i]).as_slice()).unwrap())
+            .collect::<Vec<_>>();
+        let committee = keys.iter().map(Address::from_private_key).collect::<Vec<_>>();
+        let sign = |psgt: &PartiallySignedGoatTx, i: usize| {
+            let secret = B256::from_slice(&keys[i].to_bytes());
+            crate::crypto::secp256k1::sign_message(secret, psgt.signature_hash()).unwrap()
+        };
+
+        let psgt = PartiallySignedGoatTx::new(deposit(), committee.clone(), 2)
+            .with_metadata(GoatTxMetadata::for_tx(&deposit()).with_rationale("deposit confirmed"));
+
+        // the signers sign their own copies
+        let mut first = psgt.clone();
+        let mut third = psgt.clone();
+        first.add_signature(committee[0], sign(&psgt, 0)).unwrap();
+        third.add_signature(committee[2], sign(&psgt, 2)).unwrap();
+        assert!(!first.is_complete());
+        assert_eq!(
+            first.clone().finalize().unwrap_err(),
+            PsgtError::Incomplete { signed: 1, threshold: 2 }
+        );
+
+        let mut merged = psgt.clone();
+        merged.merge(third).unwrap();
+        merged.merge(first.clone()).unwrap();
+        merged.merge(first).unwrap();
+        assert!(merged.is_complete());
+        merged.verify_signatures().unwrap();
+
+        let signed = merged.clone().finalize().unwrap();
+        assert_eq!(*signed.signature(), sign(&psgt, 0));
+        assert_eq!(signed.strip_signature(), deposit());
+
+        // invalid signatures
+        let mut invalid = psgt.clone();
+        assert_eq!(
+            invalid.add_signature(Address::ZERO, sign(&psgt, 0)),
+            Err(PsgtError::NotInCommittee(Address::ZERO))
+        );
+        invalid.add_signature(committee[1], sign(&psgt, 0)).unwrap();
+        assert_eq!(invalid.verify_signatures(), Err(PsgtError::InvalidSignature(committee[1])));
+
+        let mut conflicting = psgt.clone();
+        conflicting.add_signature(committee[0], sign(&psgt, 1)).unwrap();
+        assert_eq!(merged.merge(conflicting), Err(PsgtError::ConflictingSignature(committee[0])));
+
+        let mut other = PartiallySignedGoatTx::new(deposit(), committee, 3);
+        assert_eq!(other.merge(psgt), Err(PsgtError::Mismatch));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn psgt_serde() {
+        let mut psgt = PartiallySignedGoatTx::new(deposit(), vec![Address::with_last_byte(1)], 1);
+        psgt.add_signature(Address::with_last_byte(1), Signature::test_signature()).unwrap();
+
+        // the payload does not depend on the collected signatures
+        let payload = psgt.canonical_payload();
+        assert!(
+            payload.starts_with(r#"{"committee":["0x0000000000000000000000000000000000000001"]"#)
+        );
+        assert_eq!(psgt.payload_hash(), alloy_primitives::keccak256(&payload));
+        let mut unsigned = psgt.clone();
+        unsigned.signatures.clear();
+        assert_eq!(unsigned.payload_hash(), psgt.payload_hash());
+        unsigned.metadata.rationale = Some("other".into());
+        assert_ne!(unsigned.payload_hash(), psgt.payload_hash());
+
+        let json = serde_json::to_value(&psgt).unwrap();
+        assert_eq!(json["metadata"]["btcRefs"][0]["kind"], "output");
+        assert_eq!(json["metadata"]["btcRefs"][0]["txOut"], 2);
+        assert_eq!(serde_json::from_value::<PartiallySignedGoatTx>(json).unwrap(), psgt);
+    }
+}
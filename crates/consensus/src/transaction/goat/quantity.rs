@@ -0,0 +1,254 @@
+//! Tolerant serde of the numeric fields of the goat payloads.
+//!
+//! Used as `#[serde(with = "quantity")]`, the module serializes a number as a hex quantity, and
+//! deserializes it from a hex quantity, a decimal string or a JSON number.
+//!
+//! Depending on their version, goat-geth nodes return the ids, output indices and amounts of the
+//! goat payloads either as `0x`-prefixed hex or as decimal strings. Both are accepted, with any
+//! number of leading zeros and a `0x` or `0X` prefix, and the output is always normalized to a
+//! hex quantity. Signs, whitespace, underscores and fractions are rejected, as are values that
+//! overflow the field.
+//!
+//! Non human-readable formats use the plain serde representation of the number, so that the
+//! bincode encoding of the goat payloads is unchanged.
+
+use alloc::format;
+use alloy_primitives::U256;
+use core::fmt;
+use serde::{
+    de::{Error, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes a number as a hex quantity.
+pub(super) fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: HexOrDecimal,
+    S: Serializer,
+{
+    if !serializer.is_human_readable() {
+        return value.serialize(serializer);
+    }
+    value.to_quantity().serialize(serializer)
+}
+
+/// Deserializes a number from a hex quantity, a decimal string or a JSON number.
+pub(super) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: HexOrDecimal,
+    D: Deserializer<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return T::deserialize(deserializer);
+    }
+    let value = deserializer.deserialize_any(HexOrDecimalVisitor)?;
+    T::try_from(value)
+        .map_err(|_| D::Error::custom(format!("quantity {value} overflows {}", T::NAME)))
+}
+
+/// A number accepted by the [`serialize`] and [`deserialize`] functions.
+pub(super) trait HexOrDecimal:
+    Copy + Serialize + for<'de> Deserialize<'de> + TryFrom<U256>
+{
+    /// The name of the type, used in overflow errors.
+    const NAME: &'static str;
+
+    /// Widens the number to a quantity.
+    fn to_quantity(self) -> U256;
+}
+
+impl HexOrDecimal for u32 {
+    const NAME: &'static str = "u32";
+
+    fn to_quantity(self) -> U256 {
+        U256::from(self)
+    }
+}
+
+impl HexOrDecimal for u64 {
+    const NAME: &'static str = "u64";
+
+    fn to_quantity(self) -> U256 {
+        U256::from(self)
+    }
+}
+
+impl HexOrDecimal for U256 {
+    const NAME: &'static str = "U256";
+
+    fn to_quantity(self) -> U256 {
+        self
+    }
+}
+
+struct HexOrDecimalVisitor;
+
+impl Visitor<'_> for HexOrDecimalVisitor {
+    type Value = U256;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a hex quantity, a decimal string or an unsigned integer")
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(U256::from(v))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(U256::from(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let (digits, radix) =
+            v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")).map_or((v, 10), |hex| (hex, 16));
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(E::invalid_value(Unexpected::Str(v), &self));
+        }
+        U256::from_str_radix(digits, u64::from(radix))
+            .map_err(|_| E::custom(format!("quantity {v} overflows U256")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Quantities {
+        #[serde(with = "super")]
+        small: u32,
+        #[serde(with = "super")]
+        word: u64,
+        #[serde(with = "super")]
+        big: U256,
+    }
+
+    fn parse(value: Value) -> Result<(u32, u64, U256), serde_json::Error> {
+        let parsed: Quantities =
+            serde_json::from_value(json!({ "small": value, "word": value, "big": value }))?;
+        Ok((parsed.small, parsed.word, parsed.big))
+    }
+
+    fn parse_big(value: Value) -> Result<U256, serde_json::Error> {
+        let parsed: Quantities =
+            serde_json::from_value(json!({ "small": 0, "word": 0, "big": value }))?;
+        Ok(parsed.big)
+    }
+
+    #[test]
+    fn hex_or_decimal() {
+        let valid = [
+            (json!("0x0"), 0),
+            (json!("0"), 0),
+            (json!(0), 0),
+            (json!("0x2a"), 42),
+            (json!("0x2A"), 42),
+            (json!("0X2a"), 42),
+            (json!("0x002a"), 42),
+            (json!("42"), 42),
+            (json!("0042"), 42),
+            (json!(42), 42),
+            (json!("0xffffffff"), u32::MAX),
+            (json!("4294967295"), u32::MAX),
+            (json!(u32::MAX), u32::MAX),
+        ];
+        for (value, expected) in valid {
+            let parsed = parse(value.clone()).unwrap_or_else(|err| panic!("{value}: {err}"));
+            assert_eq!(parsed, (expected, expected as u64, U256::from(expected)), "{value}");
+        }
+
+        let invalid = [
+            json!(""),
+            json!("0x"),
+            json!("0X"),
+            json!("x2a"),
+            json!("2a"),
+            json!("0x2g"),
+            json!("-1"),
+            json!("+1"),
+            json!(" 1"),
+            json!("1 "),
+            json!("1_000"),
+            json!("1.0"),
+            json!("1e3"),
+            json!("0b1"),
+            json!("0o7"),
+            json!(-1),
+            json!(1.5),
+            json!(true),
+            json!(null),
+            json!([1]),
+            json!({}),
+        ];
+        for value in invalid {
+            assert!(parse(value.clone()).is_err(), "{value}");
+            assert!(parse_big(value.clone()).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn hex_or_decimal_bounds() {
+        // overflows of the field
+        for value in [json!("0x100000000"), json!("4294967296"), json!(u64::from(u32::MAX) + 1)] {
+            let err = parse(value.clone()).unwrap_err();
+            assert!(err.to_string().contains("overflows u32"), "{value}: {err}");
+        }
+        assert_eq!(parse_big(json!(u64::MAX)).unwrap(), U256::from(u64::MAX));
+
+        let max = U256::MAX;
+        assert_eq!(parse_big(json!(format!("{max:#x}"))).unwrap(), max);
+        assert_eq!(parse_big(json!(max.to_string())).unwrap(), max);
+        for value in [format!("0x1{}", "0".repeat(64)), (max.to_string() + "0")] {
+            let err = parse_big(json!(value)).unwrap_err();
+            assert!(err.to_string().contains("overflows U256"), "{value}: {err}");
+        }
+    }
+
+    #[test]
+    fn hex_or_decimal_output() {
+        let quantities = Quantities { small: 42, word: u64::MAX, big: U256::from(1) << 255 };
+        let json = serde_json::to_value(&quantities).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "small": "0x2a",
+                "word": "0xffffffffffffffff",
+                "big": "0x8000000000000000000000000000000000000000000000000000000000000000",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Quantities>(json).unwrap(), quantities);
+
+        let zero = Quantities { small: 0, word: 0, big: U256::ZERO };
+        let json = serde_json::to_value(&zero).unwrap();
+        assert_eq!(json, json!({ "small": "0x0", "word": "0x0", "big": "0x0" }));
+
+        // decimal input is normalized to hex
+        let parsed: Quantities =
+            serde_json::from_value(json!({ "small": "42", "word": 7, "big": "256" })).unwrap();
+        assert_eq!(
+            serde_json::to_value(parsed).unwrap(),
+            json!({ "small": "0x2a", "word": "0x7", "big": "0x100" })
+        );
+    }
+
+    #[test]
+    fn hex_or_decimal_binary() {
+        #[derive(Serialize)]
+        struct Plain {
+            small: u32,
+            word: u64,
+            big: U256,
+        }
+
+        let config = bincode::config::legacy();
+        let quantities = Quantities { small: 42, word: 7, big: U256::from(256) };
+        let encoded = bincode::serde::encode_to_vec(&quantities, config).unwrap();
+        let plain = Plain { small: 42, word: 7, big: U256::from(256) };
+        assert_eq!(encoded, bincode::serde::encode_to_vec(&plain, config).unwrap());
+
+        let (decoded, _) =
+            bincode::serde::decode_from_slice::<Quantities, _>(&encoded, config).unwrap();
+        assert_eq!(decoded, quantities);
+    }
+}
@@ -0,0 +1,144 @@
+//! Decoding of goat actions defined outside of this crate.
+
+use super::{decode_tx, Action, GoatActionMeta, Module, TxGoatInner};
+use alloc::collections::BTreeMap;
+
+/// Decodes the calldata of a downstream-defined goat action.
+///
+/// The decoder is given the whole input, and must reject trailing bytes.
+pub type GoatActionDecoder<T> = fn(&[u8]) -> alloy_rlp::Result<T>;
+
+/// An action decoded by [`decode_goat_tx`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GoatAction<T> {
+    /// An action built into this crate.
+    Builtin(TxGoatInner),
+    /// An action decoded by a decoder of the [`GoatModuleRegistry`].
+    Custom(T),
+}
+
+impl<T> GoatAction<T> {
+    /// Returns the built-in action, if any.
+    pub const fn as_builtin(&self) -> Option<&TxGoatInner> {
+        match self {
+            Self::Builtin(inner) => Some(inner),
+            Self::Custom(_) => None,
+        }
+    }
+
+    /// Returns the downstream-defined action, if any.
+    pub const fn as_custom(&self) -> Option<&T> {
+        match self {
+            Self::Builtin(_) => None,
+            Self::Custom(action) => Some(action),
+        }
+    }
+}
+
+/// Decoders of goat actions that are not built into this crate, e.g. the modules of a GOAT fork
+/// or of an experimental devnet.
+///
+/// Modules and actions are keyed by their raw identifiers, since [`Module`] and [`Action`] only
+/// represent the built-in ones. The built-in actions always take precedence, see
+/// [`decode_goat_tx`].
+#[derive(Clone, Debug)]
+pub struct GoatModuleRegistry<T> {
+    decoders: BTreeMap<(u8, u8), GoatActionDecoder<T>>,
+}
+
+impl<T> Default for GoatModuleRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> GoatModuleRegistry<T> {
+    /// Creates an empty registry.
+    pub const fn new() -> Self {
+        Self { decoders: BTreeMap::new() }
+    }
+
+    /// Registers the decoder of an action, returning the decoder it replaces, if any.
+    ///
+    /// A decoder registered for a built-in action is never consulted.
+    pub fn register(
+        &mut self,
+        module: u8,
+        action: u8,
+        decoder: GoatActionDecoder<T>,
+    ) -> Option<GoatActionDecoder<T>> {
+        self.decoders.insert((module, action), decoder)
+    }
+
+    /// Returns the decoder registered for the action, if any.
+    pub fn get(&self, module: u8, action: u8) -> Option<GoatActionDecoder<T>> {
+        self.decoders.get(&(module, action)).copied()
+    }
+
+    /// Returns the number of registered decoders.
+    pub fn len(&self) -> usize {
+        self.decoders.len()
+    }
+
+    /// Returns true if no decoder is registered.
+    pub fn is_empty(&self) -> bool {
+        self.decoders.is_empty()
+    }
+}
+
+/// Decodes the calldata of a goat action, built-in or registered.
+///
+/// The built-in actions are decoded with [`decode_tx`], and the registry is only consulted for the
+/// other identifiers. Returns an error if the action is neither built-in nor registered.
+pub fn decode_goat_tx<T>(
+    registry: &GoatModuleRegistry<T>,
+    module: u8,
+    action: u8,
+    input: &[u8],
+) -> alloy_rlp::Result<GoatAction<T>> {
+    if let (Ok(module), Ok(action)) = (Module::try_from(module), Action::try_from(action)) {
+        if GoatActionMeta::find(module, action).is_some() {
+            return decode_tx(module, action, input).map(GoatAction::Builtin);
+        }
+    }
+    let decoder = registry
+        .get(module, action)
+        .ok_or(alloy_rlp::Error::Custom("unknown goat module or action"))?;
+    decoder(input).map(GoatAction::Custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::goat::Cancel2Tx;
+    use alloy_primitives::U256;
+
+    fn decode_ping(input: &[u8]) -> alloy_rlp::Result<u8> {
+        match input {
+            [0xaa, id] => Ok(*id),
+            _ => Err(alloy_rlp::Error::Custom("bad ping")),
+        }
+    }
+
+    fn decode_nothing(_: &[u8]) -> alloy_rlp::Result<u8> {
+        Err(alloy_rlp::Error::Custom("shadowed"))
+    }
+
+    #[test]
+    fn registry_after_builtins() {
+        let mut registry = GoatModuleRegistry::new();
+        assert!(registry.register(7, 1, decode_ping).is_none());
+        assert!(registry.register(1, 2, decode_nothing).is_none());
+        assert_eq!(registry.len(), 2);
+
+        assert_eq!(decode_goat_tx(&registry, 7, 1, &[0xaa, 5]), Ok(GoatAction::Custom(5)));
+        assert!(decode_goat_tx(&registry, 7, 1, &[0xaa]).is_err());
+        assert!(decode_goat_tx(&registry, 7, 2, &[0xaa, 5]).is_err());
+
+        // built-in actions are not shadowed by the registry
+        let cancel = TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(3) });
+        let action = decode_goat_tx(&registry, 1, 2, &cancel.input()).unwrap();
+        assert_eq!(action.as_builtin(), Some(&cancel));
+        assert!(decode_goat_tx(&GoatModuleRegistry::<u8>::new(), 7, 1, &[0xaa, 5]).is_err());
+    }
+}
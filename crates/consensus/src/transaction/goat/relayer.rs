@@ -0,0 +1,23 @@
+//! Relayer module actions.
+
+use super::{
+    abi::goat_action, GoatContract, RELAYER_EXECUTOR, RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION,
+};
+use alloy_primitives::B256;
+
+goat_action! {
+    /// The submission of a new bitcoin block hash.
+    ///
+    /// Calldata of `newBlockHash(bytes32)`.
+    pub struct NewBtcBlockTx {
+        /// The bitcoin block hash.
+        pub hash: B256,
+    }
+
+    signature = "newBlockHash(bytes32)",
+    method_id = [0x94, 0xf4, 0x90, 0xbd],
+    module = RELAYER_MODULE,
+    action = RELAYER_NEW_BTC_BLOCK_ACTION,
+    sender = RELAYER_EXECUTOR,
+    contract = GoatContract::Bitcoin,
+}
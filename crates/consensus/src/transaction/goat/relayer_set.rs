@@ -0,0 +1,211 @@
+//! Off-chain tracking of the relayer committee.
+
+use alloc::vec::Vec;
+use alloy_primitives::Address;
+
+/// A rotation of the relayer committee, taking effect at the start of an epoch.
+///
+/// The [`RELAYER_MODULE`](super::RELAYER_MODULE) has no action rotating the committee, so the
+/// update has no consensus encoding, it is built by the client tracking the committee, e.g. with
+/// [`RelayerSet::update_to`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct RelayerSetUpdate {
+    /// The epoch starting with the update.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub epoch: u64,
+    /// The relayers joining the committee.
+    pub added: Vec<Address>,
+    /// The relayers leaving the committee.
+    pub removed: Vec<Address>,
+    /// The number of relayer signatures required from the epoch on.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub threshold: u64,
+}
+
+/// Errors returned when validating or updating a [`RelayerSet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum RelayerSetError {
+    /// The update is not for the epoch following the current one.
+    #[error("relayer set update for epoch {got}, expected epoch {expected}")]
+    UnexpectedEpoch {
+        /// The epoch following the current one.
+        expected: u64,
+        /// The epoch of the update.
+        got: u64,
+    },
+    /// An added relayer is already a member of the committee.
+    #[error("relayer {0} is already a member of the committee")]
+    AlreadyMember(Address),
+    /// A removed relayer is not a member of the committee.
+    #[error("relayer {0} is not a member of the committee")]
+    NotMember(Address),
+    /// The threshold is zero or exceeds the size of the committee.
+    #[error("invalid threshold {threshold} for a committee of {members} relayers")]
+    InvalidThreshold {
+        /// The threshold.
+        threshold: u64,
+        /// The size of the committee.
+        members: usize,
+    },
+}
+
+/// The relayer committee of an epoch, as tracked off-chain by applying [`RelayerSetUpdate`]s.
+///
+/// The members are kept sorted and unique.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct RelayerSet {
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    epoch: u64,
+    members: Vec<Address>,
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    threshold: u64,
+}
+
+impl RelayerSet {
+    /// Creates the committee of an epoch.
+    ///
+    /// Duplicate members are ignored. Returns an error if the threshold is zero or exceeds the
+    /// number of members.
+    pub fn new(
+        epoch: u64,
+        mut members: Vec<Address>,
+        threshold: u64,
+    ) -> Result<Self, RelayerSetError> {
+        members.sort_unstable();
+        members.dedup();
+        check_threshold(threshold, members.len())?;
+        Ok(Self { epoch, members, threshold })
+    }
+
+    /// Returns the epoch of the committee.
+    pub const fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the sorted members of the committee.
+    pub fn members(&self) -> &[Address] {
+        &self.members
+    }
+
+    /// Returns the number of relayer signatures required.
+    pub const fn threshold(&self) -> u64 {
+        self.threshold
+    }
+
+    /// Returns true if the relayer is a member of the committee.
+    pub fn contains(&self, relayer: &Address) -> bool {
+        self.members.binary_search(relayer).is_ok()
+    }
+
+    /// Applies an update, moving the committee to the epoch of the update.
+    ///
+    /// The removed relayers are removed before the added relayers are added. On error, the
+    /// committee is left unchanged.
+    pub fn apply(&mut self, update: &RelayerSetUpdate) -> Result<(), RelayerSetError> {
+        let expected = self.epoch.saturating_add(1);
+        if update.epoch != expected {
+            return Err(RelayerSetError::UnexpectedEpoch { expected, got: update.epoch });
+        }
+
+        let mut members = self.members.clone();
+        for relayer in &update.removed {
+            let index =
+                members.binary_search(relayer).map_err(|_| RelayerSetError::NotMember(*relayer))?;
+            members.remove(index);
+        }
+        for relayer in &update.added {
+            let index = members
+                .binary_search(relayer)
+                .err()
+                .ok_or(RelayerSetError::AlreadyMember(*relayer))?;
+            members.insert(index, *relayer);
+        }
+        check_threshold(update.threshold, members.len())?;
+
+        *self = Self { epoch: update.epoch, members, threshold: update.threshold };
+        Ok(())
+    }
+
+    /// Returns the update moving this committee to the given committee of the next epoch.
+    pub fn update_to(&self, next: &Self) -> RelayerSetUpdate {
+        RelayerSetUpdate {
+            epoch: next.epoch,
+            added: next.members.iter().filter(|m| !self.contains(m)).copied().collect(),
+            removed: self.members.iter().filter(|m| !next.contains(m)).copied().collect(),
+            threshold: next.threshold,
+        }
+    }
+}
+
+const fn check_threshold(threshold: u64, members: usize) -> Result<(), RelayerSetError> {
+    if threshold == 0 || threshold > members as u64 {
+        return Err(RelayerSetError::InvalidThreshold { threshold, members });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relayer(i: u8) -> Address {
+        Address::with_last_byte(i)
+    }
+
+    #[test]
+    fn relayer_set_rotation() {
+        let mut set =
+            RelayerSet::new(7, vec![relayer(3), relayer(1), relayer(2), relayer(1)], 2).unwrap();
+        assert_eq!(set.members(), [relayer(1), relayer(2), relayer(3)]);
+
+        let update = RelayerSetUpdate {
+            epoch: 8,
+            added: vec![relayer(4), relayer(0)],
+            removed: vec![relayer(2)],
+            threshold: 3,
+        };
+        let next = {
+            let mut next = set.clone();
+            next.apply(&update).unwrap();
+            next
+        };
+        assert_eq!(next.epoch(), 8);
+        assert_eq!(next.members(), [relayer(0), relayer(1), relayer(3), relayer(4)]);
+        assert!(next.contains(&relayer(4)) && !next.contains(&relayer(2)));
+
+        let diff = set.update_to(&next);
+        assert_eq!(diff.added, [relayer(0), relayer(4)]);
+        assert_eq!(diff.removed, [relayer(2)]);
+        assert_eq!(diff.threshold, 3);
+
+        // invalid updates leave the set unchanged
+        let before = set.clone();
+        let invalid = |f: fn(&mut RelayerSetUpdate)| {
+            let mut update = update.clone();
+            f(&mut update);
+            update
+        };
+        assert_eq!(
+            set.apply(&invalid(|u| u.epoch = 9)),
+            Err(RelayerSetError::UnexpectedEpoch { expected: 8, got: 9 })
+        );
+        assert_eq!(
+            set.apply(&invalid(|u| u.added.push(relayer(1)))),
+            Err(RelayerSetError::AlreadyMember(relayer(1)))
+        );
+        assert_eq!(
+            set.apply(&invalid(|u| u.removed.push(relayer(9)))),
+            Err(RelayerSetError::NotMember(relayer(9)))
+        );
+        assert_eq!(
+            set.apply(&invalid(|u| u.threshold = 5)),
+            Err(RelayerSetError::InvalidThreshold { threshold: 5, members: 4 })
+        );
+        assert_eq!(set, before);
+        assert!(RelayerSet::new(0, vec![], 0).is_err());
+    }
+}
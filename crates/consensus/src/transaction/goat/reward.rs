@@ -0,0 +1,146 @@
+//! Locking reward schedule.
+
+use super::DistributeRewardTx;
+use alloy_primitives::{Address, U256};
+
+/// The GOAT token reward schedule of the locking module.
+///
+/// The reward of an epoch is halved every `halving_interval` epochs, and the gas fees collected
+/// during the epoch are distributed along with it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct RewardSchedule {
+    /// The GOAT token reward of an epoch before the first halving.
+    pub initial_reward: U256,
+    /// The number of epochs between two halvings, or zero if the reward is never halved.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub halving_interval: u64,
+}
+
+/// The parameters of a reward epoch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct RewardEpoch {
+    /// The epoch number.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub epoch: u64,
+    /// The gas fees collected during the epoch, in wei.
+    pub gas_fee_pool: U256,
+}
+
+/// Error returned by [`RewardSchedule::verify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum RewardMismatch {
+    /// The GOAT token reward does not follow the schedule.
+    #[error("unexpected goat reward: expected {expected}, got {got}")]
+    Goat {
+        /// The scheduled reward.
+        expected: U256,
+        /// The reward of the transaction.
+        got: U256,
+    },
+    /// The gas fee reward is not the gas fee pool of the epoch.
+    #[error("unexpected gas reward: expected {expected}, got {got}")]
+    GasReward {
+        /// The gas fee pool of the epoch.
+        expected: U256,
+        /// The gas fee reward of the transaction.
+        got: U256,
+    },
+}
+
+impl RewardSchedule {
+    /// Creates a new reward schedule.
+    pub const fn new(initial_reward: U256, halving_interval: u64) -> Self {
+        Self { initial_reward, halving_interval }
+    }
+
+    /// Returns the number of halvings applied to the reward of an epoch.
+    pub const fn halvings(&self, epoch: u64) -> u64 {
+        match self.halving_interval {
+            0 => 0,
+            interval => epoch / interval,
+        }
+    }
+
+    /// Returns the GOAT token reward of an epoch.
+    pub fn goat_reward(&self, epoch: u64) -> U256 {
+        let halvings = self.halvings(epoch);
+        if halvings >= U256::BITS as u64 {
+            return U256::ZERO;
+        }
+        self.initial_reward >> halvings as usize
+    }
+
+    /// Returns the expected reward transaction of an epoch.
+    pub fn reward_tx(
+        &self,
+        id: U256,
+        recipient: Address,
+        epoch: &RewardEpoch,
+    ) -> DistributeRewardTx {
+        DistributeRewardTx {
+            id,
+            recipient,
+            goat: self.goat_reward(epoch.epoch),
+            gas_reward: epoch.gas_fee_pool,
+        }
+    }
+
+    /// Checks that the rewards of a transaction follow the schedule.
+    pub fn verify(
+        &self,
+        tx: &DistributeRewardTx,
+        epoch: &RewardEpoch,
+    ) -> Result<(), RewardMismatch> {
+        let expected = self.goat_reward(epoch.epoch);
+        if tx.goat != expected {
+            return Err(RewardMismatch::Goat { expected, got: tx.goat });
+        }
+        if tx.gas_reward != epoch.gas_fee_pool {
+            return Err(RewardMismatch::GasReward {
+                expected: epoch.gas_fee_pool,
+                got: tx.gas_reward,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halving_schedule() {
+        let schedule = RewardSchedule::new(U256::from(1000), 10);
+        assert_eq!(schedule.goat_reward(0), U256::from(1000));
+        assert_eq!(schedule.goat_reward(9), U256::from(1000));
+        assert_eq!(schedule.goat_reward(10), U256::from(500));
+        assert_eq!(schedule.goat_reward(35), U256::from(125));
+        assert_eq!(schedule.goat_reward(u64::MAX), U256::ZERO);
+
+        let constant = RewardSchedule::new(U256::from(1000), 0);
+        assert_eq!(constant.goat_reward(u64::MAX), U256::from(1000));
+    }
+
+    #[test]
+    fn verify_reward_tx() {
+        let schedule = RewardSchedule::new(U256::from(1000), 10);
+        let epoch = RewardEpoch { epoch: 12, gas_fee_pool: U256::from(42) };
+        let tx = schedule.reward_tx(U256::from(1), Address::with_last_byte(1), &epoch);
+        assert_eq!(tx.goat, U256::from(500));
+        assert_eq!(schedule.verify(&tx, &epoch), Ok(()));
+
+        assert_eq!(
+            schedule.verify(&DistributeRewardTx { goat: U256::from(1000), ..tx }, &epoch),
+            Err(RewardMismatch::Goat { expected: U256::from(500), got: U256::from(1000) })
+        );
+        assert_eq!(
+            schedule.verify(&DistributeRewardTx { gas_reward: U256::ZERO, ..tx }, &epoch),
+            Err(RewardMismatch::GasReward { expected: U256::from(42), got: U256::ZERO })
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! JSON Schemas of the goat types, for RPC documentation and client generators.
+//!
+//! The field types without a [`JsonSchema`] implementation are described by the functions of this
+//! module, matching their serde representation.
+
+use super::{Action, Module};
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// The schema of a hex encoded quantity, e.g. a [`U256`](alloy_primitives::U256) or a `u64`
+/// serialized with [`alloy_serde::quantity`].
+pub(super) fn quantity(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "description": "hex encoded quantity",
+        "type": "string",
+        "pattern": "^0x(0|[1-9a-fA-F][0-9a-fA-F]*)$"
+    })
+}
+
+/// The schema of hex encoded [`Bytes`](alloy_primitives::Bytes).
+pub(super) fn bytes(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "description": "hex encoded bytes",
+        "type": "string",
+        "pattern": "^0x([0-9a-fA-F]{2})*$"
+    })
+}
+
+macro_rules! impl_goat_id_schema {
+    ($ty:ident, $description:literal) => {
+        impl JsonSchema for $ty {
+            fn schema_name() -> Cow<'static, str> {
+                Cow::Borrowed(stringify!($ty))
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                Cow::Borrowed(concat!(module_path!(), "::", stringify!($ty)))
+            }
+
+            fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+                let mut schema = quantity(generator);
+                schema.insert("description".into(), $description.into());
+                schema
+            }
+        }
+    };
+}
+
+impl_goat_id_schema!(Module, "hex encoded goat module identifier");
+impl_goat_id_schema!(Action, "hex encoded goat action identifier");
+
+#[cfg(test)]
+mod tests {
+    use crate::transaction::goat::{TxGoat, TxGoatInner};
+    use schemars::{generate::SchemaSettings, schema_for};
+
+    #[test]
+    fn tx_goat_schema() {
+        let generator = SchemaSettings::default().for_serialize().into_generator();
+        let schema = generator.into_root_schema_for::<TxGoat>();
+        let properties = schema.get("properties").unwrap().as_object().unwrap();
+        assert_eq!(
+            properties.keys().collect::<Vec<_>>(),
+            ["action", "chainId", "input", "module", "nonce"]
+        );
+        assert_eq!(properties["nonce"]["pattern"], "^0x(0|[1-9a-fA-F][0-9a-fA-F]*)$");
+        assert_eq!(properties["module"]["$ref"], "#/$defs/Module");
+
+        // servers may also provide the decoded action
+        let schema = schema_for!(TxGoat);
+        assert!(schema.get("properties").unwrap().get("inner").is_some());
+    }
+
+    #[test]
+    fn tx_goat_inner_schema() {
+        let schema = serde_json::to_value(schema_for!(TxGoatInner)).unwrap();
+        let variants = schema["oneOf"].as_array().unwrap();
+        assert_eq!(variants.len(), 6);
+        assert_eq!(variants[0]["required"], serde_json::json!(["deposit"]));
+
+        let deposit = &schema["$defs"]["DepositTx"]["properties"];
+        assert_eq!(deposit["amount"]["pattern"], "^0x(0|[1-9a-fA-F][0-9a-fA-F]*)$");
+        assert_eq!(deposit["txOut"]["pattern"], deposit["amount"]["pattern"]);
+    }
+}
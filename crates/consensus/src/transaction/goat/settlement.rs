@@ -0,0 +1,126 @@
+//! Settlement of bridge withdrawals.
+
+use super::PaidTx;
+use alloy_primitives::{B256, U256};
+
+/// A withdrawal request to the bridge, as emitted by its `Withdraw` event.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct WithdrawalRequest {
+    /// The withdrawal id.
+    pub id: U256,
+    /// The requested amount, in wei.
+    pub amount: U256,
+}
+
+/// A withdrawal paid on bitcoin, see [`PaidTx::settle`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct WithdrawalSettlement {
+    /// The withdrawal id.
+    pub id: U256,
+    /// The bitcoin transaction id of the payment.
+    pub tx_id: B256,
+    /// The output index of the payment in the bitcoin transaction.
+    pub tx_out: u32,
+    /// The requested amount, in wei.
+    pub requested: U256,
+    /// The paid amount, in wei.
+    pub paid: U256,
+    /// The bitcoin network fee deducted from the requested amount, in wei.
+    pub network_fee: U256,
+}
+
+/// Errors returned by [`PaidTx::settle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum WithdrawalSettlementError {
+    /// The payment is for another withdrawal.
+    #[error("payment of withdrawal {paid} does not settle withdrawal {requested}")]
+    IdMismatch {
+        /// The id of the withdrawal request.
+        requested: U256,
+        /// The id of the paid withdrawal.
+        paid: U256,
+    },
+    /// More than the requested amount was paid.
+    #[error("paid amount {paid} exceeds the requested amount {requested}")]
+    Overpaid {
+        /// The requested amount, in wei.
+        requested: U256,
+        /// The paid amount, in wei.
+        paid: U256,
+    },
+}
+
+impl PaidTx {
+    /// Settles the withdrawal request paid by this transaction.
+    ///
+    /// The paid amount must not exceed the requested amount, the difference is the bitcoin network
+    /// fee of the payment.
+    pub fn settle(
+        &self,
+        request: &WithdrawalRequest,
+    ) -> Result<WithdrawalSettlement, WithdrawalSettlementError> {
+        if self.id != request.id {
+            return Err(WithdrawalSettlementError::IdMismatch {
+                requested: request.id,
+                paid: self.id,
+            });
+        }
+        let network_fee =
+            request.amount.checked_sub(self.amount).ok_or(WithdrawalSettlementError::Overpaid {
+                requested: request.amount,
+                paid: self.amount,
+            })?;
+        Ok(WithdrawalSettlement {
+            id: self.id,
+            tx_id: self.tx_id,
+            tx_out: self.tx_out,
+            requested: request.amount,
+            paid: self.amount,
+            network_fee,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settle_withdrawal() {
+        let request = WithdrawalRequest { id: U256::from(7), amount: U256::from(1_000_000) };
+        let paid = PaidTx {
+            id: U256::from(7),
+            tx_id: B256::with_last_byte(1),
+            tx_out: 1,
+            amount: U256::from(990_000),
+        };
+
+        let settlement = paid.settle(&request).unwrap();
+        assert_eq!(settlement.requested, request.amount);
+        assert_eq!(settlement.paid, paid.amount);
+        assert_eq!(settlement.network_fee, U256::from(10_000));
+        assert_eq!(settlement.tx_id, paid.tx_id);
+
+        let exact = PaidTx { amount: request.amount, ..paid };
+        assert_eq!(exact.settle(&request).unwrap().network_fee, U256::ZERO);
+
+        let overpaid = PaidTx { amount: U256::from(1_000_001), ..paid };
+        assert_eq!(
+            overpaid.settle(&request),
+            Err(WithdrawalSettlementError::Overpaid {
+                requested: request.amount,
+                paid: overpaid.amount
+            })
+        );
+
+        let other = PaidTx { id: U256::from(8), ..paid };
+        assert_eq!(
+            other.settle(&request),
+            Err(WithdrawalSettlementError::IdMismatch { requested: U256::from(7), paid: other.id })
+        );
+    }
+}
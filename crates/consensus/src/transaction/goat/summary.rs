@@ -0,0 +1,176 @@
+//! Per-block summary of the goat system transactions.
+
+use super::{AsTxGoat, GoatTxIterExt, TxGoat, TxGoatInner};
+use crate::Block;
+use alloc::vec::Vec;
+use alloy_primitives::{B256, U256};
+use core::fmt;
+
+/// A summary of the goat system transactions of a block, e.g. for ops dashboards or a per-block
+/// log line, see [`summarize_block`].
+///
+/// The [`Display`](fmt::Display) implementation is a single line of `key=value` pairs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatBlockSummary {
+    /// The number of bitcoin deposits.
+    pub deposits: usize,
+    /// The number of cancelled withdrawals.
+    pub cancels: usize,
+    /// The number of paid withdrawals.
+    pub paid: usize,
+    /// The number of completed unlocks.
+    pub unlocks: usize,
+    /// The number of distributed rewards.
+    pub rewards: usize,
+    /// The deposited amounts, in wei.
+    pub deposit_amount: U256,
+    /// The bridge taxes taken from the deposits, in wei.
+    pub deposit_tax: U256,
+    /// The distributed goat token rewards.
+    pub goat_rewards: U256,
+    /// The distributed gas rewards, in wei.
+    pub gas_rewards: U256,
+    /// The hashes of the bitcoin blocks submitted by the relayer, in order.
+    pub btc_hashes: Vec<B256>,
+}
+
+impl GoatBlockSummary {
+    /// Summarizes the goat system transactions among the given transactions.
+    pub fn from_txs<'a, T: AsTxGoat + 'a>(txs: impl IntoIterator<Item = &'a T>) -> Self {
+        let mut summary = Self::default();
+        for (_, tx) in txs.into_iter().goat_txs() {
+            summary.push(tx);
+        }
+        summary
+    }
+
+    /// Adds a goat system transaction to the summary.
+    pub fn push(&mut self, tx: &TxGoat) {
+        match &tx.inner {
+            TxGoatInner::Deposit(deposit) => {
+                self.deposits += 1;
+                self.deposit_amount = self.deposit_amount.saturating_add(deposit.amount);
+                self.deposit_tax = self.deposit_tax.saturating_add(deposit.tax);
+            }
+            TxGoatInner::Cancel2(_) => self.cancels += 1,
+            TxGoatInner::Paid(_) => self.paid += 1,
+            TxGoatInner::NewBtcBlock(block) => self.btc_hashes.push(block.hash),
+            TxGoatInner::CompleteUnlock(_) => self.unlocks += 1,
+            TxGoatInner::DistributeReward(reward) => {
+                self.rewards += 1;
+                self.goat_rewards = self.goat_rewards.saturating_add(reward.goat);
+                self.gas_rewards = self.gas_rewards.saturating_add(reward.gas_reward);
+            }
+        }
+    }
+
+    /// Returns the number of summarized goat system transactions.
+    pub const fn len(&self) -> usize {
+        self.deposits
+            + self.cancels
+            + self.paid
+            + self.btc_hashes.len()
+            + self.unlocks
+            + self.rewards
+    }
+
+    /// Returns true if no goat system transaction was summarized.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Display for GoatBlockSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "goat_txs={} deposits={} deposit_amount={} deposit_tax={} cancels={} paid={} \
+             btc_blocks={} unlocks={} rewards={} goat_rewards={} gas_rewards={}",
+            self.len(),
+            self.deposits,
+            self.deposit_amount,
+            self.deposit_tax,
+            self.cancels,
+            self.paid,
+            self.btc_hashes.len(),
+            self.unlocks,
+            self.rewards,
+            self.goat_rewards,
+            self.gas_rewards,
+        )?;
+        if let Some(hash) = self.btc_hashes.last() {
+            write!(f, " btc_tip={hash}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Summarizes the goat system transactions of a block.
+pub fn summarize_block<T: AsTxGoat, H>(block: &Block<T, H>) -> GoatBlockSummary {
+    GoatBlockSummary::from_txs(&block.body.transactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::{
+            test_utils::{new_btc_block_tx, signed_goat_tx},
+            DepositTx, DistributeRewardTx, PaidTx,
+        },
+        BlockBody, SignableTransaction, TxEnvelope, TxLegacy,
+    };
+    use alloy_primitives::Signature;
+
+    #[test]
+    fn summarize_goat_block() {
+        let goat = |nonce, inner| TxEnvelope::from(signed_goat_tx(nonce, inner));
+        let deposit = |amount: u64| DepositTx {
+            amount: U256::from(amount),
+            tax: U256::from(amount / 10),
+            ..Default::default()
+        };
+        let transactions = vec![
+            new_btc_block_tx(0, B256::with_last_byte(7)).into(),
+            goat(1, TxGoatInner::Deposit(deposit(100))),
+            goat(2, TxGoatInner::Deposit(deposit(50))),
+            goat(3, TxGoatInner::Paid(PaidTx::default())),
+            goat(
+                4,
+                TxGoatInner::DistributeReward(DistributeRewardTx {
+                    goat: U256::from(3),
+                    gas_reward: U256::from(4),
+                    ..Default::default()
+                }),
+            ),
+            TxEnvelope::from(TxLegacy::default().into_signed(Signature::test_signature())),
+        ];
+        let block: Block<TxEnvelope> = Block {
+            header: Default::default(),
+            body: BlockBody { transactions, ommers: Vec::new(), withdrawals: None },
+        };
+
+        let summary = summarize_block(&block);
+        assert_eq!(summary.len(), 5);
+        assert_eq!(summary.deposits, 2);
+        assert_eq!(summary.deposit_amount, U256::from(150));
+        assert_eq!(summary.deposit_tax, U256::from(15));
+        assert_eq!(summary.btc_hashes, [B256::with_last_byte(7)]);
+        assert_eq!(
+            summary.to_string(),
+            "goat_txs=5 deposits=2 deposit_amount=150 deposit_tax=15 cancels=0 paid=1 \
+             btc_blocks=1 unlocks=0 rewards=1 goat_rewards=3 gas_rewards=4 \
+             btc_tip=0x0000000000000000000000000000000000000000000000000000000000000007"
+        );
+        assert!(GoatBlockSummary::from_txs(&block.body.transactions[5..]).is_empty());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&summary).unwrap();
+            assert_eq!(json["depositAmount"], "0x96");
+            assert_eq!(serde_json::from_value::<GoatBlockSummary>(json).unwrap(), summary);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Goat transaction fixtures shared by the tests of the crate.
+
+use super::{NewBtcBlockTx, TxGoat, TxGoatInner};
+use crate::{SignableTransaction, Signed};
+use alloy_primitives::{ChainId, Signature, B256};
+
+/// The chain id of the fixtures.
+pub(crate) const CHAIN_ID: ChainId = 48816;
+
+/// Returns a goat transaction of [`CHAIN_ID`] executing the action.
+pub(crate) fn goat_tx(nonce: u64, inner: TxGoatInner) -> TxGoat {
+    TxGoat::new(CHAIN_ID, nonce, inner)
+}
+
+/// Returns [`goat_tx`] signed with the test signature, which is never checked for goat
+/// transactions.
+pub(crate) fn signed_goat_tx(nonce: u64, inner: TxGoatInner) -> Signed<TxGoat> {
+    goat_tx(nonce, inner).into_signed(Signature::test_signature())
+}
+
+/// Returns a signed goat transaction submitting the bitcoin block hash.
+pub(crate) fn new_btc_block_tx(nonce: u64, hash: B256) -> Signed<TxGoat> {
+    signed_goat_tx(nonce, TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash }))
+}
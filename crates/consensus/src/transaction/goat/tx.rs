@@ -0,0 +1,1068 @@
+use super::{
+    decode_tx, diff::diff_calldata, Action, CalldataDiff, GoatVerifyError, Module, TxGoatInner,
+    GOAT_TX_TYPE_ID,
+};
+use crate::{
+    transaction::{Recovered, RlpEcdsaDecodableTx, RlpEcdsaEncodableTx},
+    SignableTransaction, Signed, Transaction,
+};
+use alloc::string::String;
+use alloy_eips::{
+    eip2718::IsTyped2718, eip2930::AccessList, eip7702::SignedAuthorization, Typed2718,
+};
+use alloy_primitives::{Address, Bytes, ChainId, Signature, TxKind, B256, U256};
+use alloy_rlp::{BufMut, Decodable, Encodable, Header};
+use core::{
+    hash::{Hash, Hasher},
+    mem,
+};
+
+/// A GOAT system transaction.
+///
+/// System transactions are sent by the system executors to the protocol predeploys, and do not pay
+/// for gas. The `input` is the calldata of the predeploy call, which is decoded into `inner`
+/// according to the `module` and `action` of the transaction.
+///
+/// The `input` and `inner` fields must be kept consistent, use [`TxGoat::new`] to construct a
+/// transaction from its decoded action, and [`TxGoat::set_inner`] or [`TxGoat::set_input_checked`]
+/// to modify it. Changing only one of the fields results in a transaction whose hash does not
+/// match its decoded action.
+///
+/// Equality and hashing only take the encoded fields into account, so two transactions with the
+/// same wire encoding are equal even if their decoded actions differ.
+#[derive(Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", try_from = "serde_from::TxGoat"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[doc(alias = "GoatTransaction", alias = "TransactionGoat", alias = "GoatSystemTx")]
+pub struct TxGoat {
+    /// EIP-155: Simple replay attack protection
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+    pub chain_id: ChainId,
+    /// The module of the system action.
+    pub module: Module,
+    /// The action within the module.
+    pub action: Action,
+    /// The nonce of the system executor sending the transaction.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::quantity"))]
+    pub nonce: u64,
+    /// The calldata of the predeploy call.
+    #[cfg_attr(feature = "jsonschema", schemars(schema_with = "super::schema::bytes"))]
+    pub input: Bytes,
+    /// The decoded action.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub inner: TxGoatInner,
+}
+
+impl TxGoat {
+    /// Creates a new system transaction from its decoded action.
+    pub fn new(chain_id: ChainId, nonce: u64, inner: TxGoatInner) -> Self {
+        Self {
+            chain_id,
+            module: inner.module(),
+            action: inner.action(),
+            nonce,
+            input: inner.input(),
+            inner,
+        }
+    }
+
+    /// Get the transaction type
+    #[doc(alias = "transaction_type")]
+    pub const fn tx_type() -> u8 {
+        GOAT_TX_TYPE_ID
+    }
+
+    /// Returns the system executor sending the transaction.
+    pub fn sender(&self) -> Address {
+        self.inner.sender()
+    }
+
+    /// Returns the predeploy called by the transaction.
+    pub fn contract(&self) -> Address {
+        self.inner.contract()
+    }
+
+    /// Deserializes a goat transaction returned by an untrusted RPC server, without checking it.
+    ///
+    /// Unlike the [`Deserialize`](serde::Deserialize) implementation, the decoded action is taken
+    /// as is from the `inner` field if the server provides it, and is only decoded from the
+    /// `input` otherwise. Use [`TxGoat::verify_against_input`] to check the decoded action.
+    #[cfg(feature = "serde")]
+    pub fn from_rpc_unchecked<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        <serde_from::TxGoat as serde::Deserialize>::deserialize(deserializer)?
+            .into_unchecked()
+            .map_err(serde::de::Error::custom)
+    }
+
+    /// Replaces the decoded action, updating the `module`, `action` and `input` accordingly.
+    pub fn set_inner(&mut self, inner: TxGoatInner) {
+        self.module = inner.module();
+        self.action = inner.action();
+        self.input = inner.input();
+        self.inner = inner;
+    }
+
+    /// Replaces the `input`, updating the decoded action accordingly.
+    ///
+    /// The input must be the calldata of the current module and action of the transaction, use
+    /// [`TxGoat::set_inner`] to change the action. The transaction is left unchanged on error.
+    pub fn set_input_checked(&mut self, input: Bytes) -> Result<(), GoatVerifyError> {
+        self.inner =
+            decode_tx(self.module, self.action, &input).map_err(GoatVerifyError::Malformed)?;
+        self.input = input;
+        Ok(())
+    }
+
+    /// Checks that the decoded action, module and action of the transaction are the ones encoded
+    /// in its `input`.
+    pub fn verify_against_input(&self) -> Result<(), GoatVerifyError> {
+        let inner =
+            decode_tx(self.module, self.action, &self.input).map_err(GoatVerifyError::Malformed)?;
+        if inner != self.inner {
+            return Err(GoatVerifyError::InnerMismatch);
+        }
+        Ok(())
+    }
+
+    /// Compares the `input` against the calldata of the decoded action, field by field.
+    ///
+    /// Unlike [`TxGoat::verify_against_input`], the input is not decoded, so this also pinpoints
+    /// the fields of malformed inputs, e.g. a dirty address padding or a wrong method id.
+    pub fn verify_input_matches_inner(&self) -> Result<(), CalldataDiff> {
+        let diff = diff_calldata(&self.inner, &self.input);
+        if !diff.is_empty() {
+            return Err(CalldataDiff(diff));
+        }
+        Ok(())
+    }
+
+    /// Returns the solidity call of the transaction, see [`TxGoatInner::to_sol_call_string`].
+    pub fn to_sol_call_string(&self) -> String {
+        self.inner.to_sol_call_string()
+    }
+
+    /// Calculates a heuristic for the in-memory size of the [TxGoat] transaction.
+    #[inline]
+    pub fn size(&self) -> usize {
+        mem::size_of::<ChainId>() + // chain_id
+        mem::size_of::<Module>() + // module
+        mem::size_of::<Action>() + // action
+        mem::size_of::<u64>() + // nonce
+        self.input.len() + // input
+        mem::size_of::<TxGoatInner>() // inner
+    }
+}
+
+impl PartialEq for TxGoat {
+    fn eq(&self, other: &Self) -> bool {
+        self.chain_id == other.chain_id
+            && self.module == other.module
+            && self.action == other.action
+            && self.nonce == other.nonce
+            && self.input == other.input
+    }
+}
+
+impl Hash for TxGoat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.chain_id.hash(state);
+        self.module.hash(state);
+        self.action.hash(state);
+        self.nonce.hash(state);
+        self.input.hash(state);
+    }
+}
+
+impl Signed<TxGoat> {
+    /// Returns the transaction along with its system executor as signer.
+    ///
+    /// The sender of a goat system transaction is derived from its module, so unlike
+    /// [`SignerRecoverable`](crate::transaction::SignerRecoverable) this does not perform any
+    /// signature recovery and is available without the `k256` and `secp256k1` features.
+    pub fn into_recovered_goat(self) -> Recovered<Self> {
+        let sender = self.tx().sender();
+        Recovered::new_unchecked(self, sender)
+    }
+}
+
+impl RlpEcdsaEncodableTx for TxGoat {
+    /// Outputs the length of the transaction's fields, without a RLP header.
+    fn rlp_encoded_fields_length(&self) -> usize {
+        self.chain_id.length()
+            + self.module.length()
+            + self.action.length()
+            + self.nonce.length()
+            + self.input.0.length()
+    }
+
+    /// Encodes only the transaction's fields into the desired buffer, without
+    /// a RLP header.
+    fn rlp_encode_fields(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.chain_id.encode(out);
+        self.module.encode(out);
+        self.action.encode(out);
+        self.nonce.encode(out);
+        self.input.0.encode(out);
+    }
+
+    /// Encodes the transaction with the given signature, without a type flag.
+    ///
+    /// Like other typed transactions, the signature `v` is the bare y-parity `0` or `1`, and is
+    /// never EIP-155 encoded.
+    fn rlp_encode_signed(&self, signature: &Signature, out: &mut dyn alloy_rlp::BufMut) {
+        self.rlp_header_signed(signature).encode(out);
+        self.rlp_encode_fields(out);
+        signature.write_rlp_vrs(out, signature.v());
+    }
+}
+
+impl RlpEcdsaDecodableTx for TxGoat {
+    const DEFAULT_TX_TYPE: u8 = GOAT_TX_TYPE_ID;
+
+    /// Decodes the inner [TxGoat] fields from RLP bytes.
+    ///
+    /// NOTE: This assumes a RLP header has already been decoded, and _just_
+    /// decodes the following RLP fields in the following order:
+    ///
+    /// - `chain_id`
+    /// - `module`
+    /// - `action`
+    /// - `nonce`
+    /// - `input`
+    ///
+    /// The `inner` action is then decoded from the `input`.
+    fn rlp_decode_fields(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let chain_id = Decodable::decode(buf)?;
+        let module = Decodable::decode(buf)?;
+        let action = Decodable::decode(buf)?;
+        let nonce = Decodable::decode(buf)?;
+        let input: Bytes = Decodable::decode(buf)?;
+        let inner = decode_tx(module, action, &input)?;
+        Ok(Self { chain_id, module, action, nonce, input, inner })
+    }
+
+    /// Decodes the transaction and its signature from RLP bytes.
+    ///
+    /// The signature `v` must be the bare y-parity `0` or `1`, legacy `27`/`28` and EIP-155
+    /// encoded values are rejected.
+    fn rlp_decode_with_signature(buf: &mut &[u8]) -> alloy_rlp::Result<(Self, Signature)> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+
+        let remaining = buf.len();
+        let tx = Self::rlp_decode_fields(buf)?;
+        let signature = Signature::decode_rlp_vrs(buf, decode_parity)?;
+
+        if buf.len() + header.payload_length != remaining {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: remaining - buf.len(),
+            });
+        }
+
+        Ok((tx, signature))
+    }
+}
+
+/// Decodes the y-parity of a goat signature.
+fn decode_parity(buf: &mut &[u8]) -> alloy_rlp::Result<bool> {
+    match u64::decode(buf)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(alloy_rlp::Error::Custom("goat signature parity must be 0 or 1")),
+    }
+}
+
+impl Transaction for TxGoat {
+    #[inline]
+    fn chain_id(&self) -> Option<ChainId> {
+        Some(self.chain_id)
+    }
+
+    #[inline]
+    fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    #[inline]
+    fn gas_limit(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn gas_price(&self) -> Option<u128> {
+        None
+    }
+
+    #[inline]
+    fn max_fee_per_gas(&self) -> u128 {
+        0
+    }
+
+    #[inline]
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        None
+    }
+
+    #[inline]
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        None
+    }
+
+    #[inline]
+    fn priority_fee_or_price(&self) -> u128 {
+        0
+    }
+
+    fn effective_gas_price(&self, _base_fee: Option<u64>) -> u128 {
+        0
+    }
+
+    #[inline]
+    fn is_dynamic_fee(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn kind(&self) -> TxKind {
+        TxKind::Call(self.contract())
+    }
+
+    #[inline]
+    fn is_create(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn value(&self) -> U256 {
+        U256::ZERO
+    }
+
+    #[inline]
+    fn input(&self) -> &Bytes {
+        &self.input
+    }
+
+    #[inline]
+    fn access_list(&self) -> Option<&AccessList> {
+        None
+    }
+
+    #[inline]
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        None
+    }
+
+    #[inline]
+    fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
+        None
+    }
+
+    #[inline]
+    fn system_sender(&self) -> Option<Address> {
+        Some(self.sender())
+    }
+}
+
+impl Typed2718 for TxGoat {
+    fn ty(&self) -> u8 {
+        GOAT_TX_TYPE_ID
+    }
+}
+
+impl IsTyped2718 for TxGoat {
+    fn is_type(type_id: u8) -> bool {
+        type_id == GOAT_TX_TYPE_ID
+    }
+}
+
+impl SignableTransaction<Signature> for TxGoat {
+    fn set_chain_id(&mut self, chain_id: ChainId) {
+        self.chain_id = chain_id;
+    }
+
+    fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
+        out.put_u8(Self::tx_type());
+        self.encode(out)
+    }
+
+    fn payload_len_for_signature(&self) -> usize {
+        self.length() + 1
+    }
+}
+
+impl Encodable for TxGoat {
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.rlp_encode(out);
+    }
+
+    fn length(&self) -> usize {
+        self.rlp_encoded_length()
+    }
+}
+
+impl Decodable for TxGoat {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Self::rlp_decode(buf)
+    }
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for TxGoat {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_from {
+    //! NB: Why do we need this?
+    //!
+    //! The `inner` action is not part of the serialized transaction, it is decoded from the
+    //! `input` on deserialization. RPC servers may still provide it, in which case it must match
+    //! the `input`.
+    use super::{decode_tx, Action, Module, TxGoatInner};
+    use alloy_primitives::{Bytes, ChainId};
+
+    #[cfg(feature = "jsonschema")]
+    use crate::transaction::goat::schema;
+
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+    pub(crate) struct TxGoat {
+        #[serde(with = "alloy_serde::quantity")]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "schema::quantity"))]
+        chain_id: ChainId,
+        module: Module,
+        action: Action,
+        #[serde(with = "alloy_serde::quantity")]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "schema::quantity"))]
+        nonce: u64,
+        #[serde(alias = "data")]
+        #[cfg_attr(feature = "jsonschema", schemars(schema_with = "schema::bytes"))]
+        input: Bytes,
+        #[serde(default)]
+        inner: Option<TxGoatInner>,
+    }
+
+    impl TxGoat {
+        /// Converts into a [`super::TxGoat`], trusting the provided `inner` action if any.
+        pub(crate) fn into_unchecked(self) -> Result<super::TxGoat, alloy_rlp::Error> {
+            let Self { chain_id, module, action, nonce, input, inner } = self;
+            let inner = match inner {
+                Some(inner) => inner,
+                None => decode_tx(module, action, &input)?,
+            };
+            Ok(super::TxGoat { chain_id, module, action, nonce, input, inner })
+        }
+    }
+
+    impl TryFrom<TxGoat> for super::TxGoat {
+        type Error = super::GoatVerifyError;
+
+        fn try_from(value: TxGoat) -> Result<Self, Self::Error> {
+            let provided = value.inner.is_some();
+            let tx = value.into_unchecked().map_err(super::GoatVerifyError::Malformed)?;
+            if provided {
+                tx.verify_against_input()?;
+            }
+            Ok(tx)
+        }
+    }
+}
+
+/// Bincode-compatible [`TxGoat`] serde implementation.
+#[cfg(all(feature = "serde", feature = "serde-bincode-compat"))]
+pub(super) mod serde_bincode_compat {
+    use crate::transaction::goat::TxGoatInner;
+    use alloy_primitives::ChainId;
+    use core::fmt;
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+    use serde_with::{DeserializeAs, SerializeAs};
+
+    /// The version of the wire format written by [`TxGoat`].
+    ///
+    /// Every version stays decodable when a new one is added, so that stored transactions can be
+    /// read after the goat payloads evolve.
+    pub const TX_GOAT_WIRE_VERSION: u8 = 0;
+
+    /// The marker preceding the version of the wire format.
+    ///
+    /// Transactions encoded before the format was versioned start with their chain id, which is
+    /// always below `u64::MAX / 2 - 36` (EIP-2294), so the marker never collides with them.
+    const WIRE_MARKER: u64 = u64::MAX;
+
+    /// The number of elements of the wire format, including the marker and the version byte.
+    const WIRE_LEN: usize = 5;
+
+    /// Bincode-compatible [`super::TxGoat`] serde implementation.
+    ///
+    /// The transaction is prefixed with a marker and a [`TX_GOAT_WIRE_VERSION`] byte, followed by
+    /// the fields of that version. Version 0 holds the chain id, the nonce and the decoded action,
+    /// and is also the layout of the unprefixed transactions encoded before the format was
+    /// versioned, which are decoded as version 0. The `module`, `action` and `input` of the
+    /// transaction are derived from the decoded action.
+    ///
+    /// Intended to use with the [`serde_with::serde_as`] macro in the following way:
+    /// ```rust
+    /// use alloy_consensus::{serde_bincode_compat, transaction::goat::TxGoat};
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_with::serde_as;
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Data {
+    ///     #[serde_as(as = "serde_bincode_compat::transaction::TxGoat")]
+    ///     transaction: TxGoat,
+    /// }
+    /// ```
+    #[derive(Debug)]
+    pub struct TxGoat {
+        chain_id: ChainId,
+        nonce: u64,
+        inner: TxGoatInner,
+    }
+
+    impl From<&super::TxGoat> for TxGoat {
+        fn from(value: &super::TxGoat) -> Self {
+            Self { chain_id: value.chain_id, nonce: value.nonce, inner: value.inner }
+        }
+    }
+
+    impl From<TxGoat> for super::TxGoat {
+        fn from(value: TxGoat) -> Self {
+            Self::new(value.chain_id, value.nonce, value.inner)
+        }
+    }
+
+    impl Serialize for TxGoat {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut tuple = serializer.serialize_tuple(WIRE_LEN)?;
+            tuple.serialize_element(&WIRE_MARKER)?;
+            tuple.serialize_element(&TX_GOAT_WIRE_VERSION)?;
+            tuple.serialize_element(&self.chain_id)?;
+            tuple.serialize_element(&self.nonce)?;
+            tuple.serialize_element(&self.inner)?;
+            tuple.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TxGoat {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct TxGoatVisitor;
+
+            impl<'de> Visitor<'de> for TxGoatVisitor {
+                type Value = TxGoat;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("a versioned goat transaction")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let first: u64 =
+                        seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                    if first != WIRE_MARKER {
+                        // unprefixed transactions are laid out as version 0
+                        return version_0(first, seq, 1, &self);
+                    }
+
+                    let version: u8 =
+                        seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                    match version {
+                        0 => {
+                            let chain_id = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                            version_0(chain_id, seq, 3, &self)
+                        }
+                        version => Err(de::Error::custom(format_args!(
+                            "unsupported goat transaction wire version {version}, the latest is \
+                             {TX_GOAT_WIRE_VERSION}"
+                        ))),
+                    }
+                }
+            }
+
+            /// Reads the nonce and the decoded action of a version 0 transaction, starting at
+            /// element `index`.
+            fn version_0<'de, A: SeqAccess<'de>>(
+                chain_id: ChainId,
+                mut seq: A,
+                index: usize,
+                visitor: &TxGoatVisitor,
+            ) -> Result<TxGoat, A::Error> {
+                let nonce =
+                    seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, visitor))?;
+                let inner = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(index + 1, visitor))?;
+                Ok(TxGoat { chain_id, nonce, inner })
+            }
+
+            deserializer.deserialize_tuple(WIRE_LEN, TxGoatVisitor)
+        }
+    }
+
+    impl SerializeAs<super::TxGoat> for TxGoat {
+        fn serialize_as<S>(source: &super::TxGoat, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            Self::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de> DeserializeAs<'de, super::TxGoat> for TxGoat {
+        fn deserialize_as<D>(deserializer: D) -> Result<super::TxGoat, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Self::deserialize(deserializer).map(Into::into)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use arbitrary::Arbitrary;
+        use bincode::config;
+        use rand::Rng;
+        use serde::{Deserialize, Serialize};
+        use serde_with::serde_as;
+
+        use super::{super::TxGoat, TX_GOAT_WIRE_VERSION};
+        use crate::{serde_bincode_compat, transaction::goat::test_utils::new_btc_block_tx};
+        use alloc::format;
+        use alloy_primitives::{hex, B256};
+
+        #[test]
+        fn test_tx_goat_bincode_roundtrip() {
+            #[serde_as]
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct Data {
+                #[serde_as(as = "serde_bincode_compat::transaction::TxGoat")]
+                transaction: TxGoat,
+            }
+
+            let mut bytes = [0u8; 1024];
+            rand::thread_rng().fill(bytes.as_mut_slice());
+            let data = Data {
+                transaction: TxGoat::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap(),
+            };
+
+            let encoded = bincode::serde::encode_to_vec(&data, config::legacy()).unwrap();
+            let (decoded, _) =
+                bincode::serde::decode_from_slice::<Data, _>(&encoded, config::legacy()).unwrap();
+            assert_eq!(decoded, data);
+        }
+
+        #[test]
+        fn test_tx_goat_bincode_versions() {
+            #[serde_as]
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct Data {
+                #[serde_as(as = "serde_bincode_compat::transaction::TxGoat")]
+                transaction: TxGoat,
+            }
+
+            let data = Data {
+                transaction: new_btc_block_tx(7, B256::with_last_byte(1)).strip_signature(),
+            };
+            let encoded = bincode::serde::encode_to_vec(&data, config::legacy()).unwrap();
+            let tx = "b0be0000000000000700000000000000030000002000000000000000\
+                      0000000000000000000000000000000000000000000000000000000000000001";
+            assert_eq!(
+                hex::encode(&encoded),
+                format!("ffffffffffffffff{:02x}{tx}", TX_GOAT_WIRE_VERSION)
+            );
+            let (decoded, _) =
+                bincode::serde::decode_from_slice::<Data, _>(&encoded, config::legacy()).unwrap();
+            assert_eq!(decoded, data);
+
+            // transactions stored before the format was versioned are decoded as version 0
+            let unprefixed = hex::decode(tx).unwrap();
+            let (decoded, _) =
+                bincode::serde::decode_from_slice::<Data, _>(&unprefixed, config::legacy())
+                    .unwrap();
+            assert_eq!(decoded, data);
+
+            // including chain ids whose first byte matches a version
+            let chain_256 = hex::decode(format!("0001000000000000{}", &tx[16..])).unwrap();
+            let (decoded, _) =
+                bincode::serde::decode_from_slice::<Data, _>(&chain_256, config::legacy()).unwrap();
+            assert_eq!(decoded.transaction.chain_id, 256);
+            assert_eq!(decoded.transaction.inner, data.transaction.inner);
+
+            let mut unknown = encoded.clone();
+            unknown[8] = 1;
+            let err = bincode::serde::decode_from_slice::<Data, _>(&unknown, config::legacy())
+                .unwrap_err();
+            assert!(err.to_string().contains("unsupported goat transaction wire version 1"));
+            for truncated in [&encoded[..8], &encoded[..9], &encoded[..25], &unprefixed[..16]] {
+                assert!(bincode::serde::decode_from_slice::<Data, _>(truncated, config::legacy())
+                    .is_err());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transaction::goat::test_utils::{goat_tx, new_btc_block_tx},
+        SignableTransaction,
+    };
+    use alloc::format;
+    use alloy_eips::{Decodable2718, Encodable2718};
+    use alloy_primitives::{b256, hex, keccak256, Signature, U256};
+
+    fn new_btc_block() -> TxGoat {
+        let hash = b256!("0x000000000000000000016a6a7b2ce2c8a1dc0c2c7c16e3dd0f2a5a5d4e3bd1d2");
+        new_btc_block_tx(7, hash).strip_signature()
+    }
+
+    #[test]
+    fn encode_decode_goat() {
+        let tx = new_btc_block();
+        assert_eq!(tx.kind(), TxKind::Call(crate::transaction::goat::BITCOIN_CONTRACT));
+
+        let sig = Signature::new(U256::from(1), U256::from(2), false);
+        let signed = tx.clone().into_signed(sig);
+
+        let encoded = signed.encoded_2718();
+        assert_eq!(encoded[0], GOAT_TX_TYPE_ID);
+        let decoded = crate::Signed::<TxGoat>::decode_2718(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, signed);
+        assert_eq!(decoded.tx().inner, tx.inner);
+    }
+
+    #[test]
+    fn sol_call_string() {
+        use crate::transaction::goat::{Cancel2Tx, DepositTx};
+        use alloy_primitives::address;
+
+        assert_eq!(
+            new_btc_block().to_sol_call_string(),
+            "newBlockHash(0x000000000000000000016a6a7b2ce2c8a1dc0c2c7c16e3dd0f2a5a5d4e3bd1d2)"
+        );
+        let deposit = goat_tx(
+            8,
+            TxGoatInner::Deposit(DepositTx {
+                tx_id: b256!("0x15bb6ad9e8d0bd7c5a5e4fcd0e3b2b1d1b3e5f0ac1f6ae1d9f1bc4e0ec7c7f01"),
+                tx_out: 712616824,
+                target: address!("0x5e4e65926ba27467555eb562121fac00d24e9dd2"),
+                amount: U256::from(360677271),
+                tax: U256::from(100),
+            }),
+        );
+        assert_eq!(
+            deposit.to_sol_call_string(),
+            "deposit(0x15bb6ad9e8d0bd7c5a5e4fcd0e3b2b1d1b3e5f0ac1f6ae1d9f1bc4e0ec7c7f01, 712616824, \
+             0x5e4e65926ba27467555eb562121fac00d24e9dd2, 0x157f7f97, 100)"
+        );
+        let cancel = TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(7) });
+        assert_eq!(cancel.to_sol_call_string(), "cancel2(7)");
+    }
+
+    #[test]
+    fn input_diff() {
+        use crate::transaction::goat::{CalldataFieldDiff, DepositTx};
+
+        let mut tx = goat_tx(
+            8,
+            TxGoatInner::Deposit(DepositTx {
+                tx_out: 3,
+                amount: U256::from(100),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(tx.verify_input_matches_inner(), Ok(()));
+
+        let word = |value: u64| Bytes::from(U256::from(value).to_be_bytes::<32>());
+        let mut input = tx.input.to_vec();
+        input[4 + 2 * 32] = 0xff;
+        input[4 + 32 + 31] = 4;
+        input.push(0);
+        tx.input = input.into();
+        let diff = tx.verify_input_matches_inner().unwrap_err();
+        assert_eq!(diff.0.len(), 3);
+        assert_eq!(
+            diff.0[0],
+            CalldataFieldDiff { field: "tx_out", expected: word(3), got: word(4) }
+        );
+        assert_eq!(diff.0[1].field, "target");
+        assert_eq!(
+            diff.0[2],
+            CalldataFieldDiff { field: "trailing", expected: Bytes::new(), got: Bytes::from([0]) }
+        );
+
+        // a truncated input is reported per missing field
+        tx.input = tx.inner.input().slice(..4 + 3 * 32 + 8);
+        let diff = tx.verify_input_matches_inner().unwrap_err();
+        assert_eq!(diff.0.iter().map(|d| d.field).collect::<Vec<_>>(), ["amount", "tax"]);
+        assert_eq!(diff.0[0].got.len(), 8);
+        assert!(diff
+            .to_string()
+            .starts_with("goat input does not match the decoded action: amount"));
+    }
+
+    #[test]
+    fn recovered_goat_without_ecdsa() {
+        let recovered =
+            new_btc_block().into_signed(Signature::test_signature()).into_recovered_goat();
+        assert_eq!(recovered.signer(), crate::transaction::goat::RELAYER_EXECUTOR);
+    }
+
+    #[cfg(any(feature = "secp256k1", feature = "k256"))]
+    #[test]
+    fn recover_goat_signer() {
+        use crate::{transaction::SignerRecoverable, TxEnvelope};
+
+        // the signature is never looked at, the executor is the sender
+        let signed = new_btc_block().into_signed(Signature::test_signature());
+        let envelope = TxEnvelope::from(signed.clone());
+        for sender in [
+            SignerRecoverable::recover_signer(&signed),
+            signed.recover_signer_unchecked(),
+            signed.recover_unchecked_with_buf(&mut Vec::new()),
+            envelope.recover_signer(),
+            envelope.recover_signer_unchecked(),
+        ] {
+            assert_eq!(sender.unwrap(), crate::transaction::goat::RELAYER_EXECUTOR);
+        }
+        #[cfg(feature = "k256")]
+        assert_eq!(signed.recover_signer().unwrap(), crate::transaction::goat::RELAYER_EXECUTOR);
+    }
+
+    #[test]
+    fn system_sender() {
+        use crate::{transaction::goat::RELAYER_EXECUTOR, TxEip1559, TxEnvelope, TypedTransaction};
+
+        let signed = new_btc_block().into_signed(Signature::test_signature());
+        assert_eq!(signed.tx().system_sender(), Some(RELAYER_EXECUTOR));
+        assert_eq!(signed.system_sender(), Some(RELAYER_EXECUTOR));
+        assert_eq!(TypedTransaction::from(new_btc_block()).system_sender(), Some(RELAYER_EXECUTOR));
+        assert_eq!(TxEnvelope::from(signed).system_sender(), Some(RELAYER_EXECUTOR));
+        assert_eq!(TxEip1559::default().system_sender(), None);
+    }
+
+    // The vectors are assembled by hand from the RLP layout of the fields. The raw transactions
+    // captured from goat-geth, with the hashes it reports for both parities, are checked by the
+    // `goat_mainnet` fixtures of alloy-provider.
+    #[test]
+    fn signed_goat_rlp_parity() {
+        let tx = new_btc_block();
+        let hash = "000000000000000000016a6a7b2ce2c8a1dc0c2c7c16e3dd0f2a5a5d4e3bd1d2";
+        for (parity, v) in [(false, "80"), (true, "01")] {
+            let signed =
+                tx.clone().into_signed(Signature::new(U256::from(1), U256::from(2), parity));
+            let expected = hex::decode(format!("60ee82beb0020107a494f490bd{hash}{v}0102")).unwrap();
+
+            let encoded = signed.encoded_2718();
+            assert_eq!(encoded, expected);
+            assert_eq!(*signed.hash(), keccak256(&expected));
+
+            let decoded = crate::Signed::<TxGoat>::decode_2718(&mut expected.as_slice()).unwrap();
+            assert_eq!(decoded, signed);
+            assert_eq!(decoded.signature().v(), parity);
+            assert_eq!(decoded.hash(), signed.hash());
+        }
+
+        // legacy and EIP-155 encoded `v` values are rejected
+        for v in ["1b", "1c", "8224ec"] {
+            let raw = hex::decode(format!(
+                "60{:02x}82beb0020107a494f490bd{hash}{v}0102",
+                0xed + v.len() / 2
+            ))
+            .unwrap();
+            assert!(crate::Signed::<TxGoat>::decode_2718(&mut raw.as_slice()).is_err());
+        }
+    }
+
+    #[test]
+    fn decode_corrupt_input() {
+        let mut tx = new_btc_block();
+        tx.input = hex!("94f490bd00").into();
+        let sig = Signature::new(U256::from(1), U256::from(2), false);
+        let encoded = tx.into_signed(sig).encoded_2718();
+        assert!(crate::Signed::<TxGoat>::decode_2718(&mut encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    fn setters_keep_input_in_sync() {
+        use crate::transaction::goat::{Cancel2Tx, BRIDGE_CANCEL2_ACTION, BRIDGE_MODULE};
+
+        let mut tx = new_btc_block();
+        let cancel = TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(3) });
+        tx.set_inner(cancel);
+        assert_eq!((tx.module, tx.action), (BRIDGE_MODULE, BRIDGE_CANCEL2_ACTION));
+        assert_eq!(tx.input, cancel.input());
+        assert_eq!(tx.verify_against_input(), Ok(()));
+        assert_eq!(tx, goat_tx(7, cancel));
+
+        let cancel = TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(4) });
+        tx.set_input_checked(cancel.input()).unwrap();
+        assert_eq!(tx.inner, cancel);
+        assert_eq!(tx.verify_against_input(), Ok(()));
+
+        // the input of another action is rejected, and the transaction is left unchanged
+        let before = tx.clone();
+        let err = tx.set_input_checked(new_btc_block().input).unwrap_err();
+        assert!(matches!(err, GoatVerifyError::Malformed(_)));
+        assert_eq!(tx, before);
+    }
+
+    #[test]
+    fn eq_ignores_decoded_action() {
+        use crate::transaction::goat::Cancel2Tx;
+        use std::collections::HashSet;
+
+        let tx = new_btc_block();
+        let mut inconsistent = tx.clone();
+        inconsistent.inner = TxGoatInner::Cancel2(Cancel2Tx::default());
+        assert_eq!(inconsistent, tx);
+        assert_eq!(HashSet::from([tx.clone(), inconsistent]).len(), 1);
+
+        let mut other_chain = tx.clone();
+        other_chain.chain_id += 1;
+        assert_ne!(other_chain, tx);
+        let mut other_nonce = tx.clone();
+        other_nonce.nonce += 1;
+        assert_ne!(other_nonce, tx);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_goat() {
+        let tx = new_btc_block();
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "chainId": "0xbeb0",
+                "module": "0x2",
+                "action": "0x1",
+                "nonce": "0x7",
+                "input": "0x94f490bd000000000000000000016a6a7b2ce2c8a1dc0c2c7c16e3dd0f2a5a5d4e3bd1d2"
+            })
+        );
+        assert_eq!(serde_json::from_value::<TxGoat>(json).unwrap(), tx);
+
+        let corrupt = serde_json::json!({
+            "chainId": "0xbeb0",
+            "module": "0x2",
+            "action": "0x2",
+            "nonce": "0x7",
+            "input": "0x"
+        });
+        assert!(serde_json::from_value::<TxGoat>(corrupt).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_goat_permissive() {
+        let tx = new_btc_block();
+
+        // unknown fields, mixed-case hex, decimal numbers and a null inner, as sent by some RPC
+        // providers
+        let json = serde_json::json!({
+            "hash": "0x00000000000000000000000000000000000000000000000000000000000000aa",
+            "blockNumber": "0x1",
+            "type": "0x60",
+            "chainId": "0xbeb0",
+            "module": 2,
+            "action": "0X1",
+            "nonce": "0x7",
+            "input": "0x94F490BD000000000000000000016A6A7B2CE2C8A1DC0C2C7C16E3DD0F2A5A5D4E3BD1D2",
+            "inner": null,
+            "v": "0x0",
+        });
+        assert_eq!(serde_json::from_value::<TxGoat>(json.clone()).unwrap(), tx);
+        assert_eq!(TxGoat::from_rpc_unchecked(&json).unwrap(), tx);
+
+        // the calldata is also accepted as `data`
+        let mut json = serde_json::to_value(&tx).unwrap();
+        let input = json.as_object_mut().unwrap().remove("input").unwrap();
+        json["data"] = input;
+        assert_eq!(serde_json::from_value::<TxGoat>(json).unwrap(), tx);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_goat_fuzz() {
+        use arbitrary::Arbitrary;
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..256 {
+            let mut bytes = [0u8; 256];
+            rng.fill(bytes.as_mut_slice());
+            let mut u = arbitrary::Unstructured::new(&bytes);
+            let Ok(tx) = TxGoat::arbitrary(&mut u) else { continue };
+
+            // upper-case hex round trips
+            let mut json = serde_json::to_value(&tx).unwrap();
+            for value in json.as_object_mut().unwrap().values_mut() {
+                let upper = value.as_str().unwrap()[2..].to_ascii_uppercase();
+                *value = format!("0x{upper}").into();
+            }
+            assert_eq!(serde_json::from_value::<TxGoat>(json.clone()).unwrap(), tx);
+
+            // corrupt documents are rejected without panicking
+            let mut corrupt = json.to_string().into_bytes();
+            let idx = rng.gen_range(0..corrupt.len());
+            corrupt[idx] = rng.gen();
+            if let Ok(corrupt) = core::str::from_utf8(&corrupt) {
+                let _ = serde_json::from_str::<TxGoat>(corrupt);
+                let _ =
+                    TxGoat::from_rpc_unchecked(&mut serde_json::Deserializer::from_str(corrupt));
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn rpc_goat_unchecked() {
+        let tx = new_btc_block();
+        let mut json = serde_json::to_value(&tx).unwrap();
+        json["inner"] = serde_json::json!({ "newBtcBlock": { "hash": B256::ZERO } });
+
+        // inconsistent decoded metadata is rejected by the checked deserialization
+        assert!(serde_json::from_value::<TxGoat>(json.clone()).is_err());
+
+        let unchecked = TxGoat::from_rpc_unchecked(&json).unwrap();
+        assert_eq!(unchecked.input, tx.input);
+        assert_ne!(unchecked.inner, tx.inner);
+        assert_eq!(unchecked.verify_against_input(), Err(GoatVerifyError::InnerMismatch));
+
+        json["inner"] = serde_json::to_value(tx.inner).unwrap();
+        assert_eq!(serde_json::from_value::<TxGoat>(json.clone()).unwrap(), tx);
+        let unchecked = TxGoat::from_rpc_unchecked(&json).unwrap();
+        assert_eq!(unchecked.verify_against_input(), Ok(()));
+
+        // the inner is decoded from the input when it is not provided
+        json.as_object_mut().unwrap().remove("inner");
+        assert_eq!(TxGoat::from_rpc_unchecked(&json).unwrap(), tx);
+
+        json["input"] = "0x94f490bd".into();
+        json["inner"] = serde_json::to_value(tx.inner).unwrap();
+        let unchecked = TxGoat::from_rpc_unchecked(&json).unwrap();
+        assert_eq!(
+            unchecked.verify_against_input(),
+            Err(GoatVerifyError::Malformed(alloy_rlp::Error::InputTooShort))
+        );
+    }
+}
@@ -30,6 +30,9 @@ mod eip4844_sidecar;
 pub use eip4844_sidecar::BlobTransactionValidationError;
 pub use eip4844_sidecar::TxEip4844Sidecar;
 
+/// GOAT system transaction constants, helpers, and types.
+pub mod goat;
+
 // Re-export 4844 helpers.
 pub use alloy_eips::eip4844::{
     builder::{SidecarBuilder, SidecarCoder, SimpleCoder},
@@ -37,7 +40,7 @@ pub use alloy_eips::eip4844::{
 };
 
 pub mod pooled;
-pub use pooled::PooledTransaction;
+pub use pooled::{Eip4844Representation, PooledTransaction};
 
 /// Re-export for convenience
 pub use either::Either;
@@ -72,7 +75,8 @@ pub mod serde_bincode_compat {
     pub use super::{
         eip1559::serde_bincode_compat::*, eip2930::serde_bincode_compat::*,
         eip7702::serde_bincode_compat::*, envelope::serde_bincode_compat::*,
-        legacy::serde_bincode_compat::*, typed::serde_bincode_compat::*,
+        goat::serde_bincode_compat::*, legacy::serde_bincode_compat::*,
+        typed::serde_bincode_compat::*,
     };
 }
 
@@ -228,6 +232,14 @@ pub trait Transaction: Typed2718 + fmt::Debug + any::Any + Send + Sync + 'static
     fn authorization_count(&self) -> Option<u64> {
         self.authorization_list().map(|auths| auths.len() as u64)
     }
+
+    /// Returns the sender of a system transaction, which is not recovered from a signature but
+    /// synthesized by the protocol, e.g. the executor of a goat system transaction.
+    ///
+    /// Returns `None` for all other transactions.
+    fn system_sender(&self) -> Option<Address> {
+        None
+    }
 }
 
 /// A typed transaction envelope.
@@ -380,6 +392,11 @@ impl<T: Transaction> Transaction for alloy_serde::WithOtherFields<T> {
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         self.inner.authorization_list()
     }
+
+    #[inline]
+    fn system_sender(&self) -> Option<Address> {
+        self.inner.system_sender()
+    }
 }
 
 impl<L, R> Transaction for either::Either<L, R>
@@ -547,6 +564,13 @@ where
             Self::Right(tx) => tx.authorization_count(),
         }
     }
+
+    fn system_sender(&self) -> Option<Address> {
+        match self {
+            Self::Left(tx) => tx.system_sender(),
+            Self::Right(tx) => tx.system_sender(),
+        }
+    }
 }
 
 /// Trait for types that provide access to a transaction hash reference.
@@ -1,9 +1,12 @@
 //! Defines the exact transaction variant that is allowed to be propagated over the eth p2p
 //! protocol.
 
-use super::EthereumTxEnvelope;
+use super::{goat::GOAT_TX_TYPE_ID, EthereumTxEnvelope};
 use crate::{error::ValueError, Signed, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar};
-use alloy_eips::eip7594::Encodable7594;
+use alloy_eips::{
+    eip2718::{Eip2718Error, Eip2718Result},
+    eip7594::Encodable7594,
+};
 
 /// All possible transactions that can be included in a response to `GetPooledTransactions`.
 /// A response to `GetPooledTransactions`. This can include either a blob transaction, or a
@@ -14,6 +17,39 @@ use alloy_eips::eip7594::Encodable7594;
 /// be propagated with the sidecar over p2p.
 pub type PooledTransaction = EthereumTxEnvelope<TxEip4844WithSidecar>;
 
+/// The representation of the EIP-4844 variant of an [`EthereumTxEnvelope`].
+///
+/// An envelope carrying [`TxEip4844WithSidecar`] is a pooled transaction, which never includes a
+/// goat system transaction. Decoding such an envelope rejects the goat transaction type.
+pub trait Eip4844Representation {
+    /// Whether the envelope is the pooled representation.
+    const POOLED: bool;
+}
+
+impl Eip4844Representation for TxEip4844 {
+    const POOLED: bool = false;
+}
+
+impl<T> Eip4844Representation for TxEip4844Variant<T> {
+    const POOLED: bool = false;
+}
+
+impl<T> Eip4844Representation for TxEip4844WithSidecar<T> {
+    const POOLED: bool = true;
+}
+
+impl<Eip4844: Eip4844Representation> EthereumTxEnvelope<Eip4844> {
+    /// Rejects the goat transaction type when decoding a pooled transaction.
+    pub(super) const fn check_typed_decode(ty: u8) -> Eip2718Result<()> {
+        if Eip4844::POOLED && ty == GOAT_TX_TYPE_ID {
+            return Err(Eip2718Error::RlpError(alloy_rlp::Error::Custom(
+                "goat system transactions can not be pooled",
+            )));
+        }
+        Ok(())
+    }
+}
+
 impl<T: Encodable7594> EthereumTxEnvelope<TxEip4844WithSidecar<T>> {
     /// Converts the transaction into [`EthereumTxEnvelope<TxEip4844Variant<T>>`].
     pub fn into_envelope(self) -> EthereumTxEnvelope<TxEip4844Variant<T>> {
@@ -23,6 +59,7 @@ impl<T: Encodable7594> EthereumTxEnvelope<TxEip4844WithSidecar<T>> {
             Self::Eip1559(tx) => tx.into(),
             Self::Eip7702(tx) => tx.into(),
             Self::Eip4844(tx) => tx.into(),
+            Self::Goat(tx) => tx.into(),
         }
     }
 }
@@ -123,6 +160,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rejects_goat_tx() {
+        use crate::{transaction::goat::test_utils::new_btc_block_tx, TxEnvelope};
+        use alloy_primitives::B256;
+
+        let tx: TxEnvelope = new_btc_block_tx(0, B256::with_last_byte(1)).into();
+        let encoded = tx.encoded_2718();
+
+        assert_eq!(TxEnvelope::decode_2718(&mut &encoded[..]).unwrap(), tx);
+        assert!(PooledTransaction::decode_2718(&mut &encoded[..]).is_err());
+        assert!(PooledTransaction::decode(&mut &alloy_rlp::encode(&tx)[..]).is_err());
+    }
+
     // <https://holesky.etherscan.io/tx/0x7f60faf8a410a80d95f7ffda301d5ab983545913d3d789615df3346579f6c849>
     #[test]
     fn decode_eip1559_enveloped() {
@@ -4,6 +4,7 @@ use crate::{
     private::alloy_eips::eip2718::Eip2718Error,
     transaction::{
         eip4844::{TxEip4844, TxEip4844Variant, TxEip4844WithSidecar},
+        goat::{TxGoat, GOAT_TX_TYPE_ID},
         RlpEcdsaDecodableTx, RlpEcdsaEncodableTx,
     },
     EthereumTxEnvelope, SignableTransaction, Signed, TxEip1559, TxEip2930, TxEip7702, TxLegacy,
@@ -62,6 +63,34 @@ impl<Eip4844> From<TxEip7702> for EthereumTypedTransaction<Eip4844> {
     }
 }
 
+impl<Eip4844> From<TxGoat> for EthereumTypedTransaction<Eip4844> {
+    fn from(tx: TxGoat) -> Self {
+        Self::Goat(tx)
+    }
+}
+
+impl<Eip4844> TryFrom<EthereumTypedTransaction<Eip4844>> for TxGoat {
+    type Error = ValueError<EthereumTypedTransaction<Eip4844>>;
+
+    fn try_from(value: EthereumTypedTransaction<Eip4844>) -> Result<Self, Self::Error> {
+        match value {
+            EthereumTypedTransaction::Goat(tx) => Ok(tx),
+            _ => Err(ValueError::new(value, "Expected goat transaction")),
+        }
+    }
+}
+
+impl<'a, Eip4844> TryFrom<&'a EthereumTypedTransaction<Eip4844>> for &'a TxGoat {
+    type Error = ValueError<&'a EthereumTypedTransaction<Eip4844>>;
+
+    fn try_from(value: &'a EthereumTypedTransaction<Eip4844>) -> Result<Self, Self::Error> {
+        match value {
+            EthereumTypedTransaction::Goat(tx) => Ok(tx),
+            _ => Err(ValueError::new(value, "Expected goat transaction")),
+        }
+    }
+}
+
 impl<Eip4844> From<EthereumTxEnvelope<Eip4844>> for EthereumTypedTransaction<Eip4844> {
     fn from(envelope: EthereumTxEnvelope<Eip4844>) -> Self {
         match envelope {
@@ -70,6 +99,7 @@ impl<Eip4844> From<EthereumTxEnvelope<Eip4844>> for EthereumTypedTransaction<Eip
             EthereumTxEnvelope::Eip1559(tx) => Self::Eip1559(tx.strip_signature()),
             EthereumTxEnvelope::Eip4844(tx) => Self::Eip4844(tx.strip_signature()),
             EthereumTxEnvelope::Eip7702(tx) => Self::Eip7702(tx.strip_signature()),
+            EthereumTxEnvelope::Goat(tx) => Self::Goat(tx.strip_signature()),
         }
     }
 }
@@ -110,6 +140,7 @@ impl<Eip4844> EthereumTypedTransaction<Eip4844> {
             Self::Eip1559(tx) => EthereumTypedTransaction::Eip1559(tx),
             Self::Eip4844(tx) => EthereumTypedTransaction::Eip4844(f(tx)),
             Self::Eip7702(tx) => EthereumTypedTransaction::Eip7702(tx),
+            Self::Goat(tx) => EthereumTypedTransaction::Goat(tx),
         }
     }
 
@@ -121,6 +152,7 @@ impl<Eip4844> EthereumTypedTransaction<Eip4844> {
             Self::Eip1559(tx) => EthereumTxEnvelope::Eip1559(tx.into_signed(signature)),
             Self::Eip4844(tx) => EthereumTxEnvelope::Eip4844(Signed::new_unhashed(tx, signature)),
             Self::Eip7702(tx) => EthereumTxEnvelope::Eip7702(tx.into_signed(signature)),
+            Self::Goat(tx) => EthereumTxEnvelope::Goat(tx.into_signed(signature)),
         }
     }
 }
@@ -146,6 +178,7 @@ impl<Eip4844: RlpEcdsaDecodableTx> EthereumTypedTransaction<Eip4844> {
             0x02 => Ok(Self::Eip1559(TxEip1559::decode(buf)?)),
             0x03 => Ok(Self::Eip4844(Eip4844::rlp_decode(buf)?)),
             0x04 => Ok(Self::Eip7702(TxEip7702::decode(buf)?)),
+            GOAT_TX_TYPE_ID => Ok(Self::Goat(TxGoat::decode(buf)?)),
             _ => Err(Eip2718Error::UnexpectedType(tx_type)),
         }
     }
@@ -161,6 +194,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTypedTransaction<Eip4844> {
             Self::Eip1559(_) => TxType::Eip1559,
             Self::Eip4844(_) => TxType::Eip4844,
             Self::Eip7702(_) => TxType::Eip7702,
+            Self::Goat(_) => TxType::Goat,
         }
     }
 
@@ -196,6 +230,20 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTypedTransaction<Eip4844> {
         }
     }
 
+    /// Returns true if the transaction is a goat system transaction.
+    #[inline]
+    pub const fn is_goat(&self) -> bool {
+        matches!(self, Self::Goat(_))
+    }
+
+    /// Return the inner goat transaction if it exists.
+    pub const fn goat(&self) -> Option<&TxGoat> {
+        match self {
+            Self::Goat(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
     /// Consumes the type and returns the [`TxLegacy`] if this transaction is of that type.
     pub fn try_into_legacy(self) -> Result<TxLegacy, ValueError<Self>> {
         match self {
@@ -228,6 +276,14 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTypedTransaction<Eip4844> {
         }
     }
 
+    /// Consumes the type and returns the [`TxGoat`] if this transaction is of that type.
+    pub fn try_into_goat(self) -> Result<TxGoat, ValueError<Self>> {
+        match self {
+            Self::Goat(tx) => Ok(tx),
+            _ => Err(ValueError::new(self, "Expected goat transaction")),
+        }
+    }
+
     /// Calculate the transaction hash for the given signature.
     pub fn tx_hash(&self, signature: &Signature) -> TxHash {
         match self {
@@ -236,6 +292,7 @@ impl<Eip4844: RlpEcdsaEncodableTx> EthereumTypedTransaction<Eip4844> {
             Self::Eip1559(tx) => tx.tx_hash(signature),
             Self::Eip4844(tx) => tx.tx_hash(signature),
             Self::Eip7702(tx) => tx.tx_hash(signature),
+            Self::Goat(tx) => tx.tx_hash(signature),
         }
     }
 }
@@ -250,6 +307,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.rlp_encoded_fields_length(),
             Self::Eip4844(tx) => tx.rlp_encoded_fields_length(),
             Self::Eip7702(tx) => tx.rlp_encoded_fields_length(),
+            Self::Goat(tx) => tx.rlp_encoded_fields_length(),
         }
     }
 
@@ -260,6 +318,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.rlp_encode_fields(out),
             Self::Eip4844(tx) => tx.rlp_encode_fields(out),
             Self::Eip7702(tx) => tx.rlp_encode_fields(out),
+            Self::Goat(tx) => tx.rlp_encode_fields(out),
         }
     }
 
@@ -270,6 +329,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.eip2718_encode_with_type(signature, tx.ty(), out),
             Self::Eip4844(tx) => tx.eip2718_encode_with_type(signature, tx.ty(), out),
             Self::Eip7702(tx) => tx.eip2718_encode_with_type(signature, tx.ty(), out),
+            Self::Goat(tx) => tx.eip2718_encode_with_type(signature, tx.ty(), out),
         }
     }
 
@@ -280,6 +340,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.eip2718_encode(signature, out),
             Self::Eip4844(tx) => tx.eip2718_encode(signature, out),
             Self::Eip7702(tx) => tx.eip2718_encode(signature, out),
+            Self::Goat(tx) => tx.eip2718_encode(signature, out),
         }
     }
 
@@ -290,6 +351,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.network_encode_with_type(signature, tx.ty(), out),
             Self::Eip4844(tx) => tx.network_encode_with_type(signature, tx.ty(), out),
             Self::Eip7702(tx) => tx.network_encode_with_type(signature, tx.ty(), out),
+            Self::Goat(tx) => tx.network_encode_with_type(signature, tx.ty(), out),
         }
     }
 
@@ -300,6 +362,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.network_encode(signature, out),
             Self::Eip4844(tx) => tx.network_encode(signature, out),
             Self::Eip7702(tx) => tx.network_encode(signature, out),
+            Self::Goat(tx) => tx.network_encode(signature, out),
         }
     }
 
@@ -310,6 +373,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.tx_hash_with_type(signature, tx.ty()),
             Self::Eip4844(tx) => tx.tx_hash_with_type(signature, tx.ty()),
             Self::Eip7702(tx) => tx.tx_hash_with_type(signature, tx.ty()),
+            Self::Goat(tx) => tx.tx_hash_with_type(signature, tx.ty()),
         }
     }
 
@@ -320,6 +384,7 @@ impl<Eip4844: RlpEcdsaEncodableTx + Typed2718> RlpEcdsaEncodableTx
             Self::Eip1559(tx) => tx.tx_hash(signature),
             Self::Eip4844(tx) => tx.tx_hash(signature),
             Self::Eip7702(tx) => tx.tx_hash(signature),
+            Self::Goat(tx) => tx.tx_hash(signature),
         }
     }
 }
@@ -334,6 +399,7 @@ impl<Eip4844: SignableTransaction<Signature>> SignableTransaction<Signature>
             Self::Eip1559(tx) => tx.set_chain_id(chain_id),
             Self::Eip4844(tx) => tx.set_chain_id(chain_id),
             Self::Eip7702(tx) => tx.set_chain_id(chain_id),
+            Self::Goat(tx) => tx.set_chain_id(chain_id),
         }
     }
 
@@ -344,6 +410,7 @@ impl<Eip4844: SignableTransaction<Signature>> SignableTransaction<Signature>
             Self::Eip1559(tx) => tx.encode_for_signing(out),
             Self::Eip4844(tx) => tx.encode_for_signing(out),
             Self::Eip7702(tx) => tx.encode_for_signing(out),
+            Self::Goat(tx) => tx.encode_for_signing(out),
         }
     }
 
@@ -354,6 +421,7 @@ impl<Eip4844: SignableTransaction<Signature>> SignableTransaction<Signature>
             Self::Eip1559(tx) => tx.payload_len_for_signature(),
             Self::Eip4844(tx) => tx.payload_len_for_signature(),
             Self::Eip7702(tx) => tx.payload_len_for_signature(),
+            Self::Goat(tx) => tx.payload_len_for_signature(),
         }
     }
 }
@@ -413,6 +481,8 @@ pub(crate) mod serde_bincode_compat {
         Eip4844(Cow<'a, Eip4844>),
         /// EIP-7702 transaction
         Eip7702(crate::serde_bincode_compat::transaction::TxEip7702<'a>),
+        /// Goat system transaction
+        Goat(crate::serde_bincode_compat::transaction::TxGoat),
     }
 
     impl<'a, T: Clone> From<&'a super::EthereumTypedTransaction<T>>
@@ -425,6 +495,7 @@ pub(crate) mod serde_bincode_compat {
                 super::EthereumTypedTransaction::Eip1559(tx) => Self::Eip1559(tx.into()),
                 super::EthereumTypedTransaction::Eip4844(tx) => Self::Eip4844(Cow::Borrowed(tx)),
                 super::EthereumTypedTransaction::Eip7702(tx) => Self::Eip7702(tx.into()),
+                super::EthereumTypedTransaction::Goat(tx) => Self::Goat(tx.into()),
             }
         }
     }
@@ -437,6 +508,7 @@ pub(crate) mod serde_bincode_compat {
                 EthereumTypedTransaction::Eip1559(tx) => Self::Eip1559(tx.into()),
                 EthereumTypedTransaction::Eip4844(tx) => Self::Eip4844(tx.into_owned()),
                 EthereumTypedTransaction::Eip7702(tx) => Self::Eip7702(tx.into()),
+                EthereumTypedTransaction::Goat(tx) => Self::Goat(tx.into()),
             }
         }
     }
@@ -604,4 +676,21 @@ mod tests {
 
         assert_eq!(encoded, re_encoded);
     }
+
+    #[test]
+    fn test_try_from_goat() {
+        use crate::transaction::goat::{test_utils::new_btc_block_tx, TxGoat};
+        use alloy_primitives::B256;
+
+        let goat = new_btc_block_tx(0, B256::ZERO).strip_signature();
+        let tx = TypedTransaction::from(goat.clone());
+        assert!(tx.is_goat());
+        assert_eq!(<&TxGoat>::try_from(&tx).unwrap(), &goat);
+        assert_eq!(TxGoat::try_from(tx).unwrap(), goat);
+
+        let tx = TypedTransaction::Eip1559(TxEip1559::default());
+        assert!(!tx.is_goat());
+        assert!(<&TxGoat>::try_from(&tx).is_err());
+        assert_eq!(TxGoat::try_from(tx.clone()).unwrap_err().into_value(), tx);
+    }
 }
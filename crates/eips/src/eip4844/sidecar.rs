@@ -552,6 +552,8 @@ pub enum BlobTransactionValidationError {
     NotBlobTransaction(u8),
     /// Error variant for thrown by EIP-4844 tx variants without a sidecar.
     MissingSidecar,
+    /// The transaction is a goat system transaction, which never carries blobs.
+    GoatTxCannotHaveBlobs,
     /// The versioned hash is incorrect.
     WrongVersionedHash {
         /// The versioned hash we got
@@ -578,6 +580,9 @@ impl core::fmt::Display for BlobTransactionValidationError {
             Self::MissingSidecar => {
                 f.write_str("eip4844 tx variant without sidecar being used for verification.")
             }
+            Self::GoatTxCannotHaveBlobs => {
+                f.write_str("goat system transactions cannot have blobs")
+            }
             Self::WrongVersionedHash { have, expected } => {
                 write!(f, "wrong versioned hash: have {have}, expected {expected}")
             }
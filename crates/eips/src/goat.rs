@@ -0,0 +1,189 @@
+//! Protocol parameters of the GOAT bitcoin bridge, shared by the validation of goat system
+//! transactions.
+//!
+//! The parameters of every [`GoatHardfork`] are typed constants, see [`GoatHardfork::params`]. A
+//! network activates the hardforks at timestamps, see [`GoatParamsSchedule`].
+
+use alloc::vec::Vec;
+use alloy_primitives::{U256, U512};
+
+/// The denominator of the basis points of [`GoatParams::max_tax_bps`].
+pub const BASIS_POINTS: u64 = 10_000;
+
+/// The number of bitcoin confirmations required before a deposit can be minted, since genesis.
+pub const GOAT_BTC_CONFIRMATIONS_GENESIS: u64 = 6;
+
+/// The maximum number of deposits in a block, since genesis.
+pub const GOAT_MAX_DEPOSITS_PER_BLOCK_GENESIS: u64 = 16;
+
+/// The maximum bridge tax of a deposit in basis points of the deposited value, since genesis.
+pub const GOAT_MAX_TAX_BPS_GENESIS: u64 = 100;
+
+/// The GOAT hardforks changing the [`GoatParams`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[non_exhaustive]
+pub enum GoatHardfork {
+    /// The parameters at genesis.
+    Genesis,
+}
+
+impl GoatHardfork {
+    /// All hardforks, in activation order.
+    pub const ALL: [Self; 1] = [Self::Genesis];
+
+    /// Returns the parameters active since the hardfork.
+    pub const fn params(self) -> GoatParams {
+        GoatParams {
+            btc_confirmations: self.btc_confirmations(),
+            max_deposits_per_block: self.max_deposits_per_block(),
+            max_tax_bps: self.max_tax_bps(),
+        }
+    }
+
+    /// Returns the number of bitcoin confirmations required since the hardfork.
+    pub const fn btc_confirmations(self) -> u64 {
+        match self {
+            Self::Genesis => GOAT_BTC_CONFIRMATIONS_GENESIS,
+        }
+    }
+
+    /// Returns the maximum number of deposits in a block since the hardfork.
+    pub const fn max_deposits_per_block(self) -> u64 {
+        match self {
+            Self::Genesis => GOAT_MAX_DEPOSITS_PER_BLOCK_GENESIS,
+        }
+    }
+
+    /// Returns the maximum bridge tax in basis points since the hardfork.
+    pub const fn max_tax_bps(self) -> u64 {
+        match self {
+            Self::Genesis => GOAT_MAX_TAX_BPS_GENESIS,
+        }
+    }
+}
+
+/// Parameters of the GOAT bitcoin bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatParams {
+    /// The number of bitcoin confirmations required before a deposit can be minted.
+    pub btc_confirmations: u64,
+    /// The maximum number of deposits in a block.
+    pub max_deposits_per_block: u64,
+    /// The maximum bridge tax of a deposit, in basis points of the deposited value, i.e. of the
+    /// amount plus the tax.
+    pub max_tax_bps: u64,
+}
+
+impl GoatParams {
+    /// The parameters at genesis.
+    pub const GENESIS: Self = GoatHardfork::Genesis.params();
+
+    /// Returns true if the bridge tax of a deposit does not exceed [`GoatParams::max_tax_bps`].
+    ///
+    /// The `amount` and `tax` are those of the deposit, in wei.
+    pub fn is_tax_within_bounds(&self, amount: U256, tax: U256) -> bool {
+        let Some(total) = amount.checked_add(tax) else { return false };
+        U512::from(tax) * U512::from(BASIS_POINTS)
+            <= U512::from(total) * U512::from(self.max_tax_bps)
+    }
+
+    /// Returns true if a block with the given number of deposits is within
+    /// [`GoatParams::max_deposits_per_block`].
+    pub const fn is_deposit_count_within_bounds(&self, deposits: u64) -> bool {
+        deposits <= self.max_deposits_per_block
+    }
+}
+
+/// The [`GoatParams`] of a GOAT network over time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatParamsSchedule {
+    /// The parameters at genesis.
+    pub genesis: GoatParams,
+    /// The parameters activated by hardforks, with their activation timestamps.
+    ///
+    /// These are ordered by activation timestamps in natural order.
+    pub scheduled: Vec<(u64, GoatParams)>,
+}
+
+impl Default for GoatParamsSchedule {
+    fn default() -> Self {
+        Self::new(GoatParams::GENESIS)
+    }
+}
+
+impl GoatParamsSchedule {
+    /// Creates a schedule with the given genesis parameters.
+    pub const fn new(genesis: GoatParams) -> Self {
+        Self { genesis, scheduled: Vec::new() }
+    }
+
+    /// Configures the [`GoatParams`] scheduled by hardforks, with their activation timestamps.
+    pub fn with_scheduled(
+        mut self,
+        scheduled: impl IntoIterator<Item = (u64, GoatParams)>,
+    ) -> Self {
+        self.scheduled = scheduled.into_iter().collect();
+        self
+    }
+
+    /// Configures the parameters of the hardforks activated at the given timestamps.
+    pub fn with_hardforks(self, hardforks: impl IntoIterator<Item = (u64, GoatHardfork)>) -> Self {
+        self.with_scheduled(hardforks.into_iter().map(|(ts, fork)| (ts, fork.params())))
+    }
+
+    /// Returns the parameters active at the given timestamp.
+    pub fn params_at_timestamp(&self, timestamp: u64) -> &GoatParams {
+        self.scheduled
+            .iter()
+            .rev()
+            .find(|(ts, _)| timestamp >= *ts)
+            .map_or(&self.genesis, |(_, params)| params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn goat_params_schedule() {
+        let genesis =
+            GoatParams { btc_confirmations: 6, max_deposits_per_block: 8, max_tax_bps: 0 };
+        let fork = GoatParams { max_tax_bps: 100, ..genesis };
+        let schedule = GoatParamsSchedule::new(genesis).with_scheduled([(1000, fork)]);
+        assert_eq!(schedule.params_at_timestamp(999), &genesis);
+        assert_eq!(schedule.params_at_timestamp(1000), &fork);
+
+        // 1% of the deposited value
+        assert!(fork.is_tax_within_bounds(U256::from(99), U256::from(1)));
+        assert!(!fork.is_tax_within_bounds(U256::from(98), U256::from(2)));
+        assert!(!fork.is_tax_within_bounds(U256::MAX, U256::from(1)));
+        assert!(genesis.is_tax_within_bounds(U256::from(100), U256::ZERO));
+        assert!(!genesis.is_deposit_count_within_bounds(9));
+    }
+
+    #[test]
+    fn goat_hardfork_params() {
+        assert_eq!(GoatParams::GENESIS.btc_confirmations, GOAT_BTC_CONFIRMATIONS_GENESIS);
+        assert_eq!(GoatParams::GENESIS.max_deposits_per_block, GOAT_MAX_DEPOSITS_PER_BLOCK_GENESIS);
+        assert_eq!(GoatParams::GENESIS.max_tax_bps, GOAT_MAX_TAX_BPS_GENESIS);
+        assert!(GoatHardfork::ALL.windows(2).all(|forks| forks[0] < forks[1]));
+
+        let schedule = GoatParamsSchedule::default().with_hardforks([(0, GoatHardfork::Genesis)]);
+        assert_eq!(schedule.params_at_timestamp(0), &GoatParams::GENESIS);
+        assert_eq!(
+            GoatParamsSchedule::default().params_at_timestamp(u64::MAX),
+            &GoatParams::GENESIS
+        );
+
+        // 1% of the deposited value
+        assert!(GoatParams::GENESIS.is_tax_within_bounds(U256::from(99), U256::from(1)));
+        assert!(!GoatParams::GENESIS.is_tax_within_bounds(U256::from(98), U256::from(2)));
+    }
+}
@@ -60,3 +60,5 @@ pub mod eip7825;
 pub use eip7892::{BlobScheduleBlobParams, BlobScheduleEntry};
 
 pub mod eip7910;
+
+pub mod goat;
@@ -415,6 +415,12 @@ pub struct ChainConfig {
     #[serde(skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_u64_opt")]
     pub bpo5_time: Option<u64>,
 
+    /// GOAT V2 switch block (None = no fork, 0 = already on GOAT V2).
+    ///
+    /// Gates the goat system actions introduced with the fork.
+    #[serde(skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_u64_opt")]
+    pub goat_v2_block: Option<u64>,
+
     /// Total difficulty reached that triggers the merge consensus upgrade.
     #[serde(skip_serializing_if = "Option::is_none", with = "alloy_serde::ttd")]
     pub terminal_total_difficulty: Option<U256>,
@@ -533,6 +539,8 @@ pub mod serde_bincode_compat {
         #[serde(default)]
         bpo5_time: Option<u64>,
         #[serde(default)]
+        goat_v2_block: Option<u64>,
+        #[serde(default)]
         terminal_total_difficulty: Option<U256>,
         #[serde(default)]
         terminal_total_difficulty_passed: bool,
@@ -580,6 +588,7 @@ pub mod serde_bincode_compat {
                 bpo3_time: value.bpo3_time,
                 bpo4_time: value.bpo4_time,
                 bpo5_time: value.bpo5_time,
+                goat_v2_block: value.goat_v2_block,
                 terminal_total_difficulty: value.terminal_total_difficulty,
                 terminal_total_difficulty_passed: value.terminal_total_difficulty_passed,
                 ethash: value.ethash,
@@ -628,6 +637,7 @@ pub mod serde_bincode_compat {
                 bpo3_time: value.bpo3_time,
                 bpo4_time: value.bpo4_time,
                 bpo5_time: value.bpo5_time,
+                goat_v2_block: value.goat_v2_block,
                 terminal_total_difficulty: value.terminal_total_difficulty,
                 terminal_total_difficulty_passed: value.terminal_total_difficulty_passed,
                 ethash: value.ethash,
@@ -739,6 +749,7 @@ pub mod serde_bincode_compat {
                 bpo3_time: None,
                 bpo4_time: None,
                 bpo5_time: None,
+                goat_v2_block: None,
                 terminal_total_difficulty: None,
                 terminal_total_difficulty_passed: false,
                 ethash: None,
@@ -795,6 +806,7 @@ pub mod serde_bincode_compat {
                 bpo3_time: None,
                 bpo4_time: None,
                 bpo5_time: None,
+                goat_v2_block: None,
                 terminal_total_difficulty: None,
                 terminal_total_difficulty_passed: false,
                 ethash: None,
@@ -1030,6 +1042,11 @@ impl ChainConfig {
             && self.is_active_at_timestamp(self.cancun_time, timestamp)
     }
 
+    /// Checks if the blockchain is active at or after the GOAT V2 fork block.
+    pub fn is_goat_v2_active_at_block(&self, block: u64) -> bool {
+        self.is_active_at_block(self.goat_v2_block, block)
+    }
+
     // Private function handling the comparison logic for block numbers
     fn is_active_at_block(&self, config_block: Option<u64>, block: u64) -> bool {
         config_block.is_some_and(|cb| cb <= block)
@@ -1071,6 +1088,7 @@ impl Default for ChainConfig {
             bpo3_time: None,
             bpo4_time: None,
             bpo5_time: None,
+            goat_v2_block: None,
             terminal_total_difficulty: None,
             terminal_total_difficulty_passed: false,
             ethash: None,
@@ -2155,6 +2173,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_goat_fork_fields() {
+        let goat_genesis = r#"
+    {
+        "difficulty": "0x0",
+        "gasLimit": "0x1c9c380",
+        "alloc": {},
+        "config": {
+          "chainId": 48816,
+          "londonBlock": 0,
+          "goatV2Block": "0x64"
+        }
+    }
+    "#;
+        let genesis: Genesis = serde_json::from_str(goat_genesis).unwrap();
+        let config = &genesis.config;
+        assert_eq!(config.goat_v2_block, Some(100));
+        assert!(config.extra_fields.is_empty());
+        assert!(!config.is_goat_v2_active_at_block(99));
+        assert!(config.is_goat_v2_active_at_block(100));
+
+        let json = serde_json::to_value(config).unwrap();
+        assert_eq!(json["goatV2Block"], 100);
+        assert_eq!(serde_json::from_value::<ChainConfig>(json).unwrap(), *config);
+    }
+
     #[test]
     fn parse_extra_fields() {
         let geth_genesis = r#"
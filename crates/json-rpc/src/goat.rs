@@ -0,0 +1,305 @@
+//! Typed payloads for the GOAT relayer RPC namespace.
+//!
+//! GOAT relayers submit bitcoin deposits to the sequencer through the
+//! `goat_submitDeposit` endpoint. The types in this module model the request,
+//! the success response, and the structured error responses of that endpoint,
+//! so that relayers get compile-time checked payloads instead of hand-built
+//! JSON.
+//!
+//! Relayers also follow the sequencer through the `goat_subscribe` endpoint.
+//! Notifications of these subscriptions are delivered with the
+//! `goat_subscription` method, and carry the [`NewDepositNotification`] and
+//! [`RelayerProposalNotification`] payloads.
+
+use crate::ErrorPayload;
+use alloy_primitives::{Address, Bytes, TxHash, B256, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::borrow::{Borrow, Cow};
+
+/// The method name of the deposit submission endpoint.
+pub const GOAT_SUBMIT_DEPOSIT: &str = "goat_submitDeposit";
+
+/// The method name of the goat subscription endpoint.
+pub const GOAT_SUBSCRIBE: &str = "goat_subscribe";
+
+/// The method name of the goat unsubscription endpoint.
+pub const GOAT_UNSUBSCRIBE: &str = "goat_unsubscribe";
+
+/// The method name of goat subscription notifications.
+pub const GOAT_SUBSCRIPTION: &str = "goat_subscription";
+
+/// Error code returned when the deposit has already been processed.
+pub const DEPOSIT_ALREADY_PROCESSED_CODE: i64 = -32040;
+
+/// Error code returned when the bitcoin transaction does not have enough confirmations yet.
+pub const INSUFFICIENT_CONFIRMATIONS_CODE: i64 = -32041;
+
+/// Parameters of a `goat_submitDeposit` request.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitDepositRequest {
+    /// The bitcoin transaction id of the deposit.
+    pub btc_txid: B256,
+    /// The output index of the deposit in the bitcoin transaction.
+    pub txout: u32,
+    /// The SPV proof of the bitcoin transaction inclusion.
+    pub proof: Bytes,
+    /// The raw bitcoin transaction.
+    pub raw_btc_tx: Bytes,
+}
+
+impl SubmitDepositRequest {
+    /// Creates a new deposit submission.
+    pub const fn new(btc_txid: B256, txout: u32, proof: Bytes, raw_btc_tx: Bytes) -> Self {
+        Self { btc_txid, txout, proof, raw_btc_tx }
+    }
+}
+
+/// Response of a successful `goat_submitDeposit` request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitDepositResponse {
+    /// The hash of the goat deposit transaction that will include the deposit.
+    pub tx_hash: TxHash,
+}
+
+/// Data attached to an [`INSUFFICIENT_CONFIRMATIONS_CODE`] error response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfirmationsData {
+    /// The number of confirmations of the bitcoin transaction.
+    pub confirmations: u64,
+    /// The number of confirmations required by the node.
+    pub required: u64,
+}
+
+/// Known error responses of the `goat_submitDeposit` endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SubmitDepositError {
+    /// The deposit has already been processed.
+    #[error("deposit already processed")]
+    AlreadyProcessed,
+    /// The bitcoin transaction does not have enough confirmations yet.
+    #[error("insufficient confirmations: {}/{}", .0.confirmations, .0.required)]
+    InsufficientConfirmations(ConfirmationsData),
+}
+
+impl SubmitDepositError {
+    /// Returns the JSON-RPC error code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::AlreadyProcessed => DEPOSIT_ALREADY_PROCESSED_CODE,
+            Self::InsufficientConfirmations(_) => INSUFFICIENT_CONFIRMATIONS_CODE,
+        }
+    }
+
+    /// Returns `true` if the submission can be retried later.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::InsufficientConfirmations(_))
+    }
+
+    /// Converts the error into an [`ErrorPayload`].
+    pub fn into_error_payload(self) -> ErrorPayload<Box<RawValue>> {
+        let data = match self {
+            Self::AlreadyProcessed => None,
+            Self::InsufficientConfirmations(data) => serde_json::value::to_raw_value(&data).ok(),
+        };
+        ErrorPayload { code: self.code(), message: Cow::Owned(self.to_string()), data }
+    }
+}
+
+/// Subscription kinds of the `goat_subscribe` endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GoatSubscriptionKind {
+    /// New bitcoin deposits accepted by the sequencer.
+    ///
+    /// Notifications are [`NewDepositNotification`]s.
+    NewDeposits,
+    /// New bitcoin block proposals of the relayer set.
+    ///
+    /// Notifications are [`RelayerProposalNotification`]s.
+    RelayerProposals,
+}
+
+impl GoatSubscriptionKind {
+    /// Returns the name of the subscription kind, as used in `goat_subscribe` params.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::NewDeposits => "newDeposits",
+            Self::RelayerProposals => "relayerProposals",
+        }
+    }
+}
+
+/// Notification payload of a [`GoatSubscriptionKind::NewDeposits`] subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewDepositNotification {
+    /// The bitcoin transaction id of the deposit.
+    pub btc_txid: B256,
+    /// The output index of the deposit in the bitcoin transaction.
+    pub txout: u32,
+    /// The goat address credited by the deposit.
+    pub address: Address,
+    /// The deposited amount, in wei.
+    pub amount: U256,
+    /// The hash of the goat deposit transaction that includes the deposit.
+    pub tx_hash: TxHash,
+}
+
+/// Notification payload of a [`GoatSubscriptionKind::RelayerProposals`] subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayerProposalNotification {
+    /// The relayer proposing the bitcoin block.
+    pub proposer: Address,
+    /// The height of the proposed bitcoin block.
+    pub btc_height: u64,
+    /// The hash of the proposed bitcoin block.
+    pub btc_block_hash: B256,
+}
+
+impl<Data> ErrorPayload<Data>
+where
+    Data: Borrow<RawValue>,
+{
+    /// Attempts to interpret the error as a known [`SubmitDepositError`].
+    ///
+    /// Returns `None` if the error code is not a `goat_submitDeposit` error code, or if the
+    /// attached data is malformed.
+    pub fn as_submit_deposit_error(&self) -> Option<SubmitDepositError> {
+        match self.code {
+            DEPOSIT_ALREADY_PROCESSED_CODE => Some(SubmitDepositError::AlreadyProcessed),
+            INSUFFICIENT_CONFIRMATIONS_CODE => self
+                .try_data_as::<ConfirmationsData>()?
+                .ok()
+                .map(SubmitDepositError::InsufficientConfirmations),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EthNotification, PubSubItem, Request, SubId};
+    use alloy_primitives::{address, b256, bytes, U256};
+
+    #[test]
+    fn serde_request() {
+        let req = SubmitDepositRequest::new(
+            b256!("0x15bb8c8dd1d1ee4d26f4a54e5a1ab7e13ed6b6b1a2c1b1e5b9d1b1f5a1a1b1c1"),
+            1,
+            bytes!("0x0102"),
+            bytes!("0x0304"),
+        );
+        let json = serde_json::to_string(&req).unwrap();
+        assert_eq!(
+            json,
+            r#"{"btcTxid":"0x15bb8c8dd1d1ee4d26f4a54e5a1ab7e13ed6b6b1a2c1b1e5b9d1b1f5a1a1b1c1","txout":1,"proof":"0x0102","rawBtcTx":"0x0304"}"#
+        );
+        assert_eq!(serde_json::from_str::<SubmitDepositRequest>(&json).unwrap(), req);
+    }
+
+    #[test]
+    fn already_processed() {
+        let json = r#"{"code":-32040,"message":"deposit already processed"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.as_submit_deposit_error(), Some(SubmitDepositError::AlreadyProcessed));
+    }
+
+    #[test]
+    fn insufficient_confirmations() {
+        let json = r#"{"code":-32041,"message":"insufficient confirmations","data":{"confirmations":2,"required":6}}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        let err = payload.as_submit_deposit_error().unwrap();
+        assert_eq!(
+            err,
+            SubmitDepositError::InsufficientConfirmations(ConfirmationsData {
+                confirmations: 2,
+                required: 6
+            })
+        );
+        assert!(err.is_retryable());
+
+        let roundtrip = err.into_error_payload();
+        assert_eq!(roundtrip.as_submit_deposit_error(), Some(err));
+    }
+
+    #[test]
+    fn unrelated_error() {
+        let json = r#"{"code":-32000,"message":"execution reverted"}"#;
+        let payload: ErrorPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.as_submit_deposit_error(), None);
+    }
+
+    #[test]
+    fn goat_subscribe_request() {
+        let req = Request::new(GOAT_SUBSCRIBE, 1.into(), [GoatSubscriptionKind::NewDeposits]);
+        assert!(req.is_subscription());
+        let ser = req.serialize().unwrap();
+        assert_eq!(
+            ser.serialized().get(),
+            r#"{"method":"goat_subscribe","params":["newDeposits"],"id":1,"jsonrpc":"2.0"}"#
+        );
+
+        let kind: GoatSubscriptionKind = serde_json::from_str(r#""relayerProposals""#).unwrap();
+        assert_eq!(kind, GoatSubscriptionKind::RelayerProposals);
+        assert_eq!(kind.as_str(), "relayerProposals");
+    }
+
+    #[test]
+    fn new_deposit_notification() {
+        let json = r#"{"jsonrpc":"2.0","method":"goat_subscription","params":{"subscription":"0x9ce59a13059e417087c02d3236a0b1cc","result":{"btcTxid":"0x15bb8c8dd1d1ee4d26f4a54e5a1ab7e13ed6b6b1a2c1b1e5b9d1b1f5a1a1b1c1","txout":1,"address":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","amount":"0xde0b6b3a7640000","txHash":"0x2bd7ae4fd7a6eb2d3edf12b1a9e6d9b9c2ab5c5e9c0f3bba0b3e8db3a2b8f3c4"}}}"#;
+        let PubSubItem::Notification(EthNotification { subscription, result }) =
+            serde_json::from_str(json).unwrap()
+        else {
+            panic!("expected notification");
+        };
+        assert_eq!(
+            subscription,
+            SubId::Number("0x9ce59a13059e417087c02d3236a0b1cc".parse().unwrap())
+        );
+
+        let deposit: NewDepositNotification = serde_json::from_str(result.get()).unwrap();
+        assert_eq!(
+            deposit,
+            NewDepositNotification {
+                btc_txid: b256!(
+                    "0x15bb8c8dd1d1ee4d26f4a54e5a1ab7e13ed6b6b1a2c1b1e5b9d1b1f5a1a1b1c1"
+                ),
+                txout: 1,
+                address: address!("0x70997970c51812dc3a010c7d01b50e0d17dc79c8"),
+                amount: U256::from(1_000_000_000_000_000_000u64),
+                tx_hash: b256!(
+                    "0x2bd7ae4fd7a6eb2d3edf12b1a9e6d9b9c2ab5c5e9c0f3bba0b3e8db3a2b8f3c4"
+                ),
+            }
+        );
+        assert_eq!(serde_json::to_string(&deposit).unwrap(), result.get());
+    }
+
+    #[test]
+    fn relayer_proposal_notification() {
+        let json = r#"{"jsonrpc":"2.0","method":"goat_subscription","params":{"subscription":"0x1","result":{"proposer":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","btcHeight":840000,"btcBlockHash":"0x0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5"}}}"#;
+        let PubSubItem::Notification(EthNotification { subscription, result }) =
+            serde_json::from_str(json).unwrap()
+        else {
+            panic!("expected notification");
+        };
+        assert_eq!(subscription, SubId::Number(U256::from(1)));
+
+        let proposal: RelayerProposalNotification = serde_json::from_str(result.get()).unwrap();
+        assert_eq!(
+            proposal,
+            RelayerProposalNotification {
+                proposer: address!("0x70997970c51812dc3a010c7d01b50e0d17dc79c8"),
+                btc_height: 840000,
+                btc_block_hash: b256!(
+                    "0x0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5"
+                ),
+            }
+        );
+    }
+}
@@ -85,6 +85,8 @@ pub use common::Id;
 mod error;
 pub use error::RpcError;
 
+pub mod goat;
+
 mod notification;
 pub use notification::{EthNotification, PubSubItem, SubId};
 
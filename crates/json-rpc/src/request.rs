@@ -16,7 +16,7 @@ pub struct RequestMeta {
     pub method: Cow<'static, str>,
     /// The request ID.
     pub id: Id,
-    /// Whether the request is a subscription, other than `eth_subscribe`.
+    /// Whether the request is a subscription, other than `eth_subscribe` and `goat_subscribe`.
     is_subscription: bool,
     /// Optional extensions for the request that can be used by middleware
     /// or other components to attach additional metadata.
@@ -31,11 +31,13 @@ impl RequestMeta {
 
     /// Returns `true` if the request is a subscription.
     pub fn is_subscription(&self) -> bool {
-        self.is_subscription || self.method == "eth_subscribe"
+        self.is_subscription
+            || self.method == "eth_subscribe"
+            || self.method == crate::goat::GOAT_SUBSCRIBE
     }
 
     /// Indicates that the request is a non-standard subscription (i.e. not
-    /// "eth_subscribe").
+    /// "eth_subscribe" or "goat_subscribe").
     pub const fn set_is_subscription(&mut self) {
         self.set_subscription_status(true);
     }
@@ -102,7 +104,7 @@ impl<Params> Request<Params> {
     }
 
     /// Indicates that the request is a non-standard subscription (i.e. not
-    /// "eth_subscribe").
+    /// "eth_subscribe" or "goat_subscribe").
     pub const fn set_is_subscription(&mut self) {
         self.meta.set_is_subscription()
     }
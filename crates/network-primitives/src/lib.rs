@@ -16,4 +16,4 @@ mod block;
 pub use block::{BlockTransactionHashes, BlockTransactions, BlockTransactionsKind};
 
 mod tx_builders;
-pub use tx_builders::{TransactionBuilder4844, TransactionBuilder7702};
+pub use tx_builders::{TransactionBuilder4844, TransactionBuilder7702, TransactionBuilderGoat};
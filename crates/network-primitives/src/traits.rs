@@ -1,6 +1,6 @@
 use crate::BlockTransactions;
-use alloy_consensus::{BlockHeader, Transaction};
-use alloy_primitives::{Address, BlockHash, TxHash, B256};
+use alloy_consensus::{transaction::goat::Mint, BlockHeader, Transaction};
+use alloy_primitives::{Address, BlockHash, TxHash, B256, U256};
 use alloy_serde::WithOtherFields;
 
 /// Receipt JSON-RPC response.
@@ -60,6 +60,23 @@ pub trait ReceiptResponse {
     ///
     /// EIP98 makes this field optional.
     fn state_root(&self) -> Option<B256>;
+
+    /// Native tokens minted by the transaction outside of the EVM, e.g. by a goat deposit.
+    ///
+    /// Balance reconciliation has to account for these, as they are not part of any value
+    /// transfer. Returns `None` if the receipt does not report a mint.
+    fn mint(&self) -> Option<Mint> {
+        None
+    }
+
+    /// Native tokens minted outside of the EVM in the block up to and including this transaction,
+    /// see [`ReceiptResponse::mint`].
+    ///
+    /// The supply delta of a block can be read off its last receipt. Returns `None` if the receipt
+    /// does not report a cumulative mint.
+    fn cumulative_mint(&self) -> Option<U256> {
+        None
+    }
 }
 
 /// Transaction JSON-RPC response. Aggregates transaction data with its block and signer context.
@@ -212,6 +229,21 @@ impl<T: ReceiptResponse> ReceiptResponse for WithOtherFields<T> {
     fn state_root(&self) -> Option<B256> {
         self.inner.state_root()
     }
+
+    /// Returns the `mint` field reported by goat-geth for deposits, falling back to the inner
+    /// receipt. A malformed field is ignored.
+    fn mint(&self) -> Option<Mint> {
+        self.other.get_deserialized("mint").and_then(Result::ok).or_else(|| self.inner.mint())
+    }
+
+    /// Returns the `cumulativeMint` field reported by goat-geth, falling back to the inner
+    /// receipt. A malformed field is ignored.
+    fn cumulative_mint(&self) -> Option<U256> {
+        self.other
+            .get_deserialized("cumulativeMint")
+            .and_then(Result::ok)
+            .or_else(|| self.inner.cumulative_mint())
+    }
 }
 
 impl<T: BlockResponse> BlockResponse for WithOtherFields<T> {
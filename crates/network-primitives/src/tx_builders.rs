@@ -1,7 +1,10 @@
 use core::ops::{Deref, DerefMut};
 
 use alloc::vec::Vec;
-use alloy_consensus::BlobTransactionSidecar;
+use alloy_consensus::{
+    transaction::goat::{Action, Module},
+    BlobTransactionSidecar,
+};
 use alloy_eips::eip7702::SignedAuthorization;
 use alloy_serde::WithOtherFields;
 
@@ -50,6 +53,33 @@ pub trait TransactionBuilder7702: Default + Sized + Send + Sync + 'static {
     }
 }
 
+/// Transaction builder type supporting GOAT system transaction fields.
+pub trait TransactionBuilderGoat: Default + Sized + Send + Sync + 'static {
+    /// Get the goat module of the transaction.
+    fn module(&self) -> Option<Module>;
+
+    /// Sets the goat module of the transaction.
+    fn set_module(&mut self, module: Module);
+
+    /// Builder-pattern method for setting the goat module.
+    fn with_module(mut self, module: Module) -> Self {
+        self.set_module(module);
+        self
+    }
+
+    /// Get the goat action of the transaction.
+    fn action(&self) -> Option<Action>;
+
+    /// Sets the goat action of the transaction.
+    fn set_action(&mut self, action: Action);
+
+    /// Builder-pattern method for setting the goat action.
+    fn with_action(mut self, action: Action) -> Self {
+        self.set_action(action);
+        self
+    }
+}
+
 impl<T> TransactionBuilder4844 for WithOtherFields<T>
 where
     T: TransactionBuilder4844,
@@ -1,13 +1,18 @@
 use crate::{UnknownTxEnvelope, UnknownTypedTransaction};
 use alloy_consensus::{
-    error::ValueError, transaction::Either, Signed, Transaction as TransactionTrait, TxEip1559,
-    TxEip2930, TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy, Typed2718, TypedTransaction,
+    error::ValueError,
+    transaction::{
+        goat::{decode_tx, Action, Mint, Module, TxGoat, GOAT_TX_TYPE_ID},
+        Either,
+    },
+    Signed, Transaction as TransactionTrait, TxEip1559, TxEip2930, TxEip4844Variant, TxEip7702,
+    TxEnvelope, TxLegacy, Typed2718, TypedTransaction,
 };
 use alloy_eips::{
     eip2718::{Decodable2718, Encodable2718},
     eip7702::SignedAuthorization,
 };
-use alloy_primitives::{Bytes, ChainId, B256, U256};
+use alloy_primitives::{Address, Bytes, ChainId, B256, U256};
 use alloy_rpc_types_eth::{AccessList, TransactionRequest};
 use alloy_serde::WithOtherFields;
 
@@ -192,6 +197,14 @@ impl TransactionTrait for AnyTypedTransaction {
             Self::Unknown(inner) => inner.authorization_list(),
         }
     }
+
+    #[inline]
+    fn system_sender(&self) -> Option<Address> {
+        match self {
+            Self::Ethereum(inner) => inner.system_sender(),
+            Self::Unknown(inner) => inner.system_sender(),
+        }
+    }
 }
 
 impl Typed2718 for AnyTypedTransaction {
@@ -203,6 +216,70 @@ impl Typed2718 for AnyTypedTransaction {
     }
 }
 
+impl AnyTypedTransaction {
+    /// Returns the [`TxGoat`] variant if the transaction is a GOAT system transaction.
+    pub const fn as_goat(&self) -> Option<&TxGoat> {
+        match self {
+            Self::Ethereum(TypedTransaction::Goat(tx)) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the transaction has the [`GOAT_TX_TYPE_ID`] type, even if it could not be
+    /// parsed as a [`TxGoat`].
+    #[inline]
+    pub fn is_goat(&self) -> bool {
+        self.ty() == GOAT_TX_TYPE_ID
+    }
+
+    /// Returns the module of a GOAT system transaction.
+    ///
+    /// For an unknown transaction with the GOAT type, the module is read from its `module` field.
+    pub fn goat_module(&self) -> Option<Module> {
+        match self {
+            Self::Ethereum(tx) => tx.goat().map(|tx| tx.module),
+            Self::Unknown(tx) => goat_field(tx, "module"),
+        }
+    }
+
+    /// Returns the action of a GOAT system transaction.
+    ///
+    /// For an unknown transaction with the GOAT type, the action is read from its `action` field.
+    pub fn goat_action(&self) -> Option<Action> {
+        match self {
+            Self::Ethereum(tx) => tx.goat().map(|tx| tx.action),
+            Self::Unknown(tx) => goat_field(tx, "action"),
+        }
+    }
+
+    /// Returns the native tokens minted by a GOAT bitcoin deposit.
+    ///
+    /// For an unknown transaction with the GOAT type, the deposit is decoded from its `input`.
+    pub fn goat_mint(&self) -> Option<Mint> {
+        match self {
+            Self::Ethereum(tx) => tx.goat().and_then(|tx| tx.inner.deposit()),
+            Self::Unknown(tx) => goat_mint(tx),
+        }
+    }
+}
+
+/// Deserializes a field of an unknown transaction with the GOAT type.
+fn goat_field<T: serde::de::DeserializeOwned>(
+    tx: &UnknownTypedTransaction,
+    key: &str,
+) -> Option<T> {
+    if tx.ty.0 != GOAT_TX_TYPE_ID {
+        return None;
+    }
+    tx.fields.get_deserialized(key).and_then(Result::ok)
+}
+
+/// Decodes the deposit of an unknown transaction with the GOAT type.
+fn goat_mint(tx: &UnknownTypedTransaction) -> Option<Mint> {
+    let input = goat_field::<Bytes>(tx, "input")?;
+    decode_tx(goat_field(tx, "module")?, goat_field(tx, "action")?, &input).ok()?.deposit()
+}
+
 /// Transaction envelope for a catch-all network.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
@@ -397,6 +474,51 @@ impl AnyTxEnvelope {
     pub const fn is_eip7702(&self) -> bool {
         matches!(self.as_envelope(), Some(TxEnvelope::Eip7702(_)))
     }
+
+    /// Returns the [`TxGoat`] variant if the transaction is a GOAT system transaction.
+    pub const fn as_goat(&self) -> Option<&Signed<TxGoat>> {
+        match self.as_envelope() {
+            Some(TxEnvelope::Goat(tx)) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the transaction has the [`GOAT_TX_TYPE_ID`] type, even if it could not be
+    /// parsed as a [`TxGoat`].
+    #[inline]
+    pub fn is_goat(&self) -> bool {
+        self.ty() == GOAT_TX_TYPE_ID
+    }
+
+    /// Returns the module of a GOAT system transaction.
+    ///
+    /// For an unknown transaction with the GOAT type, the module is read from its `module` field.
+    pub fn goat_module(&self) -> Option<Module> {
+        match self {
+            Self::Ethereum(tx) => tx.as_goat().map(|tx| tx.tx().module),
+            Self::Unknown(tx) => goat_field(&tx.inner, "module"),
+        }
+    }
+
+    /// Returns the action of a GOAT system transaction.
+    ///
+    /// For an unknown transaction with the GOAT type, the action is read from its `action` field.
+    pub fn goat_action(&self) -> Option<Action> {
+        match self {
+            Self::Ethereum(tx) => tx.as_goat().map(|tx| tx.tx().action),
+            Self::Unknown(tx) => goat_field(&tx.inner, "action"),
+        }
+    }
+
+    /// Returns the native tokens minted by a GOAT bitcoin deposit.
+    ///
+    /// For an unknown transaction with the GOAT type, the deposit is decoded from its `input`.
+    pub fn goat_mint(&self) -> Option<Mint> {
+        match self {
+            Self::Ethereum(tx) => tx.as_goat().and_then(|tx| tx.tx().inner.deposit()),
+            Self::Unknown(tx) => goat_mint(&tx.inner),
+        }
+    }
 }
 
 impl Typed2718 for AnyTxEnvelope {
@@ -578,4 +700,12 @@ impl TransactionTrait for AnyTxEnvelope {
             Self::Unknown(inner) => inner.authorization_list(),
         }
     }
+
+    #[inline]
+    fn system_sender(&self) -> Option<Address> {
+        match self {
+            Self::Ethereum(inner) => inner.system_sender(),
+            Self::Unknown(inner) => inner.system_sender(),
+        }
+    }
 }
@@ -7,7 +7,7 @@ use alloy_consensus::{
     Signed, TxEip1559, TxEip2930, TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy,
 };
 use alloy_eips::{eip7702::SignedAuthorization, Typed2718};
-use alloy_primitives::{Bytes, ChainId, TxKind, B256, U256};
+use alloy_primitives::{Address, Bytes, ChainId, TxKind, B256, U256};
 pub use either::{AnyTxEnvelope, AnyTypedTransaction};
 use std::error::Error;
 
@@ -22,7 +22,7 @@ use alloy_consensus::{
     transaction::{Either, Recovered},
 };
 use alloy_network_primitives::{BlockResponse, TransactionResponse};
-pub use alloy_rpc_types_any::{AnyRpcHeader, AnyTransactionReceipt};
+pub use alloy_rpc_types_any::{AnyRpcHeader, AnyTransactionReceipt, GoatReceiptExt};
 use alloy_rpc_types_eth::{AccessList, Block, BlockTransactions, Transaction, TransactionRequest};
 use alloy_serde::WithOtherFields;
 use derive_more::From;
@@ -495,6 +495,10 @@ impl alloy_consensus::Transaction for AnyRpcTransaction {
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         self.inner.authorization_list()
     }
+
+    fn system_sender(&self) -> Option<Address> {
+        self.inner.system_sender()
+    }
 }
 
 impl TransactionResponse for AnyRpcTransaction {
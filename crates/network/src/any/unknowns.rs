@@ -375,7 +375,7 @@ impl alloy_consensus::Transaction for UnknownTxEnvelope {
 mod tests {
     use alloy_consensus::Transaction;
 
-    use crate::{AnyRpcTransaction, AnyTxEnvelope};
+    use crate::{AnyRpcTransaction, AnyTxEnvelope, AnyTypedTransaction};
 
     use super::*;
 
@@ -427,4 +427,39 @@ mod tests {
 
         assert_eq!(tx, roundrip_tx);
     }
+
+    #[test]
+    fn test_serde_goat() {
+        use alloy_consensus::{
+            transaction::goat::{DepositTx, TxGoat, TxGoatInner, BRIDGE_MODULE},
+            SignableTransaction, TxEnvelope,
+        };
+        use alloy_primitives::Signature;
+
+        let deposit = DepositTx {
+            target: Address::with_last_byte(1),
+            amount: U256::from(10),
+            tax: U256::from(1),
+            ..Default::default()
+        };
+        let inner = TxGoatInner::Deposit(deposit);
+        let tx = TxGoat::new(48815, 0, inner);
+        let envelope = TxEnvelope::from(tx.into_signed(Signature::test_signature()));
+        let mut json = serde_json::to_value(&envelope).unwrap();
+
+        let any: AnyTxEnvelope = serde_json::from_value(json.clone()).unwrap();
+        assert!(any.as_goat().is_some());
+        assert_eq!(any.goat_module(), Some(BRIDGE_MODULE));
+        assert_eq!(any.goat_mint(), inner.deposit());
+        assert_eq!(AnyTypedTransaction::from(any.clone()).goat_action(), any.goat_action());
+
+        // a goat transaction that cannot be parsed is still recognized
+        json.as_object_mut().unwrap().remove("chainId");
+        let any: AnyTxEnvelope = serde_json::from_value(json).unwrap();
+        assert!(any.is_unknown() && any.is_goat());
+        assert!(any.as_goat().is_none());
+        assert_eq!(any.goat_module(), Some(BRIDGE_MODULE));
+        assert_eq!(any.goat_mint(), inner.deposit());
+        assert_eq!(AnyTypedTransaction::from(any.clone()).goat_mint(), any.goat_mint());
+    }
 }
@@ -2,7 +2,7 @@ use crate::{
     BuildResult, Ethereum, Network, NetworkWallet, TransactionBuilder, TransactionBuilder7702,
     TransactionBuilderError,
 };
-use alloy_consensus::{TxType, TypedTransaction};
+use alloy_consensus::{transaction::goat::GOAT_TX_TYPE_ID, TxType, TypedTransaction};
 use alloy_primitives::{Address, Bytes, ChainId, TxKind, U256};
 use alloy_rpc_types_eth::{request::TransactionRequest, AccessList, TransactionInputKind};
 
@@ -122,6 +122,7 @@ impl TransactionBuilder<Ethereum> for TransactionRequest {
             TxType::Eip1559 => self.complete_1559(),
             TxType::Eip4844 => self.complete_4844(),
             TxType::Eip7702 => self.complete_7702(),
+            TxType::Goat => Err(vec!["transaction_type"]),
         }
     }
 
@@ -152,6 +153,10 @@ impl TransactionBuilder<Ethereum> for TransactionRequest {
 
     #[doc(alias = "output_transaction_type")]
     fn output_tx_type(&self) -> TxType {
+        // goat system transactions are never the preferred type, they must be requested
+        if self.transaction_type == Some(GOAT_TX_TYPE_ID) {
+            return TxType::Goat;
+        }
         self.preferred_type()
     }
 
@@ -393,4 +398,14 @@ mod tests {
         assert!(errors.contains(&"max_priority_fee_per_gas"));
         assert!(errors.contains(&"max_fee_per_gas"));
     }
+
+    #[test]
+    fn goat_output_type() {
+        let request = TransactionRequest::default().with_to(Address::ZERO);
+        assert_eq!(request.output_tx_type(), TxType::Eip1559);
+
+        let request = TransactionRequest { transaction_type: Some(0x60), ..request };
+        assert_eq!(request.output_tx_type(), TxType::Goat);
+        assert_eq!(request.output_tx_type_checked(), None);
+    }
 }
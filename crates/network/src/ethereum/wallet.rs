@@ -160,6 +160,17 @@ where
                 let sig = self.sign_transaction_inner(sender, &mut t).await?;
                 Ok(t.into_signed(sig).into())
             }
+            TypedTransaction::Goat(mut t) => {
+                // goat system transactions are sent by the synthesized executor of their module,
+                // they are never signed by another key, see `GoatWallet` for per-module signers
+                if sender == t.sender() && !self.signers.contains_key(&sender) {
+                    return Err(alloy_signer::Error::other(format!(
+                        "no signer for goat executor {sender}"
+                    )));
+                }
+                let sig = self.sign_transaction_inner(sender, &mut t).await?;
+                Ok(t.into_signed(sig).into())
+            }
         }
     }
 }
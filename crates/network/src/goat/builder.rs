@@ -0,0 +1,255 @@
+use crate::{
+    goat::Goat, BuildResult, Network, NetworkWallet, TransactionBuilder, TransactionBuilderError,
+};
+use alloy_consensus::{TxType, TypedTransaction};
+use alloy_primitives::{Address, Bytes, ChainId, TxKind, U256};
+use alloy_rpc_types_eth::{AccessList, GoatTransactionRequest, TransactionInputKind};
+use std::ops::{Deref, DerefMut};
+
+impl TransactionBuilder<Goat> for GoatTransactionRequest {
+    fn chain_id(&self) -> Option<ChainId> {
+        self.deref().chain_id()
+    }
+
+    fn set_chain_id(&mut self, chain_id: ChainId) {
+        self.deref_mut().set_chain_id(chain_id)
+    }
+
+    fn nonce(&self) -> Option<u64> {
+        self.deref().nonce()
+    }
+
+    fn set_nonce(&mut self, nonce: u64) {
+        self.deref_mut().set_nonce(nonce)
+    }
+
+    fn take_nonce(&mut self) -> Option<u64> {
+        self.deref_mut().nonce.take()
+    }
+
+    fn input(&self) -> Option<&Bytes> {
+        self.deref().input()
+    }
+
+    fn set_input<T: Into<Bytes>>(&mut self, input: T) {
+        self.deref_mut().set_input(input);
+    }
+
+    fn set_input_kind<T: Into<Bytes>>(&mut self, input: T, kind: TransactionInputKind) {
+        self.deref_mut().set_input_kind(input, kind)
+    }
+
+    fn from(&self) -> Option<Address> {
+        self.deref().from()
+    }
+
+    fn set_from(&mut self, from: Address) {
+        self.deref_mut().set_from(from);
+    }
+
+    fn kind(&self) -> Option<TxKind> {
+        self.deref().kind()
+    }
+
+    fn clear_kind(&mut self) {
+        self.deref_mut().clear_kind()
+    }
+
+    fn set_kind(&mut self, kind: TxKind) {
+        self.deref_mut().set_kind(kind)
+    }
+
+    fn value(&self) -> Option<U256> {
+        self.deref().value()
+    }
+
+    fn set_value(&mut self, value: U256) {
+        self.deref_mut().set_value(value)
+    }
+
+    fn gas_price(&self) -> Option<u128> {
+        self.deref().gas_price()
+    }
+
+    fn set_gas_price(&mut self, gas_price: u128) {
+        self.deref_mut().set_gas_price(gas_price);
+    }
+
+    fn max_fee_per_gas(&self) -> Option<u128> {
+        self.deref().max_fee_per_gas()
+    }
+
+    fn set_max_fee_per_gas(&mut self, max_fee_per_gas: u128) {
+        self.deref_mut().set_max_fee_per_gas(max_fee_per_gas);
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        self.deref().max_priority_fee_per_gas()
+    }
+
+    fn set_max_priority_fee_per_gas(&mut self, max_priority_fee_per_gas: u128) {
+        self.deref_mut().set_max_priority_fee_per_gas(max_priority_fee_per_gas);
+    }
+
+    fn gas_limit(&self) -> Option<u64> {
+        self.deref().gas_limit()
+    }
+
+    fn set_gas_limit(&mut self, gas_limit: u64) {
+        self.deref_mut().set_gas_limit(gas_limit);
+    }
+
+    /// Get the EIP-2930 access list for the transaction.
+    fn access_list(&self) -> Option<&AccessList> {
+        self.deref().access_list()
+    }
+
+    /// Sets the EIP-2930 access list.
+    fn set_access_list(&mut self, access_list: AccessList) {
+        self.deref_mut().set_access_list(access_list)
+    }
+
+    fn complete_type(&self, ty: TxType) -> Result<(), Vec<&'static str>> {
+        match ty {
+            TxType::Goat => self.complete_goat(),
+            ty => self.deref().complete_type(ty),
+        }
+    }
+
+    fn can_submit(&self) -> bool {
+        // goat system transactions are only submitted signed, so they must be buildable
+        if self.is_goat() {
+            return self.complete_goat().is_ok();
+        }
+        self.deref().can_submit()
+    }
+
+    fn can_build(&self) -> bool {
+        if self.is_goat() {
+            return self.complete_goat().is_ok();
+        }
+        self.deref().can_build()
+    }
+
+    #[doc(alias = "output_transaction_type")]
+    fn output_tx_type(&self) -> TxType {
+        if self.is_goat() {
+            return TxType::Goat;
+        }
+        self.deref().output_tx_type()
+    }
+
+    #[doc(alias = "output_transaction_type_checked")]
+    fn output_tx_type_checked(&self) -> Option<TxType> {
+        if self.is_goat() {
+            return self.complete_goat().is_ok().then_some(TxType::Goat);
+        }
+        self.deref().output_tx_type_checked()
+    }
+
+    fn prep_for_submission(&mut self) {
+        if self.is_goat() {
+            self.transaction_type = Some(TxType::Goat as u8);
+            self.trim_conflicting_keys();
+            return;
+        }
+        self.deref_mut().prep_for_submission()
+    }
+
+    fn build_unsigned(self) -> BuildResult<TypedTransaction, Goat> {
+        if self.is_goat() {
+            if let Err(missing) = self.complete_goat() {
+                return Err(TransactionBuilderError::InvalidTransactionRequest(
+                    TxType::Goat,
+                    missing,
+                )
+                .into_unbuilt(self));
+            }
+            return self.build_goat().map(Into::into).map_err(|err| {
+                let msg = err.to_string();
+                TransactionBuilderError::Custom(msg.into()).into_unbuilt(err.into_value())
+            });
+        }
+        if let Err((tx_type, missing)) = self.missing_keys() {
+            return Err(TransactionBuilderError::InvalidTransactionRequest(tx_type, missing)
+                .into_unbuilt(self));
+        }
+        Ok(self.inner.build_typed_tx().expect("checked by missing_keys"))
+    }
+
+    async fn build<W: NetworkWallet<Goat>>(
+        self,
+        wallet: &W,
+    ) -> Result<<Goat as Network>::TxEnvelope, TransactionBuilderError<Goat>> {
+        Ok(wallet.sign_request(self).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Goat, TransactionBuilder, TransactionBuilderError, TransactionBuilderGoat};
+    use alloy_consensus::{
+        transaction::goat::{
+            NewBtcBlockTx, TxGoat, TxGoatInner, RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION,
+        },
+        TxType, TypedTransaction,
+    };
+    use alloy_primitives::{Address, B256};
+    use alloy_rpc_types_eth::GoatTransactionRequest;
+
+    fn new_btc_block() -> TxGoat {
+        TxGoat::new(
+            48816,
+            0,
+            TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) }),
+        )
+    }
+
+    #[test]
+    fn build_goat_tx() {
+        let tx = new_btc_block();
+        let request = GoatTransactionRequest::default()
+            .with_chain_id(48816)
+            .with_nonce(0)
+            .with_input(tx.input.clone())
+            .with_module(RELAYER_MODULE)
+            .with_action(RELAYER_NEW_BTC_BLOCK_ACTION);
+        assert!(TransactionBuilder::<Goat>::can_build(&request));
+        assert_eq!(TransactionBuilder::<Goat>::output_tx_type(&request), TxType::Goat);
+
+        let mut prepped = request.clone();
+        TransactionBuilder::<Goat>::prep_for_submission(&mut prepped);
+        assert_eq!(prepped.transaction_type, Some(0x60));
+
+        let built = TransactionBuilder::<Goat>::build_unsigned(request).unwrap();
+        assert_eq!(built, TypedTransaction::Goat(tx));
+    }
+
+    #[test]
+    fn build_goat_tx_missing_keys() {
+        let request =
+            GoatTransactionRequest::default().with_chain_id(48816).with_module(RELAYER_MODULE);
+        assert_eq!(
+            TransactionBuilder::<Goat>::complete_type(&request, TxType::Goat),
+            Err(vec!["nonce", "input", "action"])
+        );
+        let error = TransactionBuilder::<Goat>::build_unsigned(request).unwrap_err();
+        assert!(matches!(
+            error.error,
+            TransactionBuilderError::InvalidTransactionRequest(TxType::Goat, _)
+        ));
+    }
+
+    #[test]
+    fn build_ethereum_tx() {
+        let request = GoatTransactionRequest::default()
+            .with_to(Address::ZERO)
+            .with_nonce(1)
+            .with_gas_limit(21_000)
+            .with_max_fee_per_gas(2)
+            .with_max_priority_fee_per_gas(1);
+        assert_eq!(TransactionBuilder::<Goat>::output_tx_type(&request), TxType::Eip1559);
+        let built = TransactionBuilder::<Goat>::build_unsigned(request).unwrap();
+        assert!(matches!(built, TypedTransaction::Eip1559(_)));
+    }
+}
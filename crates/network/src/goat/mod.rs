@@ -0,0 +1,42 @@
+use crate::Network;
+
+mod builder;
+
+mod wallet;
+pub use wallet::GoatWallet;
+
+/// Types for a GOAT network.
+///
+/// This is the [`Ethereum`](crate::Ethereum) network with a [`GoatTransactionRequest`], which
+/// allows building GOAT system transactions from a request with the
+/// [`TransactionBuilderGoat`](crate::TransactionBuilderGoat) methods. Receipts keep the goat
+/// specific fields, so [`ReceiptResponse::mint`](crate::ReceiptResponse::mint) reports the mint
+/// of deposits.
+///
+/// [`GoatTransactionRequest`]: alloy_rpc_types_eth::GoatTransactionRequest
+#[derive(Clone, Copy, Debug)]
+pub struct Goat {
+    _private: (),
+}
+
+impl Network for Goat {
+    type TxType = alloy_consensus::TxType;
+
+    type TxEnvelope = alloy_consensus::TxEnvelope;
+
+    type UnsignedTx = alloy_consensus::TypedTransaction;
+
+    type ReceiptEnvelope = alloy_consensus::ReceiptEnvelope;
+
+    type Header = alloy_consensus::Header;
+
+    type TransactionRequest = alloy_rpc_types_eth::transaction::GoatTransactionRequest;
+
+    type TransactionResponse = alloy_rpc_types_eth::Transaction;
+
+    type ReceiptResponse = alloy_serde::WithOtherFields<alloy_rpc_types_eth::TransactionReceipt>;
+
+    type HeaderResponse = alloy_rpc_types_eth::Header;
+
+    type BlockResponse = alloy_rpc_types_eth::Block;
+}
@@ -0,0 +1,260 @@
+//! A wallet signing goat system transactions with the keys of their module executors.
+
+use crate::{EthereumWallet, Network, NetworkWallet, TxSigner};
+use alloy_consensus::{
+    transaction::goat::{Module, TxGoat},
+    SignableTransaction, TxEnvelope, TypedTransaction,
+};
+use alloy_primitives::{Address, Signature};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// A wallet routing the signing of goat system transactions by module.
+///
+/// Goat system transactions are sent by the fixed executor of their module, see
+/// [`Module::executor`], so the signer is picked by the module of the transaction instead of its
+/// sender, e.g. the relayer key for the bridge module and the locking key for the locking module.
+/// All other transactions are signed by the inner [`EthereumWallet`].
+#[derive(Clone, Default)]
+pub struct GoatWallet {
+    wallet: EthereumWallet,
+    modules: BTreeMap<Module, Arc<dyn TxSigner<Signature> + Send + Sync>>,
+}
+
+impl std::fmt::Debug for GoatWallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GoatWallet")
+            .field("wallet", &self.wallet)
+            .field("modules", &self.modules.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl From<EthereumWallet> for GoatWallet {
+    fn from(wallet: EthereumWallet) -> Self {
+        Self::new(wallet)
+    }
+}
+
+impl GoatWallet {
+    /// Creates a new wallet signing regular transactions with the given wallet.
+    pub fn new(wallet: impl Into<EthereumWallet>) -> Self {
+        Self { wallet: wallet.into(), modules: BTreeMap::new() }
+    }
+
+    /// Registers the signer of the goat system transactions of the given module, replacing any
+    /// previous signer of the module.
+    pub fn register_module_signer<S>(&mut self, module: Module, signer: S)
+    where
+        S: TxSigner<Signature> + Send + Sync + 'static,
+    {
+        self.modules.insert(module, Arc::new(signer));
+    }
+
+    /// Registers the signer of the goat system transactions of the given module, see
+    /// [`register_module_signer`](Self::register_module_signer).
+    pub fn with_module_signer<S>(mut self, module: Module, signer: S) -> Self
+    where
+        S: TxSigner<Signature> + Send + Sync + 'static,
+    {
+        self.register_module_signer(module, signer);
+        self
+    }
+
+    /// Get the signer of the goat system transactions of the given module.
+    pub fn module_signer(
+        &self,
+        module: Module,
+    ) -> Option<Arc<dyn TxSigner<Signature> + Send + Sync + 'static>> {
+        self.modules.get(&module).cloned()
+    }
+
+    /// Returns the signer of the given module, or an error if the module has no signer.
+    fn goat_signer(
+        &self,
+        module: Module,
+    ) -> alloy_signer::Result<Arc<dyn TxSigner<Signature> + Send + Sync>> {
+        self.module_signer(module).ok_or_else(|| {
+            alloy_signer::Error::other(format!(
+                "Missing signing credential for goat module {}",
+                module.name()
+            ))
+        })
+    }
+
+    /// Returns the wallet signing regular transactions.
+    pub const fn wallet(&self) -> &EthereumWallet {
+        &self.wallet
+    }
+
+    /// Returns a mutable reference to the wallet signing regular transactions.
+    pub const fn wallet_mut(&mut self) -> &mut EthereumWallet {
+        &mut self.wallet
+    }
+}
+
+impl<N> NetworkWallet<N> for GoatWallet
+where
+    N: Network<UnsignedTx = TypedTransaction, TxEnvelope = TxEnvelope>,
+{
+    fn default_signer_address(&self) -> Address {
+        NetworkWallet::<N>::default_signer_address(&self.wallet)
+    }
+
+    /// Returns true for the addresses of the signers, including the keys of the modules and the
+    /// executors of the modules they sign for, see
+    /// [`register_module_signer`](GoatWallet::register_module_signer).
+    fn has_signer_for(&self, address: &Address) -> bool {
+        NetworkWallet::<N>::has_signer_for(&self.wallet, address)
+            || self.modules.iter().any(|(module, signer)| {
+                module.executor() == *address || signer.address() == *address
+            })
+    }
+
+    fn signer_addresses(&self) -> impl Iterator<Item = Address> {
+        NetworkWallet::<N>::signer_addresses(&self.wallet)
+            .chain(self.modules.values().map(|signer| signer.address()))
+    }
+
+    /// Signs goat system transactions with the key of their module, either given as its address or
+    /// as the executor of the module, see [`Module::executor`].
+    async fn sign_transaction_from(
+        &self,
+        sender: Address,
+        tx: TypedTransaction,
+    ) -> alloy_signer::Result<TxEnvelope> {
+        match tx {
+            TypedTransaction::Goat(t) => {
+                let signer = self.goat_signer(t.module)?;
+                if sender != t.module.executor() && sender != signer.address() {
+                    return Err(alloy_signer::Error::other(format!(
+                        "Missing signing credential for {sender} in goat module {}",
+                        t.module.name()
+                    )));
+                }
+                sign_goat(signer, t).await
+            }
+            tx => NetworkWallet::<N>::sign_transaction_from(&self.wallet, sender, tx).await,
+        }
+    }
+
+    /// Signs goat system transactions with the key of their module, and all other transactions
+    /// with the default signer.
+    async fn sign_transaction(&self, tx: TypedTransaction) -> alloy_signer::Result<TxEnvelope> {
+        match tx {
+            TypedTransaction::Goat(t) => sign_goat(self.goat_signer(t.module)?, t).await,
+            tx => NetworkWallet::<N>::sign_transaction(&self.wallet, tx).await,
+        }
+    }
+}
+
+async fn sign_goat(
+    signer: Arc<dyn TxSigner<Signature> + Send + Sync>,
+    mut tx: TxGoat,
+) -> alloy_signer::Result<TxEnvelope> {
+    let sig = signer.sign_transaction(&mut tx).await?;
+    Ok(tx.into_signed(sig).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ethereum, Goat};
+    use alloy_consensus::{
+        transaction::goat::{
+            CompleteUnlockTx, DepositTx, TxGoat, TxGoatInner, BRIDGE_MODULE, LOCKING_EXECUTOR,
+            LOCKING_MODULE, RELAYER_EXECUTOR, RELAYER_MODULE,
+        },
+        TxLegacy,
+    };
+    use alloy_primitives::ChainId;
+    use async_trait::async_trait;
+
+    /// A signer returning a fixed signature, identifying the signer in its `r` value.
+    #[derive(Debug)]
+    struct FixedSigner(u8);
+
+    #[async_trait]
+    impl TxSigner<Signature> for FixedSigner {
+        fn address(&self) -> Address {
+            Address::with_last_byte(self.0)
+        }
+
+        async fn sign_transaction(
+            &self,
+            _tx: &mut dyn SignableTransaction<Signature>,
+        ) -> alloy_signer::Result<Signature> {
+            Ok(Signature::new(alloy_primitives::U256::from(self.0), Default::default(), false))
+        }
+    }
+
+    fn signed_by(envelope: &TxEnvelope) -> u8 {
+        envelope.signature().r().to::<u8>()
+    }
+
+    #[tokio::test]
+    async fn goat_wallet_routes_by_module() {
+        let chain_id: ChainId = 48816;
+        let wallet = GoatWallet::new(EthereumWallet::new(FixedSigner(1)))
+            .with_module_signer(BRIDGE_MODULE, FixedSigner(2))
+            .with_module_signer(RELAYER_MODULE, FixedSigner(2))
+            .with_module_signer(LOCKING_MODULE, FixedSigner(3));
+
+        let deposit = TxGoat::new(chain_id, 0, TxGoatInner::Deposit(DepositTx::default()));
+        let unlock =
+            TxGoat::new(chain_id, 0, TxGoatInner::CompleteUnlock(CompleteUnlockTx::default()));
+        let legacy = TxLegacy { chain_id: Some(chain_id), ..Default::default() };
+
+        let sign = |sender: Address, tx: TypedTransaction| {
+            NetworkWallet::<Goat>::sign_transaction_from(&wallet, sender, tx)
+        };
+        assert_eq!(signed_by(&sign(RELAYER_EXECUTOR, deposit.clone().into()).await.unwrap()), 2);
+        assert_eq!(signed_by(&sign(LOCKING_EXECUTOR, unlock.clone().into()).await.unwrap()), 3);
+        assert!(sign(RELAYER_EXECUTOR, unlock.clone().into()).await.is_err());
+
+        // the sender is either the executor or the key of the module
+        let sign_from = |sender: u8, tx: TxGoat| {
+            NetworkWallet::<Goat>::sign_transaction_from(
+                &wallet,
+                Address::with_last_byte(sender),
+                tx.into(),
+            )
+        };
+        assert_eq!(signed_by(&sign_from(3, unlock.clone()).await.unwrap()), 3);
+        assert!(sign_from(2, unlock.clone()).await.is_err());
+        assert!(sign_from(1, deposit.clone()).await.is_err());
+        let tx = NetworkWallet::<Goat>::sign_transaction(&wallet, unlock.into()).await.unwrap();
+        assert_eq!(signed_by(&tx), 3);
+
+        // regular transactions are signed by the sender
+        let signer = Address::with_last_byte(1);
+        let tx = NetworkWallet::<Ethereum>::sign_transaction_from(
+            &wallet,
+            signer,
+            legacy.clone().into(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(signed_by(&tx), 1);
+        assert!(sign(RELAYER_EXECUTOR, legacy.into()).await.is_err());
+
+        // the keys are reported by their own address, and the executors of their modules are
+        // accepted as senders
+        assert!(NetworkWallet::<Goat>::has_signer_for(&wallet, &Address::with_last_byte(3)));
+        assert!(NetworkWallet::<Goat>::has_signer_for(&wallet, &signer));
+        assert!(NetworkWallet::<Goat>::has_signer_for(&wallet, &LOCKING_EXECUTOR));
+        let mut addresses = NetworkWallet::<Goat>::signer_addresses(&wallet).collect::<Vec<_>>();
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses, [1, 2, 3].map(Address::with_last_byte));
+        assert_eq!(NetworkWallet::<Goat>::default_signer_address(&wallet), signer);
+
+        // goat transactions of modules without a signer are rejected
+        let wallet = GoatWallet::new(EthereumWallet::new(FixedSigner(1)));
+        assert!(!NetworkWallet::<Goat>::has_signer_for(&wallet, &RELAYER_EXECUTOR));
+        let deposit = TxGoat::new(chain_id, 0, TxGoatInner::Deposit(DepositTx::default()));
+        let err = NetworkWallet::<Goat>::sign_transaction_from(&wallet, signer, deposit.into())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("BRIDGE"), "{err}");
+    }
+}
@@ -15,13 +15,16 @@ use core::fmt::{Debug, Display};
 mod transaction;
 pub use transaction::{
     BuildResult, FullSigner, FullSignerSync, NetworkWallet, TransactionBuilder,
-    TransactionBuilder4844, TransactionBuilder7702, TransactionBuilderError, TxSigner,
-    TxSignerSync, UnbuiltTransactionError,
+    TransactionBuilder4844, TransactionBuilder7702, TransactionBuilderError,
+    TransactionBuilderGoat, TxSigner, TxSignerSync, UnbuiltTransactionError,
 };
 
 mod ethereum;
 pub use ethereum::{Ethereum, EthereumWallet, IntoWallet};
 
+mod goat;
+pub use goat::{Goat, GoatWallet};
+
 /// Types for handling unknown network types.
 pub mod any;
 pub use any::{
@@ -118,5 +121,12 @@ macro_rules! impl_into_wallet {
                 $crate::EthereumWallet::from(self)
             }
         }
+
+        impl $(<$($generics)*>)? $crate::IntoWallet<$crate::Goat> for $signer {
+            type NetworkWallet = $crate::EthereumWallet;
+            fn into_wallet(self) -> Self::NetworkWallet {
+                $crate::EthereumWallet::from(self)
+            }
+        }
     };
 }
@@ -5,7 +5,9 @@ use alloy_rpc_types_eth::{AccessList, TransactionInputKind};
 use alloy_sol_types::SolCall;
 use futures_utils_wasm::impl_future;
 
-pub use alloy_network_primitives::{TransactionBuilder4844, TransactionBuilder7702};
+pub use alloy_network_primitives::{
+    TransactionBuilder4844, TransactionBuilder7702, TransactionBuilderGoat,
+};
 
 /// Result type for transaction builders
 pub type BuildResult<T, N> = Result<T, UnbuiltTransactionError<N>>;
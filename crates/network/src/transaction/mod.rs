@@ -1,7 +1,7 @@
 mod builder;
 pub use builder::{
     BuildResult, TransactionBuilder, TransactionBuilder4844, TransactionBuilder7702,
-    TransactionBuilderError, UnbuiltTransactionError,
+    TransactionBuilderError, TransactionBuilderGoat, UnbuiltTransactionError,
 };
 
 mod signer;
@@ -1,7 +1,7 @@
 //! This module extends the Ethereum JSON-RPC provider with the Anvil namespace's RPC methods.
 
 use crate::{PendingTransactionBuilder, Provider};
-use alloy_consensus::Blob;
+use alloy_consensus::{transaction::goat::Module, Blob};
 use alloy_network::{Network, TransactionBuilder};
 use alloy_primitives::{Address, Bytes, TxHash, B256, U128, U256, U64};
 use alloy_rpc_types_anvil::{Forking, Metadata, MineOptions, NodeInfo, ReorgOptions};
@@ -35,6 +35,13 @@ pub trait AnvilApi<N: Network>: Send + Sync {
         config: ImpersonateConfig,
     ) -> TransportResult<PendingTransactionBuilder<N>>;
 
+    /// Impersonates the system executor of the given goat module and funds it with
+    /// [`GOAT_EXECUTOR_FUNDING`], returning the executor address.
+    ///
+    /// This allows sending goat system transactions against a local or forked node, e.g. with
+    /// [`anvil_send_impersonated_transaction`](Self::anvil_send_impersonated_transaction).
+    async fn impersonate_goat_executor(&self, module: Module) -> TransportResult<Address>;
+
     /// Returns true if auto mining is enabled, and false.
     async fn anvil_get_auto_mine(&self) -> TransportResult<bool>;
 
@@ -459,8 +466,21 @@ where
 
         Ok(pending)
     }
+
+    async fn impersonate_goat_executor(&self, module: Module) -> TransportResult<Address> {
+        let executor = module.executor();
+        try_join!(
+            self.anvil_set_balance(executor, GOAT_EXECUTOR_FUNDING),
+            self.anvil_impersonate_account(executor)
+        )?;
+        Ok(executor)
+    }
 }
 
+/// The balance set on executors impersonated by [`AnvilApi::impersonate_goat_executor`]: 100
+/// ether.
+pub const GOAT_EXECUTOR_FUNDING: U256 = alloy_primitives::uint!(100_000_000_000_000_000_000_U256);
+
 /// Configuration for impersonated transactions, including optional funding and whether to stop
 /// impersonation.
 #[derive(Debug, Clone)]
@@ -561,6 +581,25 @@ mod tests {
         res.unwrap_err();
     }
 
+    #[tokio::test]
+    async fn test_impersonate_goat_executor() {
+        use alloy_consensus::transaction::goat::{LOCKING_EXECUTOR, LOCKING_MODULE};
+
+        let provider = ProviderBuilder::new().connect_anvil();
+
+        let executor = provider.impersonate_goat_executor(LOCKING_MODULE).await.unwrap();
+        assert_eq!(executor, LOCKING_EXECUTOR);
+        assert_eq!(provider.get_balance(executor).await.unwrap(), GOAT_EXECUTOR_FUNDING);
+        assert!(provider.get_accounts().await.unwrap().contains(&executor));
+
+        let tx = TransactionRequest::default()
+            .with_from(executor)
+            .with_to(Address::random())
+            .with_value(U256::from(1337));
+        let receipt = provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap();
+        assert_eq!(receipt.from, executor);
+    }
+
     #[tokio::test]
     async fn test_anvil_impersonated_send_with_config() {
         let provider = ProviderBuilder::new()
@@ -6,8 +6,8 @@ use alloy_primitives::{hex, Bytes, TxHash, B256};
 use alloy_rpc_types_debug::ExecutionWitness;
 use alloy_rpc_types_eth::{BadBlock, BlockId, BlockNumberOrTag, Bundle, StateContext};
 use alloy_rpc_types_trace::geth::{
-    BlockTraceResult, CallFrame, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace,
-    PreStateFrame, TraceResult,
+    BlockTraceResult, CallConfig, CallFrame, GethDebugTracingCallOptions, GethDebugTracingOptions,
+    GethTrace, GoatTrace, PreStateFrame, TraceResult,
 };
 use alloy_transport::TransportResult;
 
@@ -118,6 +118,14 @@ pub trait DebugApi<N: Network = Ethereum>: Send + Sync {
         trace_options: GethDebugTracingOptions,
     ) -> TransportResult<CallFrame>;
 
+    /// Reruns the transaction specified by the hash with the call tracer, and annotates the calls
+    /// to the goat predeploys with their decoded actions.
+    ///
+    /// # Note
+    ///
+    /// Not all nodes support this call.
+    async fn debug_trace_goat_transaction(&self, hash: TxHash) -> TransportResult<GoatTrace>;
+
     /// Reruns the transaction specified by the hash and returns the trace in a specified format.
     ///
     /// This method allows for the trace to be returned as a type that implements `RpcRecv` and
@@ -443,6 +451,11 @@ where
         self.debug_trace_transaction_as::<CallFrame>(hash, trace_options).await
     }
 
+    async fn debug_trace_goat_transaction(&self, hash: TxHash) -> TransportResult<GoatTrace> {
+        let trace_options = GethDebugTracingOptions::call_tracer(CallConfig::default().with_log());
+        self.debug_trace_transaction_call(hash, trace_options).await.map(GoatTrace::from)
+    }
+
     async fn debug_trace_call_as<R>(
         &self,
         tx: N::TransactionRequest,
@@ -585,6 +598,29 @@ mod test {
     use alloy_primitives::{address, U256};
     use alloy_rpc_types_eth::TransactionRequest;
 
+    #[tokio::test]
+    async fn test_debug_trace_goat_transaction() {
+        use alloy_consensus::transaction::goat::{TxGoatInner, BITCOIN_CONTRACT, RELAYER_EXECUTOR};
+
+        let asserter = alloy_transport::mock::Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        asserter.push_success(&CallFrame {
+            from: RELAYER_EXECUTOR,
+            to: Some(BITCOIN_CONTRACT),
+            input: alloy_primitives::bytes!(
+                "94f490bd00000000000000000000000000000000000000000000000000000000000000ff"
+            ),
+            typ: "CALL".to_string(),
+            ..Default::default()
+        });
+
+        let trace = provider.debug_trace_goat_transaction(B256::ZERO).await.unwrap();
+        let Some(TxGoatInner::NewBtcBlock(block)) = trace.goat_action else {
+            panic!("expected new btc block, got {:?}", trace.goat_action);
+        };
+        assert_eq!(block.hash, B256::with_last_byte(0xff));
+    }
+
     #[tokio::test]
     async fn test_debug_trace_transaction() {
         async_ci_only(|| async move {
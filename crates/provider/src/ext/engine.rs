@@ -177,6 +177,37 @@ pub trait EngineApi<N>: Send + Sync {
         &self,
         capabilities: Vec<String>,
     ) -> TransportResult<Vec<String>>;
+
+    /// Updates the goat-geth execution layer client with the given fork choice.
+    ///
+    /// This is the GOAT variant of [`EngineApi::fork_choice_updated_v3`], the payload attributes
+    /// should carry the bitcoin anchor of the new payload.
+    async fn goat_fork_choice_updated_v1(
+        &self,
+        fork_choice_state: ForkchoiceState,
+        payload_attributes: Option<PayloadAttributes>,
+    ) -> TransportResult<ForkchoiceUpdated>;
+
+    /// Retrieves an execution payload from a previously started goat-geth build process.
+    ///
+    /// This is the GOAT variant of [`EngineApi::get_payload_v4`], the goat system transactions of
+    /// the payload can be accessed with
+    /// [`ExecutionPayload::goat_txs`](alloy_rpc_types_engine::ExecutionPayload::goat_txs).
+    async fn goat_get_payload_v1(
+        &self,
+        payload_id: PayloadId,
+    ) -> TransportResult<ExecutionPayloadEnvelopeV4>;
+
+    /// Sends the given payload to the goat-geth execution layer client.
+    ///
+    /// This is the GOAT variant of [`EngineApi::new_payload_v4`].
+    async fn goat_new_payload_v1(
+        &self,
+        payload: ExecutionPayloadV3,
+        versioned_hashes: Vec<B256>,
+        parent_beacon_block_root: B256,
+        execution_requests: Vec<Bytes>,
+    ) -> TransportResult<PayloadStatus>;
 }
 
 #[cfg_attr(target_family = "wasm", async_trait::async_trait(?Send))]
@@ -306,4 +337,36 @@ where
     ) -> TransportResult<Vec<String>> {
         self.client().request("engine_exchangeCapabilities", (capabilities,)).await
     }
+
+    async fn goat_fork_choice_updated_v1(
+        &self,
+        fork_choice_state: ForkchoiceState,
+        payload_attributes: Option<PayloadAttributes>,
+    ) -> TransportResult<ForkchoiceUpdated> {
+        self.client()
+            .request("engine_goatForkchoiceUpdatedV1", (fork_choice_state, payload_attributes))
+            .await
+    }
+
+    async fn goat_get_payload_v1(
+        &self,
+        payload_id: PayloadId,
+    ) -> TransportResult<ExecutionPayloadEnvelopeV4> {
+        self.client().request("engine_goatGetPayloadV1", (payload_id,)).await
+    }
+
+    async fn goat_new_payload_v1(
+        &self,
+        payload: ExecutionPayloadV3,
+        versioned_hashes: Vec<B256>,
+        parent_beacon_block_root: B256,
+        execution_requests: Vec<Bytes>,
+    ) -> TransportResult<PayloadStatus> {
+        self.client()
+            .request(
+                "engine_goatNewPayloadV1",
+                (payload, versioned_hashes, parent_beacon_block_root, execution_requests),
+            )
+            .await
+    }
 }
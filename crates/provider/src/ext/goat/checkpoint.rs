@@ -0,0 +1,151 @@
+//! Resumable state of a [`GoatScanner`](super::GoatScanner).
+
+use super::ScannedBlock;
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, convert::Infallible};
+
+/// The position of an indexer in the chain, to resume scanning with
+/// [`GoatScanner::resume`](super::GoatScanner::resume) after a restart.
+///
+/// The checkpoint also keeps the hashes of the most recent recorded blocks, so that blocks that
+/// were reorged while the indexer was stopped are detected by
+/// [`GoatScanner::reconcile`](super::GoatScanner::reconcile).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanCheckpoint {
+    /// The number of the last recorded block.
+    pub last_block: Option<u64>,
+    /// The index of the last recorded transaction in the last recorded block, if the block was
+    /// only recorded partially.
+    pub last_tx_index: Option<u64>,
+    /// The numbers and hashes of the most recent recorded blocks, oldest first.
+    ///
+    /// At most [`MAX_RECENT_BLOCKS`](Self::MAX_RECENT_BLOCKS) blocks are kept.
+    pub recent_blocks: VecDeque<(u64, B256)>,
+}
+
+impl ScanCheckpoint {
+    /// The maximum number of recent blocks kept to detect reorgs.
+    pub const MAX_RECENT_BLOCKS: usize = 64;
+
+    /// Creates a new checkpoint, resuming at the given block.
+    pub fn starting_at(block_number: u64) -> Self {
+        Self { last_block: block_number.checked_sub(1), ..Default::default() }
+    }
+
+    /// Returns the number of the next block to scan.
+    ///
+    /// This is the last recorded block if it was only recorded partially.
+    pub const fn next_block(&self) -> u64 {
+        match (self.last_block, self.last_tx_index) {
+            (Some(number), Some(_)) => number,
+            (Some(number), None) => number + 1,
+            (None, _) => 0,
+        }
+    }
+
+    /// Records that the transaction at `tx_index` of the block, and all transactions before it,
+    /// were processed.
+    pub fn record_tx(&mut self, block: &ScannedBlock, tx_index: u64) {
+        self.push_recent(block);
+        self.last_block = Some(block.number);
+        self.last_tx_index = Some(tx_index);
+    }
+
+    /// Records that all transactions of the block were processed.
+    pub fn record_block(&mut self, block: &ScannedBlock) {
+        self.push_recent(block);
+        self.last_block = Some(block.number);
+        self.last_tx_index = None;
+    }
+
+    fn push_recent(&mut self, block: &ScannedBlock) {
+        if self.recent_blocks.back().is_some_and(|(number, _)| *number == block.number) {
+            return;
+        }
+        self.recent_blocks.push_back((block.number, block.hash));
+        while self.recent_blocks.len() > Self::MAX_RECENT_BLOCKS {
+            self.recent_blocks.pop_front();
+        }
+    }
+}
+
+/// Storage of a [`ScanCheckpoint`], e.g. a file or a database table.
+pub trait CheckpointStore {
+    /// The error returned by the storage.
+    type Error;
+
+    /// Loads the stored checkpoint, if any.
+    fn load(&self) -> Result<Option<ScanCheckpoint>, Self::Error>;
+
+    /// Stores the checkpoint, replacing the previous one.
+    fn save(&mut self, checkpoint: &ScanCheckpoint) -> Result<(), Self::Error>;
+}
+
+/// A [`CheckpointStore`] keeping the checkpoint in memory.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoint: Option<ScanCheckpoint>,
+}
+
+impl MemoryCheckpointStore {
+    /// Creates a new, empty store.
+    pub const fn new() -> Self {
+        Self { checkpoint: None }
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    type Error = Infallible;
+
+    fn load(&self) -> Result<Option<ScanCheckpoint>, Self::Error> {
+        Ok(self.checkpoint.clone())
+    }
+
+    fn save(&mut self, checkpoint: &ScanCheckpoint) -> Result<(), Self::Error> {
+        self.checkpoint = Some(checkpoint.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(number: u64) -> ScannedBlock {
+        ScannedBlock {
+            number,
+            hash: B256::with_last_byte(number as u8),
+            parent_hash: B256::with_last_byte(number as u8 - 1),
+            txs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn record_checkpoint() {
+        let mut checkpoint = ScanCheckpoint::starting_at(10);
+        assert_eq!(checkpoint.next_block(), 10);
+        assert_eq!(ScanCheckpoint::starting_at(0).next_block(), 0);
+
+        checkpoint.record_tx(&block(10), 3);
+        assert_eq!(checkpoint.next_block(), 10);
+        checkpoint.record_block(&block(10));
+        assert_eq!(checkpoint.next_block(), 11);
+        assert_eq!(checkpoint.recent_blocks, [(10, B256::with_last_byte(10))]);
+
+        for number in 11..100 {
+            checkpoint.record_block(&block(number));
+        }
+        assert_eq!(checkpoint.recent_blocks.len(), ScanCheckpoint::MAX_RECENT_BLOCKS);
+        assert_eq!(checkpoint.recent_blocks.front().unwrap().0, 36);
+
+        let mut store = MemoryCheckpointStore::new();
+        assert_eq!(store.load().unwrap(), None);
+        store.save(&checkpoint).unwrap();
+        assert_eq!(store.load().unwrap(), Some(checkpoint.clone()));
+
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(serde_json::from_str::<ScanCheckpoint>(&json).unwrap(), checkpoint);
+    }
+}
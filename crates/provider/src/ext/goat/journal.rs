@@ -0,0 +1,334 @@
+//! Journals of the signed goat system transactions of the executors.
+
+use alloy_consensus::{transaction::goat::TxGoat, Signed, TxEnvelope};
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::{Address, Bytes};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+use tracing::warn;
+
+/// Storage of the signed goat system transactions sent by the executors until they are included,
+/// e.g. so that a relayer does not lose its in-flight transactions when it restarts.
+///
+/// The pending transactions of the journal are re-broadcast by
+/// [`GoatApi::resync_executor_nonce`](super::GoatApi::resync_executor_nonce).
+pub trait TxJournal {
+    /// The error returned by the storage.
+    type Error;
+
+    /// Appends a signed transaction, replacing any pending transaction of its executor with the
+    /// same nonce.
+    fn append(&mut self, tx: Signed<TxGoat>) -> Result<(), Self::Error>;
+
+    /// Marks the transactions of the executor with a nonce lower than the given one as included,
+    /// removing them from the pending transactions.
+    fn mark_included(&mut self, executor: Address, nonce: u64) -> Result<(), Self::Error>;
+
+    /// Returns the pending transactions of the executor, ordered by nonce.
+    fn pending(&self, executor: Address) -> Result<Vec<Signed<TxGoat>>, Self::Error>;
+}
+
+/// A [`TxJournal`] keeping the transactions in memory.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryTxJournal {
+    txs: BTreeMap<Address, BTreeMap<u64, Signed<TxGoat>>>,
+}
+
+impl MemoryTxJournal {
+    /// Creates a new, empty journal.
+    pub const fn new() -> Self {
+        Self { txs: BTreeMap::new() }
+    }
+
+    /// Returns the pending transactions of all executors, ordered by executor and nonce.
+    pub fn iter(&self) -> impl Iterator<Item = &Signed<TxGoat>> + '_ {
+        self.txs.values().flat_map(BTreeMap::values)
+    }
+
+    /// Returns the number of pending transactions of all executors.
+    pub fn len(&self) -> usize {
+        self.txs.values().map(BTreeMap::len).sum()
+    }
+
+    /// Returns true if no transaction is pending.
+    pub fn is_empty(&self) -> bool {
+        self.txs.values().all(BTreeMap::is_empty)
+    }
+
+    fn insert(&mut self, tx: Signed<TxGoat>) {
+        self.txs.entry(tx.tx().sender()).or_default().insert(tx.tx().nonce, tx);
+    }
+
+    fn remove_included(&mut self, executor: Address, nonce: u64) {
+        if let Some(txs) = self.txs.get_mut(&executor) {
+            *txs = txs.split_off(&nonce);
+        }
+    }
+}
+
+impl TxJournal for MemoryTxJournal {
+    type Error = Infallible;
+
+    fn append(&mut self, tx: Signed<TxGoat>) -> Result<(), Self::Error> {
+        self.insert(tx);
+        Ok(())
+    }
+
+    fn mark_included(&mut self, executor: Address, nonce: u64) -> Result<(), Self::Error> {
+        self.remove_included(executor, nonce);
+        Ok(())
+    }
+
+    fn pending(&self, executor: Address) -> Result<Vec<Signed<TxGoat>>, Self::Error> {
+        let txs = self.txs.get(&executor).into_iter().flat_map(BTreeMap::values);
+        Ok(txs.cloned().collect())
+    }
+}
+
+/// A record of a [`FileTxJournal`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum JournalRecord {
+    /// An appended transaction, EIP-2718 encoded.
+    Append(Bytes),
+    /// The transactions of the executor below the nonce were included.
+    Included { executor: Address, nonce: u64 },
+}
+
+/// A [`TxJournal`] backed by an append-only file of JSON lines.
+///
+/// Every change is written and synced to the file before it returns, and the file is replayed
+/// when the journal is opened. Records of included transactions accumulate until the file is
+/// rewritten with [`compact`](Self::compact).
+#[derive(Debug)]
+pub struct FileTxJournal {
+    path: PathBuf,
+    file: File,
+    txs: MemoryTxJournal,
+}
+
+impl FileTxJournal {
+    /// Opens the journal at the given path, creating the file if it does not exist.
+    ///
+    /// A final record without its newline was torn by an interrupted write, which never returned
+    /// successfully: it is truncated if it is invalid, and terminated otherwise.
+    ///
+    /// Returns an error if the file cannot be opened or contains any other invalid record.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).read(true).append(true).open(&path)?;
+
+        let mut txs = MemoryTxJournal::new();
+        let mut reader = BufReader::new(&file);
+        let (mut offset, mut line) = (0, Vec::new());
+        loop {
+            line.clear();
+            let len = reader.read_until(b'\n', &mut line)?;
+            if len == 0 {
+                break;
+            }
+            let terminated = line.ends_with(b"\n");
+            if let Err(err) = replay(&mut txs, &line) {
+                if terminated {
+                    return Err(err);
+                }
+                warn!(path = %path.display(), offset, %err, "truncating torn goat tx journal record");
+                file.set_len(offset)?;
+                break;
+            }
+            if !terminated {
+                (&file).write_all(b"\n")?;
+            }
+            offset += len as u64;
+        }
+        Ok(Self { path, file, txs })
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the pending transactions of the journal.
+    pub const fn txs(&self) -> &MemoryTxJournal {
+        &self.txs
+    }
+
+    /// Rewrites the file with the pending transactions only.
+    ///
+    /// The new file is written next to the current one and then renamed over it, so the journal is
+    /// never lost if the process is interrupted.
+    pub fn compact(&mut self) -> io::Result<()> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        for tx in self.txs.iter() {
+            write_record(&mut writer, &JournalRecord::Append(encode_goat(tx)))?;
+        }
+        writer.into_inner().map_err(io::IntoInnerError::into_error)?.sync_all()?;
+        std::fs::rename(&tmp, &self.path)?;
+
+        self.file = OpenOptions::new().read(true).append(true).open(&self.path)?;
+        Ok(())
+    }
+
+    fn write(&mut self, record: &JournalRecord) -> io::Result<()> {
+        write_record(&mut self.file, record)?;
+        self.file.sync_data()
+    }
+}
+
+impl TxJournal for FileTxJournal {
+    type Error = io::Error;
+
+    fn append(&mut self, tx: Signed<TxGoat>) -> Result<(), Self::Error> {
+        self.write(&JournalRecord::Append(encode_goat(&tx)))?;
+        self.txs.insert(tx);
+        Ok(())
+    }
+
+    fn mark_included(&mut self, executor: Address, nonce: u64) -> Result<(), Self::Error> {
+        self.write(&JournalRecord::Included { executor, nonce })?;
+        self.txs.remove_included(executor, nonce);
+        Ok(())
+    }
+
+    fn pending(&self, executor: Address) -> Result<Vec<Signed<TxGoat>>, Self::Error> {
+        Ok(self.txs.pending(executor).unwrap_or_else(|never| match never {}))
+    }
+}
+
+fn write_record(writer: &mut impl Write, record: &JournalRecord) -> io::Result<()> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    writer.write_all(&line)
+}
+
+fn encode_goat(tx: &Signed<TxGoat>) -> Bytes {
+    TxEnvelope::from(tx.clone()).encoded_2718().into()
+}
+
+/// Replays a line of the file into the transactions, ignoring blank lines.
+fn replay(txs: &mut MemoryTxJournal, line: &[u8]) -> io::Result<()> {
+    if line.trim_ascii().is_empty() {
+        return Ok(());
+    }
+    match serde_json::from_slice(line)? {
+        JournalRecord::Append(encoded) => txs.insert(decode_goat(&encoded)?),
+        JournalRecord::Included { executor, nonce } => txs.remove_included(executor, nonce),
+    }
+    Ok(())
+}
+
+fn decode_goat(encoded: &[u8]) -> io::Result<Signed<TxGoat>> {
+    match TxEnvelope::decode_2718_exact(encoded) {
+        Ok(TxEnvelope::Goat(tx)) => Ok(tx),
+        Ok(_) => Err(io::Error::new(io::ErrorKind::InvalidData, "not a goat transaction")),
+        Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{
+        transaction::goat::{NewBtcBlockTx, TxGoatInner, RELAYER_EXECUTOR},
+        SignableTransaction,
+    };
+    use alloy_primitives::{Signature, B256};
+
+    fn tx(nonce: u64) -> Signed<TxGoat> {
+        let inner = TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) });
+        TxGoat::new(48816, nonce, inner).into_signed(Signature::test_signature())
+    }
+
+    fn nonces(txs: Vec<Signed<TxGoat>>) -> Vec<u64> {
+        txs.iter().map(|tx| tx.tx().nonce).collect()
+    }
+
+    #[test]
+    fn memory_tx_journal() {
+        let mut journal = MemoryTxJournal::new();
+        for nonce in [5, 3, 4] {
+            journal.append(tx(nonce)).unwrap();
+        }
+        assert_eq!(nonces(journal.pending(RELAYER_EXECUTOR).unwrap()), [3, 4, 5]);
+
+        journal.mark_included(RELAYER_EXECUTOR, 5).unwrap();
+        assert_eq!(nonces(journal.pending(RELAYER_EXECUTOR).unwrap()), [5]);
+        journal.mark_included(RELAYER_EXECUTOR, 6).unwrap();
+        assert!(journal.is_empty());
+        assert!(journal.pending(Address::ZERO).unwrap().is_empty());
+    }
+
+    #[test]
+    fn file_tx_journal() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+
+        let mut journal = FileTxJournal::open(&path).unwrap();
+        for nonce in 3..6 {
+            journal.append(tx(nonce)).unwrap();
+        }
+        journal.mark_included(RELAYER_EXECUTOR, 4).unwrap();
+        drop(journal);
+
+        // the pending transactions survive a restart
+        let mut journal = FileTxJournal::open(&path).unwrap();
+        assert_eq!(journal.pending(RELAYER_EXECUTOR).unwrap(), [tx(4), tx(5)]);
+
+        journal.compact().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+        journal.append(tx(6)).unwrap();
+        let journal = FileTxJournal::open(&path).unwrap();
+        assert_eq!(nonces(journal.pending(RELAYER_EXECUTOR).unwrap()), [4, 5, 6]);
+
+        std::fs::write(&path, "{\"append\":\"0x02\"}\n").unwrap();
+        assert!(FileTxJournal::open(&path).is_err());
+    }
+
+    #[test]
+    fn file_tx_journal_torn_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+
+        let mut journal = FileTxJournal::open(&path).unwrap();
+        journal.append(tx(3)).unwrap();
+        drop(journal);
+        let valid = std::fs::read(&path).unwrap();
+
+        // the write of the last record was interrupted
+        let mut torn = valid.clone();
+        torn.extend_from_slice(&valid[..valid.len() / 2]);
+        std::fs::write(&path, &torn).unwrap();
+        let mut journal = FileTxJournal::open(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), valid);
+        assert_eq!(journal.pending(RELAYER_EXECUTOR).unwrap(), [tx(3)]);
+
+        journal.append(tx(4)).unwrap();
+        let journal = FileTxJournal::open(&path).unwrap();
+        assert_eq!(nonces(journal.pending(RELAYER_EXECUTOR).unwrap()), [3, 4]);
+
+        // a complete record missing only its newline is kept
+        std::fs::write(&path, &valid[..valid.len() - 1]).unwrap();
+        let mut journal = FileTxJournal::open(&path).unwrap();
+        journal.append(tx(4)).unwrap();
+        let journal = FileTxJournal::open(&path).unwrap();
+        assert_eq!(nonces(journal.pending(RELAYER_EXECUTOR).unwrap()), [3, 4]);
+
+        // corruption before the last record is still an error
+        let mut corrupt = valid[..valid.len() / 2].to_vec();
+        corrupt.push(b'\n');
+        corrupt.extend_from_slice(&valid);
+        std::fs::write(&path, &corrupt).unwrap();
+        assert!(FileTxJournal::open(&path).is_err());
+    }
+}
@@ -0,0 +1,838 @@
+//! This module extends the Ethereum JSON-RPC provider with reads of the GOAT predeploys.
+use crate::{PendingTransactionError, Provider};
+use alloy_consensus::{
+    transaction::goat::{decode_call, Mint, Module, TxGoat, TxGoatInner, GOAT_TX_TYPE_ID},
+    BlockHeader, Transaction, TxEnvelope,
+};
+use alloy_eips::{eip2718::Encodable2718, BlockId};
+use alloy_network::{
+    BlockResponse, Network, ReceiptResponse, TransactionBuilder, TransactionBuilderGoat,
+    TransactionResponse,
+};
+use alloy_primitives::{Address, Bytes, TxHash, B256};
+use alloy_sol_types::{SolCall, SolInterface, SolType};
+use alloy_transport::{TransportErrorKind, TransportResult};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[cfg(target_family = "wasm")]
+use wasmtimer::std::Instant;
+
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+
+mod checkpoint;
+pub use checkpoint::{CheckpointStore, MemoryCheckpointStore, ScanCheckpoint};
+
+mod revert;
+pub use revert::{decode_goat_revert, GoatRevert};
+
+mod scanner;
+pub use scanner::{GoatScanner, ScannedBlock};
+
+mod journal;
+pub use journal::{FileTxJournal, MemoryTxJournal, TxJournal};
+
+mod nonce;
+pub use nonce::NonceResync;
+
+mod quorum;
+pub use quorum::{GoatQuorum, QuorumError};
+
+mod watcher;
+pub use watcher::{DepositEvent, DepositWatcher};
+
+/// An opt-in cache for [`GoatApi::goat_call_cached`].
+///
+/// The predeploy values change rarely, but relayers query them for every deposit. A cached read
+/// is reused until it is older than the configured time-to-live or, if
+/// [`with_max_blocks`](Self::with_max_blocks) is set, until the chain has advanced by that many
+/// blocks since it was made.
+///
+/// Reads are keyed by the called predeploy and the call data. The cache is cheap to clone, and
+/// clones share the cached reads.
+#[derive(Clone, Debug)]
+pub struct GoatReadCache {
+    ttl: Duration,
+    max_blocks: Option<u64>,
+    entries: Arc<Mutex<HashMap<(Address, Bytes), CachedRead>>>,
+}
+
+/// A read cached by a [`GoatReadCache`].
+#[derive(Clone, Debug)]
+struct CachedRead {
+    fetched_at: Instant,
+    block_number: u64,
+    output: Bytes,
+}
+
+impl GoatReadCache {
+    /// Creates a new cache whose reads expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, max_blocks: None, entries: Default::default() }
+    }
+
+    /// Also expire reads once the chain has advanced by `max_blocks` blocks since they were made.
+    ///
+    /// This requires an `eth_blockNumber` request for each cached read.
+    pub const fn with_max_blocks(mut self, max_blocks: u64) -> Self {
+        self.max_blocks = Some(max_blocks);
+        self
+    }
+
+    /// Returns the time-to-live of cached reads.
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the number of blocks after which cached reads expire, if set.
+    pub const fn max_blocks(&self) -> Option<u64> {
+        self.max_blocks
+    }
+
+    /// Returns the raw output of the cached call of `to` with `input`, if it has not expired at
+    /// the given block number.
+    ///
+    /// The block number is only taken into account if [`max_blocks`](Self::max_blocks) is set.
+    pub fn get(&self, to: Address, input: &Bytes, block_number: Option<u64>) -> Option<Bytes> {
+        let entries = self.entries.lock().unwrap();
+        let read = entries.get(&(to, input.clone()))?;
+        if read.fetched_at.elapsed() >= self.ttl {
+            return None;
+        }
+        if let (Some(max_blocks), Some(block_number)) = (self.max_blocks, block_number) {
+            // a lower block number means the chain was reorged or the node was switched
+            if block_number < read.block_number || block_number - read.block_number >= max_blocks {
+                return None;
+            }
+        }
+        Some(read.output.clone())
+    }
+
+    /// Caches the raw output of the call of `to` with `input`, made at the given block number.
+    pub fn insert(&self, to: Address, input: Bytes, block_number: u64, output: Bytes) {
+        let read = CachedRead { fetched_at: Instant::now(), block_number, output };
+        self.entries.lock().unwrap().insert((to, input), read);
+    }
+
+    /// Drops all cached reads, e.g. after observing a relayer set change.
+    pub fn invalidate(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// The result of a goat system transaction sent with [`GoatApi::send_goat_tx`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GoatTxOutcome<R> {
+    /// The hash of the transaction.
+    pub tx_hash: TxHash,
+    /// The receipt of the transaction.
+    pub receipt: R,
+    /// The native tokens minted by the transaction, if it is a successful deposit.
+    pub mint: Option<Mint>,
+}
+
+impl<R: ReceiptResponse> GoatTxOutcome<R> {
+    /// Creates the outcome of the given action from its receipt.
+    ///
+    /// The mint reported by the receipt is preferred, falling back to the one of the action if the
+    /// transaction succeeded.
+    pub fn from_receipt(receipt: R, inner: &TxGoatInner) -> Self {
+        let mint = receipt.mint().or_else(|| inner.deposit().filter(|_| receipt.status()));
+        Self { tx_hash: receipt.transaction_hash(), receipt, mint }
+    }
+
+    /// Returns true if the transaction succeeded.
+    pub fn status(&self) -> bool {
+        self.receipt.status()
+    }
+}
+
+/// A goat system transaction re-executed with `eth_call`, see [`GoatApi::replay`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GoatReplay<T, E> {
+    /// The replayed transaction.
+    pub tx: TxGoat,
+    /// The block the call was executed at.
+    pub block: BlockId,
+    /// The outcome of the call.
+    pub outcome: GoatReplayOutcome<T, E>,
+}
+
+/// The outcome of a [`GoatReplay`], with the output `T` and the custom errors `E` of the called
+/// predeploy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GoatReplayOutcome<T, E> {
+    /// The call succeeded with the given decoded output.
+    Success(T),
+    /// The call reverted.
+    Revert {
+        /// The revert data, if returned by the node.
+        data: Option<Bytes>,
+        /// The decoded revert, if the data is a custom error `E` or a generic Solidity revert.
+        revert: Option<GoatRevert<E>>,
+        /// The error message of the node.
+        message: String,
+    },
+}
+
+impl<T, E> GoatReplayOutcome<T, E> {
+    /// Returns true if the call succeeded.
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Success(_))
+    }
+}
+
+/// The JSON-RPC error code of a reverted call.
+const EXECUTION_REVERTED_CODE: i64 = 3;
+
+/// Goat predeploy reads.
+#[cfg_attr(target_family = "wasm", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait::async_trait)]
+pub trait GoatApi<N>: Send + Sync {
+    /// Calls a view function of a goat predeploy at the given block, decoding its return value.
+    ///
+    /// The interfaces of the predeploys are not part of this crate, the call is usually generated
+    /// with [`sol!`](alloy_sol_types::sol) from the ABI of the deployed contract.
+    async fn goat_call<C>(
+        &self,
+        to: Address,
+        call: C,
+        block_number: u64,
+    ) -> TransportResult<C::Return>
+    where
+        C: SolCall + Send + Sync + 'static,
+        C::Return: Send + 'static;
+
+    /// Calls a view function of a goat predeploy at the latest block, reusing the cached output
+    /// unless it has expired.
+    async fn goat_call_cached<C>(
+        &self,
+        to: Address,
+        call: C,
+        cache: &GoatReadCache,
+    ) -> TransportResult<C::Return>
+    where
+        C: SolCall + Send + Sync + 'static,
+        C::Return: Send + 'static;
+
+    /// Returns the hash of the bitcoin block most recently submitted with a
+    /// [`NewBtcBlockTx`](alloy_consensus::transaction::goat::NewBtcBlockTx) at or before the given
+    /// block.
+    ///
+    /// The given block and up to `window - 1` of its ancestors are scanned backwards, following
+    /// the parent hashes so that the scan stays on the chain of the given block even if the chain
+    /// is reorged meanwhile. Returns `None` if none of them submitted a bitcoin block.
+    async fn get_btc_anchor(&self, block: BlockId, window: u64) -> TransportResult<Option<B256>>;
+
+    /// Sends a goat system transaction and waits for its receipt.
+    ///
+    /// The request is sent from the executor of the action, so the wallet of the provider must
+    /// have a signer for it, e.g. a [`GoatWallet`](alloy_network::GoatWallet) with a signer
+    /// registered for the module of the action. The chain id and nonce are left to the fillers of
+    /// the provider, while the gas limit and price are zeroed, since system transactions do not
+    /// pay for gas.
+    async fn send_goat_tx(
+        &self,
+        inner: TxGoatInner,
+    ) -> Result<GoatTxOutcome<N::ReceiptResponse>, PendingTransactionError>
+    where
+        N: Network,
+        N::TransactionRequest: TransactionBuilderGoat;
+
+    /// Compares the next nonce of the executor of the module in the local journal with its
+    /// transaction counts in the node, at the latest block and including pending transactions.
+    ///
+    /// The transactions of the journal that were mined are marked as included. If `rebroadcast`
+    /// is true, the transactions of the journal that are unknown to the node, see
+    /// [`NonceResync::gap`], are sent again in nonce order, stopping at the first one missing from
+    /// the journal.
+    async fn resync_executor_nonce<J>(
+        &self,
+        module: Module,
+        journal: &mut J,
+        rebroadcast: bool,
+    ) -> TransportResult<NonceResync>
+    where
+        J: TxJournal + Send,
+        J::Error: std::error::Error + Send + Sync + 'static;
+
+    /// Re-executes a goat system transaction with `eth_call`, e.g. for post-mortems of failed
+    /// system transactions.
+    ///
+    /// The action is called from its executor at the parent of the block including the
+    /// transaction, or at the latest block if the transaction is pending. The output is decoded as
+    /// `R`, and reverts as the custom errors `E` or a generic Solidity revert. The return types
+    /// and errors of the predeploys are not part of this crate, they are usually generated with
+    /// [`sol!`](alloy_sol_types::sol) from the ABI of the deployed contract.
+    ///
+    /// Returns `None` if the transaction is not found, and an error if it is not a goat system
+    /// transaction or the output cannot be decoded.
+    async fn replay<R, E>(
+        &self,
+        tx_hash: TxHash,
+    ) -> TransportResult<Option<GoatReplay<R::RustType, E>>>
+    where
+        R: SolType,
+        R::RustType: Send,
+        E: SolInterface + Send;
+}
+
+#[cfg_attr(target_family = "wasm", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait::async_trait)]
+impl<N, P> GoatApi<N> for P
+where
+    N: Network,
+    P: Provider<N>,
+{
+    async fn goat_call<C>(
+        &self,
+        to: Address,
+        call: C,
+        block_number: u64,
+    ) -> TransportResult<C::Return>
+    where
+        C: SolCall + Send + Sync + 'static,
+        C::Return: Send + 'static,
+    {
+        let tx = N::TransactionRequest::default().with_to(to).with_input(call.abi_encode());
+        self.call(tx)
+            .number(block_number)
+            .decode_resp::<C>()
+            .await?
+            .map_err(TransportErrorKind::custom)
+    }
+
+    async fn goat_call_cached<C>(
+        &self,
+        to: Address,
+        call: C,
+        cache: &GoatReadCache,
+    ) -> TransportResult<C::Return>
+    where
+        C: SolCall + Send + Sync + 'static,
+        C::Return: Send + 'static,
+    {
+        let input = Bytes::from(call.abi_encode());
+        let latest =
+            if cache.max_blocks().is_some() { Some(self.get_block_number().await?) } else { None };
+        let output = match cache.get(to, &input, latest) {
+            Some(output) => output,
+            None => {
+                let block_number = match latest {
+                    Some(latest) => latest,
+                    None => self.get_block_number().await?,
+                };
+                let tx = N::TransactionRequest::default().with_to(to).with_input(input.clone());
+                let output = self.call(tx).number(block_number).await?;
+                cache.insert(to, input, block_number, output.clone());
+                output
+            }
+        };
+        C::abi_decode_returns(&output).map_err(TransportErrorKind::custom)
+    }
+
+    async fn get_btc_anchor(&self, block: BlockId, window: u64) -> TransportResult<Option<B256>> {
+        let mut next = Some(block);
+        for _ in 0..window {
+            let Some(id) = next.take() else { break };
+            let Some(block) = self.get_block(id).full().await? else { break };
+            let txs = block.transactions().as_transactions().unwrap_or_default();
+            if let Some(hash) = txs.iter().rev().find_map(new_btc_block_hash) {
+                return Ok(Some(hash));
+            }
+            let header = block.header();
+            next = (header.number() > 0).then(|| BlockId::hash(header.parent_hash()));
+        }
+        Ok(None)
+    }
+
+    async fn send_goat_tx(
+        &self,
+        inner: TxGoatInner,
+    ) -> Result<GoatTxOutcome<N::ReceiptResponse>, PendingTransactionError>
+    where
+        N::TransactionRequest: TransactionBuilderGoat,
+    {
+        let receipt = self.send_transaction(goat_request::<N>(&inner)).await?.get_receipt().await?;
+        Ok(GoatTxOutcome::from_receipt(receipt, &inner))
+    }
+
+    async fn resync_executor_nonce<J>(
+        &self,
+        module: Module,
+        journal: &mut J,
+        rebroadcast: bool,
+    ) -> TransportResult<NonceResync>
+    where
+        J: TxJournal + Send,
+        J::Error: std::error::Error + Send + Sync + 'static,
+    {
+        let executor = module.executor();
+        let latest = self.get_transaction_count(executor).latest().await?;
+        let pending = self.get_transaction_count(executor).pending().await?;
+        journal.mark_included(executor, latest).map_err(TransportErrorKind::custom)?;
+        let txs = journal.pending(executor).map_err(TransportErrorKind::custom)?;
+
+        let local = txs.last().map(|tx| tx.tx().nonce + 1);
+        let mut resync = NonceResync {
+            executor,
+            local,
+            latest,
+            pending,
+            rebroadcast: Vec::new(),
+            next_nonce: local.unwrap_or_default().max(pending),
+        };
+        if !resync.has_gap() {
+            return Ok(resync);
+        }
+
+        resync.next_nonce = pending;
+        if rebroadcast {
+            for nonce in resync.gap() {
+                let Some(tx) = txs.iter().find(|tx| tx.tx().nonce == nonce) else { break };
+                let encoded = TxEnvelope::from(tx.clone()).encoded_2718();
+                let hash = *self.send_raw_transaction(&encoded).await?.tx_hash();
+                resync.rebroadcast.push((nonce, hash));
+                resync.next_nonce = nonce + 1;
+            }
+        }
+        Ok(resync)
+    }
+
+    async fn replay<R, E>(
+        &self,
+        tx_hash: TxHash,
+    ) -> TransportResult<Option<GoatReplay<R::RustType, E>>>
+    where
+        R: SolType,
+        R::RustType: Send,
+        E: SolInterface + Send,
+    {
+        let Some(response) = self.get_transaction_by_hash(tx_hash).await? else {
+            return Ok(None);
+        };
+        let tx = goat_tx(&response)
+            .ok_or_else(|| TransportErrorKind::custom_str("not a goat system transaction"))?;
+        let block = match response.block_hash() {
+            Some(hash) => {
+                let block = self.get_block_by_hash(hash).await?.ok_or_else(|| {
+                    TransportErrorKind::custom_str("block of the goat transaction not found")
+                })?;
+                BlockId::hash(block.header().parent_hash())
+            }
+            None => BlockId::latest(),
+        };
+
+        let request = N::TransactionRequest::default()
+            .with_from(tx.sender())
+            .with_to(tx.inner.contract())
+            .with_input(tx.input.clone());
+        let outcome = match self.call(request).block(block).await {
+            Ok(output) => GoatReplayOutcome::Success(
+                R::abi_decode(&output).map_err(TransportErrorKind::custom)?,
+            ),
+            Err(err) => {
+                let Some(payload) = err.as_error_resp() else { return Err(err) };
+                let data = payload.as_revert_data();
+                if data.is_none() && payload.code != EXECUTION_REVERTED_CODE {
+                    return Err(err);
+                }
+                // nodes reporting the revert by its code may not mention it in the message
+                let data = data.or_else(|| payload.try_data_as::<Bytes>().and_then(Result::ok));
+                GoatReplayOutcome::Revert {
+                    revert: data.as_deref().and_then(|data| decode_goat_revert(data)),
+                    data,
+                    message: payload.message.to_string(),
+                }
+            }
+        };
+        Ok(Some(GoatReplay { tx, block, outcome }))
+    }
+}
+
+/// Returns the request of a goat system transaction, without its chain id and nonce.
+fn goat_request<N>(inner: &TxGoatInner) -> N::TransactionRequest
+where
+    N: Network,
+    N::TransactionRequest: TransactionBuilderGoat,
+{
+    N::TransactionRequest::default()
+        .with_from(inner.sender())
+        .with_to(inner.contract())
+        .with_input(inner.input())
+        .with_gas_limit(0)
+        .with_gas_price(0)
+        .with_module(inner.module())
+        .with_action(inner.action())
+}
+
+/// Returns the decoded action of the transaction, if it is a well-formed goat system transaction.
+fn goat_action<T: Transaction>(tx: &T) -> Option<TxGoatInner> {
+    if tx.ty() != GOAT_TX_TYPE_ID {
+        return None;
+    }
+    decode_call(tx.to()?, tx.input())?.ok()
+}
+
+/// Returns the transaction as a [`TxGoat`], if it is a well-formed goat system transaction.
+fn goat_tx<T: Transaction>(tx: &T) -> Option<TxGoat> {
+    let inner = goat_action(tx)?;
+    Some(TxGoat {
+        chain_id: tx.chain_id()?,
+        module: inner.module(),
+        action: inner.action(),
+        nonce: tx.nonce(),
+        input: tx.input().clone(),
+        inner,
+    })
+}
+
+/// Returns the bitcoin block hash submitted by the transaction, if it is a goat
+/// [`NewBtcBlockTx`](alloy_consensus::transaction::goat::NewBtcBlockTx).
+fn new_btc_block_hash<T: Transaction>(tx: &T) -> Option<B256> {
+    match goat_action(tx)? {
+        TxGoatInner::NewBtcBlock(new_block) => Some(new_block.hash),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_consensus::{
+        transaction::{
+            goat::{NewBtcBlockTx, TxGoat, BRIDGE_CONTRACT, LOCKING_CONTRACT},
+            Recovered,
+        },
+        SignableTransaction, TxEnvelope,
+    };
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_primitives::{Bytes, Signature, U256, U64};
+    use alloy_rpc_types_eth::{Block, BlockTransactions, Header};
+    use alloy_sol_types::{sol, SolValue};
+    use alloy_transport::mock::Asserter;
+
+    sol! {
+        interface IExample {
+            function value(uint256 key) external view returns (uint256);
+        }
+    }
+
+    #[tokio::test]
+    async fn goat_call() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+        asserter.push_success(&Bytes::from(U256::from(25).abi_encode()));
+        let call = IExample::valueCall { key: U256::from(1) };
+        let value = provider.goat_call(BRIDGE_CONTRACT, call, 7).await.unwrap();
+        assert_eq!(value, U256::from(25));
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cached_goat_call_ttl() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let cache = GoatReadCache::new(Duration::from_secs(60));
+        let call = |key: u64| IExample::valueCall { key: U256::from(key) };
+
+        asserter.push_success(&U64::from(10));
+        asserter.push_success(&Bytes::from(U256::from(1).abi_encode()));
+        let first = provider.goat_call_cached(BRIDGE_CONTRACT, call(1), &cache).await.unwrap();
+        assert_eq!(first, U256::from(1));
+
+        // served from the cache, without any request
+        assert_eq!(
+            provider.goat_call_cached(BRIDGE_CONTRACT, call(1), &cache).await.unwrap(),
+            first
+        );
+        assert!(asserter.read_q().is_empty());
+
+        // other call data and predeploys are cached separately
+        asserter.push_success(&U64::from(10));
+        asserter.push_success(&Bytes::from(U256::from(2).abi_encode()));
+        assert_eq!(
+            provider.goat_call_cached(BRIDGE_CONTRACT, call(2), &cache).await.unwrap(),
+            U256::from(2)
+        );
+        asserter.push_success(&U64::from(10));
+        asserter.push_success(&Bytes::from(U256::from(3).abi_encode()));
+        assert_eq!(
+            provider.goat_call_cached(LOCKING_CONTRACT, call(1), &cache).await.unwrap(),
+            U256::from(3)
+        );
+
+        cache.invalidate();
+        asserter.push_success(&U64::from(11));
+        asserter.push_success(&Bytes::from(U256::from(4).abi_encode()));
+        let second = provider.goat_call_cached(BRIDGE_CONTRACT, call(1), &cache).await.unwrap();
+        assert_eq!(second, U256::from(4));
+        assert!(asserter.read_q().is_empty());
+
+        let expired = GoatReadCache::new(Duration::ZERO);
+        let input = Bytes::from(call(1).abi_encode());
+        expired.insert(BRIDGE_CONTRACT, input.clone(), 11, Bytes::new());
+        assert_eq!(expired.get(BRIDGE_CONTRACT, &input, None), None);
+    }
+
+    #[tokio::test]
+    async fn cached_goat_call_height() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let cache = GoatReadCache::new(Duration::from_secs(60)).with_max_blocks(2);
+        let call = IExample::valueCall { key: U256::from(1) };
+
+        asserter.push_success(&U64::from(10));
+        asserter.push_success(&Bytes::from(U256::from(1).abi_encode()));
+        let first = provider.goat_call_cached(BRIDGE_CONTRACT, call.clone(), &cache).await.unwrap();
+
+        asserter.push_success(&U64::from(11));
+        assert_eq!(
+            provider.goat_call_cached(BRIDGE_CONTRACT, call.clone(), &cache).await.unwrap(),
+            first
+        );
+
+        asserter.push_success(&U64::from(12));
+        asserter.push_success(&Bytes::from(U256::from(2).abi_encode()));
+        let refreshed =
+            provider.goat_call_cached(BRIDGE_CONTRACT, call.clone(), &cache).await.unwrap();
+        assert_eq!(refreshed, U256::from(2));
+
+        // a lower block number also expires the read
+        let input = Bytes::from(call.abi_encode());
+        assert_eq!(cache.get(BRIDGE_CONTRACT, &input, Some(9)), None);
+        assert!(asserter.read_q().is_empty());
+    }
+
+    fn new_btc_block_tx(number: u64, hash: B256) -> alloy_rpc_types_eth::Transaction {
+        let tx = TxGoat::new(48816, number, TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash }));
+        let sender = tx.sender();
+        let envelope = TxEnvelope::from(tx.into_signed(Signature::test_signature()));
+        alloy_rpc_types_eth::Transaction {
+            inner: Recovered::new_unchecked(envelope, sender),
+            block_hash: None,
+            block_number: Some(number),
+            transaction_index: Some(0),
+            effective_gas_price: Some(0),
+        }
+    }
+
+    fn block(number: u64, btc_block: Option<B256>) -> Block {
+        let txs = btc_block.map(|hash| new_btc_block_tx(number, hash)).into_iter().collect();
+        let mut header: Header = Header::default();
+        header.inner.number = number;
+        header.inner.parent_hash = B256::with_last_byte(number.saturating_sub(1) as u8);
+        Block::new(header, BlockTransactions::Full(txs))
+    }
+
+    #[tokio::test]
+    async fn btc_anchor_scan() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+        asserter.push_success(&block(12, None));
+        asserter.push_success(&block(11, None));
+        asserter.push_success(&block(10, Some(B256::with_last_byte(2))));
+        let anchor = provider.get_btc_anchor(BlockId::number(12), 5).await.unwrap();
+        assert_eq!(anchor, Some(B256::with_last_byte(2)));
+        assert!(asserter.read_q().is_empty());
+
+        // the window is exhausted before reaching a submission
+        asserter.push_success(&block(12, None));
+        asserter.push_success(&block(11, None));
+        assert_eq!(provider.get_btc_anchor(BlockId::number(12), 2).await.unwrap(), None);
+        assert!(asserter.read_q().is_empty());
+
+        // the scan stops at genesis
+        asserter.push_success(&block(1, None));
+        asserter.push_success(&block(0, None));
+        assert_eq!(provider.get_btc_anchor(BlockId::number(1), 5).await.unwrap(), None);
+        assert!(asserter.read_q().is_empty());
+
+        // errors are not swallowed
+        asserter.push_failure_msg("boom");
+        assert!(provider.get_btc_anchor(BlockId::number(12), 2).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn replay() {
+        use alloy_sol_types::{sol, sol_data, Revert, SolError};
+
+        sol! {
+            #[derive(Debug, PartialEq, Eq)]
+            interface IBitcoin {
+                error StaleBlock(uint256 height);
+            }
+        }
+        use IBitcoin::IBitcoinErrors;
+
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let mut tx = new_btc_block_tx(10, B256::with_last_byte(1));
+        tx.block_hash = Some(B256::with_last_byte(10));
+
+        // the call is executed at the parent of the block including the transaction
+        asserter.push_success(&tx);
+        asserter.push_success(&block(10, None));
+        asserter.push_success(&Bytes::from(U256::from(7).to_be_bytes::<32>().to_vec()));
+        let replay = provider
+            .replay::<sol_data::Uint<256>, IBitcoinErrors>(B256::ZERO)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            replay.tx.inner,
+            TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) })
+        );
+        assert_eq!(replay.block, BlockId::hash(B256::with_last_byte(9)));
+        assert_eq!(replay.outcome, GoatReplayOutcome::Success(U256::from(7)));
+
+        // reverts are classified by their error code and decoded from their data
+        let err = IBitcoin::StaleBlock { height: U256::from(3) };
+        let data = Bytes::from(err.abi_encode());
+        asserter.push_success(&tx);
+        asserter.push_success(&block(10, None));
+        asserter.push_failure(ErrorPayload {
+            code: 3,
+            message: "execution failed".into(),
+            data: Some(serde_json::value::to_raw_value(&data).unwrap()),
+        });
+        let replay = provider.replay::<(), IBitcoinErrors>(B256::ZERO).await.unwrap().unwrap();
+        assert_eq!(
+            replay.outcome,
+            GoatReplayOutcome::Revert {
+                data: Some(data),
+                revert: Some(GoatRevert::Custom(IBitcoinErrors::StaleBlock(err))),
+                message: "execution failed".into(),
+            }
+        );
+
+        // pending transactions are replayed at the latest block
+        let data = Bytes::from(Revert::from("stale block").abi_encode());
+        tx.block_hash = None;
+        asserter.push_success(&tx);
+        asserter.push_failure(ErrorPayload {
+            code: 3,
+            message: "execution reverted: stale block".into(),
+            data: Some(serde_json::value::to_raw_value(&data).unwrap()),
+        });
+        let replay = provider.replay::<(), IBitcoinErrors>(B256::ZERO).await.unwrap().unwrap();
+        assert_eq!(replay.block, BlockId::latest());
+        let GoatReplayOutcome::Revert { revert: Some(revert), .. } = replay.outcome else {
+            panic!("expected a revert: {:?}", replay.outcome)
+        };
+        assert_eq!(revert.to_string(), "revert: stale block");
+
+        asserter.push_success(&None::<alloy_rpc_types_eth::Transaction>);
+        assert_eq!(provider.replay::<(), IBitcoinErrors>(B256::ZERO).await.unwrap(), None);
+
+        // other errors, even mentioning a revert, are returned
+        asserter.push_success(&tx);
+        asserter.push_failure_msg("revert handler unavailable");
+        assert!(provider.replay::<(), IBitcoinErrors>(B256::ZERO).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn resync_executor_nonce() {
+        use alloy_consensus::transaction::goat::{RELAYER_EXECUTOR, RELAYER_MODULE};
+
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let mut journal = MemoryTxJournal::new();
+        for nonce in 4..8 {
+            let tx = TxGoat::new(48816, nonce, TxGoatInner::NewBtcBlock(NewBtcBlockTx::default()));
+            journal.append(tx.into_signed(Signature::test_signature())).unwrap();
+        }
+
+        // nonce 6 is pending in the node, 7 was dropped by it
+        asserter.push_success(&U64::from(6));
+        asserter.push_success(&U64::from(7));
+        let resync =
+            provider.resync_executor_nonce(RELAYER_MODULE, &mut journal, false).await.unwrap();
+        assert_eq!(resync.executor, RELAYER_EXECUTOR);
+        assert_eq!((resync.local, resync.stuck(), resync.gap()), (Some(8), 1, 7..8));
+        assert_eq!(resync.next_nonce, 7);
+        assert!(resync.rebroadcast.is_empty());
+        assert_eq!(journal.len(), 2);
+
+        asserter.push_success(&U64::from(6));
+        asserter.push_success(&U64::from(7));
+        asserter.push_success(&B256::with_last_byte(7));
+        let resync =
+            provider.resync_executor_nonce(RELAYER_MODULE, &mut journal, true).await.unwrap();
+        assert_eq!(resync.rebroadcast, [(7, B256::with_last_byte(7))]);
+        assert_eq!(resync.next_nonce, 8);
+        assert!(asserter.read_q().is_empty());
+
+        asserter.push_success(&U64::from(8));
+        asserter.push_success(&U64::from(8));
+        let resync =
+            provider.resync_executor_nonce(RELAYER_MODULE, &mut journal, true).await.unwrap();
+        assert!(resync.is_synced());
+        assert_eq!(resync.next_nonce, 8);
+    }
+
+    #[test]
+    fn send_goat_tx_request() {
+        use alloy_consensus::transaction::goat::DepositTx;
+
+        let inner = TxGoatInner::Deposit(DepositTx {
+            amount: U256::from(90),
+            tax: U256::from(10),
+            ..Default::default()
+        });
+        let mut request = goat_request::<alloy_network::Goat>(&inner);
+        assert_eq!((request.gas, request.gas_price), (Some(0), Some(0)));
+        assert_eq!(request.from, Some(inner.sender()));
+
+        request.chain_id = Some(48816);
+        request.nonce = Some(3);
+        assert_eq!(request.build_goat().unwrap(), TxGoat::new(48816, 3, inner));
+    }
+
+    #[test]
+    fn goat_tx_outcome() {
+        use alloy_consensus::{
+            transaction::goat::DepositTx, Eip658Value, Receipt, ReceiptEnvelope,
+        };
+        use alloy_rpc_types_eth::TransactionReceipt;
+
+        let deposit =
+            DepositTx { amount: U256::from(90), tax: U256::from(10), ..Default::default() };
+        let receipt = |status: bool| TransactionReceipt {
+            inner: ReceiptEnvelope::Legacy(
+                Receipt { status: Eip658Value::Eip658(status), ..Default::default() }.into(),
+            ),
+            transaction_hash: B256::with_last_byte(1),
+            transaction_index: None,
+            block_hash: None,
+            block_number: None,
+            gas_used: 0,
+            effective_gas_price: 0,
+            blob_gas_used: None,
+            blob_gas_price: None,
+            from: Address::ZERO,
+            to: Some(BRIDGE_CONTRACT),
+            contract_address: None,
+        };
+
+        let outcome = GoatTxOutcome::from_receipt(receipt(true), &TxGoatInner::Deposit(deposit));
+        assert_eq!(outcome.tx_hash, B256::with_last_byte(1));
+        assert_eq!(outcome.mint, TxGoatInner::Deposit(deposit).deposit());
+        assert!(outcome.mint.is_some());
+
+        // reverted deposits do not mint
+        let outcome = GoatTxOutcome::from_receipt(receipt(false), &TxGoatInner::Deposit(deposit));
+        assert!(!outcome.status());
+        assert_eq!(outcome.mint, None);
+
+        let new_btc_block = TxGoatInner::NewBtcBlock(NewBtcBlockTx::default());
+        assert_eq!(GoatTxOutcome::from_receipt(receipt(true), &new_btc_block).mint, None);
+    }
+}
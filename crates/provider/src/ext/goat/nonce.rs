@@ -0,0 +1,49 @@
+//! Nonce recovery of the goat system executors.
+
+use alloy_primitives::{Address, TxHash};
+use std::ops::Range;
+
+/// The nonce state of a goat system executor, as returned by
+/// [`GoatApi::resync_executor_nonce`](super::GoatApi::resync_executor_nonce).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceResync {
+    /// The executor.
+    pub executor: Address,
+    /// The next nonce of the executor according to the local journal, if it has pending
+    /// transactions of the executor.
+    pub local: Option<u64>,
+    /// The transaction count of the executor at the latest block.
+    pub latest: u64,
+    /// The transaction count of the executor including the transactions pending in the node.
+    pub pending: u64,
+    /// The nonces and hashes of the re-broadcast transactions.
+    pub rebroadcast: Vec<(u64, TxHash)>,
+    /// The nonce to use for the next transaction of the executor.
+    ///
+    /// This is the local nonce if all the missing transactions were re-broadcast, otherwise the
+    /// first nonce that is still missing, so that the gap is filled by the next transaction.
+    pub next_nonce: u64,
+}
+
+impl NonceResync {
+    /// Returns the nonces of the transactions sent locally but unknown to the node, e.g. because
+    /// the node dropped them from its pool.
+    pub fn gap(&self) -> Range<u64> {
+        self.pending..self.local.unwrap_or_default().max(self.pending)
+    }
+
+    /// Returns true if some transactions sent locally are unknown to the node.
+    pub fn has_gap(&self) -> bool {
+        !self.gap().is_empty()
+    }
+
+    /// Returns the number of transactions that are pending in the node but not mined yet.
+    pub const fn stuck(&self) -> u64 {
+        self.pending.saturating_sub(self.latest)
+    }
+
+    /// Returns true if the nonce state of the node matches the local one and nothing is pending.
+    pub fn is_synced(&self) -> bool {
+        !self.has_gap() && self.stuck() == 0
+    }
+}
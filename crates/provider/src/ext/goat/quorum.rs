@@ -0,0 +1,152 @@
+//! Cross-checking of goat reads against multiple providers.
+
+use super::goat_action;
+use crate::Provider;
+use alloy_consensus::transaction::goat::{DepositTx, TxGoatInner};
+use alloy_network::{BlockResponse, Network};
+use alloy_transport::{TransportError, TransportErrorKind, TransportResult};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Error returned by the reads of a [`GoatQuorum`].
+#[derive(Debug, thiserror::Error)]
+pub enum QuorumError<T> {
+    /// A provider failed to serve the read.
+    #[error("provider {index} failed: {source}")]
+    Transport {
+        /// The index of the provider.
+        index: usize,
+        /// The error of the provider.
+        source: TransportError,
+    },
+    /// The providers returned different results.
+    #[error("goat read diverged between {} providers", .0.len())]
+    Divergence(Vec<T>),
+}
+
+/// Issues the same goat read to multiple providers and compares the decoded results.
+///
+/// Bridge watchers act on mints and deposit statuses, so a single faulty or compromised endpoint
+/// could make them act on a deposit that never happened. A read only succeeds if all providers
+/// return the same result, and a [`QuorumError::Divergence`] holding the result of each provider
+/// is returned otherwise.
+///
+/// Reads should be made at a block number that is final on all providers, a provider that has not
+/// seen the block yet or followed a different fork also diverges.
+#[derive(Clone, Debug)]
+pub struct GoatQuorum<P> {
+    providers: Vec<P>,
+}
+
+impl<P> GoatQuorum<P> {
+    /// Creates a quorum of the given providers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `providers` is empty.
+    pub fn new(providers: Vec<P>) -> Self {
+        assert!(!providers.is_empty(), "a goat quorum requires at least one provider");
+        Self { providers }
+    }
+
+    /// Returns the providers of the quorum.
+    pub fn providers(&self) -> &[P] {
+        &self.providers
+    }
+
+    /// Issues the read to all providers concurrently, returning the result if all of them agree.
+    ///
+    /// Any read of the providers can be cross-checked, e.g. a view call of a predeploy made with
+    /// [`GoatApi::goat_call`](super::GoatApi::goat_call).
+    pub async fn read<'a, T, F, Fut>(&'a self, read: F) -> Result<T, QuorumError<T>>
+    where
+        T: PartialEq,
+        F: FnMut(&'a P) -> Fut,
+        Fut: Future<Output = TransportResult<T>>,
+    {
+        let mut results = Vec::with_capacity(self.providers.len());
+        for (index, result) in
+            join_all(self.providers.iter().map(read)).await.into_iter().enumerate()
+        {
+            results.push(result.map_err(|source| QuorumError::Transport { index, source })?);
+        }
+
+        if results.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(QuorumError::Divergence(results));
+        }
+        Ok(results.pop().expect("a quorum is not empty"))
+    }
+
+    /// Returns the deposits minted by the goat system transactions of the given block.
+    pub async fn deposits<N>(
+        &self,
+        block_number: u64,
+    ) -> Result<Vec<DepositTx>, QuorumError<Vec<DepositTx>>>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        self.read(|provider| block_deposits(provider, block_number)).await
+    }
+}
+
+/// Fetches a block and extracts its goat deposits.
+async fn block_deposits<N, P>(provider: &P, number: u64) -> TransportResult<Vec<DepositTx>>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let block = provider
+        .get_block_by_number(number.into())
+        .full()
+        .await?
+        .ok_or_else(|| TransportErrorKind::custom_str(&format!("block {number} not found")))?;
+    Ok(block
+        .transactions()
+        .as_transactions()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|tx| match goat_action(tx)? {
+            TxGoatInner::Deposit(deposit) => Some(deposit),
+            _ => None,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_primitives::U64;
+    use alloy_transport::mock::Asserter;
+
+    #[tokio::test]
+    async fn quorum_reads() {
+        let asserters = [Asserter::new(), Asserter::new()];
+        let quorum = GoatQuorum::new(
+            asserters
+                .iter()
+                .map(|asserter| ProviderBuilder::new().connect_mocked_client(asserter.clone()))
+                .collect(),
+        );
+
+        for asserter in &asserters {
+            asserter.push_success(&U64::from(7));
+        }
+        assert_eq!(quorum.read(|provider| provider.get_block_number()).await.unwrap(), 7);
+
+        asserters[0].push_success(&U64::from(7));
+        asserters[1].push_success(&U64::from(8));
+        assert!(matches!(
+            quorum.read(|provider| provider.get_block_number()).await,
+            Err(QuorumError::Divergence(results)) if results == [7, 8]
+        ));
+
+        asserters[0].push_success(&U64::from(7));
+        asserters[1].push_failure_msg("boom");
+        assert!(matches!(
+            quorum.read(|provider| provider.get_block_number()).await,
+            Err(QuorumError::Transport { index: 1, .. })
+        ));
+    }
+}
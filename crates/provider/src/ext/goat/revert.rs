@@ -0,0 +1,68 @@
+//! Decoding of the reverts of the goat predeploys.
+
+use alloy_sol_types::{GenericRevertReason, SolInterface};
+use std::fmt;
+
+/// A decoded revert of a goat predeploy, see [`decode_goat_revert`].
+///
+/// The custom errors `E` of the predeploys are not part of this crate, they are usually generated
+/// with [`sol!`](alloy_sol_types::sol) from the ABI of the deployed contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GoatRevert<E> {
+    /// A custom error of the predeploy.
+    Custom(E),
+    /// A `revert` with a reason string, a panic, or a raw UTF-8 reason.
+    Reason(GenericRevertReason),
+}
+
+impl<E: fmt::Debug> fmt::Display for GoatRevert<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(err) => write!(f, "{err:?}"),
+            Self::Reason(reason) => reason.fmt(f),
+        }
+    }
+}
+
+impl<E: fmt::Debug> std::error::Error for GoatRevert<E> {}
+
+/// Decodes the revert data of a goat predeploy call, e.g. from
+/// [`ErrorPayload::as_revert_data`](alloy_json_rpc::ErrorPayload::as_revert_data).
+///
+/// The custom errors `E` are tried first, then the generic Solidity reverts. Returns `None` if the
+/// data matches none of them.
+pub fn decode_goat_revert<E: SolInterface>(data: &[u8]) -> Option<GoatRevert<E>> {
+    if let Ok(err) = E::abi_decode(data) {
+        return Some(GoatRevert::Custom(err));
+    }
+    GenericRevertReason::decode(data).map(GoatRevert::Reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+    use alloy_sol_types::{sol, Revert, SolError};
+
+    sol! {
+        #[derive(Debug, PartialEq, Eq)]
+        interface IPausable {
+            error Paused(uint256 until);
+        }
+    }
+
+    #[test]
+    fn goat_reverts() {
+        use IPausable::IPausableErrors;
+
+        let err = IPausable::Paused { until: U256::from(3) };
+        let revert = decode_goat_revert::<IPausableErrors>(&err.abi_encode()).unwrap();
+        assert_eq!(revert, GoatRevert::Custom(IPausableErrors::Paused(err)));
+        assert_eq!(revert.to_string(), "Paused(Paused { until: 3 })");
+
+        let revert =
+            decode_goat_revert::<IPausableErrors>(&Revert::from("paused").abi_encode()).unwrap();
+        assert_eq!(revert.to_string(), "revert: paused");
+        assert_eq!(decode_goat_revert::<IPausableErrors>(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+}
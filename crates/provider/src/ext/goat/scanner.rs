@@ -0,0 +1,324 @@
+//! Concurrent scanning of block ranges for goat system transactions.
+
+use super::{goat_tx, ScanCheckpoint};
+use crate::Provider;
+use alloy_consensus::{transaction::goat::TxGoat, BlockHeader};
+use alloy_network::{
+    primitives::{BlockResponse, HeaderResponse},
+    Network,
+};
+use alloy_primitives::B256;
+use alloy_transport::{TransportErrorKind, TransportResult};
+use futures::{stream, Stream, StreamExt};
+
+/// The goat system transactions of a block, as yielded by [`GoatScanner::scan_blocks`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedBlock {
+    /// The number of the block.
+    pub number: u64,
+    /// The hash of the block.
+    pub hash: B256,
+    /// The hash of the parent block.
+    pub parent_hash: B256,
+    /// The goat system transactions of the block, along with their index in the block.
+    pub txs: Vec<(u64, TxGoat)>,
+}
+
+/// Scans block ranges for goat system transactions, fetching the blocks concurrently.
+#[derive(Clone, Copy, Debug)]
+pub struct GoatScanner {
+    concurrency: usize,
+}
+
+impl Default for GoatScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoatScanner {
+    /// The default number of blocks fetched concurrently.
+    pub const DEFAULT_CONCURRENCY: usize = 8;
+
+    /// Creates a new scanner fetching [`DEFAULT_CONCURRENCY`](Self::DEFAULT_CONCURRENCY) blocks
+    /// concurrently.
+    pub const fn new() -> Self {
+        Self { concurrency: Self::DEFAULT_CONCURRENCY }
+    }
+
+    /// Sets the maximum number of blocks fetched concurrently.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Returns the maximum number of blocks fetched concurrently.
+    pub const fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Returns a stream of the goat system transactions of the blocks `from..=to`, along with the
+    /// number of their block.
+    ///
+    /// The transactions are yielded in chain order, even though the blocks are fetched
+    /// concurrently. A block that fails to be fetched, or that does not exist, yields an error in
+    /// its place, and the stream continues with the next block.
+    pub fn scan_range<'a, N, P>(
+        &self,
+        provider: &'a P,
+        from: u64,
+        to: u64,
+    ) -> impl Stream<Item = TransportResult<(u64, TxGoat)>> + 'a
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        self.scan_blocks(provider, from, to).flat_map(|res| {
+            stream::iter(match res {
+                Ok(block) => block.txs.into_iter().map(|(_, tx)| Ok((block.number, tx))).collect(),
+                Err(err) => vec![Err(err)],
+            })
+        })
+    }
+
+    /// Returns a stream of the blocks `from..=to` with their goat system transactions, including
+    /// blocks without any.
+    ///
+    /// The blocks are yielded in chain order, see [`scan_range`](Self::scan_range).
+    pub fn scan_blocks<'a, N, P>(
+        &self,
+        provider: &'a P,
+        from: u64,
+        to: u64,
+    ) -> impl Stream<Item = TransportResult<ScannedBlock>> + 'a
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        stream::iter(from..=to)
+            .map(move |number| fetch_block(provider, number))
+            .buffered(self.concurrency)
+    }
+
+    /// Resumes scanning from the checkpoint, up to and including block `to`.
+    ///
+    /// The transactions recorded in the checkpoint are skipped. Call
+    /// [`reconcile`](Self::reconcile) first to detect blocks of the checkpoint that were reorged
+    /// while the indexer was stopped.
+    pub fn resume<'a, N, P>(
+        &self,
+        provider: &'a P,
+        checkpoint: &ScanCheckpoint,
+        to: u64,
+    ) -> impl Stream<Item = TransportResult<ScannedBlock>> + 'a
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        let partial = checkpoint.last_block.zip(checkpoint.last_tx_index);
+        self.scan_blocks(provider, checkpoint.next_block(), to).map(move |res| {
+            let mut block = res?;
+            if let Some((number, last_tx_index)) = partial {
+                if block.number == number {
+                    block.txs.retain(|(index, _)| *index > last_tx_index);
+                }
+            }
+            Ok(block)
+        })
+    }
+
+    /// Rewinds the checkpoint to the latest of its recent blocks that is still part of the chain.
+    ///
+    /// Returns the number of the first reorged block if the checkpoint was rewound. Returns an
+    /// error, leaving the checkpoint unchanged, if none of its recent blocks are part of the
+    /// chain anymore.
+    pub async fn reconcile<N, P>(
+        &self,
+        provider: &P,
+        checkpoint: &mut ScanCheckpoint,
+    ) -> TransportResult<Option<u64>>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        let mut reorged = None;
+        for (i, &(number, hash)) in checkpoint.recent_blocks.iter().enumerate().rev() {
+            let canonical = provider.get_block_by_number(number.into()).await?;
+            if canonical.is_some_and(|block| block.header().hash() == hash) {
+                if reorged.is_some() {
+                    checkpoint.recent_blocks.truncate(i + 1);
+                    checkpoint.last_block = Some(number);
+                    checkpoint.last_tx_index = None;
+                }
+                return Ok(reorged);
+            }
+            reorged = Some(number);
+        }
+        reorged.map_or(Ok(None), |number| {
+            Err(TransportErrorKind::custom_str(&format!(
+                "checkpoint blocks down to {number} were reorged, deeper than its recent blocks"
+            )))
+        })
+    }
+}
+
+/// Fetches a block and extracts its goat system transactions.
+async fn fetch_block<N, P>(provider: &P, number: u64) -> TransportResult<ScannedBlock>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let block = provider
+        .get_block_by_number(number.into())
+        .full()
+        .await?
+        .ok_or_else(|| TransportErrorKind::custom_str(&format!("block {number} not found")))?;
+    let header = block.header();
+    let txs = block.transactions().as_transactions().unwrap_or_default();
+    Ok(ScannedBlock {
+        number,
+        hash: header.hash(),
+        parent_hash: header.parent_hash(),
+        txs: txs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, tx)| Some((index as u64, goat_tx(tx)?)))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_consensus::{
+        transaction::{
+            goat::{Cancel2Tx, NewBtcBlockTx, TxGoatInner},
+            Recovered,
+        },
+        SignableTransaction, TxEnvelope, TxLegacy,
+    };
+    use alloy_primitives::{Address, Signature, B256, U256};
+    use alloy_rpc_types_eth::{Block, BlockTransactions, Header, Transaction};
+    use alloy_transport::mock::Asserter;
+
+    fn rpc_tx(envelope: TxEnvelope, sender: Address, number: u64) -> Transaction {
+        Transaction {
+            inner: Recovered::new_unchecked(envelope, sender),
+            block_hash: None,
+            block_number: Some(number),
+            transaction_index: None,
+            effective_gas_price: Some(0),
+        }
+    }
+
+    fn block(number: u64, goat: &[TxGoat]) -> Block {
+        let legacy = TxEnvelope::from(TxLegacy::default().into_signed(Signature::test_signature()));
+        let txs = goat
+            .iter()
+            .map(|tx| {
+                let sender = tx.sender();
+                rpc_tx(tx.clone().into_signed(Signature::test_signature()).into(), sender, number)
+            })
+            .chain([rpc_tx(legacy, Address::ZERO, number)])
+            .collect();
+        let header = Header {
+            hash: B256::with_last_byte(number as u8),
+            inner: alloy_consensus::Header { number, ..Default::default() },
+            ..Default::default()
+        };
+        Block::new(header, BlockTransactions::Full(txs))
+    }
+
+    #[tokio::test]
+    async fn scan_range() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+        let tx = |nonce, inner| TxGoat::new(48816, nonce, inner);
+        let a = tx(0, TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) }));
+        let b = tx(1, TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(2) }));
+        let c = tx(2, TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(3) }));
+        asserter.push_success(&block(5, &[a.clone(), b.clone()]));
+        asserter.push_success(&block(6, &[]));
+        asserter.push_success(&block(7, std::slice::from_ref(&c)));
+
+        let scanner = GoatScanner::new().with_concurrency(2);
+        let txs = scanner.scan_range(&provider, 5, 7).map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(txs, [(5, a), (5, b), (7, c)]);
+        assert!(asserter.read_q().is_empty());
+
+        asserter.push_success(&Option::<Block>::None);
+        let res = scanner.scan_range(&provider, 8, 8).collect::<Vec<_>>().await;
+        assert!(matches!(res[..], [Err(_)]));
+
+        assert_eq!(scanner.scan_range(&provider, 9, 8).count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn resume_checkpoint() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let scanner = GoatScanner::new();
+
+        let tx = |nonce| TxGoat::new(48816, nonce, TxGoatInner::Cancel2(Cancel2Tx::default()));
+        asserter.push_success(&block(5, &[tx(0), tx(1)]));
+        let mut blocks = scanner.scan_blocks(&provider, 5, 5).collect::<Vec<_>>().await;
+        let first = blocks.pop().unwrap().unwrap();
+        assert_eq!(first.txs.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 1]);
+
+        // the indexer stops after the first transaction
+        let mut checkpoint = ScanCheckpoint::starting_at(5);
+        checkpoint.record_tx(&first, 0);
+
+        asserter.push_success(&block(5, &[tx(0), tx(1)]));
+        asserter.push_success(&block(6, &[tx(2)]));
+        let resumed =
+            scanner.resume(&provider, &checkpoint, 6).map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(resumed.len(), 2);
+        assert_eq!(resumed[0].txs, [(1, tx(1))]);
+        assert_eq!(resumed[1].txs, [(0, tx(2))]);
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reconcile_checkpoint() {
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let scanner = GoatScanner::new();
+
+        let scanned = |number| ScannedBlock {
+            number,
+            hash: block(number, &[]).header.hash,
+            parent_hash: B256::ZERO,
+            txs: Vec::new(),
+        };
+        let mut checkpoint = ScanCheckpoint::starting_at(5);
+        for number in 5..8 {
+            checkpoint.record_block(&scanned(number));
+        }
+
+        // nothing was reorged
+        asserter.push_success(&block(7, &[]));
+        assert_eq!(scanner.reconcile(&provider, &mut checkpoint).await.unwrap(), None);
+        assert_eq!(checkpoint.next_block(), 8);
+
+        // blocks 6 and 7 were replaced
+        let fork = |number| {
+            let mut block = block(number, &[]);
+            block.header.hash = B256::with_last_byte(0xff);
+            block
+        };
+        asserter.push_success(&fork(7));
+        asserter.push_success(&fork(6));
+        asserter.push_success(&block(5, &[]));
+        assert_eq!(scanner.reconcile(&provider, &mut checkpoint).await.unwrap(), Some(6));
+        assert_eq!(checkpoint.next_block(), 6);
+        assert_eq!(checkpoint.recent_blocks.len(), 1);
+
+        // all recent blocks were replaced
+        asserter.push_success(&fork(5));
+        assert!(scanner.reconcile(&provider, &mut checkpoint).await.is_err());
+        assert_eq!(checkpoint.next_block(), 6);
+    }
+}
@@ -0,0 +1,336 @@
+//! Reorg-aware tracking of goat bridge deposits.
+
+use super::goat_action;
+use crate::Provider;
+use alloy_consensus::{
+    transaction::goat::{DepositKey, DepositTx, TxGoatInner},
+    BlockHeader,
+};
+use alloy_network::{
+    primitives::{BlockResponse, HeaderResponse},
+    Network,
+};
+use alloy_primitives::B256;
+use alloy_transport::TransportResult;
+use std::collections::VecDeque;
+
+/// An event emitted by the [`DepositWatcher`] for a single deposit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositEvent {
+    /// The deposit reached the configured number of confirmations.
+    Confirmed {
+        /// The bitcoin outpoint of the deposit.
+        key: DepositKey,
+        /// The deposit.
+        deposit: DepositTx,
+        /// The number of the block including the deposit.
+        block_number: u64,
+        /// The hash of the block including the deposit.
+        block_hash: B256,
+    },
+    /// The block including the deposit was reorged out before the deposit was confirmed.
+    ///
+    /// The deposit may be included again in a later block, which is reported as a new deposit.
+    Reorged {
+        /// The bitcoin outpoint of the deposit.
+        key: DepositKey,
+        /// The number of the reorged block.
+        block_number: u64,
+        /// The hash of the reorged block.
+        block_hash: B256,
+    },
+}
+
+impl DepositEvent {
+    /// Returns the bitcoin outpoint of the deposit.
+    pub const fn key(&self) -> DepositKey {
+        match self {
+            Self::Confirmed { key, .. } | Self::Reorged { key, .. } => *key,
+        }
+    }
+}
+
+/// A block tracked by the [`DepositWatcher`].
+#[derive(Debug)]
+struct TrackedBlock {
+    number: u64,
+    hash: B256,
+    /// The deposits of the block that are not confirmed yet.
+    deposits: Vec<DepositTx>,
+}
+
+/// Tracks the goat deposits of the chain, emitting a [`DepositEvent`] once a deposit is confirmed
+/// or if its block is reorged out before.
+///
+/// Blocks must be fed in order with [`process_block`](Self::process_block), or fetched from a
+/// provider with [`poll`](Self::poll). A reorg is detected when the parent hash of a block does not
+/// match the tracked block before it, in which case the tracked blocks are unwound until the chain
+/// matches again.
+///
+/// Only the last `confirmations` blocks are tracked, a reorg deeper than the confirmation depth is
+/// not detected.
+#[derive(Debug)]
+pub struct DepositWatcher {
+    confirmations: u64,
+    next_block: Option<u64>,
+    blocks: VecDeque<TrackedBlock>,
+}
+
+impl DepositWatcher {
+    /// Creates a new watcher confirming deposits once their block is `confirmations` deep, i.e. a
+    /// deposit is confirmed in its own block if `confirmations` is 1.
+    ///
+    /// The watcher starts at the latest block on the first [`poll`](Self::poll), use
+    /// [`with_start_block`](Self::with_start_block) to start at an earlier block.
+    pub fn new(confirmations: u64) -> Self {
+        Self { confirmations: confirmations.max(1), next_block: None, blocks: VecDeque::new() }
+    }
+
+    /// Starts watching at the given block.
+    pub const fn with_start_block(mut self, block_number: u64) -> Self {
+        self.next_block = Some(block_number);
+        self
+    }
+
+    /// Returns the number of confirmations after which deposits are confirmed.
+    pub const fn confirmations(&self) -> u64 {
+        self.confirmations
+    }
+
+    /// Returns the number of the next block to process, if known.
+    pub const fn next_block(&self) -> Option<u64> {
+        self.next_block
+    }
+
+    /// Returns the number and hash of the latest processed block.
+    pub fn tip(&self) -> Option<(u64, B256)> {
+        self.blocks.back().map(|block| (block.number, block.hash))
+    }
+
+    /// Processes the next block, returning the events it caused.
+    ///
+    /// If the block does not build on the latest processed block, the latest processed block is
+    /// unwound instead, and the block at [`next_block`](Self::next_block) must be processed again.
+    /// Blocks other than [`next_block`](Self::next_block) are ignored.
+    pub fn process_block<B>(&mut self, block: &B) -> Vec<DepositEvent>
+    where
+        B: BlockResponse,
+        B::Header: HeaderResponse,
+    {
+        let header = block.header();
+        let number = header.number();
+        if self.next_block.is_some_and(|next| next != number) {
+            return Vec::new();
+        }
+
+        if let Some(tip) = self.blocks.back() {
+            if tip.hash != header.parent_hash() {
+                let tip = self.blocks.pop_back().expect("tip exists");
+                self.next_block = Some(tip.number);
+                return tip
+                    .deposits
+                    .into_iter()
+                    .map(|deposit| DepositEvent::Reorged {
+                        key: deposit.deposit_key(),
+                        block_number: tip.number,
+                        block_hash: tip.hash,
+                    })
+                    .collect();
+            }
+        }
+
+        let deposits = block
+            .transactions()
+            .as_transactions()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|tx| match goat_action(tx)? {
+                TxGoatInner::Deposit(deposit) => Some(deposit),
+                _ => None,
+            })
+            .collect();
+        self.blocks.push_back(TrackedBlock { number, hash: header.hash(), deposits });
+        self.next_block = Some(number + 1);
+
+        let mut events = Vec::new();
+        // the oldest tracked block is kept after its deposits are confirmed, to check the parent
+        // hash of the next block
+        let window = self.confirmations as usize;
+        while self.blocks.len() > window {
+            self.blocks.pop_front();
+        }
+        if self.blocks.len() == window {
+            let confirmed = self.blocks.front_mut().expect("window is not empty");
+            events.extend(confirmed.deposits.drain(..).map(|deposit| DepositEvent::Confirmed {
+                key: deposit.deposit_key(),
+                deposit,
+                block_number: confirmed.number,
+                block_hash: confirmed.hash,
+            }));
+        }
+        events
+    }
+
+    /// Fetches and processes all blocks up to the latest block, returning the events they caused.
+    pub async fn poll<N, P>(&mut self, provider: &P) -> TransportResult<Vec<DepositEvent>>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        let latest = provider.get_block_number().await?;
+        let mut events = Vec::new();
+        let mut next = self.next_block.unwrap_or(latest);
+        while next <= latest {
+            let Some(block) = provider.get_block_by_number(next.into()).full().await? else {
+                break;
+            };
+            events.extend(self.process_block(&block));
+            next = self.next_block.unwrap_or(latest + 1);
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{
+        transaction::{goat::TxGoat, Recovered},
+        SignableTransaction, TxEnvelope,
+    };
+    use alloy_primitives::{Signature, U256, U64};
+    use alloy_rpc_types_eth::{Block, BlockTransactions, Header, Transaction};
+
+    fn deposit(id: u8) -> DepositTx {
+        DepositTx { tx_id: B256::with_last_byte(id), amount: U256::from(id), ..Default::default() }
+    }
+
+    /// Returns block `number` of the given fork, building on block `number - 1` of `parent_fork`.
+    fn block(number: u64, fork: u8, parent_fork: u8, deposits: &[DepositTx]) -> Block {
+        let txs = deposits
+            .iter()
+            .map(|deposit| {
+                let tx = TxGoat::new(48816, number, TxGoatInner::Deposit(*deposit));
+                let sender = tx.sender();
+                let envelope = TxEnvelope::from(tx.into_signed(Signature::test_signature()));
+                Transaction {
+                    inner: Recovered::new_unchecked(envelope, sender),
+                    block_hash: None,
+                    block_number: Some(number),
+                    transaction_index: None,
+                    effective_gas_price: Some(0),
+                }
+            })
+            .collect();
+        let header = Header {
+            hash: hash(number, fork),
+            inner: alloy_consensus::Header {
+                number,
+                parent_hash: hash(number - 1, parent_fork),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        Block::new(header, BlockTransactions::Full(txs))
+    }
+
+    fn hash(number: u64, fork: u8) -> B256 {
+        let mut hash = B256::left_padding_from(&number.to_be_bytes());
+        hash[0] = fork;
+        hash
+    }
+
+    #[test]
+    fn confirms_deposits() {
+        let mut watcher = DepositWatcher::new(2).with_start_block(10);
+        assert!(watcher.process_block(&block(10, 0, 0, &[])).is_empty());
+        assert!(watcher.process_block(&block(11, 0, 0, &[deposit(1), deposit(2)])).is_empty());
+        // blocks other than the next one are ignored
+        assert!(watcher.process_block(&block(14, 0, 0, &[])).is_empty());
+
+        let events = watcher.process_block(&block(12, 0, 0, &[]));
+        assert_eq!(
+            events.iter().map(DepositEvent::key).collect::<Vec<_>>(),
+            [deposit(1).deposit_key(), deposit(2).deposit_key()]
+        );
+        assert_eq!(
+            events[0],
+            DepositEvent::Confirmed {
+                key: deposit(1).deposit_key(),
+                deposit: deposit(1),
+                block_number: 11,
+                block_hash: hash(11, 0),
+            }
+        );
+        assert!(watcher.process_block(&block(13, 0, 0, &[])).is_empty());
+        assert_eq!(watcher.tip(), Some((13, hash(13, 0))));
+    }
+
+    #[test]
+    fn reorgs_unconfirmed_deposits() {
+        let mut watcher = DepositWatcher::new(3).with_start_block(10);
+        watcher.process_block(&block(10, 0, 0, &[]));
+        watcher.process_block(&block(11, 0, 0, &[deposit(1)]));
+        watcher.process_block(&block(12, 0, 0, &[deposit(2)]));
+
+        // block 13 of a fork branching off after block 11
+        assert_eq!(
+            watcher.process_block(&block(13, 1, 1, &[])),
+            [DepositEvent::Reorged {
+                key: deposit(2).deposit_key(),
+                block_number: 12,
+                block_hash: hash(12, 0),
+            }]
+        );
+        assert_eq!(watcher.next_block(), Some(12));
+
+        // deposit 2 is included again on the fork
+        assert!(watcher.process_block(&block(12, 1, 0, &[deposit(2)])).is_empty());
+        let events = watcher.process_block(&block(13, 1, 1, &[]));
+        assert_eq!(
+            events.iter().map(DepositEvent::key).collect::<Vec<_>>(),
+            [deposit(1).deposit_key()]
+        );
+        let events = watcher.process_block(&block(14, 1, 1, &[]));
+        assert!(matches!(
+            events[..],
+            [DepositEvent::Confirmed { block_number: 12, block_hash, .. }] if block_hash == hash(12, 1)
+        ));
+    }
+
+    #[tokio::test]
+    async fn poll_deposits() {
+        let asserter = alloy_transport::mock::Asserter::new();
+        let provider = crate::ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let mut watcher = DepositWatcher::new(2);
+
+        asserter.push_success(&U64::from(10));
+        asserter.push_success(&block(10, 0, 0, &[deposit(1)]));
+        assert!(watcher.poll(&provider).await.unwrap().is_empty());
+
+        asserter.push_success(&U64::from(11));
+        asserter.push_success(&block(11, 0, 0, &[deposit(2)]));
+        let events = watcher.poll(&provider).await.unwrap();
+        assert_eq!(
+            events.iter().map(DepositEvent::key).collect::<Vec<_>>(),
+            [deposit(1).deposit_key()]
+        );
+
+        // block 11 was replaced by a fork, which is fetched once the reorg is detected
+        asserter.push_success(&U64::from(12));
+        asserter.push_success(&block(12, 1, 1, &[]));
+        asserter.push_success(&block(11, 1, 0, &[]));
+        asserter.push_success(&block(12, 1, 1, &[]));
+        let events = watcher.poll(&provider).await.unwrap();
+        assert_eq!(
+            events,
+            [DepositEvent::Reorged {
+                key: deposit(2).deposit_key(),
+                block_number: 11,
+                block_hash: hash(11, 0),
+            }]
+        );
+        assert!(asserter.read_q().is_empty());
+        assert_eq!(watcher.tip(), Some((12, hash(12, 1))));
+    }
+}
@@ -8,7 +8,7 @@ pub use admin::AdminApi;
 #[cfg(feature = "anvil-api")]
 mod anvil;
 #[cfg(feature = "anvil-api")]
-pub use anvil::{AnvilApi, ImpersonateConfig};
+pub use anvil::{AnvilApi, ImpersonateConfig, GOAT_EXECUTOR_FUNDING};
 
 #[cfg(feature = "engine-api")]
 mod engine;
@@ -20,6 +20,16 @@ mod debug;
 #[cfg(feature = "debug-api")]
 pub use debug::DebugApi;
 
+#[cfg(feature = "goat-api")]
+mod goat;
+#[cfg(feature = "goat-api")]
+pub use goat::{
+    decode_goat_revert, CheckpointStore, DepositEvent, DepositWatcher, FileTxJournal, GoatApi,
+    GoatQuorum, GoatReadCache, GoatReplay, GoatReplayOutcome, GoatRevert, GoatScanner,
+    GoatTxOutcome, MemoryCheckpointStore, MemoryTxJournal, NonceResync, QuorumError,
+    ScanCheckpoint, ScannedBlock, TxJournal,
+};
+
 #[cfg(feature = "net-api")]
 mod net;
 #[cfg(feature = "net-api")]
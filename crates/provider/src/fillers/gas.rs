@@ -6,6 +6,7 @@ use crate::{
     utils::Eip1559Estimation,
     Provider,
 };
+use alloy_consensus::transaction::goat::GOAT_TX_TYPE_ID;
 use alloy_eips::eip4844::BLOB_TX_MIN_BLOB_GASPRICE;
 use alloy_json_rpc::RpcError;
 use alloy_network::{Network, TransactionBuilder, TransactionBuilder4844};
@@ -47,6 +48,9 @@ pub enum GasFillable {
 ///   and `max_priority_fee_per_gas` fields if unset.
 /// - If the network does not support EIP-1559, it will fallback to the legacy tx and populate the
 ///   `gas_limit` and `gas_price` fields if unset.
+/// - If the request builds a goat system transaction, the `gas_limit` and `gas_price` fields are
+///   set to zero if unset, without any estimation, since the node refuses to estimate system
+///   transactions and they do not pay for gas.
 ///
 /// # Example
 ///
@@ -70,6 +74,12 @@ pub enum GasFillable {
 pub struct GasFiller;
 
 impl GasFiller {
+    /// Returns true if the request builds a goat system transaction.
+    fn is_goat<N: Network>(tx: &N::TransactionRequest) -> bool {
+        let ty: u8 = tx.output_tx_type().into();
+        ty == GOAT_TX_TYPE_ID
+    }
+
     async fn prepare_legacy<P, N>(
         &self,
         provider: &P,
@@ -153,6 +163,13 @@ impl<N: Network> TxFiller<N> for GasFiller {
     where
         P: Provider<N>,
     {
+        if Self::is_goat::<N>(tx) {
+            return Ok(GasFillable::Legacy {
+                gas_limit: tx.gas_limit().unwrap_or_default(),
+                gas_price: tx.gas_price().unwrap_or_default(),
+            });
+        }
+
         if tx.gas_price().is_some() {
             self.prepare_legacy(provider, tx).await
         } else {
@@ -0,0 +1,238 @@
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+    Provider,
+};
+use alloy_consensus::transaction::goat::{GOAT_TX_TYPE_ID, LOCKING_EXECUTOR, RELAYER_EXECUTOR};
+use alloy_json_rpc::RpcError;
+use alloy_network::{Network, NetworkWallet, TransactionBuilder};
+use alloy_primitives::Address;
+use alloy_transport::TransportResult;
+use std::sync::Arc;
+
+/// Error returned by the [`GoatTxGuard`] when a goat transaction would not be signed by a system
+/// executor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum NonExecutorGoatTx {
+    /// The wallet would sign the transaction with a key that is not a system executor.
+    #[error(
+        "goat system transactions (type {GOAT_TX_TYPE_ID:#x}) can only be signed by a system \
+         executor, but the wallet would sign with {0}"
+    )]
+    Signer(Address),
+    /// The transaction is sent by a system executor the wallet has no signer for.
+    #[error("no signer for goat executor {0}")]
+    MissingExecutor(Address),
+}
+
+/// A [`TxFiller`] that rejects goat system transactions the wallet would not sign as a system
+/// executor.
+///
+/// Goat system transactions are only valid when signed by one of the system executors. Requests
+/// that blindly forward a typed transaction, for example from a dApp, would otherwise be signed by
+/// the user's key and only fail once the node rejects them. This guard fails the fill instead, with
+/// a descriptive error.
+///
+/// Goat transactions are detected by the [`output_tx_type`](TransactionBuilder::output_tx_type) of
+/// the request, so the guard works for any network whose requests can describe them, e.g.
+/// [`Ethereum`](alloy_network::Ethereum), [`AnyNetwork`](alloy_network::AnyNetwork) and
+/// [`Goat`](alloy_network::Goat). The signer is resolved like the
+/// [`WalletFiller`](super::WalletFiller) does, from the `from` of the request or the default signer
+/// of the wallet, and must both be an executor and have a signer in the wallet. The guard should
+/// be given the wallet of the provider.
+///
+/// # Example
+///
+/// ```
+/// # use alloy_network::EthereumWallet;
+/// # use alloy_provider::{fillers::GoatTxGuard, ProviderBuilder};
+/// # use alloy_signer_local::PrivateKeySigner;
+/// # async fn test(url: url::Url) -> Result<(), Box<dyn std::error::Error>> {
+/// let pk: PrivateKeySigner = "0x...".parse()?;
+/// let wallet = EthereumWallet::from(pk);
+/// let provider = ProviderBuilder::new()
+///     .filler(GoatTxGuard::new(wallet.clone()))
+///     .wallet(wallet)
+///     .connect_http(url);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GoatTxGuard<W> {
+    wallet: W,
+    executors: Arc<Vec<Address>>,
+}
+
+impl<W> GoatTxGuard<W> {
+    /// Creates a new guard for the wallet, accepting goat transactions from the system executors.
+    pub fn new(wallet: W) -> Self {
+        Self { wallet, executors: Arc::new(vec![RELAYER_EXECUTOR, LOCKING_EXECUTOR]) }
+    }
+
+    /// Adds an executor to the set of accepted signers, e.g. for devnets with custom executors.
+    pub fn with_executor(mut self, executor: Address) -> Self {
+        Arc::make_mut(&mut self.executors).push(executor);
+        self
+    }
+
+    /// Returns the wallet of the guard.
+    pub const fn wallet(&self) -> &W {
+        &self.wallet
+    }
+
+    /// Returns the accepted executors.
+    pub fn executors(&self) -> &[Address] {
+        &self.executors
+    }
+
+    /// Returns `true` if the address is an accepted executor.
+    pub fn is_executor(&self, address: &Address) -> bool {
+        self.executors.contains(address)
+    }
+
+    /// Checks the request, returning an error if it is a goat transaction the wallet would not
+    /// sign as an executor.
+    pub fn check<N>(&self, tx: &N::TransactionRequest) -> Result<(), NonExecutorGoatTx>
+    where
+        N: Network,
+        W: NetworkWallet<N>,
+    {
+        let ty: u8 = tx.output_tx_type().into();
+        if ty != GOAT_TX_TYPE_ID {
+            return Ok(());
+        }
+        let signer = tx.from().unwrap_or_else(|| self.wallet.default_signer_address());
+        if !self.is_executor(&signer) {
+            return Err(NonExecutorGoatTx::Signer(signer));
+        }
+        if !self.wallet.has_signer_for(&signer) {
+            return Err(NonExecutorGoatTx::MissingExecutor(signer));
+        }
+        Ok(())
+    }
+}
+
+impl<W, N> TxFiller<N> for GoatTxGuard<W>
+where
+    N: Network,
+    W: NetworkWallet<N> + Clone,
+{
+    type Fillable = ();
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if self.check::<N>(tx).is_ok() {
+            FillerControlFlow::Finished
+        } else {
+            FillerControlFlow::Ready
+        }
+    }
+
+    fn fill_sync(&self, _tx: &mut SendableTx<N>) {}
+
+    async fn prepare<P>(
+        &self,
+        _provider: &P,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: Provider<N>,
+    {
+        self.check::<N>(tx).map_err(RpcError::local_usage)
+    }
+
+    async fn fill(
+        &self,
+        _fillable: Self::Fillable,
+        tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProviderBuilder;
+    use alloy_consensus::transaction::goat::{
+        NewBtcBlockTx, TxGoat, TxGoatInner, RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION,
+    };
+    use alloy_network::{AnyNetwork, Ethereum, EthereumWallet, Goat, GoatWallet};
+    use alloy_primitives::B256;
+    use alloy_rpc_types_eth::{GoatTransactionRequest, TransactionRequest};
+    use alloy_serde::WithOtherFields;
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn goat_request(from: Option<Address>) -> TransactionRequest {
+        TransactionRequest { transaction_type: Some(GOAT_TX_TYPE_ID), from, ..Default::default() }
+    }
+
+    #[test]
+    fn check() {
+        let user = PrivateKeySigner::random();
+        let guard = GoatTxGuard::new(EthereumWallet::from(user.clone()));
+        let check = |tx: &TransactionRequest| guard.check::<Ethereum>(tx);
+
+        assert!(check(&TransactionRequest::default()).is_ok());
+        assert!(check(&TransactionRequest::default().from(user.address())).is_ok());
+        // the default signer of the wallet signs requests without a sender
+        assert_eq!(check(&goat_request(None)), Err(NonExecutorGoatTx::Signer(user.address())));
+        assert_eq!(
+            check(&goat_request(Some(user.address()))),
+            Err(NonExecutorGoatTx::Signer(user.address()))
+        );
+        // a self-declared executor sender is not enough, the wallet must be able to sign for it
+        assert_eq!(
+            check(&goat_request(Some(RELAYER_EXECUTOR))),
+            Err(NonExecutorGoatTx::MissingExecutor(RELAYER_EXECUTOR))
+        );
+
+        let any = WithOtherFields::new(goat_request(Some(user.address())));
+        assert_eq!(guard.check::<AnyNetwork>(&any), Err(NonExecutorGoatTx::Signer(user.address())));
+
+        let guard = guard.with_executor(user.address());
+        assert!(guard.check::<Ethereum>(&goat_request(None)).is_ok());
+    }
+
+    #[test]
+    fn check_goat() {
+        let user = PrivateKeySigner::random();
+        let guard = GoatTxGuard::new(GoatWallet::new(user.clone()));
+
+        // requests marked as goat transactions by their action only
+        let request = GoatTransactionRequest {
+            action: Some(RELAYER_NEW_BTC_BLOCK_ACTION),
+            ..Default::default()
+        };
+        assert_eq!(guard.check::<Goat>(&request), Err(NonExecutorGoatTx::Signer(user.address())));
+
+        // converted goat transactions are sent by the executor of their module
+        let tx = TxGoat::new(0, 0, TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::ZERO }));
+        let request = <GoatTransactionRequest as From<TxGoat>>::from(tx);
+        assert_eq!(request.inner.from, Some(RELAYER_EXECUTOR));
+        assert_eq!(
+            guard.check::<Goat>(&request),
+            Err(NonExecutorGoatTx::MissingExecutor(RELAYER_EXECUTOR))
+        );
+
+        let guard = GoatTxGuard::new(
+            GoatWallet::new(user).with_module_signer(RELAYER_MODULE, PrivateKeySigner::random()),
+        );
+        assert!(guard.check::<Goat>(&request).is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_user_signer() {
+        let user = PrivateKeySigner::random();
+        let wallet = EthereumWallet::from(user.clone());
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .filler(GoatTxGuard::new(wallet.clone()))
+            .wallet(wallet)
+            .connect_mocked_client(alloy_transport::mock::Asserter::new());
+
+        let err = provider.fill(goat_request(None)).await.unwrap_err();
+        assert!(err.to_string().contains("can only be signed by a system executor"), "{err}");
+        let err = provider.fill(goat_request(Some(RELAYER_EXECUTOR))).await.unwrap_err();
+        assert!(err.to_string().contains("no signer for goat executor"), "{err}");
+    }
+}
@@ -27,6 +27,9 @@ pub use nonce::{CachedNonceManager, NonceFiller, NonceManager, SimpleNonceManage
 mod gas;
 pub use gas::{BlobGasFiller, GasFillable, GasFiller};
 
+mod goat;
+pub use goat::{GoatTxGuard, NonExecutorGoatTx};
+
 mod join_fill;
 pub use join_fill::JoinFill;
 use tracing::error;
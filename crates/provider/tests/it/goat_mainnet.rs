@@ -0,0 +1,168 @@
+//! Conformance tests against pinned blocks of the GOAT mainnet.
+//!
+//! Every block is pinned by a fixture in `testdata/goat_mainnet`, holding its number, its hash,
+//! its raw RLP header, its raw transactions with the hashes reported by goat-geth, and its raw
+//! receipts. The fixtures are checked offline by [`goat_mainnet_fixtures`]: the header must hash
+//! to the pinned hash, the transactions and receipts must match the roots of the header, and every
+//! signed goat transaction must decode, re-encode to the same bytes and hash to the reported hash.
+//! Together, the fixtures must cover both signature parities of the goat transactions.
+//!
+//! A fixture is captured from a GOAT node with the `goat_mainnet_capture` test, run with
+//! `--ignored`, `GOAT_RPC_URL` pointing to the node and `GOAT_BLOCK_NUMBER` to a block containing
+//! goat system transactions. The captured block is checked against the live chain again by
+//! `goat_mainnet_live`, also run with `--ignored` and `GOAT_RPC_URL`.
+
+use alloy_consensus::{
+    proofs::{calculate_receipt_root, calculate_transaction_root},
+    transaction::goat::decode_raw_tx,
+    Header, ReceiptEnvelope, TxEnvelope,
+};
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::{keccak256, Bytes, B256};
+use alloy_provider::{Provider, ProviderBuilder};
+use alloy_rlp::{Decodable, Encodable};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A pinned block of the GOAT mainnet.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct BlockFixture {
+    #[serde(with = "alloy_serde::quantity")]
+    number: u64,
+    hash: B256,
+    /// The RLP encoded header.
+    header: Bytes,
+    /// The EIP-2718 encoded transactions.
+    transactions: Vec<Bytes>,
+    /// The hashes of the transactions, as reported by the node.
+    transaction_hashes: Vec<B256>,
+    /// The EIP-2718 encoded receipts.
+    receipts: Vec<Bytes>,
+}
+
+impl BlockFixture {
+    /// Checks the fixture offline, returning the signature parities of the goat system
+    /// transactions of the block.
+    fn check(&self) -> Vec<bool> {
+        let header = Header::decode(&mut self.header.as_ref()).unwrap();
+        assert_eq!(header.hash_slow(), self.hash, "block {}", self.number);
+        assert_eq!(header.number, self.number);
+
+        let txs = self
+            .transactions
+            .iter()
+            .map(|tx| TxEnvelope::decode_2718_exact(tx).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(txs.len(), self.transaction_hashes.len());
+        let mut parities = Vec::new();
+        for ((tx, encoded), hash) in
+            txs.iter().zip(&self.transactions).zip(&self.transaction_hashes)
+        {
+            assert_eq!(&tx.encoded_2718(), encoded);
+            assert_eq!(tx.tx_hash(), hash);
+            match decode_raw_tx(encoded) {
+                Ok(goat) => {
+                    assert_eq!(Some(&goat), tx.as_goat());
+                    assert_eq!(&goat.encoded_2718(), encoded);
+                    assert_eq!(goat.hash(), hash);
+                    assert_eq!(keccak256(encoded), *hash);
+                    goat.tx().verify_against_input().unwrap();
+                    parities.push(goat.signature().v());
+                }
+                Err(err) => assert!(err.is_not_goat(), "{}: {err}", tx.tx_hash()),
+            }
+        }
+        assert_eq!(calculate_transaction_root(&txs), header.transactions_root);
+
+        let receipts = self
+            .receipts
+            .iter()
+            .map(|receipt| ReceiptEnvelope::decode_2718_exact(receipt).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(receipts.len(), txs.len());
+        assert_eq!(calculate_receipt_root(&receipts), header.receipts_root);
+        parities
+    }
+}
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/it/testdata/goat_mainnet")
+}
+
+fn fixtures() -> Vec<BlockFixture> {
+    let Ok(dir) = std::fs::read_dir(fixtures_dir()) else {
+        return Vec::new();
+    };
+    let mut paths = dir.map(|entry| entry.unwrap().path()).collect::<Vec<_>>();
+    paths.sort();
+    paths
+        .iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .map(|path| serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap())
+        .collect()
+}
+
+async fn fetch_fixture(provider: &impl Provider, number: u64) -> BlockFixture {
+    let block = provider.get_block_by_number(number.into()).full().await.unwrap().unwrap();
+    let receipts = provider.get_block_receipts(number.into()).await.unwrap().unwrap();
+
+    let mut header = Vec::new();
+    block.header.inner.encode(&mut header);
+    let transaction_hashes = block.transactions.hashes().collect();
+    BlockFixture {
+        number,
+        hash: block.header.hash,
+        header: header.into(),
+        transactions: block
+            .transactions
+            .into_transactions()
+            .map(|tx| tx.into_inner().encoded_2718().into())
+            .collect(),
+        transaction_hashes,
+        receipts: receipts
+            .into_iter()
+            .map(|receipt| receipt.into_primitives_receipt().inner.encoded_2718().into())
+            .collect(),
+    }
+}
+
+#[test]
+fn goat_mainnet_fixtures() {
+    let fixtures = fixtures();
+    let parities = fixtures.iter().flat_map(BlockFixture::check).collect::<Vec<_>>();
+    if !fixtures.is_empty() {
+        assert!(parities.contains(&false), "no goat transaction with an even signature parity");
+        assert!(parities.contains(&true), "no goat transaction with an odd signature parity");
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn goat_mainnet_capture() {
+    let url = std::env::var("GOAT_RPC_URL").expect("GOAT_RPC_URL must be set");
+    let number: u64 = std::env::var("GOAT_BLOCK_NUMBER")
+        .expect("GOAT_BLOCK_NUMBER must be set")
+        .parse()
+        .expect("GOAT_BLOCK_NUMBER must be a block number");
+    let provider = ProviderBuilder::new().connect_http(url.parse().unwrap());
+
+    let fixture = fetch_fixture(&provider, number).await;
+    assert!(!fixture.check().is_empty(), "block {number} has no goat system transactions");
+
+    std::fs::create_dir_all(fixtures_dir()).unwrap();
+    let path = fixtures_dir().join(format!("block_{number}.json"));
+    std::fs::write(path, serde_json::to_string_pretty(&fixture).unwrap() + "\n").unwrap();
+}
+
+#[tokio::test]
+#[ignore]
+async fn goat_mainnet_live() {
+    let url = std::env::var("GOAT_RPC_URL").expect("GOAT_RPC_URL must be set");
+    let provider = ProviderBuilder::new().connect_http(url.parse().unwrap());
+
+    let fixtures = fixtures();
+    assert!(!fixtures.is_empty(), "no pinned block in {}", fixtures_dir().display());
+    for fixture in fixtures {
+        assert_eq!(fetch_fixture(&provider, fixture.number).await, fixture);
+    }
+}
@@ -0,0 +1,56 @@
+//! Decoding of `eth_getBlockReceipts` responses of goat-geth.
+
+use alloy_consensus::{transaction::goat::BRIDGE_CONTRACT, ReceiptEnvelope, TxType};
+use alloy_network::{AnyNetwork, ReceiptResponse};
+use alloy_primitives::{Address, U256};
+use alloy_provider::{Provider, ProviderBuilder};
+use alloy_rpc_types_eth::{BlockId, GoatReceiptFields};
+use alloy_transport::mock::Asserter;
+use serde_json::Value;
+
+const BLOCK_RECEIPTS: &str = include_str!("testdata/goat_block_receipts.json");
+
+fn block_receipts() -> Value {
+    serde_json::from_str(BLOCK_RECEIPTS).unwrap()
+}
+
+#[tokio::test]
+async fn goat_block_receipts() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+    asserter.push_success(&block_receipts());
+    let receipts = provider.get_block_receipts(BlockId::number(500)).await.unwrap().unwrap();
+    assert_eq!(receipts.len(), 3);
+    assert!(matches!(receipts[0].inner, ReceiptEnvelope::Goat(_)));
+    assert!(matches!(receipts[1].inner, ReceiptEnvelope::Goat(_)));
+    assert!(matches!(receipts[2].inner, ReceiptEnvelope::Eip1559(_)));
+    assert_eq!(receipts[1].to(), Some(BRIDGE_CONTRACT));
+    assert!(receipts.iter().all(|receipt| receipt.status()));
+}
+
+#[tokio::test]
+async fn goat_block_receipts_any_network() {
+    let asserter = Asserter::new();
+    let provider =
+        ProviderBuilder::new().network::<AnyNetwork>().connect_mocked_client(asserter.clone());
+
+    // receipts of transaction types unknown to alloy are decoded as well
+    let mut receipts = block_receipts();
+    let mut unknown = receipts[2].clone();
+    unknown["type"] = "0x7e".into();
+    unknown["transactionIndex"] = "0x3".into();
+    receipts.as_array_mut().unwrap().push(unknown);
+    asserter.push_success(&receipts);
+
+    let mut receipts = provider.get_block_receipts(BlockId::number(500)).await.unwrap().unwrap();
+    let types = receipts.iter().map(|receipt| receipt.inner.inner.r#type).collect::<Vec<_>>();
+    assert_eq!(types, [TxType::Goat as u8, TxType::Goat as u8, 2, 0x7e]);
+
+    // the minted amount of the deposit is preserved
+    let mint = GoatReceiptFields::take_from(&mut receipts[1].other).unwrap().mint.unwrap();
+    assert_eq!(mint.target, Address::with_last_byte(0xaa));
+    assert_eq!(mint.amount, U256::from(10u64.pow(18)));
+    assert_eq!(mint.tax, U256::from(10u64.pow(16)));
+    assert!(receipts.iter().all(|receipt| receipt.other.is_empty()));
+}
@@ -0,0 +1,92 @@
+//! Signing goat system transactions with the wallet of a [`Goat`] provider.
+
+use alloy_consensus::{
+    transaction::goat::{
+        CompleteUnlockTx, NewBtcBlockTx, TxGoat, TxGoatInner, LOCKING_MODULE, RELAYER_EXECUTOR,
+        RELAYER_MODULE,
+    },
+    Signed, TxEnvelope,
+};
+use alloy_network::{Goat, GoatWallet, TransactionBuilder};
+use alloy_primitives::{Address, B256};
+use alloy_provider::{ProviderBuilder, SendableTx};
+use alloy_rpc_types_eth::GoatTransactionRequest;
+use alloy_signer_local::PrivateKeySigner;
+use alloy_transport::mock::Asserter;
+
+fn new_btc_block() -> TxGoat {
+    TxGoat::new(48816, 0, TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) }))
+}
+
+fn complete_unlock() -> TxGoat {
+    TxGoat::new(48816, 0, TxGoatInner::CompleteUnlock(CompleteUnlockTx::default()))
+}
+
+/// Returns the goat transaction of a filled request, along with the address of the key that
+/// signed it.
+fn signed_goat(tx: SendableTx<Goat>) -> (Signed<TxGoat>, Address) {
+    let SendableTx::Envelope(TxEnvelope::Goat(signed)) = tx else {
+        panic!("expected a signed goat transaction");
+    };
+    let signer = signed.signature().recover_address_from_prehash(&signed.signature_hash()).unwrap();
+    (signed, signer)
+}
+
+#[tokio::test]
+async fn goat_wallet_filler() {
+    let signer = PrivateKeySigner::random();
+    let provider = ProviderBuilder::new()
+        .disable_recommended_fillers()
+        .network::<Goat>()
+        .wallet(signer.clone())
+        .connect_mocked_client(Asserter::new());
+
+    // the synthesized executor has no key in the wallet, the user key never signs for it
+    let err = provider.fill(new_btc_block().into()).await.unwrap_err();
+    assert!(
+        err.to_string().contains(&format!("no signer for goat executor {RELAYER_EXECUTOR}")),
+        "{err}"
+    );
+    let mut request: GoatTransactionRequest = new_btc_block().into();
+    request.set_from(RELAYER_EXECUTOR);
+    assert!(provider.fill(request).await.is_err());
+
+    // unknown senders are rejected
+    let mut request: GoatTransactionRequest = new_btc_block().into();
+    request.set_from(Address::with_last_byte(1));
+    assert!(provider.fill(request).await.is_err());
+}
+
+#[tokio::test]
+async fn goat_wallet_module_signers() {
+    let (default, relayer, locking) =
+        (PrivateKeySigner::random(), PrivateKeySigner::random(), PrivateKeySigner::random());
+    let wallet = GoatWallet::new(default.clone())
+        .with_module_signer(RELAYER_MODULE, relayer.clone())
+        .with_module_signer(LOCKING_MODULE, locking.clone());
+    let provider = ProviderBuilder::new()
+        .disable_recommended_fillers()
+        .network::<Goat>()
+        .wallet(wallet)
+        .connect_mocked_client(Asserter::new());
+
+    let (_, key) = signed_goat(provider.fill(new_btc_block().into()).await.unwrap());
+    assert_eq!(key, relayer.address());
+    let (_, key) = signed_goat(provider.fill(complete_unlock().into()).await.unwrap());
+    assert_eq!(key, locking.address());
+}
+
+#[tokio::test]
+async fn goat_gas_filler() {
+    // the mocked node has no response to an estimation
+    let provider = ProviderBuilder::new()
+        .disable_recommended_fillers()
+        .network::<Goat>()
+        .with_gas_estimation()
+        .connect_mocked_client(Asserter::new());
+
+    let SendableTx::Builder(request) = provider.fill(new_btc_block().into()).await.unwrap() else {
+        panic!("expected a request");
+    };
+    assert_eq!((request.gas, request.gas_price), (Some(0), Some(0)));
+}
@@ -1,7 +1,12 @@
 #![allow(dead_code)]
 #![allow(missing_docs)]
 
+mod goat_receipts;
+mod goat_wallet;
 mod mock;
 
+#[cfg(feature = "network-tests")]
+mod goat_mainnet;
+
 #[cfg(feature = "ws")]
 mod ws;
@@ -1,5 +1,5 @@
 use crate::RawSubscription;
-use alloy_json_rpc::SerializedRequest;
+use alloy_json_rpc::{goat, SerializedRequest};
 use alloy_primitives::B256;
 use parking_lot::Mutex;
 use serde_json::value::RawValue;
@@ -77,6 +77,18 @@ impl ActiveSubscription {
         &self.request
     }
 
+    /// The method used to cancel the subscription on the server.
+    ///
+    /// Subscriptions opened with `goat_subscribe` are cancelled with `goat_unsubscribe`, all
+    /// others with `eth_unsubscribe`.
+    pub(crate) fn unsubscribe_method(&self) -> &'static str {
+        if self.request.method() == goat::GOAT_SUBSCRIBE {
+            goat::GOAT_UNSUBSCRIBE
+        } else {
+            "eth_unsubscribe"
+        }
+    }
+
     /// Get a subscription.
     pub(crate) fn subscribe(&self) -> RawSubscription {
         if self.tx.is_empty() {
@@ -68,6 +68,11 @@ impl SubscriptionManager {
         self.local_to_server.get_by_left(local_id)
     }
 
+    /// Get the method used to cancel a subscription on the server.
+    pub(crate) fn unsubscribe_method_for(&self, local_id: &B256) -> Option<&'static str> {
+        self.local_to_sub.get_by_left(local_id).map(ActiveSubscription::unsubscribe_method)
+    }
+
     /// Drop all server_ids.
     pub(crate) fn drop_server_ids(&mut self) {
         self.local_to_server.clear();
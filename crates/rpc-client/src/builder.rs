@@ -1,5 +1,10 @@
 use crate::{BuiltInConnectionString, RpcClient};
-use alloy_transport::{BoxTransport, IntoBoxTransport, TransportConnect, TransportResult};
+#[cfg(feature = "reqwest")]
+use alloy_transport::layers::{FailoverLayer, FailoverService};
+use alloy_transport::{
+    layers::{RetryBackoffLayer, RetryPolicy},
+    BoxTransport, IntoBoxTransport, TransportConnect, TransportResult,
+};
 use tower::{
     layer::util::{Identity, Stack},
     Layer, ServiceBuilder,
@@ -33,6 +38,17 @@ impl<L> ClientBuilder<L> {
         ClientBuilder { builder: self.builder.layer(layer) }
     }
 
+    /// Add a [`RetryBackoffLayer`] to the stack, retrying rate limited requests.
+    ///
+    /// The layer can be configured with a jittered backoff and per-method retry budgets, see
+    /// [`RetryBackoffLayer::with_jitter`] and [`RetryBackoffLayer::with_method_max_retries`].
+    pub fn retry_backoff<P: RetryPolicy>(
+        self,
+        layer: RetryBackoffLayer<P>,
+    ) -> ClientBuilder<Stack<RetryBackoffLayer<P>, L>> {
+        self.layer(layer)
+    }
+
     /// Create a new [`RpcClient`] with the given transport and the configured
     /// layers.
     ///
@@ -75,6 +91,27 @@ impl<L> ClientBuilder<L> {
         self.transport(transport, is_local)
     }
 
+    /// Convenience function to create a new [`RpcClient`] with a [`FailoverService`] over
+    /// [`reqwest`] HTTP transports to the given URLs.
+    ///
+    /// Requests are sent to the first URL until it fails or falls behind the others, see
+    /// [`FailoverLayer`] for the health configuration.
+    #[cfg(feature = "reqwest")]
+    pub fn failover_http(
+        self,
+        layer: FailoverLayer,
+        urls: impl IntoIterator<Item = url::Url>,
+    ) -> RpcClient
+    where
+        L: Layer<FailoverService<alloy_transport_http::Http<reqwest::Client>>>,
+        L::Service: IntoBoxTransport,
+    {
+        let transports = urls.into_iter().map(alloy_transport_http::Http::new).collect::<Vec<_>>();
+        let is_local = transports.iter().all(|transport| transport.guess_local());
+
+        self.transport(layer.layer(transports), is_local)
+    }
+
     /// Convenience function to create a new [`RpcClient`] with a `hyper` HTTP transport.
     #[cfg(all(not(target_family = "wasm"), feature = "hyper"))]
     pub fn hyper_http(self, url: url::Url) -> RpcClient
@@ -208,7 +208,7 @@ where
     }
 
     /// Set the request to be a non-standard subscription (i.e. not
-    /// "eth_subscribe").
+    /// "eth_subscribe" or "goat_subscribe").
     ///
     /// # Panics
     ///
@@ -1,5 +1,6 @@
-use crate::{poller::PollerBuilder, BatchRequest, ClientBuilder, RpcCall};
+use crate::{poller::PollerBuilder, BatchRequest, ClientBuilder, RawTransactionBatch, RpcCall};
 use alloy_json_rpc::{Id, Request, RpcRecv, RpcSend};
+use alloy_primitives::Bytes;
 use alloy_transport::{mock::Asserter, BoxTransport, IntoBoxTransport};
 use std::{
     borrow::Cow,
@@ -181,6 +182,16 @@ impl RpcClient {
     pub fn new_batch(&self) -> BatchRequest<'_> {
         BatchRequest::new(&self.0)
     }
+
+    /// Create a new [`RawTransactionBatch`] submitting the given EIP-2718 encoded transactions.
+    #[inline]
+    pub fn new_raw_transaction_batch<I>(&self, txs: I) -> RawTransactionBatch<'_>
+    where
+        I: IntoIterator,
+        I::Item: Into<Bytes>,
+    {
+        RawTransactionBatch::new(&self.0, txs)
+    }
 }
 
 impl Deref for RpcClient {
@@ -27,6 +27,9 @@ pub use client::{ClientRef, NoParams, RpcClient, RpcClientInner, WeakClient};
 mod poller;
 pub use poller::{PollChannel, PollerBuilder, PollerStream};
 
+mod raw_batch;
+pub use raw_batch::RawTransactionBatch;
+
 #[cfg(feature = "ws")]
 pub use alloy_transport_ws::WsConnect;
 
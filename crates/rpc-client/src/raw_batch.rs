@@ -0,0 +1,143 @@
+use crate::{BatchRequest, ClientRef};
+use alloy_primitives::{Bytes, TxHash};
+use alloy_transport::{
+    layers::{RateLimitRetryPolicy, RetryPolicy},
+    TransportResult,
+};
+use std::time::Duration;
+
+#[cfg(target_family = "wasm")]
+use wasmtimer::tokio::sleep;
+
+#[cfg(not(target_family = "wasm"))]
+use tokio::time::sleep;
+
+/// The default number of times a transaction is resubmitted.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// The default delay between two submissions.
+const DEFAULT_BACKOFF: Duration = Duration::from_millis(500);
+
+/// A batch of signed raw transactions, submitted with `eth_sendRawTransaction` in a single
+/// JSON-RPC batch.
+///
+/// This is intended for bursts of submissions, such as the goat deposit transactions a relayer
+/// submits once a bitcoin block is confirmed. The result of each transaction is reported
+/// separately, and the transactions that failed with an error accepted by the [`RetryPolicy`] are
+/// resubmitted together in a new batch, up to the configured number of retries.
+#[derive(Debug)]
+#[must_use = "this batch does nothing unless sent"]
+pub struct RawTransactionBatch<'a, P = RateLimitRetryPolicy> {
+    client: ClientRef<'a>,
+    txs: Vec<Bytes>,
+    max_retries: u32,
+    backoff: Duration,
+    policy: P,
+}
+
+impl<'a> RawTransactionBatch<'a> {
+    /// Creates a new batch of the given EIP-2718 encoded transactions.
+    pub fn new<I>(client: ClientRef<'a>, txs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Bytes>,
+    {
+        Self {
+            client,
+            txs: txs.into_iter().map(Into::into).collect(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+            policy: RateLimitRetryPolicy::default(),
+        }
+    }
+}
+
+impl<'a, P: RetryPolicy> RawTransactionBatch<'a, P> {
+    /// Sets the maximum number of times a transaction is resubmitted. Defaults to `3`.
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay between two submissions. Defaults to 500ms.
+    ///
+    /// A longer backoff hinted by a failed submission takes precedence.
+    pub const fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] deciding which failed submissions are retried.
+    pub fn with_policy<Q: RetryPolicy>(self, policy: Q) -> RawTransactionBatch<'a, Q> {
+        RawTransactionBatch {
+            client: self.client,
+            txs: self.txs,
+            max_retries: self.max_retries,
+            backoff: self.backoff,
+            policy,
+        }
+    }
+
+    /// Returns the number of transactions in the batch.
+    pub const fn len(&self) -> usize {
+        self.txs.len()
+    }
+
+    /// Returns true if the batch has no transactions.
+    pub const fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
+    /// Submits the transactions.
+    ///
+    /// Returns the result of each transaction, in the order of the batch. The outer error is only
+    /// returned if a whole batch request failed and could not be retried.
+    pub async fn send(self) -> TransportResult<Vec<TransportResult<TxHash>>> {
+        let mut results: Vec<Option<TransportResult<TxHash>>> =
+            std::iter::repeat_with(|| None).take(self.txs.len()).collect();
+        let mut pending: Vec<usize> = (0..self.txs.len()).collect();
+        let mut attempt = 0;
+
+        while !pending.is_empty() {
+            let can_retry = attempt < self.max_retries;
+            let mut backoff = self.backoff;
+
+            let mut batch = BatchRequest::new(self.client);
+            let waiters = pending
+                .iter()
+                .map(|&index| {
+                    let params = (self.txs[index].clone(),);
+                    batch.add_call("eth_sendRawTransaction", &params).map(|w| (index, w))
+                })
+                .collect::<TransportResult<Vec<_>>>()?;
+
+            if let Err(err) = batch.send().await {
+                if !can_retry || !self.policy.should_retry(&err) {
+                    return Err(err);
+                }
+                backoff = backoff.max(self.policy.backoff_hint(&err).unwrap_or_default());
+            } else {
+                pending.clear();
+                for (index, waiter) in waiters {
+                    match waiter.await {
+                        Err(err) if can_retry && self.policy.should_retry(&err) => {
+                            let hint = self.policy.backoff_hint(&err).unwrap_or_default();
+                            backoff = backoff.max(hint);
+                            pending.push(index);
+                        }
+                        result => results[index] = Some(result),
+                    }
+                }
+                if pending.is_empty() {
+                    break;
+                }
+            }
+
+            attempt += 1;
+            trace!(pending = pending.len(), attempt, ?backoff, "retrying raw transaction batch");
+            sleep(backoff).await;
+        }
+
+        Ok(results.into_iter().map(|result| result.expect("all transactions settled")).collect())
+    }
+}
@@ -4,6 +4,10 @@
 #[cfg(feature = "reqwest")]
 mod http;
 
+mod raw_batch;
+
+mod retry;
+
 #[cfg(feature = "pubsub")]
 mod ws;
 
@@ -0,0 +1,57 @@
+use alloy_json_rpc::ErrorPayload;
+use alloy_primitives::{bytes, B256};
+use alloy_rpc_client::RpcClient;
+use alloy_transport::mock::Asserter;
+use std::{borrow::Cow, time::Duration};
+
+const fn rate_limited() -> ErrorPayload {
+    ErrorPayload { code: 429, message: Cow::Borrowed("rate limited"), data: None }
+}
+
+#[tokio::test]
+async fn raw_transaction_batch_retries_failed_items() {
+    let asserter = Asserter::new();
+    let client = RpcClient::mocked(asserter.clone());
+
+    let hashes = [B256::with_last_byte(1), B256::with_last_byte(2), B256::with_last_byte(3)];
+    // first batch: one success, one rate limited, one rejected
+    asserter.push_success(&hashes[0]);
+    asserter.push_failure(rate_limited());
+    asserter.push_failure_msg("nonce too low");
+    // second batch: only the rate limited transaction is resubmitted
+    asserter.push_success(&hashes[1]);
+
+    let results = client
+        .new_raw_transaction_batch([bytes!("0x6001"), bytes!("0x6002"), bytes!("0x6003")])
+        .with_backoff(Duration::ZERO)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(*results[0].as_ref().unwrap(), hashes[0]);
+    assert_eq!(*results[1].as_ref().unwrap(), hashes[1]);
+    assert!(results[2].as_ref().unwrap_err().as_error_resp().is_some());
+    assert!(asserter.read_q().is_empty());
+}
+
+#[tokio::test]
+async fn raw_transaction_batch_gives_up_after_max_retries() {
+    let asserter = Asserter::new();
+    let client = RpcClient::mocked(asserter.clone());
+
+    for _ in 0..3 {
+        asserter.push_failure(rate_limited());
+    }
+
+    let results = client
+        .new_raw_transaction_batch([bytes!("0x6001")])
+        .with_max_retries(2)
+        .with_backoff(Duration::ZERO)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(results[0].as_ref().unwrap_err().as_error_resp().unwrap().code, 429);
+    assert!(asserter.read_q().is_empty());
+}
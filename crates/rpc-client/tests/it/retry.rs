@@ -0,0 +1,32 @@
+use alloy_json_rpc::ErrorPayload;
+use alloy_primitives::U64;
+use alloy_rpc_client::ClientBuilder;
+use alloy_transport::{
+    layers::RetryBackoffLayer,
+    mock::{Asserter, MockTransport},
+};
+use std::borrow::Cow;
+
+const fn rate_limited() -> ErrorPayload {
+    ErrorPayload { code: -32005, message: Cow::Borrowed("limit exceeded"), data: None }
+}
+
+#[tokio::test]
+async fn client_builder_retry_backoff() {
+    let asserter = Asserter::new();
+    let client = ClientBuilder::default()
+        .retry_backoff(
+            RetryBackoffLayer::new(3, 0, 10_000).with_method_max_retries("eth_blockNumber", 1),
+        )
+        .transport(MockTransport::new(asserter.clone()), true);
+
+    asserter.push_failure(rate_limited());
+    asserter.push_success(&U64::from(7));
+    let number: U64 = client.request_noparams("eth_blockNumber").await.unwrap();
+    assert_eq!(number, U64::from(7));
+
+    asserter.push_failure(rate_limited());
+    asserter.push_failure(rate_limited());
+    client.request_noparams::<U64>("eth_blockNumber").await.unwrap_err();
+    assert!(asserter.read_q().is_empty());
+}
@@ -7,7 +7,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod transaction;
-pub use transaction::AnyTransactionReceipt;
+pub use transaction::{AnyTransactionReceipt, GoatReceiptExt};
 
 mod block;
 pub use block::AnyRpcHeader;
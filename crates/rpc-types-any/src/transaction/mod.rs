@@ -1,2 +1,2 @@
 mod receipt;
-pub use receipt::AnyTransactionReceipt;
+pub use receipt::{AnyTransactionReceipt, GoatReceiptExt};
@@ -1,15 +1,45 @@
+use alloy_consensus::transaction::goat::Mint;
 use alloy_consensus_any::AnyReceiptEnvelope;
-use alloy_rpc_types_eth::{Log, TransactionReceipt};
+use alloy_primitives::U256;
+use alloy_rpc_types_eth::{GoatReceiptFields, Log, TransactionReceipt};
 use alloy_serde::WithOtherFields;
 
 /// Alias for a catch-all receipt type.
 #[doc(alias = "AnyTxReceipt")]
 pub type AnyTransactionReceipt = WithOtherFields<TransactionReceipt<AnyReceiptEnvelope<Log>>>;
 
+/// Typed access to the GOAT fields of an [`AnyTransactionReceipt`].
+///
+/// The fields are kept in the other fields of the receipt, so they survive a serde round-trip
+/// untouched, see [`GoatReceiptFields`].
+pub trait GoatReceiptExt {
+    /// Reads the goat fields of the receipt.
+    ///
+    /// Missing fields are `None`, only malformed fields are an error.
+    fn goat_fields(&self) -> serde_json::Result<GoatReceiptFields>;
+
+    /// Returns the native tokens minted by a goat deposit. A malformed field is ignored.
+    fn goat_mint(&self) -> Option<Mint> {
+        self.goat_fields().ok()?.mint
+    }
+
+    /// Returns the native tokens minted in the block up to and including the transaction, in wei.
+    /// A malformed field is ignored.
+    fn goat_cumulative_mint(&self) -> Option<U256> {
+        self.goat_fields().ok()?.cumulative_mint
+    }
+}
+
+impl GoatReceiptExt for AnyTransactionReceipt {
+    fn goat_fields(&self) -> serde_json::Result<GoatReceiptFields> {
+        GoatReceiptFields::from_other(&self.other)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use alloy_primitives::b256;
+    use alloy_primitives::{address, b256};
 
     #[test]
     fn deserialize_tx_receipt_op() {
@@ -144,4 +174,26 @@ mod test {
         assert_eq!(other.gas_used_for_l1, "0x2c906");
         assert_eq!(other.l1_block_number, "0x1323b96");
     }
+
+    #[test]
+    fn goat_receipt_roundtrip() {
+        let receipt_json = r#"{"blockHash":"0x00000000000000000000000000000000000000000000000000000000000000a1","blockNumber":"0x1f4","contractAddress":null,"cumulativeGasUsed":"0x0","effectiveGasPrice":"0x0","from":"0xbc10000000000000000000000000000000001000","gasUsed":"0x0","logs":[],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","status":"0x1","to":"0xbc10000000000000000000000000000000000003","transactionHash":"0x00000000000000000000000000000000000000000000000000000000000000b1","transactionIndex":"0x1","type":"0x60","mint":{"target":"0x00000000000000000000000000000000000000aa","amount":"0xde0b6b3a7640000","tax":"0x2386f26fc10000"},"cumulativeMint":"0xde0b6b3a7640000"}"#;
+        let receipt = serde_json::from_str::<AnyTransactionReceipt>(receipt_json).unwrap();
+
+        let mint = receipt.goat_mint().unwrap();
+        assert_eq!(mint.target, address!("0x00000000000000000000000000000000000000aa"));
+        assert_eq!(mint.amount + mint.tax, U256::from(101) * U256::from(10u64.pow(16)));
+        assert_eq!(receipt.goat_cumulative_mint(), Some(mint.amount));
+
+        let roundtrip: AnyTransactionReceipt =
+            serde_json::from_str(&serde_json::to_string(&receipt).unwrap()).unwrap();
+        assert_eq!(roundtrip, receipt);
+        assert_eq!(roundtrip.goat_mint(), Some(mint));
+
+        let mut malformed = receipt;
+        malformed.other.insert("mint".to_string(), "0x1".into());
+        assert!(malformed.goat_fields().is_err());
+        assert_eq!(malformed.goat_mint(), None);
+        assert_eq!(malformed.goat_cumulative_mint(), None);
+    }
 }
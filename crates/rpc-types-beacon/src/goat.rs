@@ -0,0 +1,173 @@
+//! Types for the sequencer endpoints of the GOAT consensus layer.
+//!
+//! GOAT blocks are produced by a rotating set of sequencers, one of which proposes the block of
+//! each slot. Like the beacon API, numbers are encoded as decimal strings and the payload of a
+//! response is wrapped in a `data` field.
+
+use alloy_primitives::{Address, Bytes, B256};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// A response of the sequencer endpoints, wrapping its payload in a `data` field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct GoatDataResponse<T> {
+    /// The payload of the response.
+    pub data: T,
+}
+
+/// The response to a request for the sequencer set.
+pub type SequencerSetResponse = GoatDataResponse<SequencerSet>;
+
+/// The response to a request for the sequencer rotations.
+pub type SequencerRotationsResponse = GoatDataResponse<Vec<SequencerRotation>>;
+
+/// The response to a request for the proposal slots.
+pub type ProposalSlotsResponse = GoatDataResponse<Vec<ProposalSlot>>;
+
+/// A sequencer of the GOAT consensus layer.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct Sequencer {
+    /// The address of the sequencer.
+    pub address: Address,
+    /// The compressed secp256k1 public key of the sequencer.
+    pub pubkey: Bytes,
+    /// The voting power of the sequencer.
+    #[serde_as(as = "DisplayFromStr")]
+    pub voting_power: u64,
+}
+
+/// The sequencer set at a block height.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct SequencerSet {
+    /// The block height of the set.
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+    /// The sequencer proposing the block at the height.
+    pub proposer: Address,
+    /// The sequencers of the set, which may be omitted if empty.
+    #[serde(default)]
+    pub sequencers: Vec<Sequencer>,
+}
+
+impl SequencerSet {
+    /// Returns the sequencer with the given address.
+    pub fn get(&self, address: &Address) -> Option<&Sequencer> {
+        self.sequencers.iter().find(|sequencer| sequencer.address == *address)
+    }
+
+    /// Returns the total voting power of the set.
+    pub fn total_voting_power(&self) -> u64 {
+        self.sequencers.iter().map(|sequencer| sequencer.voting_power).sum()
+    }
+}
+
+/// The reason of a [`SequencerRotation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationReason {
+    /// The proposer was rotated on schedule.
+    Scheduled,
+    /// The proposer missed its slot.
+    MissedSlot,
+    /// The proposer was jailed.
+    Jailed,
+    /// A reason unknown to this version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A change of the proposing sequencer.
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequencerRotation {
+    /// The first block height proposed by the next sequencer.
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+    /// The previous proposing sequencer.
+    pub previous: Address,
+    /// The next proposing sequencer.
+    pub next: Address,
+    /// The reason of the rotation.
+    pub reason: RotationReason,
+}
+
+/// The status of a [`ProposalSlot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlotStatus {
+    /// The slot has not been proposed yet.
+    Pending,
+    /// A block was proposed in the slot.
+    Proposed,
+    /// The proposer missed the slot.
+    Missed,
+}
+
+/// The proposal slot of a block height and consensus round.
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalSlot {
+    /// The block height of the slot.
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+    /// The consensus round of the slot within the height.
+    #[serde_as(as = "DisplayFromStr")]
+    pub round: u32,
+    /// The sequencer assigned to the slot.
+    pub proposer: Address,
+    /// The status of the slot.
+    pub status: SlotStatus,
+    /// The hash of the proposed block, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<B256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use similar_asserts::assert_eq;
+
+    #[test]
+    fn serde_sequencer_set() {
+        let s = r#"{"data":{"height":"1024","proposer":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","sequencers":[{"address":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","pubkey":"0x0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2","voting_power":"10"},{"address":"0x3c44cdddb6a900fa2b585dd299e03d12fa4293bc","pubkey":"0x02ba5734d8f7091719471e7f7ed6b9df170dc70cc661ca05e688601ad984f068b0","voting_power":"5"}]}}"#;
+        let res: SequencerSetResponse = serde_json::from_str(s).unwrap();
+        let proposer = address!("0x70997970c51812dc3a010c7d01b50e0d17dc79c8");
+        assert_eq!(res.data.height, 1024);
+        assert_eq!(res.data.get(&proposer).unwrap().voting_power, 10);
+        assert_eq!(res.data.total_voting_power(), 15);
+        assert_eq!(serde_json::to_string(&res).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_rotations_and_slots() {
+        let s = r#"{"data":[{"height":"1025","previous":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","next":"0x3c44cdddb6a900fa2b585dd299e03d12fa4293bc","reason":"missed_slot"}]}"#;
+        let res: SequencerRotationsResponse = serde_json::from_str(s).unwrap();
+        assert_eq!(res.data[0].reason, RotationReason::MissedSlot);
+        assert_eq!(serde_json::to_string(&res).unwrap(), s);
+
+        let unknown = s.replace("missed_slot", "governance");
+        let res: SequencerRotationsResponse = serde_json::from_str(&unknown).unwrap();
+        assert_eq!(res.data[0].reason, RotationReason::Unknown);
+
+        let s = r#"{"data":[{"height":"1024","round":"0","proposer":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","status":"proposed","block_hash":"0x4b56f1a6bdceb76d1b843e978c70ab88e38aa19f1a67be851b10ce4eec65b7d4"},{"height":"1025","round":"1","proposer":"0x3c44cdddb6a900fa2b585dd299e03d12fa4293bc","status":"pending"}]}"#;
+        let res: ProposalSlotsResponse = serde_json::from_str(s).unwrap();
+        assert_eq!(res.data[0].status, SlotStatus::Proposed);
+        assert!(res.data[1].block_hash.is_none());
+        assert_eq!(serde_json::to_string(&res).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_permissive() {
+        // unknown fields, mixed-case hex and an omitted empty sequencer list
+        let s = r#"{"data":{"height":"7","proposer":"0x70997970C51812dc3A010C7d01b50e0d17dc79C8","sequencers":[],"epoch":"1"},"execution_optimistic":false}"#;
+        let res: SequencerSetResponse = serde_json::from_str(s).unwrap();
+        assert_eq!(res.data.proposer, address!("0x70997970c51812dc3a010c7d01b50e0d17dc79c8"));
+        let s =
+            r#"{"data":{"height":"7","proposer":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8"}}"#;
+        let res: SequencerSetResponse = serde_json::from_str(s).unwrap();
+        assert!(res.data.sequencers.is_empty());
+    }
+}
@@ -39,6 +39,9 @@ pub mod withdrawals;
 /// Types for the beacon genesis endpoint.
 pub mod genesis;
 
+/// Types for the GOAT sequencer consensus endpoints.
+pub mod goat;
+
 /// BLS signature type
 pub type BlsSignature = FixedBytes<BLS_SIGNATURE_BYTES_LEN>;
 
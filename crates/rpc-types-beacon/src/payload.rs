@@ -156,6 +156,11 @@ struct BeaconPayloadAttributes {
     withdrawals: Option<Vec<Withdrawal>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     parent_beacon_block_root: Option<B256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    btc_block_hash: Option<B256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    btc_height: Option<u64>,
 }
 
 /// A helper module for serializing and deserializing the payload attributes for the beacon API.
@@ -182,6 +187,8 @@ pub mod beacon_api_payload_attributes {
             suggested_fee_recipient: payload_attributes.suggested_fee_recipient,
             withdrawals: payload_attributes.withdrawals.clone(),
             parent_beacon_block_root: payload_attributes.parent_beacon_block_root,
+            btc_block_hash: payload_attributes.btc_block_hash,
+            btc_height: payload_attributes.btc_height,
         };
         beacon_api_payload_attributes.serialize(serializer)
     }
@@ -198,6 +205,8 @@ pub mod beacon_api_payload_attributes {
             suggested_fee_recipient: beacon_api_payload_attributes.suggested_fee_recipient,
             withdrawals: beacon_api_payload_attributes.withdrawals,
             parent_beacon_block_root: beacon_api_payload_attributes.parent_beacon_block_root,
+            btc_block_hash: beacon_api_payload_attributes.btc_block_hash,
+            btc_height: beacon_api_payload_attributes.btc_height,
         })
     }
 }
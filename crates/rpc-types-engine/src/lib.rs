@@ -63,3 +63,7 @@ pub const CAPABILITIES: &[&str] = &[
     "engine_getPayloadBodiesByHashV1",
     "engine_getPayloadBodiesByRangeV1",
 ];
+
+/// The list of GOAT-specific Engine capabilities available over the engine endpoint of goat-geth.
+pub const GOAT_CAPABILITIES: &[&str] =
+    &["engine_goatForkchoiceUpdatedV1", "engine_goatGetPayloadV1", "engine_goatNewPayloadV1"];
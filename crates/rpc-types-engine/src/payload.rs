@@ -6,8 +6,10 @@ use alloc::{
     vec::Vec,
 };
 use alloy_consensus::{
-    constants::MAXIMUM_EXTRA_DATA_SIZE, Blob, Block, BlockBody, BlockHeader, Bytes48, Header,
-    HeaderInfo, Transaction, EMPTY_OMMER_ROOT_HASH,
+    constants::{GOAT_MAXIMUM_EXTRA_DATA_SIZE, MAXIMUM_EXTRA_DATA_SIZE},
+    transaction::goat::{decode_raw_tx, GoatDecodeError, TxGoat},
+    Blob, Block, BlockBody, BlockHeader, Bytes48, GoatHeaderExtra, Header, HeaderInfo, Signed,
+    Transaction, EMPTY_OMMER_ROOT_HASH,
 };
 use alloy_eips::{
     calc_next_block_base_fee,
@@ -1321,6 +1323,35 @@ impl ExecutionPayload {
         }
     }
 
+    /// Converts the [`ExecutionPayload`] of a GOAT chain to [`Block`].
+    ///
+    /// See also [`ExecutionPayload::into_goat_block_raw`].
+    pub fn try_into_goat_block<T: Decodable2718>(self) -> Result<Block<T>, PayloadError> {
+        self.into_goat_block_raw()?.try_map_transactions(|tx| {
+            T::decode_2718_exact(tx.as_ref())
+                .map_err(alloy_rlp::Error::from)
+                .map_err(PayloadError::from)
+        })
+    }
+
+    /// Converts the [`ExecutionPayload`] of a GOAT chain to [`Block`] with raw [`Bytes`]
+    /// transactions.
+    ///
+    /// The `extra_data` of a GOAT header is an encoded [`GoatHeaderExtra`], so instead of
+    /// [`MAXIMUM_EXTRA_DATA_SIZE`] it is limited to [`GOAT_MAXIMUM_EXTRA_DATA_SIZE`] and must
+    /// decode as a header extension.
+    pub fn into_goat_block_raw(mut self) -> Result<Block<Bytes>, PayloadError> {
+        let extra_data = core::mem::take(&mut self.as_v1_mut().extra_data);
+        if extra_data.len() > GOAT_MAXIMUM_EXTRA_DATA_SIZE
+            || GoatHeaderExtra::decode_extra_data(&extra_data).is_err()
+        {
+            return Err(PayloadError::ExtraData(extra_data));
+        }
+        let mut block = self.into_block_raw()?;
+        block.header.extra_data = extra_data;
+        Ok(block)
+    }
+
     /// Returns a reference to the V1 payload.
     pub const fn as_v1(&self) -> &ExecutionPayloadV1 {
         match self {
@@ -1580,6 +1611,17 @@ impl ExecutionPayload {
                 })
         })
     }
+
+    /// Returns an iterator over the goat system transactions in this payload.
+    ///
+    /// Transactions of other types are skipped by looking at their type byte only, goat
+    /// transactions are decoded on the fly.
+    pub fn goat_txs(&self) -> impl Iterator<Item = Result<Signed<TxGoat>, GoatDecodeError>> + '_ {
+        self.transactions()
+            .iter()
+            .map(|tx_bytes| decode_raw_tx(tx_bytes))
+            .filter(|res| !res.as_ref().is_err_and(GoatDecodeError::is_not_goat))
+    }
 }
 
 impl From<ExecutionPayloadV1> for ExecutionPayload {
@@ -1880,6 +1922,23 @@ pub struct PayloadAttributes {
     /// See also <https://github.com/ethereum/execution-apis/blob/main/src/engine/cancun.md#payloadattributesv3>
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub parent_beacon_block_root: Option<B256>,
+    /// Hash of the bitcoin block the new payload is anchored to.
+    ///
+    /// GOAT extension: the consensus layer communicates the bitcoin tip to the execution layer.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub btc_block_hash: Option<B256>,
+    /// Height of the bitcoin block the new payload is anchored to.
+    ///
+    /// GOAT extension, see [`PayloadAttributes::btc_block_hash`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "alloy_serde::quantity::opt"
+        )
+    )]
+    pub btc_height: Option<u64>,
 }
 
 /// This structure contains the result of processing a payload or fork choice update.
@@ -2958,4 +3017,83 @@ mod tests {
             assert_eq!(with_encoded.encoded_bytes(), &transaction);
         }
     }
+
+    #[test]
+    fn roundtrip_payload_with_goat_txs() {
+        use alloy_consensus::{
+            transaction::goat::{NewBtcBlockTx, TxGoatInner},
+            SignableTransaction, TxLegacy,
+        };
+        use alloy_primitives::Signature;
+
+        let goat = TxGoat::new(
+            1,
+            0,
+            TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(1) }),
+        )
+        .into_signed(Signature::test_signature());
+        let legacy = TxLegacy::default().into_signed(Signature::test_signature());
+        let block = Block {
+            header: Header::default(),
+            body: BlockBody {
+                transactions: vec![TxEnvelope::from(goat.clone()), TxEnvelope::from(legacy)],
+                ommers: vec![],
+                withdrawals: None,
+            },
+        };
+
+        let payload = ExecutionPayload::from(ExecutionPayloadV1::from_block_slow(&block));
+        assert_eq!(payload.as_v1().transactions[0][0], 0x60);
+        assert_eq!(payload.goat_txs().collect::<Vec<_>>(), vec![Ok(goat)]);
+
+        let decoded: Block<TxEnvelope> = payload.try_into_block().unwrap();
+        assert_eq!(decoded.body.transactions, block.body.transactions);
+    }
+
+    #[test]
+    fn goat_payload_extra_data() {
+        let extra = GoatHeaderExtra::new(
+            b256!("0x00000000000000000001c6b2f3f7a8d7e3a6b6f2c4d3e2b1a09f8e7d6c5b4a39"),
+            859208,
+        );
+        let block: Block<TxEnvelope> = Block {
+            header: Header { base_fee_per_gas: Some(7), ..Default::default() }
+                .with_goat_extra(extra),
+            body: BlockBody::default(),
+        };
+        assert!(block.header.extra_data.len() > MAXIMUM_EXTRA_DATA_SIZE);
+
+        let payload = ExecutionPayload::from(ExecutionPayloadV1::from_block_slow(&block));
+        assert!(matches!(
+            payload.clone().try_into_block::<TxEnvelope>(),
+            Err(PayloadError::ExtraData(_))
+        ));
+        let decoded: Block<TxEnvelope> = payload.try_into_goat_block().unwrap();
+        assert_eq!(decoded.header.goat_extra(), Ok(extra));
+        assert_eq!(decoded.header.hash_slow(), block.header.hash_slow());
+
+        // vanity data is not a header extension
+        let mut payload = ExecutionPayload::from(ExecutionPayloadV1::from_block_slow(&block));
+        payload.as_v1_mut().extra_data = Bytes::from_static(b"vanity");
+        assert!(matches!(payload.into_goat_block_raw(), Err(PayloadError::ExtraData(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_payload_attributes_btc_anchor() {
+        let s = r#"{"timestamp":"0x6704d2a0","prevRandao":"0x0000000000000000000000000000000000000000000000000000000000000000","suggestedFeeRecipient":"0x0000000000000000000000000000000000000000","parentBeaconBlockRoot":"0x0000000000000000000000000000000000000000000000000000000000000001","btcBlockHash":"0x00000000000000000001c6b2f3f7a8d7e3a6b6f2c4d3e2b1a09f8e7d6c5b4a39","btcHeight":"0xd1c48"}"#;
+        let attributes: PayloadAttributes = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            attributes.btc_block_hash,
+            Some(b256!("0x00000000000000000001c6b2f3f7a8d7e3a6b6f2c4d3e2b1a09f8e7d6c5b4a39"))
+        );
+        assert_eq!(attributes.btc_height, Some(859208));
+        assert_eq!(serde_json::to_string(&attributes).unwrap(), s);
+
+        // the anchor is optional
+        let attributes = PayloadAttributes { btc_block_hash: None, btc_height: None, ..attributes };
+        let s = serde_json::to_string(&attributes).unwrap();
+        assert!(!s.contains("btc"));
+        assert_eq!(serde_json::from_str::<PayloadAttributes>(&s).unwrap(), attributes);
+    }
 }
@@ -628,6 +628,64 @@ impl From<Header> for alloy_serde::WithOtherFields<Header> {
     }
 }
 
+/// The GOAT specific fields of a block, as returned by goat-geth next to the regular header
+/// fields.
+///
+/// The fields end up in the [`OtherFields`](alloy_serde::OtherFields) of a
+/// [`WithOtherFields`](alloy_serde::WithOtherFields) block or header, use
+/// [`GoatBlockFields::from_other`] or [`GoatBlockFields::take_from`] to read them.
+#[cfg(feature = "serde")]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GoatBlockFields {
+    /// The height of the latest confirmed bitcoin block.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub btc_height: Option<u64>,
+    /// The hash of the latest confirmed bitcoin block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub btc_block_hash: Option<B256>,
+}
+
+#[cfg(feature = "serde")]
+impl GoatBlockFields {
+    const BTC_HEIGHT: &'static str = "btcHeight";
+    const BTC_BLOCK_HASH: &'static str = "btcBlockHash";
+
+    /// Reads the goat fields from the other fields of a block or header.
+    ///
+    /// Missing fields are `None`, only malformed fields are an error.
+    pub fn from_other(other: &alloy_serde::OtherFields) -> serde_json::Result<Self> {
+        other.deserialize_as()
+    }
+
+    /// Removes the goat fields from the other fields of a block or header and returns them.
+    ///
+    /// Malformed fields are removed as well.
+    pub fn take_from(other: &mut alloy_serde::OtherFields) -> serde_json::Result<Self> {
+        let fields: alloy_serde::OtherFields = [Self::BTC_HEIGHT, Self::BTC_BLOCK_HASH]
+            .into_iter()
+            .filter_map(|key| other.remove_entry(key))
+            .collect();
+        fields.deserialize_into()
+    }
+
+    /// Returns true if none of the goat fields are present.
+    pub const fn is_empty(&self) -> bool {
+        self.btc_height.is_none() && self.btc_block_hash.is_none()
+    }
+
+    /// Returns the [`GoatHeaderExtra`](alloy_consensus::GoatHeaderExtra) the block is anchored
+    /// to, if both fields are present.
+    pub const fn header_extra(&self) -> Option<alloy_consensus::GoatHeaderExtra> {
+        match (self.btc_block_hash, self.btc_height) {
+            (Some(hash), Some(height)) => Some(alloy_consensus::GoatHeaderExtra::new(hash, height)),
+            _ => None,
+        }
+    }
+}
+
 /// BlockOverrides is a set of header fields to override.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1036,6 +1094,33 @@ mod tests {
         assert_eq!(block, block2);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn goat_block_fields() {
+        let hash = B256::with_last_byte(7);
+        let mut block: alloy_serde::WithOtherFields<Block> = Block::<Transaction>::default().into();
+        block.other.insert("btcHeight".to_string(), "0x3039".into());
+        block.other.insert("btcBlockHash".to_string(), hash.to_string().into());
+        block.other.insert("unrelated".to_string(), true.into());
+
+        let json = serde_json::to_string(&block).unwrap();
+        let mut block = serde_json::from_str::<alloy_serde::WithOtherFields<Block>>(&json).unwrap();
+        let fields = GoatBlockFields::from_other(&block.other).unwrap();
+        assert_eq!(fields, GoatBlockFields { btc_height: Some(12345), btc_block_hash: Some(hash) });
+        assert_eq!(fields.header_extra(), Some(alloy_consensus::GoatHeaderExtra::new(hash, 12345)));
+
+        assert_eq!(GoatBlockFields::take_from(&mut block.other).unwrap(), fields);
+        assert_eq!(block.other.keys().collect::<Vec<_>>(), ["unrelated"]);
+
+        // absent on non-goat blocks
+        let fields = GoatBlockFields::take_from(&mut block.other).unwrap();
+        assert!(fields.is_empty());
+        assert_eq!(fields.header_extra(), None);
+
+        block.other.insert("btcHeight".to_string(), "twelve".into());
+        assert!(GoatBlockFields::from_other(&block.other).is_err());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_missing_uncles_block() {
@@ -1,5 +1,6 @@
 use crate::{BlockNumberOrTag, Log as RpcLog, Transaction};
 use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloy_consensus::transaction::goat::GoatContract;
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{
     keccak256,
@@ -420,12 +421,37 @@ pub struct Filter {
     pub topics: [Topic; 4],
 }
 
+/// A [`SolEvent`] emitted by a goat predeploy, e.g. a `sol!` binding of a bridge event.
+///
+/// This lets the emitting predeploy be derived from the event type along with its topics, see
+/// [`Filter::event_filter`].
+///
+/// [`SolEvent`]: alloy_sol_types::SolEvent
+pub trait GoatEvent: alloy_sol_types::SolEvent {
+    /// The predeploy emitting the event.
+    const CONTRACT: GoatContract;
+}
+
 impl Filter {
     /// Creates a new, empty filter
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates a filter for the goat event `E` in the given block range.
+    ///
+    /// The address and event signature are derived from the event type, see [`GoatEvent`].
+    pub fn event_filter<E: GoatEvent>(
+        from_block: impl Into<BlockNumberOrTag>,
+        to_block: impl Into<BlockNumberOrTag>,
+    ) -> Self {
+        Self::new()
+            .address(*E::CONTRACT.address())
+            .event_signature(E::SIGNATURE_HASH)
+            .from_block(from_block)
+            .to_block(to_block)
+    }
+
     /// Sets the inner filter object
     ///
     /// *NOTE:* ranges are always inclusive
@@ -1620,6 +1646,29 @@ mod tests {
         serde_json::to_value(t).expect("Failed to serialize value")
     }
 
+    #[test]
+    fn goat_event_filter() {
+        use alloy_consensus::transaction::goat::BRIDGE_CONTRACT;
+        use alloy_sol_types::{sol, SolEvent};
+
+        sol! {
+            event Deposited(address indexed target, uint256 amount);
+        }
+        impl GoatEvent for Deposited {
+            const CONTRACT: GoatContract = GoatContract::Bridge;
+        }
+
+        let filter = Filter::event_filter::<Deposited>(10u64, BlockNumberOrTag::Latest);
+        assert_eq!(filter.get_from_block(), Some(10));
+        assert_eq!(filter.get_to_block(), None);
+        assert!(filter.address.matches(&BRIDGE_CONTRACT));
+        assert!(filter.topics[0].matches(&keccak256("Deposited(address,uint256)")));
+        assert_eq!(
+            filter.topics[0].to_value_or_array(),
+            Some(ValueOrArray::Value(Deposited::SIGNATURE_HASH))
+        );
+    }
+
     // <https://hoodi.etherscan.io/block/400001>
     #[test]
     #[cfg(feature = "serde")]
@@ -42,6 +42,9 @@ pub use log::*;
 #[cfg(feature = "serde")]
 pub mod pubsub;
 
+mod reconcile;
+pub use reconcile::{reconcile_block, DepositDiscrepancy, DepositedEvent};
+
 mod raw_log;
 pub use raw_log::{logs_bloom, Log as RawLog};
 
@@ -53,6 +56,9 @@ pub use syncing::*;
 pub mod transaction;
 pub use transaction::*;
 
+#[cfg(all(feature = "serde", any(test, feature = "test-utils")))]
+pub mod test_utils;
+
 mod work;
 pub use work::Work;
 
@@ -1,8 +1,8 @@
 //! Ethereum types for pub-sub
 
 use crate::{Filter, Header, Log, Transaction};
-use alloc::{boxed::Box, format};
-use alloy_primitives::B256;
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use alloy_primitives::{Address, B256, U256};
 use alloy_serde::WithOtherFields;
 
 /// Subscription result.
@@ -20,6 +20,10 @@ pub enum SubscriptionResult<T = Transaction> {
     FullTransaction(Box<T>),
     /// SyncStatus
     SyncState(PubSubSyncStatus),
+    /// Goat bridge deposit
+    GoatDeposit(Box<GoatDeposit>),
+    /// Goat bridge withdrawal
+    GoatWithdrawal(Box<GoatWithdrawal>),
 }
 
 /// Response type for a SyncStatus subscription.
@@ -49,6 +53,51 @@ pub struct SyncStatusMetadata {
     pub highest_block: Option<u64>,
 }
 
+/// Response type for a [`SubscriptionKind::GoatDeposits`] subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatDeposit {
+    /// The hash of the goat deposit transaction.
+    pub transaction_hash: B256,
+    /// The number of the block that includes the deposit transaction.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub block_number: u64,
+    /// The bitcoin transaction id of the deposit.
+    pub btc_txid: B256,
+    /// The output index of the deposit in the bitcoin transaction.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub txout: u32,
+    /// The goat address credited by the deposit.
+    pub address: Address,
+    /// The deposited amount, in wei.
+    pub amount: U256,
+}
+
+/// Response type for a [`SubscriptionKind::GoatWithdrawals`] subscription.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatWithdrawal {
+    /// The hash of the transaction that requested the withdrawal.
+    pub transaction_hash: B256,
+    /// The number of the block that includes the withdrawal request.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub block_number: u64,
+    /// The id assigned to the withdrawal by the bridge contract.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub id: u64,
+    /// The goat address that requested the withdrawal.
+    pub address: Address,
+    /// The withdrawn amount, in wei.
+    pub amount: U256,
+    /// The maximum bitcoin transaction fee rate accepted by the sender, in sat/vbyte.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub max_tx_price: u64,
+    /// The bitcoin address receiving the withdrawal.
+    pub receiver: String,
+}
+
 #[cfg(feature = "serde")]
 impl<T> serde::Serialize for SubscriptionResult<T>
 where
@@ -64,6 +113,8 @@ where
             Self::TransactionHash(ref hash) => hash.serialize(serializer),
             Self::FullTransaction(ref tx) => tx.serialize(serializer),
             Self::SyncState(ref sync) => sync.serialize(serializer),
+            Self::GoatDeposit(ref deposit) => deposit.serialize(serializer),
+            Self::GoatWithdrawal(ref withdrawal) => withdrawal.serialize(serializer),
         }
     }
 }
@@ -101,6 +152,38 @@ pub enum SubscriptionKind {
     /// indicating that the synchronization has started (true), finished (false) or an object with
     /// various progress indicators.
     Syncing,
+    /// Goat bridge deposits subscription.
+    ///
+    /// Fires a notification for every bitcoin deposit processed by a goat deposit transaction in
+    /// a newly imported block. The notification is a [`GoatDeposit`].
+    GoatDeposits,
+    /// Goat bridge withdrawals subscription.
+    ///
+    /// Fires a notification for every withdrawal requested from the goat bridge contract in a
+    /// newly imported block. The notification is a [`GoatWithdrawal`].
+    GoatWithdrawals,
+}
+
+/// Parameters of the [`SubscriptionKind::GoatDeposits`] and
+/// [`SubscriptionKind::GoatWithdrawals`] subscriptions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", deny_unknown_fields))]
+pub struct GoatSubscriptionFilter {
+    /// The goat addresses to follow. An empty list matches every address.
+    pub addresses: Vec<Address>,
+}
+
+impl GoatSubscriptionFilter {
+    /// Creates a filter following the given addresses.
+    pub const fn new(addresses: Vec<Address>) -> Self {
+        Self { addresses }
+    }
+
+    /// Returns `true` if the filter matches the given address.
+    pub fn matches(&self, address: &Address) -> bool {
+        self.addresses.is_empty() || self.addresses.contains(address)
+    }
 }
 
 /// Any additional parameters for a subscription.
@@ -113,6 +196,8 @@ pub enum Params {
     Logs(Box<Filter>),
     /// Boolean parameter for new pending transactions.
     Bool(bool),
+    /// Goat bridge parameters.
+    Goat(GoatSubscriptionFilter),
 }
 
 impl Params {
@@ -127,6 +212,12 @@ impl Params {
     pub const fn is_logs(&self) -> bool {
         matches!(self, Self::Logs(_))
     }
+
+    /// Returns true if it's a goat bridge parameter.
+    #[inline]
+    pub const fn is_goat(&self) -> bool {
+        matches!(self, Self::Goat(_))
+    }
 }
 
 impl From<Filter> for Params {
@@ -135,6 +226,12 @@ impl From<Filter> for Params {
     }
 }
 
+impl From<GoatSubscriptionFilter> for Params {
+    fn from(filter: GoatSubscriptionFilter) -> Self {
+        Self::Goat(filter)
+    }
+}
+
 impl From<bool> for Params {
     fn from(value: bool) -> Self {
         Self::Bool(value)
@@ -151,6 +248,7 @@ impl serde::Serialize for Params {
             Self::None => (&[] as &[serde_json::Value]).serialize(serializer),
             Self::Logs(logs) => logs.serialize(serializer),
             Self::Bool(full) => full.serialize(serializer),
+            Self::Goat(filter) => filter.serialize(serializer),
         }
     }
 }
@@ -173,9 +271,14 @@ impl<'a> serde::Deserialize<'a> for Params {
             return Ok(val.into());
         }
 
-        serde_json::from_value::<Filter>(v)
-            .map(Into::into)
-            .map_err(|e| D::Error::custom(format!("Invalid Pub-Sub parameters: {e}")))
+        match serde_json::from_value::<Filter>(v.clone()) {
+            Ok(filter) => Ok(filter.into()),
+            // Goat bridge parameters are rejected by the strict filter deserializer, so they are
+            // only tried when the value is not a log filter.
+            Err(e) => serde_json::from_value::<GoatSubscriptionFilter>(v)
+                .map(Into::into)
+                .map_err(|_| D::Error::custom(format!("Invalid Pub-Sub parameters: {e}"))),
+        }
     }
 }
 
@@ -272,4 +375,76 @@ mod tests {
         let expected = serde_json::to_string(&filter).unwrap();
         assert_eq!(serialized, expected);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn goat_subscription_kinds() {
+        assert_eq!(
+            serde_json::to_string(&SubscriptionKind::GoatDeposits).unwrap(),
+            r#""goatDeposits""#
+        );
+        assert_eq!(
+            serde_json::from_str::<SubscriptionKind>(r#""goatWithdrawals""#).unwrap(),
+            SubscriptionKind::GoatWithdrawals
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn params_serde_goat() {
+        let address = alloy_primitives::address!("0x70997970c51812dc3a010c7d01b50e0d17dc79c8");
+        let param: Params = GoatSubscriptionFilter::new(alloc::vec![address]).into();
+        assert!(param.is_goat());
+
+        let serialized = serde_json::to_string(&(SubscriptionKind::GoatDeposits, &param)).unwrap();
+        assert_eq!(
+            serialized,
+            r#"["goatDeposits",{"addresses":["0x70997970c51812dc3a010c7d01b50e0d17dc79c8"]}]"#
+        );
+        let s: Params =
+            serde_json::from_str(r#"{"addresses":["0x70997970c51812dc3a010c7d01b50e0d17dc79c8"]}"#)
+                .unwrap();
+        assert_eq!(s, param);
+
+        // A log filter is still a log filter.
+        let s: Params =
+            serde_json::from_str(r#"{"address":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8"}"#)
+                .unwrap();
+        assert!(s.is_logs());
+
+        let err = serde_json::from_str::<Params>(r#"{"unknown":true}"#).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid Pub-Sub parameters"));
+    }
+
+    #[test]
+    fn goat_subscription_filter_matches() {
+        let address = Address::with_last_byte(1);
+        assert!(GoatSubscriptionFilter::default().matches(&address));
+        assert!(GoatSubscriptionFilter::new(alloc::vec![address]).matches(&address));
+        assert!(!GoatSubscriptionFilter::new(alloc::vec![Address::ZERO]).matches(&address));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn goat_subscription_results() {
+        let deposit = r#"{"transactionHash":"0x2bd7ae4fd7a6eb2d3edf12b1a9e6d9b9c2ab5c5e9c0f3bba0b3e8db3a2b8f3c4","blockNumber":"0x10","btcTxid":"0x15bb8c8dd1d1ee4d26f4a54e5a1ab7e13ed6b6b1a2c1b1e5b9d1b1f5a1a1b1c1","txout":"0x1","address":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","amount":"0xde0b6b3a7640000"}"#;
+        let result: SubscriptionResult = serde_json::from_str(deposit).unwrap();
+        let SubscriptionResult::GoatDeposit(ref parsed) = result else {
+            panic!("expected goat deposit, got {result:?}");
+        };
+        assert_eq!(parsed.block_number, 16);
+        assert_eq!(parsed.txout, 1);
+        assert_eq!(parsed.amount, U256::from(1_000_000_000_000_000_000u64));
+        assert_eq!(serde_json::to_string(&result).unwrap(), deposit);
+
+        let withdrawal = r#"{"transactionHash":"0x2bd7ae4fd7a6eb2d3edf12b1a9e6d9b9c2ab5c5e9c0f3bba0b3e8db3a2b8f3c4","blockNumber":"0x11","id":"0x2","address":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","amount":"0xde0b6b3a7640000","maxTxPrice":"0xa","receiver":"bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"}"#;
+        let result: SubscriptionResult = serde_json::from_str(withdrawal).unwrap();
+        let SubscriptionResult::GoatWithdrawal(ref parsed) = result else {
+            panic!("expected goat withdrawal, got {result:?}");
+        };
+        assert_eq!(parsed.id, 2);
+        assert_eq!(parsed.max_tx_price, 10);
+        assert_eq!(parsed.receiver, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        assert_eq!(serde_json::to_string(&result).unwrap(), withdrawal);
+    }
 }
@@ -0,0 +1,254 @@
+//! Reconciliation of the goat deposits of a block with the logs of its receipts.
+
+use crate::{Block, GoatEvent, Transaction, TransactionReceipt};
+use alloc::vec::Vec;
+use alloy_consensus::{
+    transaction::goat::{AsTxGoat, Mint},
+    Transaction as TransactionTrait, TxReceipt,
+};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_network_primitives::TransactionResponse;
+use alloy_primitives::{map::B256HashMap, TxHash};
+
+/// The [`GoatEvent`] emitted by the bridge predeploy for every processed deposit, see
+/// [`reconcile_block`].
+pub trait DepositedEvent: GoatEvent {
+    /// Returns the deposit reported by the event.
+    fn mint(&self) -> Mint;
+}
+
+/// A discrepancy between the goat deposits of a block and the logs of its receipts, as returned by
+/// [`reconcile_block`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositDiscrepancy {
+    /// The receipt of a deposit transaction is missing.
+    MissingReceipt {
+        /// The hash of the deposit transaction.
+        tx_hash: TxHash,
+    },
+    /// A deposit transaction did not emit a deposit log.
+    MissingLog {
+        /// The hash of the deposit transaction.
+        tx_hash: TxHash,
+        /// The deposit of the transaction.
+        deposit: Mint,
+    },
+    /// The deposit log of a deposit transaction does not match the deposit.
+    Mismatch {
+        /// The hash of the deposit transaction.
+        tx_hash: TxHash,
+        /// The deposit of the transaction.
+        deposit: Mint,
+        /// The deposit reported by the log.
+        log: Mint,
+    },
+    /// A deposit log was emitted by a transaction that is not a deposit, or in excess of the
+    /// deposit of the transaction.
+    UnexpectedLog {
+        /// The hash of the transaction emitting the log.
+        tx_hash: TxHash,
+        /// The deposit reported by the log.
+        log: Mint,
+    },
+}
+
+impl DepositDiscrepancy {
+    /// Returns the hash of the transaction with the discrepancy.
+    pub const fn tx_hash(&self) -> TxHash {
+        match self {
+            Self::MissingReceipt { tx_hash }
+            | Self::MissingLog { tx_hash, .. }
+            | Self::Mismatch { tx_hash, .. }
+            | Self::UnexpectedLog { tx_hash, .. } => *tx_hash,
+        }
+    }
+}
+
+/// Checks that every goat deposit of the block has a matching `E` log in its receipt, and that
+/// every `E` log belongs to a deposit with the same recipient, amount and tax.
+///
+/// Only the logs emitted by the predeploy of `E` are considered, and the receipts are matched to
+/// the transactions by hash. The block must contain the full transactions. Returns the
+/// discrepancies in transaction order, followed by those of the receipts of transactions that are
+/// not in the block. An empty result means the block is consistent.
+pub fn reconcile_block<E: DepositedEvent>(
+    block: &Block<Transaction<impl TransactionTrait + Encodable2718 + AsTxGoat>, impl Sized>,
+    receipts: &[TransactionReceipt<impl TxReceipt<Log: AsRef<alloy_primitives::Log>>>],
+) -> Vec<DepositDiscrepancy> {
+    let mut logs = receipts
+        .iter()
+        .map(|receipt| {
+            let logs = receipt
+                .logs()
+                .iter()
+                .map(AsRef::as_ref)
+                .filter(|log| log.address == *E::CONTRACT.address())
+                .filter_map(|log| E::decode_log(log).ok())
+                .map(|log| log.data.mint());
+            (receipt.transaction_hash, logs.collect::<Vec<_>>())
+        })
+        .collect::<B256HashMap<_>>();
+
+    let mut discrepancies = Vec::new();
+    for tx in block.transactions.txns() {
+        let tx_hash = tx.tx_hash();
+        let deposit = tx.inner.inner().as_tx_goat().and_then(|tx| tx.inner.deposit());
+        let Some(logs) = logs.remove(&tx_hash) else {
+            if deposit.is_some() {
+                discrepancies.push(DepositDiscrepancy::MissingReceipt { tx_hash });
+            }
+            continue;
+        };
+
+        let mut logs = logs.into_iter();
+        if let Some(deposit) = deposit {
+            match logs.next() {
+                None => discrepancies.push(DepositDiscrepancy::MissingLog { tx_hash, deposit }),
+                Some(log) if log != deposit => {
+                    discrepancies.push(DepositDiscrepancy::Mismatch { tx_hash, deposit, log })
+                }
+                Some(_) => {}
+            }
+        }
+        discrepancies.extend(logs.map(|log| DepositDiscrepancy::UnexpectedLog { tx_hash, log }));
+    }
+
+    // receipts of transactions that are not in the block
+    for receipt in receipts {
+        let Some(logs) = logs.remove(&receipt.transaction_hash) else { continue };
+        let tx_hash = receipt.transaction_hash;
+        discrepancies
+            .extend(logs.into_iter().map(|log| DepositDiscrepancy::UnexpectedLog { tx_hash, log }));
+    }
+    discrepancies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockTransactions;
+    use alloy_consensus::{
+        transaction::{
+            goat::{DepositTx, GoatContract, NewBtcBlockTx, TxGoat, TxGoatInner, BRIDGE_CONTRACT},
+            Recovered,
+        },
+        Receipt, ReceiptEnvelope, SignableTransaction, TxEnvelope,
+    };
+    use alloy_primitives::{Address, LogData, Signature, B256, U256};
+    use alloy_sol_types::{sol, SolEvent};
+
+    sol! {
+        event Deposited(address indexed target, uint256 amount, uint256 tax);
+    }
+
+    impl GoatEvent for Deposited {
+        const CONTRACT: GoatContract = GoatContract::Bridge;
+    }
+
+    impl DepositedEvent for Deposited {
+        fn mint(&self) -> Mint {
+            Mint { target: self.target, amount: self.amount, tax: self.tax }
+        }
+    }
+
+    fn deposit(n: u8) -> DepositTx {
+        DepositTx {
+            tx_id: B256::with_last_byte(n),
+            target: Address::with_last_byte(n),
+            amount: U256::from(n) * U256::from(10),
+            tax: U256::from(n),
+            ..Default::default()
+        }
+    }
+
+    fn tx(nonce: u64, inner: TxGoatInner) -> Transaction {
+        let tx = TxGoat::new(48816, nonce, inner);
+        let sender = tx.sender();
+        let envelope = TxEnvelope::from(tx.into_signed(Signature::test_signature()));
+        Transaction {
+            inner: Recovered::new_unchecked(envelope, sender),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+        }
+    }
+
+    fn receipt(tx: &Transaction, logs: &[(Address, DepositTx)]) -> TransactionReceipt {
+        let logs = logs
+            .iter()
+            .map(|(address, deposit)| {
+                let event =
+                    Deposited { target: deposit.target, amount: deposit.amount, tax: deposit.tax };
+                let data: LogData = event.encode_log_data();
+                crate::Log {
+                    inner: alloy_primitives::Log { address: *address, data },
+                    ..Default::default()
+                }
+            })
+            .collect();
+        TransactionReceipt {
+            inner: ReceiptEnvelope::Legacy(
+                Receipt { status: true.into(), cumulative_gas_used: 0, logs }.into(),
+            ),
+            transaction_hash: tx.tx_hash(),
+            transaction_index: None,
+            block_hash: None,
+            block_number: None,
+            gas_used: 0,
+            effective_gas_price: 0,
+            blob_gas_used: None,
+            blob_gas_price: None,
+            from: Address::ZERO,
+            to: None,
+            contract_address: None,
+        }
+    }
+
+    #[test]
+    fn reconcile_deposits() {
+        let txs = vec![
+            tx(0, TxGoatInner::NewBtcBlock(NewBtcBlockTx::default())),
+            tx(1, TxGoatInner::Deposit(deposit(1))),
+            tx(2, TxGoatInner::Deposit(deposit(2))),
+            tx(3, TxGoatInner::Deposit(deposit(3))),
+            tx(4, TxGoatInner::Deposit(deposit(4))),
+        ];
+        let mut receipts = vec![
+            // logs of other contracts are ignored
+            receipt(&txs[0], &[(Address::ZERO, deposit(1))]),
+            receipt(&txs[1], &[(BRIDGE_CONTRACT, deposit(1))]),
+            receipt(&txs[2], &[]),
+            receipt(&txs[3], &[(BRIDGE_CONTRACT, deposit(4)), (BRIDGE_CONTRACT, deposit(3))]),
+        ];
+        let block = Block::<Transaction>::empty(Default::default())
+            .with_transactions(BlockTransactions::Full(txs.clone()));
+        let hash = |i: usize| txs[i].tx_hash();
+
+        let mint = |n| TxGoatInner::Deposit(deposit(n)).deposit().unwrap();
+        assert_eq!(
+            reconcile_block::<Deposited>(&block, &receipts),
+            [
+                DepositDiscrepancy::MissingLog { tx_hash: hash(2), deposit: mint(2) },
+                DepositDiscrepancy::Mismatch { tx_hash: hash(3), deposit: mint(3), log: mint(4) },
+                DepositDiscrepancy::UnexpectedLog { tx_hash: hash(3), log: mint(3) },
+                DepositDiscrepancy::MissingReceipt { tx_hash: hash(4) },
+            ]
+        );
+
+        // a deposit log without a deposit transaction
+        receipts[0] = receipt(&txs[0], &[(BRIDGE_CONTRACT, deposit(1))]);
+        let other = tx(5, TxGoatInner::Deposit(deposit(5)));
+        receipts.push(receipt(&other, &[(BRIDGE_CONTRACT, deposit(5))]));
+        let discrepancies = reconcile_block::<Deposited>(&block, &receipts);
+        assert_eq!(
+            discrepancies[0],
+            DepositDiscrepancy::UnexpectedLog { tx_hash: hash(0), log: mint(1) }
+        );
+        assert_eq!(
+            discrepancies.last(),
+            Some(&DepositDiscrepancy::UnexpectedLog { tx_hash: other.tx_hash(), log: mint(5) })
+        );
+        assert_eq!(discrepancies.len(), 6);
+    }
+}
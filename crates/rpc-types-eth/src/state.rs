@@ -2,6 +2,7 @@
 
 use crate::BlockOverrides;
 use alloc::boxed::Box;
+use alloy_consensus::transaction::goat::{LOCKING_EXECUTOR, RELAYER_EXECUTOR};
 use alloy_eips::eip7702::constants::EIP7702_DELEGATION_DESIGNATOR;
 use alloy_primitives::{
     map::{AddressHashMap, B256HashMap},
@@ -115,6 +116,19 @@ impl StateOverridesBuilder {
         self.overrides.entry(address).or_default().set_state_diff(state_diff);
         self
     }
+
+    /// Configures the account overrides required to `eth_call` goat system actions, such as
+    /// deposits and unlocks, as the goat executors on a fork of a non-goat chain.
+    ///
+    /// Both executors are funded with `u128::MAX` wei to pay for gas, and any code at their
+    /// addresses is cleared so that they are treated as externally owned accounts. The
+    /// predeploys themselves are not overridden, their bytecode can be installed with
+    /// [`with_code`](Self::with_code) if the fork does not contain them.
+    pub fn with_goat_executor_overrides(self) -> Self {
+        [RELAYER_EXECUTOR, LOCKING_EXECUTOR].into_iter().fold(self, |builder, executor| {
+            builder.with_balance(executor, U256::from(u128::MAX)).with_code(executor, Bytes::new())
+        })
+    }
 }
 
 impl FromIterator<(Address, AccountOverride)> for StateOverridesBuilder {
@@ -345,6 +359,24 @@ mod tests {
     use alloy_primitives::{address, map::B256HashMap, Bytes, B256, U256};
     use similar_asserts::assert_eq;
 
+    #[test]
+    fn test_goat_executor_overrides() {
+        let overrides = StateOverridesBuilder::default()
+            .with_balance(RELAYER_EXECUTOR, U256::from(1))
+            .with_nonce(RELAYER_EXECUTOR, 7)
+            .with_goat_executor_overrides()
+            .build();
+
+        assert_eq!(overrides.len(), 2);
+        for executor in [RELAYER_EXECUTOR, LOCKING_EXECUTOR] {
+            let account = &overrides[&executor];
+            assert_eq!(account.balance, Some(U256::from(u128::MAX)));
+            assert_eq!(account.code, Some(Bytes::new()));
+        }
+        assert_eq!(overrides[&RELAYER_EXECUTOR].nonce, Some(7));
+        assert_eq!(overrides[&LOCKING_EXECUTOR].nonce, None);
+    }
+
     #[test]
     fn test_default_account_override() {
         let acc_override = AccountOverride::default();
@@ -0,0 +1,227 @@
+//! Golden-file snapshot helpers for the JSON forms of the RPC types.
+//!
+//! Snapshots are stored as pretty-printed JSON files. Snapshots are only ever written if
+//! [`UPDATE_SNAPSHOTS_ENV`] is set, to create new snapshots or to overwrite existing ones after an
+//! intentional change, so that a missing snapshot never passes silently.
+
+use crate::{Log, Transaction, TransactionReceipt};
+use alloc::vec::Vec;
+use alloy_consensus::{
+    transaction::{
+        goat::{
+            Cancel2Tx, CompleteUnlockTx, DepositTx, DistributeRewardTx, NewBtcBlockTx, PaidTx,
+            TxGoat, TxGoatInner, BRIDGE_CONTRACT, RELAYER_EXECUTOR,
+        },
+        Recovered,
+    },
+    Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom, SignableTransaction, TxEnvelope,
+};
+use alloy_primitives::{address, b256, bytes, Address, LogData, Signature, B256, U256};
+use core::fmt::Debug;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fs, path::Path};
+
+/// The environment variable that, if set, writes missing snapshots and overwrites existing ones.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "ALLOY_UPDATE_SNAPSHOTS";
+
+/// Asserts that the JSON form of `value` matches the snapshot at `path`.
+///
+/// The snapshot must also deserialize back into `value`, which catches fields that are
+/// serialized but skipped or renamed on deserialization, and vice versa.
+///
+/// # Panics
+///
+/// Panics if the snapshot does not match, or if it is missing.
+#[track_caller]
+pub fn assert_json_snapshot<T>(path: impl AsRef<Path>, value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let path = path.as_ref();
+    let mut json = serde_json::to_string_pretty(value).unwrap();
+    json.push('\n');
+
+    let snapshot = if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(path, &json).unwrap();
+        json.clone()
+    } else {
+        fs::read_to_string(path).unwrap_or_else(|err| {
+            panic!(
+                "missing snapshot {}: {err}, set {UPDATE_SNAPSHOTS_ENV} to write it",
+                path.display()
+            )
+        })
+    };
+
+    assert!(
+        snapshot == json,
+        "snapshot {} does not match, set {UPDATE_SNAPSHOTS_ENV} to overwrite it\n\
+         --- snapshot\n{snapshot}\n+++ actual\n{json}",
+        path.display()
+    );
+    let decoded: T = serde_json::from_str(&snapshot).unwrap();
+    assert_eq!(&decoded, value, "snapshot {} does not roundtrip", path.display());
+}
+
+/// A named goat transaction for each goat action, as returned by `eth_getTransactionByHash`.
+pub fn goat_transactions() -> Vec<(&'static str, Transaction)> {
+    let recipient = address!("0xd8da6bf26964af9d7eed9e03e53415d37aa96045");
+    let btc_txid = b256!("0xb0b8d5a7b8a1e4a4a8bdd0cbf6fc46f6d1d31ae4b8f3f7b0d0a6b2a4f0a4b1c2");
+    let ether = U256::from(10u128.pow(18));
+
+    [
+        (
+            "deposit",
+            TxGoatInner::Deposit(DepositTx {
+                tx_id: btc_txid,
+                tx_out: 2,
+                target: recipient,
+                amount: ether,
+                tax: U256::from(10u128.pow(15)),
+            }),
+        ),
+        ("cancel2", TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(7) })),
+        (
+            "paid",
+            TxGoatInner::Paid(PaidTx {
+                id: U256::from(7),
+                tx_id: btc_txid,
+                tx_out: 1,
+                amount: ether,
+            }),
+        ),
+        ("new_btc_block", TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: btc_txid })),
+        (
+            "complete_unlock",
+            TxGoatInner::CompleteUnlock(CompleteUnlockTx {
+                id: U256::from(3),
+                recipient,
+                token: Address::ZERO,
+                amount: ether,
+            }),
+        ),
+        (
+            "distribute_reward",
+            TxGoatInner::DistributeReward(DistributeRewardTx {
+                id: U256::from(4),
+                recipient,
+                goat: ether,
+                gas_reward: U256::from(10u128.pow(16)),
+            }),
+        ),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, (name, inner))| (name, goat_transaction(i as u64, inner)))
+    .collect()
+}
+
+fn goat_transaction(index: u64, inner: TxGoatInner) -> Transaction {
+    let tx = TxGoat::new(48816, index, inner);
+    let sender = tx.sender();
+    let envelope = TxEnvelope::from(tx.into_signed(Signature::new(U256::ZERO, U256::ZERO, false)));
+    Transaction {
+        inner: Recovered::new_unchecked(envelope, sender),
+        block_hash: Some(B256::with_last_byte(1)),
+        block_number: Some(100),
+        transaction_index: Some(index),
+        effective_gas_price: Some(0),
+    }
+}
+
+/// A named receipt for each goat receipt shape, as returned by `eth_getTransactionReceipt`.
+pub fn goat_receipts() -> Vec<(&'static str, TransactionReceipt)> {
+    let log = Log {
+        inner: alloy_primitives::Log {
+            address: BRIDGE_CONTRACT,
+            data: LogData::new_unchecked(
+                vec![b256!("0x2e7e5a1b39a8bf2d8e04cdb0de0f1d6e8b4c4aa2d25b3d5e6f3b2b2c1c0c1d1e")],
+                bytes!("0000000000000000000000000000000000000000000000000de0b6b3a7640000"),
+            ),
+        },
+        block_hash: Some(B256::with_last_byte(1)),
+        block_number: Some(100),
+        block_timestamp: None,
+        transaction_hash: Some(B256::with_last_byte(2)),
+        transaction_index: Some(0),
+        log_index: Some(0),
+        removed: false,
+    };
+
+    [
+        ("success", Eip658Value::Eip658(true), vec![log]),
+        ("failure", Eip658Value::Eip658(false), vec![]),
+    ]
+    .into_iter()
+    .map(|(name, status, logs)| {
+        let receipt = Receipt { status, cumulative_gas_used: 0, logs };
+        let receipt = TransactionReceipt {
+            inner: ReceiptEnvelope::Goat(ReceiptWithBloom::from(receipt)),
+            transaction_hash: B256::with_last_byte(2),
+            transaction_index: Some(0),
+            block_hash: Some(B256::with_last_byte(1)),
+            block_number: Some(100),
+            gas_used: 0,
+            effective_gas_price: 0,
+            blob_gas_used: None,
+            blob_gas_price: None,
+            from: RELAYER_EXECUTOR,
+            to: Some(BRIDGE_CONTRACT),
+            contract_address: None,
+        };
+        (name, receipt)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoatTransactionRequest;
+    use std::path::PathBuf;
+
+    fn snapshot_path(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata/goat").join(name)
+    }
+
+    #[test]
+    fn goat_transaction_snapshots() {
+        for (name, tx) in goat_transactions() {
+            assert_json_snapshot(snapshot_path(&format!("tx_{name}.json")), &tx);
+
+            let request = GoatTransactionRequest::from(tx.inner.into_inner());
+            assert_json_snapshot(snapshot_path(&format!("request_{name}.json")), &request);
+        }
+    }
+
+    #[test]
+    fn goat_receipt_snapshots() {
+        for (name, receipt) in goat_receipts() {
+            assert_json_snapshot(snapshot_path(&format!("receipt_{name}.json")), &receipt);
+        }
+    }
+
+    #[test]
+    fn snapshot_mismatch() {
+        if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+            return;
+        }
+        let dir = std::env::temp_dir().join(format!("alloy-snapshot-{}", std::process::id()));
+        let path = dir.join("value.json");
+
+        // a missing snapshot fails the assertion and is not written
+        let missing = std::panic::catch_unwind(|| assert_json_snapshot(&path, &1u64));
+        assert!(missing.is_err());
+        assert!(!path.exists());
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "1\n").unwrap();
+        assert_json_snapshot(&path, &1u64);
+        assert!(std::panic::catch_unwind(|| assert_json_snapshot(&path, &2u64)).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
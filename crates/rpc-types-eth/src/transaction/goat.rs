@@ -0,0 +1,344 @@
+//! GOAT system transaction request type.
+
+use crate::TransactionRequest;
+use alloc::{vec, vec::Vec};
+use alloy_consensus::{
+    error::ValueError,
+    transaction::goat::{decode_tx, Action, Module, TxGoat, GOAT_ACTIONS, GOAT_TX_TYPE_ID},
+    TxEnvelope, TypedTransaction,
+};
+use alloy_network_primitives::TransactionBuilderGoat;
+use alloy_primitives::ChainId;
+use core::ops::{Deref, DerefMut};
+
+/// A [`TransactionRequest`] that can also describe a GOAT system transaction.
+///
+/// A goat system transaction is built from the `chainId`, `nonce` and `input` of the request,
+/// along with its goat `module` and `action`. Any other request is built as a regular Ethereum
+/// transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GoatTransactionRequest {
+    /// The underlying transaction request.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub inner: TransactionRequest,
+    /// The goat module of a system transaction.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub module: Option<Module>,
+    /// The goat action of a system transaction.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub action: Option<Action>,
+}
+
+impl GoatTransactionRequest {
+    /// Creates a new request wrapping the given transaction request.
+    pub const fn new(inner: TransactionRequest) -> Self {
+        Self { inner, module: None, action: None }
+    }
+
+    /// Consumes the type and returns the wrapped transaction request.
+    pub fn into_inner(self) -> TransactionRequest {
+        self.inner
+    }
+
+    /// Returns true if the request describes a goat system transaction, i.e. if it has a goat
+    /// module, action, or transaction type.
+    pub fn is_goat(&self) -> bool {
+        self.module.is_some()
+            || self.action.is_some()
+            || self.inner.transaction_type == Some(GOAT_TX_TYPE_ID)
+    }
+
+    /// Checks if all the fields required to build a goat system transaction are set.
+    ///
+    /// Returns the names of the missing fields otherwise.
+    pub fn complete_goat(&self) -> Result<(), Vec<&'static str>> {
+        let mut missing = vec![];
+        if self.inner.chain_id.is_none() {
+            missing.push("chain_id");
+        }
+        if self.inner.nonce.is_none() {
+            missing.push("nonce");
+        }
+        if self.inner.input.input().is_none() {
+            missing.push("input");
+        }
+        if self.module.is_none() {
+            missing.push("module");
+        }
+        if self.action.is_none() {
+            missing.push("action");
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
+    /// Builds a goat system transaction from the request.
+    ///
+    /// Returns an error if a required field is missing, if the input is not valid calldata of the
+    /// goat action, or if the recipient is not the predeploy called by the action.
+    pub fn build_goat(self) -> Result<TxGoat, ValueError<Self>> {
+        if self.complete_goat().is_err() {
+            return Err(ValueError::new_static(
+                self,
+                "Missing required fields for goat transaction",
+            ));
+        }
+        let (Some(chain_id), Some(nonce), Some(input), Some(module), Some(action)) = (
+            self.inner.chain_id,
+            self.inner.nonce,
+            self.inner.input.input(),
+            self.module,
+            self.action,
+        ) else {
+            unreachable!("checked by complete_goat")
+        };
+
+        let inner = match decode_tx(module, action, input) {
+            Ok(inner) => inner,
+            Err(_) => return Err(ValueError::new_static(self, "Invalid goat transaction input")),
+        };
+        if self.inner.to.is_some_and(|to| to.to() != Some(&inner.contract())) {
+            return Err(ValueError::new_static(
+                self,
+                "Goat transaction recipient does not match the called predeploy",
+            ));
+        }
+        Ok(TxGoat::new(chain_id, nonce, inner))
+    }
+
+    /// Completes a partially specified goat system transaction the way the node would, so that it
+    /// can be built and signed offline, without any RPC call.
+    ///
+    /// The `chainId` and `nonce` are only set if missing. A missing module and action are derived
+    /// from the method id of the input. The sender, recipient, value and type are set to those of
+    /// the action, and the gas limit and price are zeroed, since system transactions do not pay
+    /// for gas.
+    ///
+    /// Returns an error if the input is not valid calldata of a goat action, or if it conflicts
+    /// with the module, action or recipient of the request.
+    pub fn fill_defaults(
+        mut self,
+        chain_id: ChainId,
+        nonce: u64,
+    ) -> Result<Self, ValueError<Self>> {
+        self.inner.chain_id.get_or_insert(chain_id);
+        self.inner.nonce.get_or_insert(nonce);
+
+        let selector = self.inner.input.input().and_then(|input| input.get(..4));
+        let Some(meta) = GOAT_ACTIONS.iter().find(|meta| {
+            selector == Some(meta.selector.as_slice())
+                && self.module.is_none_or(|module| module == meta.module)
+                && self.action.is_none_or(|action| action == meta.action)
+        }) else {
+            return Err(ValueError::new_static(self, "Unknown goat action"));
+        };
+        self.module = Some(meta.module);
+        self.action = Some(meta.action);
+
+        let mut request = Self::from(self.build_goat()?);
+        request.inner.gas = Some(0);
+        request.inner.gas_price = Some(0);
+        Ok(request)
+    }
+}
+
+impl Deref for GoatTransactionRequest {
+    type Target = TransactionRequest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for GoatTransactionRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl TransactionBuilderGoat for GoatTransactionRequest {
+    fn module(&self) -> Option<Module> {
+        self.module
+    }
+
+    fn set_module(&mut self, module: Module) {
+        self.module = Some(module);
+    }
+
+    fn action(&self) -> Option<Action> {
+        self.action
+    }
+
+    fn set_action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl From<TransactionRequest> for GoatTransactionRequest {
+    fn from(inner: TransactionRequest) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<GoatTransactionRequest> for TransactionRequest {
+    fn from(request: GoatTransactionRequest) -> Self {
+        request.inner
+    }
+}
+
+impl From<TxGoat> for GoatTransactionRequest {
+    fn from(tx: TxGoat) -> Self {
+        Self { module: Some(tx.module), action: Some(tx.action), inner: tx.into() }
+    }
+}
+
+impl From<TypedTransaction> for GoatTransactionRequest {
+    fn from(tx: TypedTransaction) -> Self {
+        match tx {
+            TypedTransaction::Goat(tx) => tx.into(),
+            tx => Self::new(tx.into()),
+        }
+    }
+}
+
+impl From<TxEnvelope> for GoatTransactionRequest {
+    fn from(envelope: TxEnvelope) -> Self {
+        match envelope {
+            TxEnvelope::Goat(tx) => tx.strip_signature().into(),
+            envelope => Self::new(envelope.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransactionInput;
+    use alloy_consensus::transaction::goat::{
+        NewBtcBlockTx, TxGoatInner, BITCOIN_CONTRACT, BRIDGE_CONTRACT, BRIDGE_MODULE,
+        RELAYER_EXECUTOR, RELAYER_MODULE, RELAYER_NEW_BTC_BLOCK_ACTION,
+    };
+    use alloy_primitives::B256;
+
+    fn new_btc_block() -> TxGoat {
+        TxGoat::new(
+            48816,
+            3,
+            TxGoatInner::NewBtcBlock(NewBtcBlockTx { hash: B256::with_last_byte(7) }),
+        )
+    }
+
+    #[test]
+    fn build_goat_request() {
+        let tx = new_btc_block();
+        let request = GoatTransactionRequest::from(tx.clone());
+        assert!(request.is_goat());
+        assert_eq!(request.complete_goat(), Ok(()));
+        assert_eq!(request.build_goat().unwrap(), tx);
+
+        let request = GoatTransactionRequest::new(TransactionRequest {
+            chain_id: Some(48816),
+            nonce: Some(3),
+            ..Default::default()
+        })
+        .with_module(RELAYER_MODULE)
+        .with_action(RELAYER_NEW_BTC_BLOCK_ACTION);
+        let mut request = request;
+        request.inner.input = tx.input.clone().into();
+        request.inner.to = Some(BITCOIN_CONTRACT.into());
+        assert_eq!(request.build_goat().unwrap(), tx);
+    }
+
+    #[test]
+    fn build_goat_request_invalid() {
+        let request = GoatTransactionRequest::default().with_module(RELAYER_MODULE);
+        assert!(request.is_goat());
+        assert_eq!(request.complete_goat(), Err(vec!["chain_id", "nonce", "input", "action"]));
+        assert!(request.build_goat().is_err());
+
+        let mut request = GoatTransactionRequest::from(new_btc_block());
+        request.inner.to = Some(BRIDGE_CONTRACT.into());
+        assert!(request.clone().build_goat().is_err());
+
+        request.inner.to = None;
+        request.inner.input = Default::default();
+        request.inner.input.input = Some(NewBtcBlockTx::METHOD_ID.into());
+        assert!(request.build_goat().is_err());
+
+        assert!(!GoatTransactionRequest::default().is_goat());
+    }
+
+    #[test]
+    fn fill_goat_request() {
+        let tx = new_btc_block();
+        let request = GoatTransactionRequest::new(TransactionRequest {
+            input: tx.input.clone().into(),
+            ..Default::default()
+        });
+        assert!(!request.is_goat());
+
+        let filled = request.clone().fill_defaults(48816, 3).unwrap();
+        assert_eq!(filled.module, Some(RELAYER_MODULE));
+        assert_eq!(filled.action, Some(RELAYER_NEW_BTC_BLOCK_ACTION));
+        assert_eq!(filled.inner.from, Some(RELAYER_EXECUTOR));
+        assert_eq!(filled.inner.to, Some(BITCOIN_CONTRACT.into()));
+        assert_eq!((filled.inner.gas, filled.inner.gas_price), (Some(0), Some(0)));
+        assert_eq!(filled.build_goat().unwrap(), tx);
+
+        // the fields of the request take precedence
+        let mut request = request.with_module(RELAYER_MODULE);
+        request.inner.nonce = Some(7);
+        let filled = request.clone().fill_defaults(48816, 3).unwrap();
+        assert_eq!(filled.build_goat().unwrap().nonce, 7);
+
+        // conflicting fields
+        assert!(request.clone().with_module(BRIDGE_MODULE).fill_defaults(48816, 3).is_err());
+        request.inner.to = Some(BRIDGE_CONTRACT.into());
+        assert!(request.fill_defaults(48816, 3).is_err());
+
+        // unknown or malformed calldata
+        assert!(GoatTransactionRequest::default().fill_defaults(48816, 3).is_err());
+        let mut request = GoatTransactionRequest::default();
+        request.inner.input = TransactionInput::new(NewBtcBlockTx::METHOD_ID.into());
+        assert!(request.fill_defaults(48816, 3).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_goat_request() {
+        let request = GoatTransactionRequest::from(new_btc_block());
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["module"], "0x2");
+        assert_eq!(json["action"], "0x1");
+        assert_eq!(json["type"], "0x60");
+        assert_eq!(serde_json::from_value::<GoatTransactionRequest>(json).unwrap(), request);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_goat_request_permissive() {
+        let request = GoatTransactionRequest::from(new_btc_block());
+
+        // missing goat fields, unknown fields and mixed-case hex
+        let mut json = serde_json::to_value(&request).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("module");
+        object.remove("action");
+        object.insert("unknownField".into(), serde_json::json!({ "nested": [1, 2] }));
+        for value in object.values_mut() {
+            if let Some(hex) = value.as_str().and_then(|s| s.strip_prefix("0x")) {
+                *value = format!("0x{}", hex.to_ascii_uppercase()).into();
+            }
+        }
+        let parsed: GoatTransactionRequest = serde_json::from_value(json).unwrap();
+        assert_eq!((parsed.module, parsed.action), (None, None));
+        assert_eq!(parsed.inner, request.inner);
+        // the goat fields are inferred from the calldata
+        assert_eq!(parsed.fill_defaults(48816, 3).unwrap().build_goat().unwrap(), new_btc_block());
+    }
+}
@@ -1,8 +1,8 @@
 //! RPC types for transactions
 
 use alloy_consensus::{
-    EthereumTxEnvelope, EthereumTypedTransaction, Signed, TxEip1559, TxEip2930, TxEip4844,
-    TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy, Typed2718,
+    transaction::goat::TxGoat, EthereumTxEnvelope, EthereumTypedTransaction, Signed, TxEip1559,
+    TxEip2930, TxEip4844, TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy, Typed2718,
 };
 use alloy_eips::eip2718::Encodable2718;
 use alloy_network_primitives::TransactionResponse;
@@ -22,7 +22,12 @@ pub use alloy_eips::{
 mod error;
 pub use error::ConversionError;
 
+mod goat;
+pub use goat::GoatTransactionRequest;
+
 mod receipt;
+#[cfg(feature = "serde")]
+pub use receipt::GoatReceiptFields;
 pub use receipt::TransactionReceipt;
 
 pub mod request;
@@ -156,6 +161,12 @@ where
         self.inner.gas_price().is_some()
     }
 
+    /// Returns true if the transaction is sent by a sender synthesized by the protocol, e.g. a
+    /// goat system transaction.
+    pub fn is_system_tx(&self) -> bool {
+        self.inner.system_sender().is_some()
+    }
+
     /// Converts a consensus `tx` with an additional context `tx_info` into an RPC [`Transaction`].
     pub fn from_transaction(tx: Recovered<T>, tx_info: TransactionInfo) -> Self {
         let TransactionInfo {
@@ -227,6 +238,22 @@ impl<Eip4844> Transaction<EthereumTxEnvelope<Eip4844>> {
     {
         self.inner.map(|tx| tx.into_signed())
     }
+
+    /// Returns the goat system transaction, if it is one.
+    pub const fn as_goat(&self) -> Option<&Signed<TxGoat>> {
+        match self.inner.inner() {
+            EthereumTxEnvelope::Goat(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Consumes the transaction and returns the goat system transaction.
+    ///
+    /// Returns an error if the transaction is of another type, or if its decoded action does not
+    /// match its `input`.
+    pub fn try_into_goat(self) -> Result<Signed<TxGoat>, ConversionError> {
+        self.try_into()
+    }
 }
 
 impl<T> From<&Transaction<T>> for TransactionInfo
@@ -314,6 +341,22 @@ impl<Eip4844> TryFrom<Transaction<EthereumTxEnvelope<Eip4844>>> for Signed<TxEip
     }
 }
 
+impl<Eip4844> TryFrom<Transaction<EthereumTxEnvelope<Eip4844>>> for Signed<TxGoat> {
+    type Error = ConversionError;
+
+    fn try_from(tx: Transaction<EthereumTxEnvelope<Eip4844>>) -> Result<Self, Self::Error> {
+        match tx.inner.into_inner() {
+            EthereumTxEnvelope::Goat(tx) => {
+                tx.tx()
+                    .verify_against_input()
+                    .map_err(|err| ConversionError::Custom(format!("{err}")))?;
+                Ok(tx)
+            }
+            tx => Err(ConversionError::Custom(format!("expected Goat, got {}", tx.tx_type()))),
+        }
+    }
+}
+
 impl<Eip4844, Other> From<Transaction<EthereumTxEnvelope<Eip4844>>> for EthereumTxEnvelope<Other>
 where
     Self: From<EthereumTxEnvelope<Eip4844>>,
@@ -411,6 +454,10 @@ impl<T: TransactionTrait> TransactionTrait for Transaction<T> {
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         self.inner.authorization_list()
     }
+
+    fn system_sender(&self) -> Option<Address> {
+        self.inner.system_sender()
+    }
 }
 
 impl<T: TransactionTrait + Encodable2718> TransactionResponse for Transaction<T> {
@@ -555,6 +602,46 @@ mod tests {
         tx.into()
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn try_into_goat() {
+        use alloy_consensus::{
+            transaction::goat::{Cancel2Tx, TxGoatInner},
+            SignableTransaction,
+        };
+        use alloy_primitives::Signature;
+
+        let goat = TxGoat::new(48815, 3, TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(7) }));
+        let signed = goat.clone().into_signed(Signature::test_signature());
+        let tx = Transaction::from_transaction(
+            Recovered::new_unchecked(TxEnvelope::from(signed.clone()), goat.sender()),
+            TransactionInfo::default(),
+        );
+        let tx = serde_json::from_str::<Transaction>(&serde_json::to_string(&tx).unwrap()).unwrap();
+        assert!(tx.is_system_tx());
+        assert_eq!(tx.as_goat(), Some(&signed));
+        assert_eq!(tx.clone().try_into_goat().unwrap(), signed);
+
+        // the decoded action must match the input
+        let mut mismatched = tx;
+        let TxEnvelope::Goat(signed) = mismatched.inner.inner_mut() else { unreachable!() };
+        let (mut goat, signature, _) = signed.clone().into_parts();
+        goat.inner = TxGoatInner::Cancel2(Cancel2Tx { id: U256::from(8) });
+        *signed = goat.into_signed(signature);
+        assert!(mismatched.try_into_goat().is_err());
+
+        let legacy = Transaction::from_transaction(
+            Recovered::new_unchecked(
+                TxEnvelope::from(TxLegacy::default().into_signed(Signature::test_signature())),
+                Address::ZERO,
+            ),
+            TransactionInfo::default(),
+        );
+        assert!(!legacy.is_system_tx());
+        assert!(legacy.as_goat().is_none());
+        assert!(legacy.try_into_goat().is_err());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn into_request_legacy() {
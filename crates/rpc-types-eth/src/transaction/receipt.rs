@@ -1,7 +1,7 @@
 use crate::Log;
 use alloy_consensus::{ReceiptEnvelope, TxReceipt, TxType};
 use alloy_network_primitives::ReceiptResponse;
-use alloy_primitives::{Address, BlockHash, TxHash, B256};
+use alloy_primitives::{Address, BlockHash, TxHash, B256, U256};
 use alloy_sol_types::SolEvent;
 
 /// Transaction receipt
@@ -146,6 +146,7 @@ impl TransactionReceipt {
             | ReceiptEnvelope::Eip2930(receipt)
             | ReceiptEnvelope::Eip4844(receipt)
             | ReceiptEnvelope::Eip7702(receipt)
+            | ReceiptEnvelope::Goat(receipt)
             | ReceiptEnvelope::Legacy(receipt) => receipt.receipt.status.coerce_status(),
         }
     }
@@ -305,6 +306,10 @@ impl<T: TxReceipt<Log = Log>> ReceiptResponse for TransactionReceipt<T> {
     fn state_root(&self) -> Option<B256> {
         self.inner.status_or_post_state().as_post_state()
     }
+
+    fn cumulative_mint(&self) -> Option<U256> {
+        self.inner.cumulative_mint()
+    }
 }
 
 impl From<TransactionReceipt> for TransactionReceipt<ReceiptEnvelope<alloy_primitives::Log>> {
@@ -313,6 +318,65 @@ impl From<TransactionReceipt> for TransactionReceipt<ReceiptEnvelope<alloy_primi
     }
 }
 
+/// The GOAT specific fields of a receipt, as returned by goat-geth next to the regular receipt
+/// fields.
+///
+/// Receipts of goat deposit transactions include the minted native tokens, which is otherwise only
+/// available by decoding the transaction, and the native tokens minted in the block so far. The
+/// fields end up in the
+/// [`OtherFields`](alloy_serde::OtherFields) of a [`WithOtherFields`](alloy_serde::WithOtherFields)
+/// receipt, e.g. of the `AnyNetwork`, use [`GoatReceiptFields::from_other`] or
+/// [`GoatReceiptFields::take_from`] to read them.
+#[cfg(feature = "serde")]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GoatReceiptFields {
+    /// The native tokens minted by a deposit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mint: Option<alloy_consensus::transaction::goat::Mint>,
+    /// The native tokens minted in the block up to and including the transaction, in wei.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cumulative_mint: Option<U256>,
+}
+
+#[cfg(feature = "serde")]
+impl GoatReceiptFields {
+    /// Whether the goat fields are part of the consensus encoding of the receipt.
+    ///
+    /// They are not, so the receipts root only commits to the regular receipt fields, and the
+    /// [`Encodable2718`](alloy_eips::eip2718::Encodable2718) encoding of a goat receipt is the
+    /// same with or without them.
+    pub const IN_CONSENSUS_ENCODING: bool = false;
+
+    const MINT: &'static str = "mint";
+    const CUMULATIVE_MINT: &'static str = "cumulativeMint";
+
+    /// Reads the goat fields from the other fields of a receipt.
+    ///
+    /// Missing fields are `None`, only malformed fields are an error.
+    pub fn from_other(other: &alloy_serde::OtherFields) -> serde_json::Result<Self> {
+        other.deserialize_as()
+    }
+
+    /// Removes the goat fields from the other fields of a receipt and returns them.
+    ///
+    /// Malformed fields are removed as well.
+    pub fn take_from(other: &mut alloy_serde::OtherFields) -> serde_json::Result<Self> {
+        let fields: alloy_serde::OtherFields = [Self::MINT, Self::CUMULATIVE_MINT]
+            .into_iter()
+            .filter_map(|key| other.remove_entry(key))
+            .collect();
+        fields.deserialize_into()
+    }
+
+    /// Returns true if none of the goat fields are present.
+    pub const fn is_empty(&self) -> bool {
+        self.mint.is_none() && self.cumulative_mint.is_none()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -441,4 +505,80 @@ mod test {
         let proper_receipt: TransactionReceipt = serde_json::from_str(proper_receipt).unwrap();
         assert_eq!(proper_receipt.effective_gas_price, 12500000000);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn goat_receipt_fields() {
+        use alloy_consensus::transaction::goat::{Mint, BRIDGE_CONTRACT, RELAYER_EXECUTOR};
+        use alloy_primitives::U256;
+        use alloy_serde::WithOtherFields;
+
+        let json = r#"{"blockHash":"0x0000000000000000000000000000000000000000000000000000000000000001","blockNumber":"0x64","contractAddress":null,"cumulativeGasUsed":"0x0","effectiveGasPrice":"0x0","from":"0xbc10000000000000000000000000000000001000","gasUsed":"0x0","logs":[],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","status":"0x1","to":"0xbc10000000000000000000000000000000000003","transactionHash":"0x0000000000000000000000000000000000000000000000000000000000000002","transactionIndex":"0x0","type":"0x60","mint":{"target":"0x00000000000000000000000000000000000000aa","amount":"0xde0b6b3a7640000","tax":"0x2386f26fc10000"},"cumulativeMint":"0xe043da617250000"}"#;
+
+        // the plain receipt ignores the goat fields
+        let receipt: TransactionReceipt = serde_json::from_str(json).unwrap();
+        assert!(matches!(receipt.inner, ReceiptEnvelope::Goat(_)));
+        assert_eq!((receipt.from, receipt.to), (RELAYER_EXECUTOR, Some(BRIDGE_CONTRACT)));
+
+        let mut receipt: WithOtherFields<TransactionReceipt> = serde_json::from_str(json).unwrap();
+        let mint = Mint {
+            target: Address::with_last_byte(0xaa),
+            amount: U256::from(10u64.pow(18)),
+            tax: U256::from(10u64.pow(16)),
+        };
+        assert_eq!(GoatReceiptFields::from_other(&receipt.other).unwrap().mint, Some(mint));
+        assert_eq!(receipt.mint(), Some(mint));
+        let cumulative_mint = mint.amount + mint.tax;
+        assert_eq!(receipt.cumulative_mint(), Some(cumulative_mint));
+        assert_eq!(receipt.inner.cumulative_mint(), None);
+        let fields = GoatReceiptFields::take_from(&mut receipt.other).unwrap();
+        assert_eq!(
+            fields,
+            GoatReceiptFields { mint: Some(mint), cumulative_mint: Some(cumulative_mint) }
+        );
+        assert!(receipt.other.is_empty());
+        assert_eq!((receipt.mint(), receipt.cumulative_mint()), (None, None));
+        assert!(GoatReceiptFields::take_from(&mut receipt.other).unwrap().is_empty());
+
+        receipt.other.insert("mint".to_string(), "0x1".into());
+        assert!(GoatReceiptFields::from_other(&receipt.other).is_err());
+        assert_eq!(receipt.mint(), None);
+
+        // mixed-case hex and unknown fields in the mint
+        receipt.other.insert(
+            "mint".to_string(),
+            serde_json::json!({
+                "target": "0x00000000000000000000000000000000000000AA",
+                "amount": "0xDE0B6B3A7640000",
+                "tax": "0x2386F26FC10000",
+                "taxRecipient": "0x00000000000000000000000000000000000000bb",
+            }),
+        );
+        assert_eq!(receipt.mint(), Some(mint));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn goat_receipt_fields_not_encoded() {
+        use alloy_consensus::proofs::calculate_receipt_root;
+        use alloy_eips::eip2718::Encodable2718;
+        use alloy_serde::WithOtherFields;
+
+        let json = r#"{"blockHash":"0x0000000000000000000000000000000000000000000000000000000000000001","blockNumber":"0x64","contractAddress":null,"cumulativeGasUsed":"0x0","effectiveGasPrice":"0x0","from":"0xbc10000000000000000000000000000000001000","gasUsed":"0x0","logs":[],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","status":"0x1","to":"0xbc10000000000000000000000000000000000003","transactionHash":"0x0000000000000000000000000000000000000000000000000000000000000002","transactionIndex":"0x0","type":"0x60"}"#;
+        let with_fields = json.replace(
+            r#""type":"0x60""#,
+            r#""type":"0x60","mint":{"target":"0x00000000000000000000000000000000000000aa","amount":"0x1","tax":"0x0"},"cumulativeMint":"0x1""#,
+        );
+
+        const _: () = assert!(!GoatReceiptFields::IN_CONSENSUS_ENCODING);
+        let plain: TransactionReceipt = serde_json::from_str(json).unwrap();
+        let extended: WithOtherFields<TransactionReceipt> =
+            serde_json::from_str(&with_fields).unwrap();
+        assert!(!GoatReceiptFields::from_other(&extended.other).unwrap().is_empty());
+
+        let plain = plain.into_primitives_receipt().inner;
+        let extended = extended.inner.into_primitives_receipt().inner;
+        assert_eq!(extended.encoded_2718(), plain.encoded_2718());
+        assert_eq!(calculate_receipt_root(&[extended]), calculate_receipt_root(&[plain]));
+    }
 }
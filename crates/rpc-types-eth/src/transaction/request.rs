@@ -7,9 +7,10 @@ use alloc::{
     vec::Vec,
 };
 use alloy_consensus::{
-    error::ValueError, transaction::Recovered, SignableTransaction, TxEip1559, TxEip2930,
-    TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxEip7702, TxEnvelope, TxLegacy, TxType,
-    Typed2718, TypedTransaction,
+    error::ValueError,
+    transaction::{goat::TxGoat, Recovered},
+    SignableTransaction, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar,
+    TxEip7702, TxEnvelope, TxLegacy, TxType, Typed2718, TypedTransaction,
 };
 use alloy_eips::eip7702::SignedAuthorization;
 use alloy_network_primitives::{TransactionBuilder4844, TransactionBuilder7702};
@@ -714,6 +715,16 @@ impl TransactionRequest {
                 self.blob_versioned_hashes = None;
                 self.sidecar = None;
             }
+            TxType::Goat => {
+                self.gas_price = None;
+                self.max_fee_per_gas = None;
+                self.max_priority_fee_per_gas = None;
+                self.max_fee_per_blob_gas = None;
+                self.blob_versioned_hashes = None;
+                self.sidecar = None;
+                self.access_list = None;
+                self.authorization_list = None;
+            }
         }
     }
 
@@ -886,6 +897,8 @@ impl TransactionRequest {
             TxType::Eip1559 => self.complete_1559(),
             TxType::Eip4844 => self.complete_4844(),
             TxType::Eip7702 => self.complete_7702(),
+            // goat system transactions are never built from a request
+            TxType::Goat => Err(vec!["transaction_type"]),
         } {
             Err((pref, missing))
         } else {
@@ -993,6 +1006,7 @@ impl TransactionRequest {
             TxType::Eip1559 => self.complete_1559().ok(),
             TxType::Eip4844 => self.complete_4844().ok(),
             TxType::Eip7702 => self.complete_7702().ok(),
+            TxType::Goat => None,
         }?;
         Some(pref)
     }
@@ -1013,6 +1027,7 @@ impl TransactionRequest {
             // `sidecar` is a hard requirement since this must be a _sendable_ transaction.
             TxType::Eip4844 => self.build_4844_with_sidecar().expect("checked)").into(),
             TxType::Eip7702 => self.build_7702().expect("checked)").into(),
+            TxType::Goat => return Err(self),
         })
     }
 
@@ -1034,6 +1049,10 @@ impl TransactionRequest {
             TxType::Eip1559 => self.build_1559().map(Into::into),
             TxType::Eip4844 => self.build_4844_variant().map(Into::into),
             TxType::Eip7702 => self.build_7702().map(Into::into),
+            TxType::Goat => Err(ValueError::new(
+                self,
+                "Goat system transactions can not be built from a request.",
+            )),
         }
         .map_err(|e| {
             let error = e.to_string();
@@ -1262,6 +1281,21 @@ impl From<TxEip7702> for TransactionRequest {
     }
 }
 
+impl From<TxGoat> for TransactionRequest {
+    fn from(tx: TxGoat) -> Self {
+        Self {
+            from: Some(tx.sender()),
+            to: Some(tx.contract().into()),
+            value: Some(U256::ZERO),
+            nonce: Some(tx.nonce),
+            chain_id: Some(tx.chain_id),
+            transaction_type: Some(TxGoat::tx_type()),
+            input: tx.input.into(),
+            ..Default::default()
+        }
+    }
+}
+
 impl From<TypedTransaction> for TransactionRequest {
     fn from(tx: TypedTransaction) -> Self {
         match tx {
@@ -1270,6 +1304,7 @@ impl From<TypedTransaction> for TransactionRequest {
             TypedTransaction::Eip1559(tx) => tx.into(),
             TypedTransaction::Eip4844(tx) => tx.into(),
             TypedTransaction::Eip7702(tx) => tx.into(),
+            TypedTransaction::Goat(tx) => tx.into(),
         }
     }
 }
@@ -1362,6 +1397,8 @@ impl From<TxEnvelope> for TransactionRequest {
                     tx.strip_signature().into()
                 }
             }
+            // the sender of a goat system transaction is the system executor of its action
+            TxEnvelope::Goat(tx) => tx.strip_signature().into(),
         }
     }
 }
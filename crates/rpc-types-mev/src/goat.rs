@@ -0,0 +1,150 @@
+//! Goat system transaction checks for bundles.
+//!
+//! Goat system transactions are only ever created by the sequencer, so bundles submitted by
+//! builders must not contain them.
+
+use crate::{BundleItem, EthSendBundle, MevSendBundle};
+use alloy_consensus::transaction::goat::GOAT_TX_TYPE_ID;
+use std::fmt;
+
+/// Returns `true` if the EIP-2718 encoded transaction is a goat system transaction.
+///
+/// Only the type byte is checked, the transaction is not decoded.
+pub fn is_goat_raw_tx(tx: &[u8]) -> bool {
+    tx.first() == Some(&GOAT_TX_TYPE_ID)
+}
+
+/// Error returned when a bundle contains a goat system transaction.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("bundle contains a goat system transaction at {}", DisplayPath(.path))]
+pub struct GoatTxInBundle {
+    /// The position of the transaction in the bundle.
+    ///
+    /// Each entry is an index into the body of a (nested) bundle, the first entry being the index
+    /// into the outermost bundle.
+    pub path: Vec<usize>,
+}
+
+struct DisplayPath<'a>(&'a [usize]);
+
+impl fmt::Display for DisplayPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, index) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl BundleItem {
+    /// Returns `true` if the item is a goat system transaction.
+    ///
+    /// Nested bundles are not inspected, see [`MevSendBundle::check_goat_txs`].
+    pub fn is_goat_tx(&self) -> bool {
+        matches!(self, Self::Tx { tx, .. } if is_goat_raw_tx(tx))
+    }
+}
+
+impl MevSendBundle {
+    /// Checks that the bundle, including its nested bundles, contains no goat system
+    /// transactions.
+    pub fn check_goat_txs(&self) -> Result<(), GoatTxInBundle> {
+        for (index, item) in self.bundle_body.iter().enumerate() {
+            if item.is_goat_tx() {
+                return Err(GoatTxInBundle { path: vec![index] });
+            }
+            if let BundleItem::Bundle { bundle } = item {
+                bundle.check_goat_txs().map_err(|mut err| {
+                    err.path.insert(0, index);
+                    err
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes all goat system transactions from the bundle, including its nested bundles.
+    ///
+    /// Returns the number of removed transactions.
+    pub fn strip_goat_txs(&mut self) -> usize {
+        let len = self.bundle_body.len();
+        self.bundle_body.retain(|item| !item.is_goat_tx());
+        let mut removed = len - self.bundle_body.len();
+        for item in &mut self.bundle_body {
+            if let BundleItem::Bundle { bundle } = item {
+                removed += bundle.strip_goat_txs();
+            }
+        }
+        removed
+    }
+}
+
+impl EthSendBundle {
+    /// Checks that the bundle contains no goat system transactions.
+    pub fn check_goat_txs(&self) -> Result<(), GoatTxInBundle> {
+        self.txs
+            .iter()
+            .position(|tx| is_goat_raw_tx(tx))
+            .map_or(Ok(()), |index| Err(GoatTxInBundle { path: vec![index] }))
+    }
+
+    /// Removes all goat system transactions from the bundle.
+    ///
+    /// Returns the number of removed transactions.
+    pub fn strip_goat_txs(&mut self) -> usize {
+        let len = self.txs.len();
+        self.txs.retain(|tx| !is_goat_raw_tx(tx));
+        len - self.txs.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProtocolVersion;
+    use alloy_primitives::{bytes, Bytes};
+
+    fn tx(tx: Bytes) -> BundleItem {
+        BundleItem::Tx { tx, can_revert: false }
+    }
+
+    #[test]
+    fn mev_bundle_goat_txs() {
+        let goat = bytes!("60ee820929020107a4");
+        let user = bytes!("02f86b0180");
+        let nested =
+            MevSendBundle::new(1, None, ProtocolVersion::V0_1, vec![tx(user.clone()), tx(goat)]);
+        let mut bundle = MevSendBundle::new(
+            1,
+            None,
+            ProtocolVersion::V0_1,
+            vec![tx(user.clone()), BundleItem::Bundle { bundle: nested }],
+        );
+
+        let err = bundle.check_goat_txs().unwrap_err();
+        assert_eq!(err.path, vec![1, 1]);
+        assert_eq!(err.to_string(), "bundle contains a goat system transaction at 1.1");
+
+        assert_eq!(bundle.strip_goat_txs(), 1);
+        bundle.check_goat_txs().unwrap();
+        let BundleItem::Bundle { bundle: nested } = &bundle.bundle_body[1] else {
+            panic!("expected nested bundle");
+        };
+        assert_eq!(nested.bundle_body, vec![tx(user)]);
+    }
+
+    #[test]
+    fn eth_bundle_goat_txs() {
+        let mut bundle = EthSendBundle {
+            txs: vec![bytes!("f86b80"), bytes!("60ee82"), bytes!("02f86b"), bytes!("60")],
+            ..Default::default()
+        };
+        assert_eq!(bundle.check_goat_txs(), Err(GoatTxInBundle { path: vec![1] }));
+        assert_eq!(bundle.strip_goat_txs(), 2);
+        assert_eq!(bundle.txs, vec![bytes!("f86b80"), bytes!("02f86b")]);
+        bundle.check_goat_txs().unwrap();
+    }
+}
@@ -18,6 +18,9 @@ pub use eth_calls::*;
 mod flashblocks;
 pub use flashblocks::*;
 
+mod goat;
+pub use goat::*;
+
 mod mev_calls;
 pub use mev_calls::*;
 
@@ -0,0 +1,127 @@
+//! Goat annotations of geth call traces.
+
+use crate::geth::CallFrame;
+use alloy_consensus::transaction::goat::{decode_call, TxGoatInner};
+use serde::{Deserialize, Serialize};
+
+/// A [`CallFrame`] annotated with the goat action it performs.
+///
+/// This is the call tracer output of a transaction, where every call to a goat predeploy with a
+/// known action carries the decoded action data, so that explorers do not have to decode the
+/// calldata themselves.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoatTrace {
+    /// The call frame, without its sub calls.
+    #[serde(flatten)]
+    pub frame: CallFrame,
+    /// The decoded goat action of the call, if it is a call to a goat predeploy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goat_action: Option<TxGoatInner>,
+    /// The annotated sub calls.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls: Vec<Self>,
+}
+
+impl GoatTrace {
+    /// Returns an iterator over the decoded goat actions of this call and all its sub calls, in
+    /// call order.
+    pub fn goat_actions(&self) -> impl Iterator<Item = &TxGoatInner> + '_ {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || {
+            let trace = stack.pop()?;
+            stack.extend(trace.calls.iter().rev());
+            Some(trace)
+        })
+        .filter_map(|trace| trace.goat_action.as_ref())
+    }
+
+    /// Converts the annotated trace back into a plain [`CallFrame`].
+    pub fn into_call_frame(self) -> CallFrame {
+        CallFrame {
+            calls: self.calls.into_iter().map(Self::into_call_frame).collect(),
+            ..self.frame
+        }
+    }
+}
+
+impl From<CallFrame> for GoatTrace {
+    fn from(mut frame: CallFrame) -> Self {
+        let calls = core::mem::take(&mut frame.calls).into_iter().map(Self::from).collect();
+        // calls with malformed calldata revert, so they are left unannotated
+        let goat_action =
+            frame.to.and_then(|to| decode_call(to, &frame.input)).and_then(Result::ok);
+        Self { frame, goat_action, calls }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::transaction::goat::{BRIDGE_CONTRACT, RELAYER_EXECUTOR};
+    use alloy_primitives::{address, hex, Bytes, U256};
+    use similar_asserts::assert_eq;
+
+    const DEPOSIT_INPUT: [u8; 164] = hex!(
+        "904183cb"
+        "b0b8d5a7b8a1e4a4a8bdd0cbf6fc46f6d1d31ae4b8f3f7b0d0a6b2a4f0a4b1c2"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        "0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+        "00000000000000000000000000000000000000000000000000038d7ea4c68000"
+    );
+
+    fn deposit_frame() -> CallFrame {
+        CallFrame {
+            from: RELAYER_EXECUTOR,
+            to: Some(BRIDGE_CONTRACT),
+            input: Bytes::from_static(&DEPOSIT_INPUT),
+            typ: "CALL".to_string(),
+            calls: vec![CallFrame {
+                from: BRIDGE_CONTRACT,
+                to: Some(address!("0xd8da6bf26964af9d7eed9e03e53415d37aa96045")),
+                value: Some(U256::from(10u128.pow(18))),
+                typ: "CALL".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn annotate_goat_frames() {
+        let frame = deposit_frame();
+        let trace = GoatTrace::from(frame.clone());
+
+        let Some(TxGoatInner::Deposit(deposit)) = trace.goat_action else {
+            panic!("expected deposit, got {:?}", trace.goat_action);
+        };
+        assert_eq!(deposit.tx_out, 2);
+        assert_eq!(deposit.amount, U256::from(10u128.pow(18)));
+        assert!(trace.frame.calls.is_empty());
+        assert_eq!(trace.calls.len(), 1);
+        assert_eq!(trace.calls[0].goat_action, None);
+        assert_eq!(trace.goat_actions().count(), 1);
+
+        assert_eq!(trace.into_call_frame(), frame);
+    }
+
+    #[test]
+    fn malformed_goat_frame() {
+        let mut frame = deposit_frame();
+        frame.input = Bytes::copy_from_slice(&DEPOSIT_INPUT[..36]);
+        assert_eq!(GoatTrace::from(frame).goat_action, None);
+    }
+
+    #[test]
+    fn serde_goat_trace() {
+        let trace = GoatTrace::from(deposit_frame());
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["to"], BRIDGE_CONTRACT.to_string().to_lowercase());
+        assert_eq!(json["goatAction"]["deposit"]["txOut"], "0x2");
+        assert_eq!(json["calls"][0]["type"], "CALL");
+        assert!(json["calls"][0].get("goatAction").is_none());
+
+        assert_eq!(serde_json::from_value::<GoatTrace>(json).unwrap(), trace);
+    }
+}
@@ -12,6 +12,7 @@ use std::{borrow::Cow, collections::BTreeMap, time::Duration};
 pub use self::{
     call::{CallConfig, CallFrame, CallKind, CallLogFrame, FlatCallConfig},
     four_byte::FourByteFrame,
+    goat::GoatTrace,
     noop::NoopFrame,
     pre_state::{
         AccountChangeKind, AccountState, DiffMode, DiffStateKind, PreStateConfig, PreStateFrame,
@@ -22,6 +23,7 @@ pub use self::{
 pub mod call;
 pub mod erc7562;
 pub mod four_byte;
+pub mod goat;
 pub mod mux;
 pub mod noop;
 pub mod pre_state;
@@ -125,12 +125,18 @@ impl Serialize for TxpoolInspectSummary {
 /// the transactions currently pending for inclusion in the next block(s), as well
 /// as the ones that are being scheduled for future execution only.
 ///
+/// Entries that can not be deserialized as `T`, e.g. transactions of a type unknown to `T`, are
+/// skipped rather than failing the whole response.
+///
 /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
 pub struct TxpoolContent<T = Transaction> {
     /// pending tx
+    #[serde(deserialize_with = "lenient::sender_map")]
     pub pending: BTreeMap<Address, BTreeMap<String, T>>,
     /// queued tx
+    #[serde(deserialize_with = "lenient::sender_map")]
     pub queued: BTreeMap<Address, BTreeMap<String, T>>,
 }
 
@@ -198,13 +204,67 @@ impl<T> TxpoolContent<T> {
 ///
 /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_contentFrom) for more details
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
 pub struct TxpoolContentFrom<T = Transaction> {
     /// pending tx
+    #[serde(deserialize_with = "lenient::nonce_map")]
     pub pending: BTreeMap<String, T>,
     /// queued tx
+    #[serde(deserialize_with = "lenient::nonce_map")]
     pub queued: BTreeMap<String, T>,
 }
 
+/// Deserialization of txpool transactions that skips the entries `T` can not represent.
+mod lenient {
+    use super::*;
+
+    /// A txpool entry, which is either a transaction or an entry `T` can not represent.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MaybeTx<T> {
+        Tx(T),
+        Unknown(de::IgnoredAny),
+    }
+
+    impl<T> MaybeTx<T> {
+        fn into_tx(self) -> Option<T> {
+            match self {
+                Self::Tx(tx) => Some(tx),
+                Self::Unknown(_) => None,
+            }
+        }
+    }
+
+    pub(super) fn nonce_map<'de, D, T>(deserializer: D) -> Result<BTreeMap<String, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let entries = BTreeMap::<String, MaybeTx<T>>::deserialize(deserializer)?;
+        Ok(entries.into_iter().filter_map(|(nonce, tx)| Some((nonce, tx.into_tx()?))).collect())
+    }
+
+    pub(super) fn sender_map<'de, D, T>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Address, BTreeMap<String, T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let senders = BTreeMap::<Address, BTreeMap<String, MaybeTx<T>>>::deserialize(deserializer)?;
+        Ok(senders
+            .into_iter()
+            .filter_map(|(sender, entries)| {
+                let txs = entries
+                    .into_iter()
+                    .filter_map(|(nonce, tx)| Some((nonce, tx.into_tx()?)))
+                    .collect::<BTreeMap<_, _>>();
+                (!txs.is_empty()).then_some((sender, txs))
+            })
+            .collect())
+    }
+}
+
 impl<T> Default for TxpoolContentFrom<T> {
     fn default() -> Self {
         Self { pending: BTreeMap::new(), queued: BTreeMap::new() }
@@ -427,6 +487,85 @@ mod tests {
         assert_eq!(deserialized, serde_json::from_str::<TxpoolContent>(&serialized).unwrap());
     }
 
+    #[test]
+    fn serde_txpool_content_goat() {
+        // goat system transactions can be queued for the system executors
+        let txpool_content_json = r#"
+{
+  "pending": {},
+  "queued": {
+    "0xbc10000000000000000000000000000000001000": {
+      "7": {
+        "blockHash": null,
+        "blockNumber": null,
+        "from": "0xbc10000000000000000000000000000000001000",
+        "gas": "0x0",
+        "gasPrice": "0x0",
+        "hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "input": "0x94f490bd000000000000000000016a6a7b2ce2c8a1dc0c2c7c16e3dd0f2a5a5d4e3bd1d2",
+        "nonce": "0x7",
+        "to": "0xbc10000000000000000000000000000000000005",
+        "transactionIndex": null,
+        "value": "0x0",
+        "type": "0x60",
+        "chainId": "0x929",
+        "module": "0x2",
+        "action": "0x1",
+        "v": "0x0",
+        "r": "0x0",
+        "s": "0x0"
+      }
+    }
+  }
+}"#;
+        let deserialized: TxpoolContent = serde_json::from_str(txpool_content_json).unwrap();
+        let tx = deserialized.queued_transactions().next().unwrap();
+        assert!(tx.inner.is_goat());
+        assert_eq!(tx.inner.signer(), tx.inner.as_goat().unwrap().tx().sender());
+
+        let serialized = serde_json::to_string(&deserialized).unwrap();
+        assert_eq!(deserialized, serde_json::from_str::<TxpoolContent>(&serialized).unwrap());
+    }
+
+    #[test]
+    fn serde_txpool_content_unknown_type() {
+        let tx = |nonce: u64, ty: &str| {
+            serde_json::json!({
+                "blockHash": null,
+                "blockNumber": null,
+                "from": "0x00000000863b56a3c1f0f1be8bc4f8b7bd78f57a",
+                "gas": "0x2af9e",
+                "gasPrice": "0x218718356",
+                "hash": "0x2c0f1c8a3e6d3b2d1b3d1c4c6e9f0e1a8a7b4b2f3c1d0e9f8a7b6c5d4e3f2a1b",
+                "input": "0x",
+                "nonce": format!("{nonce:#x}"),
+                "to": "0x00000000863b56a3c1f0f1be8bc4f8b7bd78f57a",
+                "transactionIndex": null,
+                "value": "0x0",
+                "type": ty,
+                "v": "0x25",
+                "r": "0x1",
+                "s": "0x1"
+            })
+        };
+        let sender = serde_json::json!({ "1": tx(1, "0x0"), "2": tx(2, "0x7e") });
+        let json = serde_json::json!({
+            "pending": {
+                "0x00000000863b56a3c1f0f1be8bc4f8b7bd78f57a": sender,
+                "0x0000000000000000000000000000000000000001": { "3": tx(3, "0x7e") },
+            },
+            "queued": {},
+        });
+
+        let content: TxpoolContent = serde_json::from_value(json).unwrap();
+        assert_eq!(content.pending.len(), 1);
+        assert_eq!(content.pending_transactions().map(|tx| tx.nonce()).collect::<Vec<_>>(), [1]);
+
+        let from: TxpoolContentFrom =
+            serde_json::from_value(serde_json::json!({ "pending": sender, "queued": {} })).unwrap();
+        assert_eq!(from.pending.keys().collect::<Vec<_>>(), ["1"]);
+    }
+
     #[test]
     fn serde_txpool_inspect() {
         let txpool_inspect_json = r#"
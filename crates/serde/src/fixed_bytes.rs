@@ -0,0 +1,70 @@
+//! Serde functions for encoding fixed-size byte arrays as `0x`-prefixed hex strings.
+//!
+//! # Example
+//! ```
+//! use alloy_serde;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct Container {
+//!     #[serde(with = "alloy_serde::fixed_bytes")]
+//!     value: [u8; 8],
+//! }
+//!
+//! let val = Container { value: [0, 0, 0, 0, 0, 0, 0x12, 0x34] };
+//! let s = serde_json::to_string(&val).unwrap();
+//! assert_eq!(s, "{\"value\":\"0x0000000000001234\"}");
+//!
+//! let deserialized: Container = serde_json::from_str(&s).unwrap();
+//! assert_eq!(val, deserialized);
+//! ```
+
+use alloy_primitives::FixedBytes;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a fixed-size byte array as a `0x`-prefixed hex string.
+pub fn serialize<S, const N: usize>(value: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    FixedBytes::<N>::from(value).serialize(serializer)
+}
+
+/// Deserializes a fixed-size byte array from a hex string of exactly `N` bytes.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FixedBytes::<N>::deserialize(deserializer).map(|bytes| bytes.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Calldata {
+        #[serde(with = "super")]
+        data: [u8; 36],
+    }
+
+    #[test]
+    fn fixed_bytes_roundtrip() {
+        let mut data = [0u8; 36];
+        data[0] = 0xab;
+        data[35] = 0x01;
+        let val = Calldata { data };
+        let s = serde_json::to_string(&val).unwrap();
+        assert_eq!(
+            s,
+            r#"{"data":"0xab0000000000000000000000000000000000000000000000000000000000000000000001"}"#
+        );
+        assert_eq!(serde_json::from_str::<Calldata>(&s).unwrap(), val);
+    }
+
+    #[test]
+    fn fixed_bytes_wrong_length() {
+        serde_json::from_str::<Calldata>(r#"{"data":"0xab"}"#).unwrap_err();
+        serde_json::from_str::<Calldata>(r#"{"data":[1,2,3]}"#).unwrap_err();
+    }
+}
@@ -16,11 +16,15 @@ pub mod displayfromstr;
 
 pub mod checksum;
 
+pub mod fixed_bytes;
+
 mod optional;
 pub use self::optional::*;
 
 pub mod quantity;
 
+pub mod selector;
+
 /// Storage related helpers.
 pub mod storage;
 pub use storage::JsonStorageKey;
@@ -0,0 +1,90 @@
+//! Serde functions for encoding 4-byte function selectors as `0x`-prefixed hex strings.
+//!
+//! # Example
+//! ```
+//! use alloy_serde;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct Container {
+//!     #[serde(with = "alloy_serde::selector")]
+//!     selector: [u8; 4],
+//! }
+//!
+//! let val = Container { selector: [0x94, 0xf4, 0x90, 0xbd] };
+//! let s = serde_json::to_string(&val).unwrap();
+//! assert_eq!(s, "{\"selector\":\"0x94f490bd\"}");
+//!
+//! let deserialized: Container = serde_json::from_str(&s).unwrap();
+//! assert_eq!(val, deserialized);
+//! ```
+
+use serde::{Deserializer, Serializer};
+
+/// Serializes a function selector as a `0x`-prefixed hex string.
+pub fn serialize<S>(value: &[u8; 4], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::fixed_bytes::serialize(value, serializer)
+}
+
+/// Deserializes a function selector from a hex string of exactly 4 bytes.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<[u8; 4], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::fixed_bytes::deserialize(deserializer)
+}
+
+/// Serde functions for encoding optional function selectors as `0x`-prefixed hex strings.
+pub mod opt {
+    use alloy_primitives::Selector;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an optional function selector as a `0x`-prefixed hex string.
+    pub fn serialize<S>(value: &Option<[u8; 4]>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.map(Selector::from).serialize(serializer)
+    }
+
+    /// Deserializes an optional function selector from a hex string of exactly 4 bytes.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<[u8; 4]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Selector>::deserialize(deserializer)?.map(|selector| selector.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Call {
+        #[serde(with = "super")]
+        selector: [u8; 4],
+        #[serde(with = "super::opt", default, skip_serializing_if = "Option::is_none")]
+        fallback: Option<[u8; 4]>,
+    }
+
+    #[test]
+    fn selector_roundtrip() {
+        let val = Call { selector: [0xb5, 0x5a, 0xda, 0x39], fallback: Some([0, 0, 0, 1]) };
+        let s = serde_json::to_string(&val).unwrap();
+        assert_eq!(s, r#"{"selector":"0xb55ada39","fallback":"0x00000001"}"#);
+        assert_eq!(serde_json::from_str::<Call>(&s).unwrap(), val);
+
+        let val = Call { selector: [0xb5, 0x5a, 0xda, 0x39], fallback: None };
+        assert_eq!(serde_json::from_str::<Call>(r#"{"selector":"0xb55ada39"}"#).unwrap(), val);
+    }
+
+    #[test]
+    fn selector_wrong_length() {
+        serde_json::from_str::<Call>(r#"{"selector":"0xb55ada"}"#).unwrap_err();
+        serde_json::from_str::<Call>(r#"{"selector":"0xb55ada3900"}"#).unwrap_err();
+    }
+}
@@ -0,0 +1,217 @@
+//! Keys of the goat system executors, derived from a mnemonic phrase.
+
+use crate::{LocalSignerError, MnemonicBuilder, PrivateKeySigner};
+use alloy_consensus::transaction::goat::{
+    Module, BRIDGE_MODULE, LOCKING_EXECUTOR, LOCKING_MODULE, RELAYER_EXECUTOR, RELAYER_MODULE,
+};
+use alloy_network::{EthereumWallet, GoatWallet};
+use alloy_primitives::{Address, ChainId};
+use alloy_signer::Signer;
+use coins_bip39::{English, Wordlist};
+use std::fmt;
+
+/// The role of a goat executor key.
+///
+/// The relayer key signs the actions of the bridge and relayer modules, the locking key the
+/// actions of the locking module, see [`Module::executor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GoatRole {
+    /// The key of the relayer executor.
+    Relayer,
+    /// The key of the locking executor.
+    Locking,
+}
+
+impl GoatRole {
+    /// All roles.
+    pub const ALL: [Self; 2] = [Self::Relayer, Self::Locking];
+
+    /// Returns the role signing the actions of the given module, if the module is known.
+    pub const fn for_module(module: Module) -> Option<Self> {
+        match module {
+            BRIDGE_MODULE | RELAYER_MODULE => Some(Self::Relayer),
+            LOCKING_MODULE => Some(Self::Locking),
+            _ => None,
+        }
+    }
+
+    /// Returns the label of the role.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Relayer => "relayer",
+            Self::Locking => "locking",
+        }
+    }
+
+    /// Returns the fixed derivation path of the key of the role.
+    ///
+    /// Every role uses its own BIP-44 account, so that the executor keys never collide with the
+    /// regular accounts `m/44'/60'/0'/0/{index}` of the same mnemonic.
+    pub const fn derivation_path(self) -> &'static str {
+        match self {
+            Self::Relayer => "m/44'/60'/1'/0/0",
+            Self::Locking => "m/44'/60'/2'/0/0",
+        }
+    }
+
+    /// Returns the system executor address the role signs for.
+    pub const fn executor(self) -> Address {
+        match self {
+            Self::Relayer => RELAYER_EXECUTOR,
+            Self::Locking => LOCKING_EXECUTOR,
+        }
+    }
+}
+
+impl fmt::Display for GoatRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// The executor keys of a goat node, labeled by [`GoatRole`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_consensus::transaction::goat::LOCKING_MODULE;
+/// use alloy_signer_local::{GoatKeyring, GoatRole};
+///
+/// let phrase = "test test test test test test test test test test test junk";
+/// let keyring = GoatKeyring::from_mnemonic(phrase)?;
+/// assert_eq!(keyring.signer_for_module(LOCKING_MODULE), Some(keyring.signer(GoatRole::Locking)));
+/// # Ok::<_, alloy_signer_local::LocalSignerError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct GoatKeyring {
+    relayer: PrivateKeySigner,
+    locking: PrivateKeySigner,
+}
+
+impl GoatKeyring {
+    /// Creates a new keyring from the given keys.
+    pub const fn new(relayer: PrivateKeySigner, locking: PrivateKeySigner) -> Self {
+        Self { relayer, locking }
+    }
+
+    /// Derives the keys from an English mnemonic phrase at the fixed
+    /// [derivation paths](GoatRole::derivation_path) of the roles.
+    pub fn from_mnemonic<P: Into<String>>(phrase: P) -> Result<Self, LocalSignerError> {
+        Self::from_builder(&MnemonicBuilder::<English>::from_phrase(phrase))
+    }
+
+    /// Derives the keys with the phrase, wordlist and password of the builder, at the fixed
+    /// [derivation paths](GoatRole::derivation_path) of the roles.
+    ///
+    /// The derivation path of the builder is ignored.
+    pub fn from_builder<W: Wordlist + Clone>(
+        builder: &MnemonicBuilder<W>,
+    ) -> Result<Self, LocalSignerError> {
+        let derive =
+            |role: GoatRole| builder.clone().derivation_path(role.derivation_path())?.build();
+        Ok(Self::new(derive(GoatRole::Relayer)?, derive(GoatRole::Locking)?))
+    }
+
+    /// Sets the chain id of all keys.
+    pub fn with_chain_id(mut self, chain_id: Option<ChainId>) -> Self {
+        self.relayer.set_chain_id(chain_id);
+        self.locking.set_chain_id(chain_id);
+        self
+    }
+
+    /// Returns the key of the given role.
+    pub const fn signer(&self, role: GoatRole) -> &PrivateKeySigner {
+        match role {
+            GoatRole::Relayer => &self.relayer,
+            GoatRole::Locking => &self.locking,
+        }
+    }
+
+    /// Returns the key signing the actions of the given module, if the module is known.
+    pub const fn signer_for_module(&self, module: Module) -> Option<&PrivateKeySigner> {
+        match GoatRole::for_module(module) {
+            Some(role) => Some(self.signer(role)),
+            None => None,
+        }
+    }
+
+    /// Returns the keys along with their role.
+    pub fn iter(&self) -> impl Iterator<Item = (GoatRole, &PrivateKeySigner)> {
+        GoatRole::ALL.into_iter().map(|role| (role, self.signer(role)))
+    }
+
+    /// Returns a [`GoatWallet`] signing the goat system transactions with the keys, and all other
+    /// transactions with the given wallet.
+    pub fn into_wallet(self, wallet: impl Into<EthereumWallet>) -> GoatWallet {
+        GoatWallet::new(wallet)
+            .with_module_signer(BRIDGE_MODULE, self.relayer.clone())
+            .with_module_signer(RELAYER_MODULE, self.relayer)
+            .with_module_signer(LOCKING_MODULE, self.locking)
+    }
+
+    /// Returns the key of the relayer role.
+    pub const fn relayer(&self) -> &PrivateKeySigner {
+        &self.relayer
+    }
+
+    /// Returns the key of the locking role.
+    pub const fn locking(&self) -> &PrivateKeySigner {
+        &self.locking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::TxSigner;
+
+    const PHRASE: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn goat_keyring_from_mnemonic() {
+        let keyring = GoatKeyring::from_mnemonic(PHRASE).unwrap();
+        for (role, signer) in keyring.iter() {
+            let expected = MnemonicBuilder::from_phrase(PHRASE)
+                .derivation_path(role.derivation_path())
+                .unwrap()
+                .build()
+                .unwrap();
+            assert_eq!(signer.address(), expected.address(), "{role}");
+        }
+        assert_ne!(keyring.relayer().address(), keyring.locking().address());
+
+        // the executor keys are not regular accounts of the mnemonic
+        let first = MnemonicBuilder::from_phrase_first(PHRASE).address();
+        assert!(keyring.iter().all(|(_, signer)| signer.address() != first));
+
+        assert_eq!(keyring.signer_for_module(BRIDGE_MODULE), Some(keyring.relayer()));
+        assert_eq!(keyring.signer_for_module(RELAYER_MODULE), Some(keyring.relayer()));
+        assert_eq!(keyring.signer_for_module(LOCKING_MODULE), Some(keyring.locking()));
+        assert_eq!(
+            GoatRole::for_module(LOCKING_MODULE).map(GoatRole::executor),
+            Some(LOCKING_MODULE.executor())
+        );
+
+        // the password changes the keys
+        let builder = MnemonicBuilder::from_phrase(PHRASE).password("secret");
+        let other = GoatKeyring::from_builder(&builder).unwrap().with_chain_id(Some(48816));
+        assert_ne!(other.relayer().address(), keyring.relayer().address());
+        assert!(other.iter().all(|(_, signer)| signer.chain_id() == Some(48816)));
+    }
+
+    #[test]
+    fn goat_keyring_into_wallet() {
+        let keyring = GoatKeyring::from_mnemonic(PHRASE).unwrap();
+        let default = MnemonicBuilder::from_phrase_first(PHRASE);
+        let wallet = keyring.clone().into_wallet(default.clone());
+        for module in [BRIDGE_MODULE, RELAYER_MODULE, LOCKING_MODULE] {
+            let signer = wallet.module_signer(module).unwrap();
+            assert_eq!(
+                TxSigner::address(&*signer),
+                keyring.signer_for_module(module).unwrap().address(),
+                "{module}"
+            );
+        }
+        assert_eq!(wallet.wallet().default_signer().address(), default.address());
+    }
+}
@@ -22,6 +22,11 @@ mod mnemonic;
 #[cfg(feature = "mnemonic")]
 pub use mnemonic::{MnemonicBuilder, MnemonicBuilderError, MnemonicSignerIter};
 
+#[cfg(feature = "mnemonic")]
+mod goat;
+#[cfg(feature = "mnemonic")]
+pub use goat::{GoatKeyring, GoatRole};
+
 mod private_key;
 
 #[cfg(feature = "yubihsm")]
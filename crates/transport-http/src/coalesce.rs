@@ -0,0 +1,345 @@
+use alloy_json_rpc::{goat, RequestPacket, ResponsePacket, RpcError};
+use alloy_transport::{TransportError, TransportErrorKind, TransportFut};
+use futures::{
+    future::{BoxFuture, WeakShared},
+    FutureExt,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// The methods that are never coalesced by default, because they are not idempotent or create
+/// server-side state that belongs to the caller.
+pub const DEFAULT_EXCLUDED_METHODS: &[&str] = &[
+    "eth_sendRawTransaction",
+    "eth_sendTransaction",
+    "eth_subscribe",
+    "eth_unsubscribe",
+    "eth_newFilter",
+    "eth_newBlockFilter",
+    "eth_newPendingTransactionFilter",
+    "eth_getFilterChanges",
+    "eth_uninstallFilter",
+    goat::GOAT_SUBMIT_DEPOSIT,
+    goat::GOAT_SUBSCRIBE,
+    goat::GOAT_UNSUBSCRIBE,
+];
+
+/// The method and params of a request.
+type RequestKey = (String, Option<String>);
+
+/// The response of a coalesced request, shared by its callers.
+type SharedFuture = BoxFuture<'static, Result<ResponsePacket, Arc<TransportError>>>;
+
+/// The requests in flight, along with the id of their entry.
+///
+/// Only weak references to the responses are kept, so that a request whose callers are all gone is
+/// dropped, removing its entry.
+type InFlight = Arc<Mutex<HashMap<RequestKey, (u64, WeakShared<SharedFuture>)>>>;
+
+/// A layer coalescing identical in-flight requests into a single upstream request.
+///
+/// Two single requests are identical if they have the same method and params. While a request is
+/// in flight, identical requests wait for its response instead of being sent, and each caller
+/// receives the response with its own request id. This avoids redundant reads when many tasks ask
+/// for the same block or receipt at the same time, e.g. several goat deposit watchers.
+///
+/// Batch requests, subscriptions and the methods excluded with
+/// [`with_excluded_method`](Self::with_excluded_method), by default the
+/// [`DEFAULT_EXCLUDED_METHODS`], are always sent as is.
+#[derive(Clone, Debug)]
+pub struct CoalesceLayer {
+    excluded_methods: Vec<String>,
+}
+
+impl Default for CoalesceLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoalesceLayer {
+    /// Creates a new layer excluding the [`DEFAULT_EXCLUDED_METHODS`].
+    pub fn new() -> Self {
+        Self { excluded_methods: DEFAULT_EXCLUDED_METHODS.iter().map(|m| m.to_string()).collect() }
+    }
+
+    /// Excludes the given method from coalescing.
+    pub fn with_excluded_method(mut self, method: impl Into<String>) -> Self {
+        let method = method.into();
+        if !self.excluded_methods.contains(&method) {
+            self.excluded_methods.push(method);
+        }
+        self
+    }
+
+    /// Returns the methods that are never coalesced.
+    pub fn excluded_methods(&self) -> &[String] {
+        &self.excluded_methods
+    }
+}
+
+impl<S> Layer<S> for CoalesceLayer {
+    type Service = CoalesceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CoalesceService {
+            inner,
+            excluded_methods: self.excluded_methods.clone().into(),
+            in_flight: Default::default(),
+            next_id: Default::default(),
+        }
+    }
+}
+
+/// A service coalescing identical in-flight requests, see [`CoalesceLayer`].
+#[derive(Clone, Debug)]
+pub struct CoalesceService<S> {
+    inner: S,
+    excluded_methods: Arc<[String]>,
+    in_flight: InFlight,
+    next_id: Arc<AtomicU64>,
+}
+
+impl<S> CoalesceService<S> {
+    /// Returns the number of distinct requests currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().unwrap().len()
+    }
+
+    /// Returns the key of the request if it may be coalesced.
+    fn coalesce_key(&self, request: &RequestPacket) -> Option<RequestKey> {
+        let request = request.as_single()?;
+        let method = request.method();
+        if request.is_subscription() || self.excluded_methods.iter().any(|m| m == method) {
+            return None;
+        }
+        Some((method.to_string(), request.params().map(|params| params.get().to_string())))
+    }
+}
+
+impl<S> Service<RequestPacket> for CoalesceService<S>
+where
+    S: Service<RequestPacket, Response = ResponsePacket, Error = TransportError>
+        + Send
+        + 'static
+        + Clone,
+    S::Future: Send + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let Some(key) = self.coalesce_key(&request) else {
+            return Box::pin(self.inner.call(request));
+        };
+        let id = request.as_single().expect("only single requests are coalesced").id().clone();
+
+        // the lock is never held while calling the inner service, or while dropping a response,
+        // which would remove its entry
+        let in_flight = self.in_flight.lock().unwrap().get(&key).and_then(|(_, w)| w.upgrade());
+        let response = match in_flight {
+            Some(response) => response,
+            None => {
+                let fut = self.inner.call(request);
+                let guard = InFlightGuard {
+                    in_flight: self.in_flight.clone(),
+                    key: key.clone(),
+                    id: self.next_id.fetch_add(1, Ordering::Relaxed),
+                };
+                let entry_id = guard.id;
+                let response = async move {
+                    let res = fut.await.map_err(Arc::new);
+                    drop(guard);
+                    res
+                }
+                .boxed()
+                .shared();
+
+                let mut in_flight = self.in_flight.lock().unwrap();
+                let raced = in_flight.get(&key).and_then(|(_, weak)| weak.upgrade());
+                if raced.is_none() {
+                    let weak = response.downgrade().expect("the response is not polled yet");
+                    in_flight.insert(key, (entry_id, weak));
+                }
+                drop(in_flight);
+                // an identical request was sent meanwhile, ours is dropped without being polled
+                raced.unwrap_or(response)
+            }
+        };
+
+        Box::pin(async move {
+            match response.await {
+                Ok(ResponsePacket::Single(mut response)) => {
+                    response.id = id;
+                    Ok(ResponsePacket::Single(response))
+                }
+                Ok(packet) => Ok(packet),
+                Err(err) => Err(Arc::try_unwrap(err).unwrap_or_else(|err| clone_error(&err))),
+            }
+        })
+    }
+}
+
+/// Removes the entry of a coalesced request once it completed, or once all its callers are gone.
+#[derive(Debug)]
+struct InFlightGuard {
+    in_flight: InFlight,
+    key: RequestKey,
+    id: u64,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        // the entry may already belong to a newer identical request
+        if in_flight.get(&self.key).is_some_and(|(id, _)| *id == self.id) {
+            in_flight.remove(&self.key);
+        }
+    }
+}
+
+/// Clones the error of a coalesced request for a caller that cannot take the original error, which
+/// only the last remaining holder receives.
+///
+/// Errors that cannot be cloned are wrapped in a custom error, whose source is the shared original
+/// error.
+fn clone_error(err: &Arc<TransportError>) -> TransportError {
+    match &**err {
+        RpcError::ErrorResp(payload) => RpcError::ErrorResp(payload.clone()),
+        RpcError::NullResp => RpcError::NullResp,
+        RpcError::UnsupportedFeature(feature) => RpcError::UnsupportedFeature(feature),
+        RpcError::Transport(TransportErrorKind::HttpError(err)) => {
+            TransportErrorKind::http_error(err.status, err.body.clone())
+        }
+        RpcError::Transport(TransportErrorKind::BackendGone) => TransportErrorKind::backend_gone(),
+        _ => TransportErrorKind::custom(err.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{Id, Request, Response, ResponsePayload};
+    use serde_json::value::RawValue;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn request(id: u64, method: &'static str, block: &str) -> RequestPacket {
+        Request::new(method, Id::Number(id), [block.to_string()]).serialize().unwrap().into()
+    }
+
+    #[tokio::test]
+    async fn coalesces_identical_requests() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let inner = tower::service_fn({
+            let calls = calls.clone();
+            move |request: RequestPacket| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                let id = request.as_single().unwrap().id().clone();
+                async move {
+                    tokio::task::yield_now().await;
+                    let payload = RawValue::from_string(r#""0x1""#.to_string()).unwrap();
+                    Ok::<_, TransportError>(ResponsePacket::Single(Response {
+                        id,
+                        payload: ResponsePayload::Success(payload),
+                    }))
+                }
+            }
+        });
+        let mut service = CoalesceLayer::new().layer(inner);
+
+        let a = service.call(request(1, "eth_getBlockByNumber", "0x5"));
+        let b = service.call(request(2, "eth_getBlockByNumber", "0x5"));
+        let c = service.call(request(3, "eth_getBlockByNumber", "0x6"));
+        let d = service.call(request(4, "eth_sendRawTransaction", "0x00"));
+        let e = service.call(request(5, "eth_sendRawTransaction", "0x00"));
+        assert_eq!(service.in_flight(), 2);
+
+        let (a, b, c, d, e) = futures::join!(a, b, c, d, e);
+        let ids = [a, b, c, d, e].map(|res| res.unwrap().as_single().unwrap().id.clone());
+        assert_eq!(ids, [1, 2, 3, 4, 5].map(Id::Number));
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(service.in_flight(), 0);
+
+        // completed requests are sent again
+        service.call(request(6, "eth_getBlockByNumber", "0x5")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn drops_abandoned_requests() {
+        let inner = tower::service_fn(|_: RequestPacket| futures::future::pending());
+        let mut service = CoalesceLayer::new().layer(inner);
+
+        let a = service.call(request(1, "eth_getBlockByNumber", "0x5"));
+        let b = service.call(request(2, "eth_getBlockByNumber", "0x5"));
+        assert_eq!(service.in_flight(), 1);
+        drop(a);
+        assert_eq!(service.in_flight(), 1);
+        drop(b);
+        assert_eq!(service.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn propagates_original_error() {
+        let inner = tower::service_fn(|_: RequestPacket| async {
+            Err::<ResponsePacket, _>(TransportErrorKind::custom(std::io::Error::other("boom")))
+        });
+        let mut service = CoalesceLayer::new().layer(inner);
+        let io_error = |err: &TransportError| match err {
+            RpcError::Transport(TransportErrorKind::Custom(err)) => {
+                err.downcast_ref::<std::io::Error>().map(ToString::to_string)
+            }
+            _ => None,
+        };
+
+        // a single caller receives the original error
+        let err = service.call(request(1, "eth_getBlockByNumber", "0x5")).await.unwrap_err();
+        assert_eq!(io_error(&err).as_deref(), Some("boom"));
+
+        // errors that cannot be cloned are shared as the source of a custom error
+        let a = service.call(request(1, "eth_getBlockByNumber", "0x5"));
+        let b = service.call(request(2, "eth_getBlockByNumber", "0x5"));
+        let (a, b) = futures::join!(a, b);
+        for err in [a.unwrap_err(), b.unwrap_err()] {
+            let original = match &err {
+                RpcError::Transport(TransportErrorKind::Custom(err)) => err
+                    .downcast_ref::<Arc<TransportError>>()
+                    .and_then(|err| io_error(err))
+                    .or_else(|| err.downcast_ref::<std::io::Error>().map(ToString::to_string)),
+                _ => None,
+            };
+            assert_eq!(original.as_deref(), Some("boom"), "{err:?}");
+        }
+        assert_eq!(service.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn shares_errors() {
+        let inner = tower::service_fn(|_: RequestPacket| async {
+            Err::<ResponsePacket, _>(TransportErrorKind::http_error(429, "slow down".into()))
+        });
+        let mut service = CoalesceLayer::new().layer(inner);
+
+        let a = service.call(request(1, "eth_getBlockByNumber", "0x5"));
+        let b = service.call(request(2, "eth_getBlockByNumber", "0x5"));
+        let (a, b) = futures::join!(a, b);
+        for err in [a.unwrap_err(), b.unwrap_err()] {
+            assert!(matches!(
+                err,
+                RpcError::Transport(TransportErrorKind::HttpError(ref err)) if err.is_rate_limit_err()
+            ));
+        }
+    }
+}
@@ -20,6 +20,11 @@ pub use hyper;
 #[cfg(all(not(target_family = "wasm"), feature = "hyper"))]
 pub use hyper_util;
 
+#[cfg(feature = "coalesce")]
+mod coalesce;
+#[cfg(feature = "coalesce")]
+pub use coalesce::{CoalesceLayer, CoalesceService, DEFAULT_EXCLUDED_METHODS};
+
 #[cfg(all(not(target_family = "wasm"), feature = "hyper", feature = "jwt-auth"))]
 mod layers;
 #[cfg(all(not(target_family = "wasm"), feature = "hyper", feature = "jwt-auth"))]
@@ -215,7 +215,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_json_rpc::PubSubItem;
+    use alloy_json_rpc::{goat::RelayerProposalNotification, PubSubItem};
     use std::future::poll_fn;
 
     #[tokio::test]
@@ -239,6 +239,28 @@ mod tests {
         let _obj = reader.next().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_goat_subscription() {
+        let mock = tokio_test::io::Builder::new()
+            // subscription response, followed by a partial notification
+            .read(br#"{"jsonrpc":"2.0","id":1,"result":"0x1"}{"jsonrpc":"2.0","method":"goat_subscription","#)
+            // trigger pending read
+            .wait(std::time::Duration::from_millis(1))
+            // complete notification
+            .read(br#""params":{"subscription":"0x1","result":{"proposer":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","btcHeight":840000,"btcBlockHash":"0x0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5"}}}"#)
+            .build();
+
+        let mut reader = ReadJsonStream::<_, PubSubItem>::new(mock);
+        assert!(matches!(reader.next().await, Some(PubSubItem::Response(_))));
+
+        let Some(PubSubItem::Notification(notification)) = reader.next().await else {
+            panic!("expected notification");
+        };
+        let proposal: RelayerProposalNotification =
+            serde_json::from_str(notification.result.get()).unwrap();
+        assert_eq!(proposal.btc_height, 840000);
+    }
+
     #[tokio::test]
     async fn test_large_invalid() {
         let mock = tokio_test::io::Builder::new()
@@ -0,0 +1,408 @@
+use crate::{time::Instant, TransportError, TransportErrorKind, TransportFut};
+use alloy_json_rpc::{Id, Request, RequestPacket, ResponsePacket};
+use core::time::Duration;
+use futures::future::join_all;
+use parking_lot::Mutex;
+use std::{
+    num::NonZeroU32,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+use tracing::{debug, trace};
+
+const DEFAULT_MAX_BLOCK_LAG: u64 = 5;
+const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(12);
+
+/// The [`FailoverService`] consumes multiple transports and sends each request
+/// to a single active transport, rotating to the next healthy transport when
+/// the active one fails or falls behind.
+///
+/// Unlike the [`FallbackService`](super::FallbackService), requests are never
+/// sent to multiple transports in parallel, so the service is safe to use for
+/// transaction submission.
+///
+/// A transport is healthy if its last requests did not fail
+/// [`max_consecutive_failures`](FailoverLayer::with_max_consecutive_failures)
+/// times in a row and its block height, as reported by `eth_blockNumber`, is
+/// at most [`max_block_lag`](FailoverLayer::with_max_block_lag) blocks behind
+/// the highest block height of all transports. The block heights are checked
+/// before a request once the
+/// [`health_check_interval`](FailoverLayer::with_health_check_interval) has
+/// elapsed since the last check.
+#[derive(Debug, Clone)]
+pub struct FailoverService<S> {
+    /// The list of transports to use
+    transports: Arc<Vec<S>>,
+    /// The health of the transports and the active transport
+    state: Arc<Mutex<FailoverState>>,
+    /// The health configuration
+    config: FailoverLayer,
+}
+
+/// The shared state of a [`FailoverService`].
+#[derive(Debug)]
+struct FailoverState {
+    /// The index of the active transport
+    active: usize,
+    /// The health of each transport
+    health: Vec<TransportHealth>,
+    /// The last time the block heights were checked
+    last_health_check: Option<Instant>,
+}
+
+/// The health of a transport.
+#[derive(Debug, Clone, Copy, Default)]
+struct TransportHealth {
+    /// The number of requests that failed since the last successful request
+    consecutive_failures: u32,
+    /// The last block height reported by the transport
+    block_number: Option<u64>,
+}
+
+impl FailoverState {
+    /// Returns the highest block height reported by any transport.
+    fn best_block_number(&self) -> Option<u64> {
+        self.health.iter().filter_map(|health| health.block_number).max()
+    }
+
+    /// Returns the transport indices in the order they should be tried.
+    ///
+    /// Starting from the active transport, the healthy transports are tried
+    /// first, followed by the unhealthy ones as a last resort.
+    fn candidates(&self, config: &FailoverLayer) -> Vec<usize> {
+        let best = self.best_block_number();
+        let len = self.health.len();
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) = (0..len)
+            .map(|offset| (self.active + offset) % len)
+            .partition(|&idx| self.is_healthy(idx, best, config));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    /// Returns true if the transport neither failed too often nor lags behind.
+    fn is_healthy(&self, idx: usize, best: Option<u64>, config: &FailoverLayer) -> bool {
+        let health = &self.health[idx];
+        let lagging = match (best, health.block_number) {
+            (Some(best), Some(block_number)) => best - block_number > config.max_block_lag,
+            _ => false,
+        };
+        health.consecutive_failures < config.max_consecutive_failures && !lagging
+    }
+}
+
+impl<S: Clone> FailoverService<S> {
+    /// Create a new failover service from a list of transports, using the
+    /// default health configuration of [`FailoverLayer`].
+    ///
+    /// The first transport is the initially active one.
+    pub fn new(transports: Vec<S>) -> Self {
+        FailoverLayer::default().service(transports)
+    }
+
+    /// Returns the index of the active transport.
+    pub fn active(&self) -> usize {
+        self.state.lock().active
+    }
+
+    /// Returns true if the health check interval has elapsed, marking the
+    /// check as started.
+    fn start_health_check(&self) -> bool {
+        let mut state = self.state.lock();
+        let due = state
+            .last_health_check
+            .is_none_or(|last| last.elapsed() >= self.config.health_check_interval);
+        if due {
+            state.last_health_check = Some(Instant::now());
+        }
+        due
+    }
+
+    /// Records the result of a request to the transport.
+    fn track(&self, idx: usize, success: bool) {
+        let mut state = self.state.lock();
+        let health = &mut state.health[idx];
+        if success {
+            health.consecutive_failures = 0;
+            if state.active != idx {
+                debug!(from = state.active, to = idx, "Rotating active transport");
+                state.active = idx;
+            }
+        } else {
+            health.consecutive_failures = health.consecutive_failures.saturating_add(1);
+        }
+    }
+}
+
+impl<S> FailoverService<S>
+where
+    S: Service<RequestPacket, Future = TransportFut<'static>, Error = TransportError>
+        + Send
+        + Clone
+        + 'static,
+{
+    /// Queries the block height of all transports in parallel and updates
+    /// their health.
+    ///
+    /// This is done automatically before a request once the health check
+    /// interval has elapsed.
+    pub async fn check_health(&self) {
+        let futures = self.transports.iter().cloned().map(|mut transport| async move {
+            let req = Request::new("eth_blockNumber", Id::Number(0), ()).serialize().ok()?;
+            let res = transport.call(RequestPacket::Single(req)).await.ok()?;
+            let block_number: String = res.single_payload()?.try_success_as()?.ok()?;
+            u64::from_str_radix(block_number.strip_prefix("0x")?, 16).ok()
+        });
+        let block_numbers = join_all(futures).await;
+
+        let mut state = self.state.lock();
+        for (health, block_number) in state.health.iter_mut().zip(block_numbers) {
+            match block_number {
+                Some(block_number) => {
+                    health.block_number = Some(block_number);
+                    health.consecutive_failures = 0;
+                }
+                None => health.consecutive_failures = health.consecutive_failures.saturating_add(1),
+            }
+        }
+        trace!(health = ?state.health, "Checked transport health");
+    }
+
+    /// Make a request to the failover service middleware.
+    ///
+    /// The transports are tried one at a time, starting with the active
+    /// transport if it is healthy. The first transport that succeeds becomes
+    /// the active transport. If all transports fail, the last error is
+    /// returned.
+    async fn make_request(&self, req: RequestPacket) -> Result<ResponsePacket, TransportError> {
+        if self.start_health_check() {
+            self.check_health().await;
+        }
+
+        let candidates = self.state.lock().candidates(&self.config);
+        let mut last_error = None;
+
+        for idx in candidates {
+            let mut transport = self.transports[idx].clone();
+            match transport.call(req.clone()).await {
+                Ok(response) => {
+                    self.track(idx, true);
+                    return Ok(response);
+                }
+                Err(error) => {
+                    trace!("Transport[{}] failed: {:?}, trying next", idx, error);
+                    self.track(idx, false);
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| TransportErrorKind::custom_str("No transports configured")))
+    }
+}
+
+impl<S> Service<RequestPacket> for FailoverService<S>
+where
+    S: Service<RequestPacket, Future = TransportFut<'static>, Error = TransportError>
+        + Send
+        + Sync
+        + Clone
+        + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Service is always ready
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move { this.make_request(req).await })
+    }
+}
+
+/// Failover layer for rotating between transports on failure or lag. This
+/// layer will consume a list of transports and send each request to the
+/// active one, see [`FailoverService`].
+///
+/// By default, a transport is considered unhealthy after 3 consecutive
+/// failures or when it is more than 5 blocks behind the other transports, and
+/// the block heights are checked every 12 seconds.
+#[derive(Debug, Clone, Copy)]
+pub struct FailoverLayer {
+    /// The number of blocks a transport may lag behind
+    max_block_lag: u64,
+    /// The number of consecutive failures after which a transport is unhealthy
+    max_consecutive_failures: u32,
+    /// The minimum time between block height checks
+    health_check_interval: Duration,
+}
+
+impl FailoverLayer {
+    /// Set the number of blocks a transport may lag behind the highest block
+    /// height of all transports.
+    pub const fn with_max_block_lag(mut self, max_block_lag: u64) -> Self {
+        self.max_block_lag = max_block_lag;
+        self
+    }
+
+    /// Set the number of consecutive failures after which a transport is
+    /// considered unhealthy.
+    pub const fn with_max_consecutive_failures(mut self, max: NonZeroU32) -> Self {
+        self.max_consecutive_failures = max.get();
+        self
+    }
+
+    /// Set the minimum time between block height checks.
+    pub const fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    fn service<S>(&self, transports: Vec<S>) -> FailoverService<S> {
+        let state = FailoverState {
+            active: 0,
+            health: vec![TransportHealth::default(); transports.len()],
+            last_health_check: None,
+        };
+        FailoverService {
+            transports: Arc::new(transports),
+            state: Arc::new(Mutex::new(state)),
+            config: *self,
+        }
+    }
+}
+
+impl<S> Layer<Vec<S>> for FailoverLayer
+where
+    S: Service<RequestPacket, Future = TransportFut<'static>, Error = TransportError>
+        + Send
+        + Clone
+        + 'static,
+{
+    type Service = FailoverService<S>;
+
+    fn layer(&self, inner: Vec<S>) -> Self::Service {
+        self.service(inner)
+    }
+}
+
+impl Default for FailoverLayer {
+    fn default() -> Self {
+        Self {
+            max_block_lag: DEFAULT_MAX_BLOCK_LAG,
+            max_consecutive_failures: DEFAULT_MAX_CONSECUTIVE_FAILURES,
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{Response, ResponsePayload};
+    use serde_json::value::RawValue;
+    use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+    /// A mock node at a configurable block height that can be taken offline.
+    #[derive(Clone, Default)]
+    struct MockNode {
+        block_number: Arc<AtomicU64>,
+        offline: Arc<AtomicBool>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockNode {
+        fn at(block_number: u64) -> Self {
+            let node = Self::default();
+            node.block_number.store(block_number, Ordering::SeqCst);
+            node
+        }
+    }
+
+    impl Service<RequestPacket> for MockNode {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: RequestPacket) -> Self::Future {
+            let node = self.clone();
+            Box::pin(async move {
+                if node.offline.load(Ordering::SeqCst) {
+                    return Err(TransportErrorKind::custom_str("offline"));
+                }
+                let req = req.as_single().unwrap();
+                if req.method() != "eth_blockNumber" {
+                    node.calls.fetch_add(1, Ordering::SeqCst);
+                }
+                let block_number = node.block_number.load(Ordering::SeqCst);
+                let payload = RawValue::from_string(format!("\"{block_number:#x}\"")).unwrap();
+                Ok(ResponsePacket::Single(Response {
+                    id: req.id().clone(),
+                    payload: ResponsePayload::Success(payload),
+                }))
+            })
+        }
+    }
+
+    fn request() -> RequestPacket {
+        RequestPacket::Single(Request::new("eth_chainId", Id::Number(1), ()).serialize().unwrap())
+    }
+
+    #[tokio::test]
+    async fn failover_on_failure() {
+        let nodes = [MockNode::at(100), MockNode::at(100), MockNode::at(100)];
+        let mut service = FailoverLayer::default()
+            .with_max_consecutive_failures(NonZeroU32::new(1).unwrap())
+            .layer(nodes.to_vec());
+
+        service.call(request()).await.unwrap();
+        assert_eq!(service.active(), 0);
+
+        nodes[0].offline.store(true, Ordering::SeqCst);
+        service.call(request()).await.unwrap();
+        assert_eq!(service.active(), 1);
+
+        // the service sticks to the new active transport once the failed one recovers
+        nodes[0].offline.store(false, Ordering::SeqCst);
+        service.call(request()).await.unwrap();
+        assert_eq!(service.active(), 1);
+        assert_eq!(nodes.each_ref().map(|node| node.calls.load(Ordering::SeqCst)), [1, 2, 0]);
+
+        for node in &nodes {
+            node.offline.store(true, Ordering::SeqCst);
+        }
+        assert!(service.call(request()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failover_on_lag() {
+        let nodes = [MockNode::at(100), MockNode::at(100)];
+        let mut service = FailoverLayer::default()
+            .with_max_block_lag(2)
+            .with_health_check_interval(Duration::ZERO)
+            .layer(nodes.to_vec());
+
+        service.call(request()).await.unwrap();
+        assert_eq!(service.active(), 0);
+
+        // within the allowed lag
+        nodes[1].block_number.store(102, Ordering::SeqCst);
+        service.call(request()).await.unwrap();
+        assert_eq!(service.active(), 0);
+
+        nodes[1].block_number.store(103, Ordering::SeqCst);
+        service.call(request()).await.unwrap();
+        assert_eq!(service.active(), 1);
+        assert_eq!(nodes.each_ref().map(|node| node.calls.load(Ordering::SeqCst)), [2, 1]);
+    }
+}
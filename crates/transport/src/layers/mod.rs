@@ -15,3 +15,7 @@ pub use retry::{
 /// FallbackLayer
 mod fallback;
 pub use fallback::{FallbackLayer, FallbackService};
+
+/// FailoverLayer
+mod failover;
+pub use failover::{FailoverLayer, FailoverService};
@@ -5,6 +5,8 @@ use crate::{
 use alloy_json_rpc::{RequestPacket, ResponsePacket};
 use core::fmt;
 use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
     sync::{
         atomic::{AtomicU32, Ordering},
         Arc,
@@ -38,6 +40,10 @@ pub struct RetryBackoffLayer<P: RetryPolicy = RateLimitRetryPolicy> {
     compute_units_per_second: u64,
     /// The average cost of a request. Defaults to [DEFAULT_AVG_COST].
     avg_cost: u64,
+    /// The maximum random delay added to each backoff.
+    jitter: Duration,
+    /// The maximum number of retries of specific methods, overriding `max_rate_limit_retries`.
+    method_retries: Vec<(String, u32)>,
     /// The [RetryPolicy] to use. Defaults to [RateLimitRetryPolicy].
     policy: P,
 }
@@ -54,6 +60,8 @@ impl RetryBackoffLayer {
             initial_backoff,
             compute_units_per_second,
             avg_cost: DEFAULT_AVG_COST,
+            jitter: Duration::ZERO,
+            method_retries: Vec::new(),
             policy: RateLimitRetryPolicy,
         }
     }
@@ -93,8 +101,30 @@ impl<P: RetryPolicy> RetryBackoffLayer<P> {
             compute_units_per_second,
             policy,
             avg_cost: DEFAULT_AVG_COST,
+            jitter: Duration::ZERO,
+            method_retries: Vec::new(),
         }
     }
+
+    /// Sets the maximum random delay added to each backoff. Defaults to zero.
+    ///
+    /// Jitter spreads the retries of concurrent requests that were rate limited at the same time,
+    /// instead of retrying them all at once.
+    pub const fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the maximum number of retries of the given method, overriding the maximum number of
+    /// retries of the layer.
+    ///
+    /// A batch request is retried at most as many times as the most restricted method it contains.
+    pub fn with_method_max_retries(mut self, method: impl Into<String>, max_retries: u32) -> Self {
+        let method = method.into();
+        self.method_retries.retain(|(m, _)| *m != method);
+        self.method_retries.push((method, max_retries));
+        self
+    }
 }
 
 /// [RateLimitRetryPolicy] implements [RetryPolicy] to determine whether to retry depending on the
@@ -184,6 +214,8 @@ impl<S, P: RetryPolicy + Clone> Layer<S> for RetryBackoffLayer<P> {
             compute_units_per_second: self.compute_units_per_second,
             requests_enqueued: Arc::new(AtomicU32::new(0)),
             avg_cost: self.avg_cost,
+            jitter: self.jitter,
+            method_retries: self.method_retries.clone(),
         }
     }
 }
@@ -206,12 +238,38 @@ pub struct RetryBackoffService<S, P: RetryPolicy = RateLimitRetryPolicy> {
     requests_enqueued: Arc<AtomicU32>,
     /// The average cost of a request.
     avg_cost: u64,
+    /// The maximum random delay added to each backoff.
+    jitter: Duration,
+    /// The maximum number of retries of specific methods.
+    method_retries: Vec<(String, u32)>,
 }
 
 impl<S, P: RetryPolicy> RetryBackoffService<S, P> {
     const fn initial_backoff(&self) -> Duration {
         Duration::from_millis(self.initial_backoff)
     }
+
+    /// Returns the maximum number of retries of the request, which is the lowest budget of the
+    /// methods it contains.
+    fn max_retries(&self, request: &RequestPacket) -> u32 {
+        request
+            .method_names()
+            .filter_map(|method| {
+                self.method_retries.iter().find(|(m, _)| m == method).map(|(_, max)| *max)
+            })
+            .min()
+            .map_or(self.max_rate_limit_retries, |max| max.min(self.max_rate_limit_retries))
+    }
+
+    /// Returns a random delay of at most the configured jitter.
+    fn jitter(&self) -> Duration {
+        if self.jitter.is_zero() {
+            return Duration::ZERO;
+        }
+        // `RandomState` is randomly seeded, which is enough to spread retries
+        let random = RandomState::new().hash_one(self.requests_enqueued.load(Ordering::Relaxed));
+        self.jitter.mul_f64(random as f64 / u64::MAX as f64)
+    }
 }
 
 impl<S, P> Service<RequestPacket> for RetryBackoffService<S, P>
@@ -238,6 +296,7 @@ where
         let mut inner = std::mem::replace(&mut self.inner, inner);
         Box::pin(async move {
             let ahead_in_queue = this.requests_enqueued.fetch_add(1, Ordering::SeqCst) as u64;
+            let max_retries = this.max_retries(&request);
             let mut rate_limit_retry_number: u32 = 0;
             loop {
                 let err;
@@ -258,7 +317,7 @@ where
                 let should_retry = this.policy.should_retry(&err);
                 if should_retry {
                     rate_limit_retry_number += 1;
-                    if rate_limit_retry_number > this.max_rate_limit_retries {
+                    if rate_limit_retry_number > max_retries {
                         this.requests_enqueued.fetch_sub(1, Ordering::SeqCst);
                         return Err(TransportErrorKind::custom_str(&format!(
                             "Max retries exceeded {err}"
@@ -279,14 +338,17 @@ where
                         current_queued_reqs,
                         ahead_in_queue,
                     );
+                    let jitter = this.jitter();
                     let total_backoff = next_backoff
-                        + std::time::Duration::from_secs(seconds_to_wait_for_compute_budget);
+                        + std::time::Duration::from_secs(seconds_to_wait_for_compute_budget)
+                        + jitter;
 
                     trace!(
                         total_backoff_millis = total_backoff.as_millis(),
                         budget_backoff_millis = seconds_to_wait_for_compute_budget * 1000,
                         default_backoff_millis = next_backoff.as_millis(),
                         backoff_hint_millis = backoff_hint.map(|d| d.as_millis()),
+                        jitter_millis = jitter.as_millis(),
                         "(all in ms) backing off due to rate limit"
                     );
 
@@ -336,4 +398,69 @@ mod tests {
         let offset = compute_unit_offset_in_secs(17, 10, 2, 2);
         assert_eq!(offset, 2);
     }
+
+    fn request(methods: &[&'static str]) -> RequestPacket {
+        let mut packet = RequestPacket::with_capacity(methods.len());
+        for (id, method) in methods.iter().enumerate() {
+            let request = alloy_json_rpc::Request::new(*method, (id as u64).into(), ());
+            packet.push(request.serialize().unwrap());
+        }
+        packet
+    }
+
+    #[test]
+    fn method_retry_budgets() {
+        let layer = RetryBackoffLayer::new(10, 0, 10_000)
+            .with_method_max_retries("eth_sendRawTransaction", 2)
+            .with_method_max_retries("eth_getLogs", 20);
+        let service = layer.layer(crate::mock::MockTransport::new(Default::default()));
+
+        assert_eq!(service.max_retries(&request(&["eth_blockNumber"])), 10);
+        assert_eq!(service.max_retries(&request(&["eth_sendRawTransaction"])), 2);
+        // a budget can not exceed the maximum number of retries of the layer
+        assert_eq!(service.max_retries(&request(&["eth_getLogs"])), 10);
+        assert_eq!(
+            service.max_retries(&request(&["eth_blockNumber", "eth_sendRawTransaction"])),
+            2
+        );
+    }
+
+    #[test]
+    fn bounded_jitter() {
+        let transport = crate::mock::MockTransport::new(Default::default());
+        let service = RetryBackoffLayer::new(10, 0, 10_000).layer(transport.clone());
+        assert_eq!(service.jitter(), Duration::ZERO);
+
+        let jitter = Duration::from_millis(50);
+        let service = RetryBackoffLayer::new(10, 0, 10_000).with_jitter(jitter).layer(transport);
+        for _ in 0..100 {
+            assert!(service.jitter() <= jitter);
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limited_requests_within_budget() {
+        let asserter = crate::mock::Asserter::new();
+        let rate_limited = alloy_json_rpc::ErrorPayload {
+            code: -32005,
+            message: "limit exceeded".into(),
+            data: None,
+        };
+        let mut service = RetryBackoffLayer::new(3, 0, 10_000)
+            .with_method_max_retries("eth_sendRawTransaction", 1)
+            .with_jitter(Duration::from_millis(1))
+            .layer(crate::mock::MockTransport::new(asserter.clone()));
+
+        // retried once, then succeeds
+        asserter.push_failure(rate_limited.clone());
+        asserter.push_success(&"0x1");
+        let response = service.call(request(&["eth_sendRawTransaction"])).await.unwrap();
+        assert!(response.is_success());
+
+        // the budget of the method is exhausted
+        asserter.push_failure(rate_limited.clone());
+        asserter.push_failure(rate_limited);
+        assert!(service.call(request(&["eth_sendRawTransaction"])).await.is_err());
+        assert!(asserter.read_q().is_empty());
+    }
 }
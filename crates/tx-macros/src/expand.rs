@@ -1,4 +1,4 @@
-use crate::parse::{GroupedVariants, VariantKind};
+use crate::parse::{GroupedVariants, TypedDecodeCheck, VariantKind};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{Ident, Path};
@@ -31,6 +31,8 @@ pub(crate) struct Expander {
     pub(crate) variants: GroupedVariants,
     /// Optional typed transaction enum name.
     pub(crate) typed: Option<Ident>,
+    /// Optional check run before decoding a typed transaction.
+    pub(crate) typed_decode_check: Option<TypedDecodeCheck>,
 }
 
 impl Expander {
@@ -455,6 +457,11 @@ impl Expander {
                 fn authorization_list(&self) -> Option<&[#alloy_eips::eip7702::SignedAuthorization]> {
                     match self { #(Self::#variant_names(tx) => tx.authorization_list(),)* }
                 }
+
+                #[inline]
+                fn system_sender(&self) -> Option<#alloy_primitives::Address> {
+                    match self { #(Self::#variant_names(tx) => tx.system_sender(),)* }
+                }
             }
         }
     }
@@ -572,12 +579,21 @@ impl Expander {
 
         let variant_types = self.variants.variant_types();
 
+        let (check, check_bounds) = match &self.typed_decode_check {
+            Some(TypedDecodeCheck { path, bound }) => {
+                (quote! { #path(ty)?; }, quote! { #(#bound,)* })
+            }
+            None => (quote! {}, quote! {}),
+        };
+
         quote! {
             impl #impl_generics #alloy_eips::Decodable2718 for #input_type_name #ty_generics
             where
+                #check_bounds
                 #(#variant_types: #alloy_eips::Decodable2718),*
             {
                 fn typed_decode(ty: u8, buf: &mut &[u8]) -> #alloy_eips::eip2718::Eip2718Result<Self> {
+                    #check
                     match ty.try_into().map_err(|_| #alloy_rlp::Error::Custom("unexpected tx type"))? {
                         #(#typed_decode_arms,)*
                     }
@@ -592,6 +608,7 @@ impl Expander {
 
             impl #impl_generics #alloy_rlp::Decodable for #input_type_name #ty_generics
             where
+                #check_bounds
                 #(#variant_types: #alloy_eips::Decodable2718),*
             {
                 fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
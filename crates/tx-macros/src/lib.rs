@@ -28,6 +28,9 @@ use syn::{parse_macro_input, parse_quote, DeriveInput, Error, Ident};
 /// - `#[envelope(alloy_consensus = path::to::alloy)]` - Custom path to alloy_consensus crate
 /// - `#[envelope(typed = MyTypedTransaction)]` - Generate a corresponding TypedTransaction enum
 ///   (optional)
+/// - `#[envelope(typed_decode_check(path = Self::check, bound = "T: Bound"))]` - Call `check(ty)`
+///   before decoding a typed transaction, e.g. to reject a type in one instantiation of a generic
+///   envelope (optional)
 ///
 /// # Variant Attributes
 /// - Each variant must be annotated with `envelope` attribute with one of the following options:
@@ -72,6 +75,7 @@ fn expand_transaction_envelope(input: syn::DeriveInput) -> Result<proc_macro2::T
         args.alloy_consensus.clone().unwrap_or_else(|| parse_quote!(::alloy_consensus));
     let generics = args.generics.clone();
     let typed = args.typed.clone();
+    let typed_decode_check = args.typed_decode_check.clone();
     let serde_cfg = match args.serde_cfg.as_ref() {
         Some(syn::Meta::List(list)) => list.tokens.clone(),
         Some(_) => {
@@ -116,6 +120,7 @@ fn expand_transaction_envelope(input: syn::DeriveInput) -> Result<proc_macro2::T
         alloy_rlp,
         variants,
         typed,
+        typed_decode_check,
     };
     Ok(expander.expand())
 }
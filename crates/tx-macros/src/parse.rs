@@ -36,10 +36,25 @@ pub(crate) struct EnvelopeArgs {
     #[darling(default)]
     pub typed: Option<Ident>,
 
+    /// Optional check run with the type of a typed transaction before it is decoded.
+    #[darling(default)]
+    pub typed_decode_check: Option<TypedDecodeCheck>,
+
     /// The enum data (variants).
     pub data: darling::ast::Data<EnvelopeVariant, ()>,
 }
 
+/// Arguments of the `typed_decode_check` container attribute.
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct TypedDecodeCheck {
+    /// Path to a `fn(u8) -> Eip2718Result<()>` called with the transaction type.
+    pub path: Path,
+
+    /// Additional where predicates required by the check.
+    #[darling(default)]
+    pub bound: Vec<syn::WherePredicate>,
+}
+
 /// Variant of transaction envelope enum.
 #[derive(Debug, FromVariant)]
 #[darling(attributes(envelope), forward_attrs(serde, doc))]